@@ -1,5 +1,8 @@
+use std::fs::File;
+use std::io::BufReader;
 use std::ops::RangeInclusive;
 use std::path::Path;
+use std::str::FromStr;
 use std::{env, fs};
 
 use proc_macro2::{Ident, Literal, TokenStream};
@@ -7,6 +10,27 @@
 
 const SISO_REF_FILE: &str = "./enumerations/SISO-REF-010.xml";
 
+/// Environment variable to generate the code from another SISO-REF-010 XML file than `SISO_REF_FILE`,
+/// for instance a specific revision. Relative paths are resolved from the `dis-rs` package directory.
+const SISO_REF_FILE_ENV: &str = "DIS_RS_SISO_REF_FILE";
+
+/// Environment variable pointing to an overlay XML file, having the same structure as SISO-REF-010,
+/// that is merged into the source file (e.g., national extensions).
+/// Enum rows with the same value, bitfields with the same uid, and entity types in the overlay replace those of the source file.
+/// Other enums, rows, bitfields and entity types in the overlay are added.
+const SISO_REF_OVERLAY_ENV: &str = "DIS_RS_SISO_REF_OVERLAY";
+
+/// Environment variable with a comma-separated list of uids of enums to generate in addition to `ENUM_UIDS`.
+/// An entry is either a uid (`"600"`), or a uid with a name for the resulting enum (`"600=MyEnum"`).
+const EXTRA_ENUM_UIDS_ENV: &str = "DIS_RS_EXTRA_ENUM_UIDS";
+
+/// Environment variable set by cargo when the `entity-types` feature is enabled,
+/// selecting the generation of the entity type catalogue.
+const ENTITY_TYPES_FEATURE_ENV: &str = "CARGO_FEATURE_ENTITY_TYPES";
+
+/// Attribute of the root element of the XML file holding the release of the file.
+const RELEASE_ATTRIBUTE: &str = "release";
+
 /// Array containing all the uids of enumerations that should be generated.
 /// Each entry is a tuple containing:
 /// - the uid,
@@ -22,16 +46,22 @@
 ///
 /// Finally, some enums have variants that result in empty names (`""`) or duplicate names (such as 'Emitter Name').
 /// The bool flag will append `"_value"` to the name of the variant to make it unique
-const ENUM_UIDS: [(usize, Option<&str>, Option<usize>, bool); 152] = [
+const ENUM_UIDS: [(usize, Option<&str>, Option<usize>, bool); 173] = [
     (3, Some("ProtocolVersion"), None, false), // Protocol Version
     (4, Some("PduType"), None, false),         // PDU Type
     (5, Some("ProtocolFamily"), None, false),  // PDU Family
     (6, Some("ForceId"), None, false),         // Force Id
     (7, None, None, false),                    // Entity Kind
     (8, None, None, false),                    // Domain
-    // 9-28 // (Sub-)Categories
+    (9, Some("PlatformLandCategory"), None, false), // Land Domain Categories
+    (10, Some("PlatformAirCategory"), None, false), // Air Domain Categories
+    (11, Some("PlatformSurfaceCategory"), None, false), // Surface Domain Categories
+    (12, Some("PlatformSubsurfaceCategory"), None, false), // Subsurface Domain Categories
+    (13, Some("PlatformSpaceCategory"), None, false), // Space Domain Categories
+    (15, Some("MunitionCategory"), None, false), // Munition Category
+    // 14, 16-28 // Other (sub-)categories, available through the entity type catalogue
     (29, None, None, false), // Country
-    // 30 // Entity Types records
+    // 30 // Entity Types records, see `ENTITY_TYPES_UID`
     // 31-43 // Bitfields, see `BITFIELD_UIDS`
     (44, None, None, false), // Dead Reckoning Algorithm
     (45, None, None, false), // Entity Marking Character Set
@@ -108,13 +138,28 @@
     (212, Some("StationName"), None, false), // IsPartOf-Station Name
     (213, None, None, false),                // IsGroupOf-Grouped Entity Category
     (224, None, None, true),                 // Transfer Control-Transfer Type
+    (245, Some("GriddedDataCoordinateSystem"), None, false), // Gridded Data-Coordinate System
+    (246, Some("GriddedDataConstantGrid"), None, false), // Gridded Data-Constant Grid
+    (247, Some("GriddedDataSampleType"), None, false), // Gridded Data-Sample Type
+    (248, Some("EnvironmentalProcessModelType"), None, false), // Environmental Process-Model Type
+    (250, Some("EnvironmentRecordType"), None, false), // Environmental Process-Record Type
     (270, None, Some(16), false),            // Signal Encoding Class
     (271, None, Some(16), true),             // Signal Encoding Type
     (281, Some("APAStatus"), None, false),   // APA Parameter Index-APA Status
     (282, Some("SeparationReasonForSeparation"), None, false), // Separation VP-Reason for Separation
     (283, Some("SeparationPreEntityIndicator"), None, false),  // Separation VP-Pre-Entity Indicator
-    (295, Some("AttributeActionCode"), None, false),           // Attribute Action Code
-    (296, Some("DrParametersType"), None, false),              // Dead Reckoning Parameters Type
+    (285, Some("IOWarfareType"), None, false),                 // IO Action-IO Warfare Type
+    (286, Some("IOSimulationSource"), None, false),            // IO Action-IO Simulation Source
+    (287, Some("IOActionType"), None, false),                  // IO Action-IO Action Type
+    (288, Some("IOActionPhase"), None, false),                 // IO Action-IO Action Phase
+    (289, Some("IOReportType"), None, false),                  // IO Report-IO Report Type
+    (290, Some("IOEffectStatus"), None, false),                // IO Effects Record-IO Status
+    (291, Some("IOEffectLinkType"), None, false),              // IO Effects Record-IO Link Type
+    (292, Some("IOEffectType"), None, false),                  // IO Effects Record-IO Effect
+    (293, Some("IOEffectProcess"), None, false),               // IO Effects Record-IO Process
+    (294, Some("IOCommsNodeType"), None, false), // IO Comms Node Record-Comms Node Type
+    (295, Some("AttributeActionCode"), None, false), // Attribute Action Code
+    (296, Some("DrParametersType"), None, false), // Dead Reckoning Parameters Type
     (301, Some("TransferredEntityIndicator"), None, false), // DIS-PDU Status-Transferred Entity Indicator (TEI)
     (302, Some("LvcIndicator"), None, false),               // DIS-PDU Status-LVC Indicator (LVC)
     (303, Some("CoupledExtensionIndicator"), None, false), // DIS-PDU Status-Coupled Extension Indicator (CEI)
@@ -194,13 +239,29 @@
     (889, None, None, false), // Damage Area
 ];
 
-const BITFIELD_UIDS: [RangeInclusive<usize>; 3] = [
+const BITFIELD_UIDS: [RangeInclusive<usize>; 6] = [
     450..=462, // Capabilities
-    // 483..=487, // Point Object Appearances
-    // 488..=489, // Linear Object Appearances
-    31..=43, // Appearances
-    68..=68, // StopFreeze Frozen Behavior
-             // 149..=149, // UA-Propulsion Plant Configuration -- does not compile as of yet
+    483..=487, // Point Object Appearances
+    488..=489, // Linear Object Appearances
+    31..=43,   // Appearances
+    68..=68,   // StopFreeze Frozen Behavior
+    149..=149, // UA-Propulsion Plant Configuration
+];
+
+/// Some bitfields have a name that clashes with a generated enum, such as the
+/// 'UA-Propulsion Plant Configuration' bitfield (149) and the `UAPropulsionPlantConfiguration` enum (335).
+/// The names of the Point and Linear Object Appearances are fixed, because the
+/// `PointObjectAppearance` and `LinearObjectAppearance` models refer to them.
+/// Each entry overrides the name of the bitfield with the given uid.
+const BITFIELD_NAME_OVERRIDES: [(usize, &str); 8] = [
+    (149, "PropulsionPlantConfiguration"),
+    (483, "CraterAppearance"),
+    (484, "RibbonBridgeAppearance"),
+    (485, "AirBurstAppearance"),
+    (486, "GroundBurstAppearance"),
+    (487, "ExhaustSmokeAppearance"),
+    (488, "TankDitchAppearance"),
+    (489, "LaneMarkerAppearance"),
 ];
 
 /// Some enums cross-reference "record" elements.
@@ -211,6 +272,10 @@
 /// from the XML structure will be changed to a regular `EnumItem::Basic`
 const SKIP_XREF_UIDS: [usize; 1] = [220];
 
+/// UID of the 'Entity Types' records (the `cet` element), from which the entity type catalogue is generated.
+/// The catalogue holds an entry for each category, subcategory, specific and extra in the records.
+const ENTITY_TYPES_UID: usize = 30;
+
 #[derive(Debug, Clone)]
 pub enum GenerationItem {
     Enum(Enum),
@@ -298,23 +363,186 @@ pub struct BitfieldItem {
     pub xref: Option<usize>,
 }
 
+#[derive(Debug, Clone)]
+pub struct EntityTypeItem {
+    pub kind: usize,
+    pub domain: usize,
+    pub country: usize,
+    pub category: usize,
+    pub subcategory: usize,
+    pub specific: usize,
+    pub extra: usize,
+    pub description: String,
+}
+
+impl EnumItem {
+    #[must_use]
+    pub fn value(&self) -> usize {
+        match self {
+            EnumItem::Basic(item) => item.value,
+            EnumItem::Range(item) => *item.range.start(),
+            EnumItem::CrossRef(item) => item.value,
+        }
+    }
+}
+
+impl EntityTypeItem {
+    #[must_use]
+    pub fn key(&self) -> (usize, usize, usize, usize, usize, usize, usize) {
+        (
+            self.kind,
+            self.domain,
+            self.country,
+            self.category,
+            self.subcategory,
+            self.specific,
+            self.extra,
+        )
+    }
+}
+
+/// The contents extracted from a SISO-REF-010 (or overlay) XML file.
+struct Source {
+    release: Option<String>,
+    items: Vec<GenerationItem>,
+    entity_types: Vec<EntityTypeItem>,
+}
+
 fn main() {
-    let mut reader = Reader::from_file(Path::new(SISO_REF_FILE)).unwrap();
-    reader.config_mut().trim_text(true);
+    println!("cargo:rerun-if-changed=build.rs");
+    for variable in [SISO_REF_FILE_ENV, SISO_REF_OVERLAY_ENV, EXTRA_ENUM_UIDS_ENV] {
+        println!("cargo:rerun-if-env-changed={variable}");
+    }
+
+    let source_file = env::var(SISO_REF_FILE_ENV).unwrap_or_else(|_| String::from(SISO_REF_FILE));
+    let overlay_file = env::var(SISO_REF_OVERLAY_ENV).ok();
+    let extra_enum_uids = parse_extra_enum_uids(&env::var(EXTRA_ENUM_UIDS_ENV).unwrap_or_default());
+    let with_entity_types = env::var_os(ENTITY_TYPES_FEATURE_ENV).is_some();
+    let enum_uids: Vec<(usize, Option<&str>, Option<usize>, bool)> = ENUM_UIDS
+        .iter()
+        .copied()
+        .chain(
+            extra_enum_uids
+                .iter()
+                .map(|(uid, name)| (*uid, name.as_deref(), None, false)),
+        )
+        .collect();
 
-    // Extract enums and bitfields from the source file
-    let generation_items = extraction::extract(&mut reader);
+    // Extract enums, bitfields and entity types from the source file, and merge the overlay file
+    let mut source = extract_source(&source_file, &enum_uids, with_entity_types);
+    let overlay = overlay_file
+        .map(|overlay_file| extract_source(&overlay_file, &enum_uids, with_entity_types));
+    if let Some(overlay) = &overlay {
+        merge_overlay(&mut source.items, &overlay.items);
+        source
+            .entity_types
+            .splice(0..0, overlay.entity_types.iter().cloned());
+    }
+    sort_entity_types(&mut source.entity_types);
 
     // Generate all code for enums
-    let generated = generation::generate(&generation_items);
+    let generated = generation::generate(
+        &source.items,
+        source.release.as_deref().unwrap_or("unknown"),
+        overlay
+            .as_ref()
+            .map(|overlay| overlay.release.as_deref().unwrap_or("unknown")),
+    );
+    write_generated(&generated, "enumerations.rs");
+
+    if with_entity_types {
+        let generated = generation::generate_entity_types(&source.entity_types);
+        write_generated(&generated, "entity_types.rs");
+    }
+}
+
+fn open_reader(path: &str) -> Reader<BufReader<File>> {
+    let mut reader = Reader::from_file(Path::new(path))
+        .unwrap_or_else(|error| panic!("Cannot open SISO-REF-010 file '{path}': {error}"));
+    reader.config_mut().trim_text(true);
+    reader
+}
+
+/// Extracts all contents from the file, reading the file in a separate pass for the release, enums and bitfields, and entity types.
+/// The entity types are only extracted when `with_entity_types` is set.
+fn extract_source(
+    path: &str,
+    enum_uids: &[(usize, Option<&str>, Option<usize>, bool)],
+    with_entity_types: bool,
+) -> Source {
+    println!("cargo:rerun-if-changed={path}");
+    Source {
+        release: extraction::extract_root_attribute(&mut open_reader(path), RELEASE_ATTRIBUTE),
+        items: extraction::extract(&mut open_reader(path), enum_uids),
+        entity_types: if with_entity_types {
+            extraction::extract_entity_types(&mut open_reader(path))
+        } else {
+            Vec::new()
+        },
+    }
+}
+
+fn parse_extra_enum_uids(value: &str) -> Vec<(usize, Option<String>)> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let (uid, name) = match entry.split_once('=') {
+                Some((uid, name)) => (uid.trim(), Some(name.trim().to_string())),
+                None => (entry, None),
+            };
+            let uid = usize::from_str(uid)
+                .unwrap_or_else(|_| panic!("Invalid uid '{uid}' in {EXTRA_ENUM_UIDS_ENV}"));
+            (uid, name)
+        })
+        .collect()
+}
+
+/// Merges the enums and bitfields of an overlay into those of the source.
+/// Rows of an enum present in both are merged by value, other items of the overlay replace or are added to the source.
+fn merge_overlay(items: &mut Vec<GenerationItem>, overlay: &[GenerationItem]) {
+    for overlay_item in overlay {
+        match (
+            items
+                .iter_mut()
+                .find(|item| item.uid() == overlay_item.uid()),
+            overlay_item,
+        ) {
+            (Some(GenerationItem::Enum(existing)), GenerationItem::Enum(overlay_enum)) => {
+                for row in &overlay_enum.items {
+                    if let Some(existing_row) = existing
+                        .items
+                        .iter_mut()
+                        .find(|existing_row| existing_row.value() == row.value())
+                    {
+                        *existing_row = row.clone();
+                    } else {
+                        existing.items.push(row.clone());
+                    }
+                }
+            }
+            (Some(existing), overlay_item) => *existing = overlay_item.clone(),
+            (None, overlay_item) => items.push(overlay_item.clone()),
+        }
+    }
+}
 
+/// Sorts the entity types for lookup using binary search.
+/// When an entity type is listed multiple times, the first occurrence is kept.
+fn sort_entity_types(items: &mut Vec<EntityTypeItem>) {
+    items.sort_by_key(EntityTypeItem::key);
+    items.dedup_by_key(|item| item.key());
+}
+
+fn write_generated(generated: &TokenStream, file_name: &str) {
     // format generated code using prettyplease
     let ast = syn::parse_file(&generated.to_string())
         .expect("Error parsing generated code for pretty printing.");
     let contents = prettyplease::unparse(&ast);
 
     // Save to file
-    let dest_path = Path::new(&env::var("OUT_DIR").unwrap()).join("enumerations.rs");
+    let dest_path = Path::new(&env::var("OUT_DIR").unwrap()).join(file_name);
     fs::write(dest_path, contents).unwrap();
 }
 
@@ -375,7 +603,8 @@ fn format_name(value: &str, uid: usize) -> String {
 
 fn format_field_name(name: &str) -> String {
     #[allow(clippy::collapsible_str_replace)]
-    name.to_lowercase()
+    let field_name = name
+        .to_lowercase()
         .replace(" / ", "_")
         .replace(' ', "_")
         .replace('-', "")
@@ -384,17 +613,26 @@ fn format_field_name(name: &str) -> String {
         .replace('(', "")
         .replace(')', "")
         .replace('=', "_")
-        .replace('\'', "")
+        .replace('\'', "");
+
+    // Field names such as 'Type' are keywords in Rust, use a raw identifier for these
+    if syn::parse_str::<syn::Ident>(&field_name).is_err() {
+        format!("r#{field_name}")
+    } else {
+        field_name
+    }
 }
 
 mod extraction {
     use crate::{
-        BasicEnumItem, Bitfield, BitfieldItem, CrossRefEnumItem, Enum, EnumItem, GenerationItem,
-        RangeEnumItem, BITFIELD_UIDS, ENUM_UIDS, SKIP_XREF_UIDS,
+        format_field_name, BasicEnumItem, Bitfield, BitfieldItem, CrossRefEnumItem, EntityTypeItem,
+        Enum, EnumItem, GenerationItem, RangeEnumItem, BITFIELD_NAME_OVERRIDES, BITFIELD_UIDS,
+        ENTITY_TYPES_UID, SKIP_XREF_UIDS,
     };
     use quick_xml::events::{BytesStart, Event};
     use quick_xml::name::QName;
     use quick_xml::Reader;
+    use std::collections::HashMap;
     use std::fs::File;
     use std::io::BufReader;
     use std::ops::RangeInclusive;
@@ -418,9 +656,23 @@ mod extraction {
     const BITFIELD_ROW_ATTR_BIT_POSITION: QName = QName(b"bit_position");
     const BITFIELD_ROW_ATTR_LENGTH: QName = QName(b"length");
     const BITFIELD_ROW_ATTR_XREF: QName = QName(b"xref");
+    const ENTITY_TYPES_ELEMENT: QName = QName(b"cet");
+    const ENTITY_ELEMENT: QName = QName(b"entity");
+    const ENTITY_ATTR_KIND: QName = QName(b"kind");
+    const ENTITY_ATTR_DOMAIN: QName = QName(b"domain");
+    const ENTITY_ATTR_COUNTRY: QName = QName(b"country");
+    const CATEGORY_ELEMENT: &[u8] = b"category";
+    const SUBCATEGORY_ELEMENT: &[u8] = b"subcategory";
+    const SPECIFIC_ELEMENT: &[u8] = b"specific";
+    const EXTRA_ELEMENT: &[u8] = b"extra";
+    const RANGE_ELEMENT_SUFFIX: &[u8] = b"_range";
+    const XREF_ELEMENT_SUFFIX: &[u8] = b"_xref";
 
     #[allow(clippy::too_many_lines)]
-    pub fn extract(reader: &mut Reader<BufReader<File>>) -> Vec<GenerationItem> {
+    pub fn extract(
+        reader: &mut Reader<BufReader<File>>,
+        enum_uids: &[(usize, Option<&str>, Option<usize>, bool)],
+    ) -> Vec<GenerationItem> {
         let mut buf = Vec::new();
         let mut items = Vec::new();
         let mut current_item = None;
@@ -430,11 +682,12 @@ pub fn extract(reader: &mut Reader<BufReader<File>>) -> Vec<GenerationItem> {
             match reader.read_event_into(&mut buf) {
                 Ok(Event::Start(ref element)) => match element.name() {
                     ENUM_ELEMENT => {
-                        current_item = if let Ok(extracted) = extract_enum(element, reader) {
-                            Some(GenerationItem::Enum(extracted))
-                        } else {
-                            None
-                        }
+                        current_item =
+                            if let Ok(extracted) = extract_enum(element, reader, enum_uids) {
+                                Some(GenerationItem::Enum(extracted))
+                            } else {
+                                None
+                            }
                     }
                     ENUM_ROW_ELEMENT => {
                         current_item = if let (Some(GenerationItem::Enum(mut current)), Ok(item)) =
@@ -468,7 +721,7 @@ pub fn extract(reader: &mut Reader<BufReader<File>>) -> Vec<GenerationItem> {
                             if let (Some(GenerationItem::Bitfield(mut current)), Ok(item)) =
                                 (current_item, extract_bitfield_item(element, reader))
                             {
-                                current.fields.push(item);
+                                push_bitfield_item(&mut current, item);
                                 Some(GenerationItem::Bitfield(current))
                             } else {
                                 None
@@ -514,7 +767,7 @@ pub fn extract(reader: &mut Reader<BufReader<File>>) -> Vec<GenerationItem> {
                             if let (Some(GenerationItem::Bitfield(mut current)), Ok(item)) =
                                 (current_item, extract_bitfield_item(element, reader))
                             {
-                                current.fields.push(item);
+                                push_bitfield_item(&mut current, item);
                                 Some(GenerationItem::Bitfield(current))
                             } else {
                                 None
@@ -530,13 +783,248 @@ pub fn extract(reader: &mut Reader<BufReader<File>>) -> Vec<GenerationItem> {
         items
     }
 
-    fn extract_enum(element: &BytesStart, reader: &Reader<BufReader<File>>) -> Result<Enum, ()> {
+    /// Extracts the value of an attribute of the root element of the file, such as the release.
+    pub fn extract_root_attribute(
+        reader: &mut Reader<BufReader<File>>,
+        attribute: &str,
+    ) -> Option<String> {
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref element) | Event::Empty(ref element)) => {
+                    return element
+                        .try_get_attribute(attribute)
+                        .ok()
+                        .flatten()
+                        .and_then(|attr| attr.decode_and_unescape_value(reader.decoder()).ok())
+                        .map(|value| value.to_string());
+                }
+                Ok(Event::Eof) | Err(_) => return None,
+                _ => (), // skip the declaration, comments, etc.
+            }
+            buf.clear();
+        }
+    }
+
+    /// Extracts the entity type records (`ENTITY_TYPES_UID`), flattening the tree of
+    /// entity, category, subcategory, specific and extra elements into an item per element.
+    ///
+    /// The rows of all enums are collected as well, to resolve elements that refer to the rows of
+    /// another enum (e.g., `subcategory_xref`), which precede the entity types in the file.
+    pub fn extract_entity_types(reader: &mut Reader<BufReader<File>>) -> Vec<EntityTypeItem> {
+        let mut buf = Vec::new();
+        let mut items = Vec::new();
+        let mut enum_rows: HashMap<usize, Vec<(usize, String)>> = HashMap::new();
+        let mut current_enum = None;
+        let mut in_entity_types = false;
+        let mut current = EntityTypeItem {
+            kind: 0,
+            domain: 0,
+            country: 0,
+            category: 0,
+            subcategory: 0,
+            specific: 0,
+            extra: 0,
+            description: String::new(),
+        };
+
+        loop {
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(ref element)) if element.name() == ENTITY_TYPES_ELEMENT => {
+                    in_entity_types = attribute_usize(element, reader, ELEMENT_ATTR_UID)
+                        == Some(ENTITY_TYPES_UID);
+                }
+                Ok(Event::End(ref element)) if element.name() == ENTITY_TYPES_ELEMENT => {
+                    in_entity_types = false;
+                }
+                Ok(Event::Start(ref element)) if element.name() == ENUM_ELEMENT => {
+                    current_enum = attribute_usize(element, reader, ELEMENT_ATTR_UID);
+                }
+                Ok(Event::End(ref element)) if element.name() == ENUM_ELEMENT => {
+                    current_enum = None;
+                }
+                Ok(Event::Start(ref element) | Event::Empty(ref element))
+                    if element.name() == ENUM_ROW_ELEMENT =>
+                {
+                    let value = attribute_usize(element, reader, ENUM_ROW_ATTR_VALUE);
+                    let description = attribute_string(element, reader, ENUM_ROW_ATTR_DESC);
+                    if let (Some(uid), Some(value), Some(description)) =
+                        (current_enum, value, description)
+                    {
+                        enum_rows.entry(uid).or_default().push((value, description));
+                    }
+                }
+                Ok(Event::Start(ref element) | Event::Empty(ref element)) if in_entity_types => {
+                    extract_entity_type_item(element, reader, &enum_rows, &mut current, &mut items);
+                }
+                Ok(Event::Eof) => break, // exits the loop when reaching end of file
+                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                _ => (), // There are several other `Event`s we do not consider here
+            }
+            buf.clear();
+        }
+
+        items
+    }
+
+    fn extract_entity_type_item(
+        element: &BytesStart,
+        reader: &Reader<BufReader<File>>,
+        enum_rows: &HashMap<usize, Vec<(usize, String)>>,
+        current: &mut EntityTypeItem,
+        items: &mut Vec<EntityTypeItem>,
+    ) {
+        if element.name() == ENTITY_ELEMENT {
+            current.kind = attribute_usize(element, reader, ENTITY_ATTR_KIND).unwrap_or_default();
+            current.domain =
+                attribute_usize(element, reader, ENTITY_ATTR_DOMAIN).unwrap_or_default();
+            current.country =
+                attribute_usize(element, reader, ENTITY_ATTR_COUNTRY).unwrap_or_default();
+            current.category = 0;
+            current.subcategory = 0;
+            current.specific = 0;
+            current.extra = 0;
+            return;
+        }
+
+        let element_name = element.name();
+        if let Some(level) = element_name.as_ref().strip_suffix(XREF_ELEMENT_SUFFIX) {
+            extract_entity_type_xref(level, element, reader, enum_rows, current, items);
+            return;
+        }
+        let (level, values) =
+            if let Some(level) = element_name.as_ref().strip_suffix(RANGE_ELEMENT_SUFFIX) {
+                let value_min = attribute_usize(element, reader, ENUM_ROW_ATTR_VALUE_MIN);
+                let value_max = attribute_usize(element, reader, ENUM_ROW_ATTR_VALUE_MAX);
+                let (Some(value_min), Some(value_max)) = (value_min, value_max) else {
+                    // something is wrong with the attributes of the element, skip it.
+                    return;
+                };
+                (level, RangeInclusive::new(value_min, value_max))
+            } else {
+                let Some(value) = attribute_usize(element, reader, ENUM_ROW_ATTR_VALUE) else {
+                    return;
+                };
+                (element_name.as_ref(), RangeInclusive::new(value, value))
+            };
+        let Some(description) = attribute_string(element, reader, ENUM_ROW_ATTR_DESC) else {
+            return;
+        };
+
+        for value in values {
+            if !set_entity_type_level(current, level, value) {
+                return;
+            }
+            items.push(EntityTypeItem {
+                description: description.clone(),
+                ..current.clone()
+            });
+        }
+    }
+
+    /// Extracts the records of an element that refers to the rows of another enum, such as the
+    /// subcategories of munitions. An element with its own value and description is a single record,
+    /// otherwise each row of the referred enum is a record at the level of the element.
+    fn extract_entity_type_xref(
+        level: &[u8],
+        element: &BytesStart,
+        reader: &Reader<BufReader<File>>,
+        enum_rows: &HashMap<usize, Vec<(usize, String)>>,
+        current: &mut EntityTypeItem,
+        items: &mut Vec<EntityTypeItem>,
+    ) {
+        let value = attribute_usize(element, reader, ENUM_ROW_ATTR_VALUE);
+        let description = attribute_string(element, reader, ENUM_ROW_ATTR_DESC);
+        let xref = attribute_usize(element, reader, ENUM_ROW_ATTR_XREF);
+        let rows = match (value, description, xref) {
+            (Some(value), Some(description), _) => vec![(value, description)],
+            (_, _, Some(xref)) if enum_rows.contains_key(&xref) => enum_rows[&xref].clone(),
+            _ => {
+                println!(
+                    "cargo:warning=Skipped entity type element '{}' of {}:{}:{}, its xref cannot be resolved",
+                    String::from_utf8_lossy(element.name().as_ref()),
+                    current.kind,
+                    current.domain,
+                    current.country
+                );
+                return;
+            }
+        };
+
+        for (value, description) in rows {
+            if !set_entity_type_level(current, level, value) {
+                return;
+            }
+            items.push(EntityTypeItem {
+                description,
+                ..current.clone()
+            });
+        }
+    }
+
+    /// Sets the field of `current` for the level (element name) to `value`, resetting the lower levels.
+    /// Returns `false` when the element is not a level of the entity type tree.
+    fn set_entity_type_level(current: &mut EntityTypeItem, level: &[u8], value: usize) -> bool {
+        match level {
+            CATEGORY_ELEMENT => {
+                current.category = value;
+                current.subcategory = 0;
+                current.specific = 0;
+                current.extra = 0;
+            }
+            SUBCATEGORY_ELEMENT => {
+                current.subcategory = value;
+                current.specific = 0;
+                current.extra = 0;
+            }
+            SPECIFIC_ELEMENT => {
+                current.specific = value;
+                current.extra = 0;
+            }
+            EXTRA_ELEMENT => {
+                current.extra = value;
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn attribute_string(
+        element: &BytesStart,
+        reader: &Reader<BufReader<File>>,
+        attribute: QName,
+    ) -> Option<String> {
+        element
+            .try_get_attribute(attribute)
+            .ok()
+            .flatten()
+            .and_then(|attr| attr.decode_and_unescape_value(reader.decoder()).ok())
+            .map(|value| value.to_string())
+    }
+
+    fn attribute_usize(
+        element: &BytesStart,
+        reader: &Reader<BufReader<File>>,
+        attribute: QName,
+    ) -> Option<usize> {
+        if let Ok(Some(attr_value)) = element.try_get_attribute(attribute) {
+            usize::from_str(&reader.decoder().decode(&attr_value.value).unwrap()).ok()
+        } else {
+            None
+        }
+    }
+
+    fn extract_enum(
+        element: &BytesStart,
+        reader: &Reader<BufReader<File>>,
+        enum_uids: &[(usize, Option<&str>, Option<usize>, bool)],
+    ) -> Result<Enum, ()> {
         let uid = if let Ok(Some(attr_uid)) = element.try_get_attribute(ELEMENT_ATTR_UID) {
             Some(usize::from_str(&reader.decoder().decode(&attr_uid.value).unwrap()).unwrap())
         } else {
             None
         };
-        let should_generate = ENUM_UIDS.iter().find(|&&tuple| tuple.0 == uid.unwrap());
+        let should_generate = enum_uids.iter().find(|&&tuple| tuple.0 == uid.unwrap());
 
         if let Some(should_generate) = should_generate {
             let name_override = should_generate.1;
@@ -692,8 +1180,12 @@ fn extract_bitfield(
             }
         }
 
+        let name_override = BITFIELD_NAME_OVERRIDES
+            .iter()
+            .find(|(override_uid, _)| Some(*override_uid) == uid)
+            .map(|(_, name)| (*name).to_string());
         let name = if let Ok(Some(attr_name)) = element.try_get_attribute(ELEMENT_ATTR_NAME) {
-            Some(String::from_utf8(attr_name.value.to_vec()).unwrap())
+            name_override.or_else(|| Some(String::from_utf8(attr_name.value.to_vec()).unwrap()))
         } else {
             None
         };
@@ -716,6 +1208,20 @@ fn extract_bitfield(
         }
     }
 
+    /// Adds the item to the bitfield, making the name of the item unique within the bitfield
+    /// by appending the bit position when needed.
+    fn push_bitfield_item(bitfield: &mut Bitfield, mut item: BitfieldItem) {
+        let field_name = format_field_name(&item.name);
+        if bitfield
+            .fields
+            .iter()
+            .any(|field| format_field_name(&field.name) == field_name)
+        {
+            item.name = format!("{} {}", item.name, item.bit_position);
+        }
+        bitfield.fields.push(item);
+    }
+
     fn extract_bitfield_item(
         element: &BytesStart,
         reader: &Reader<BufReader<File>>,
@@ -760,12 +1266,16 @@ fn extract_bitfield_item(
 
 mod generation {
     use crate::{
-        format_field_name, format_name, format_name_postfix, Bitfield, BitfieldItem, Enum,
-        EnumItem, GenerationItem, Ident, Literal, TokenStream,
+        format_field_name, format_name, format_name_postfix, Bitfield, BitfieldItem,
+        EntityTypeItem, Enum, EnumItem, GenerationItem, Ident, Literal, TokenStream,
     };
     use quote::{format_ident, quote};
 
-    pub fn generate(items: &Vec<GenerationItem>) -> TokenStream {
+    pub fn generate(
+        items: &Vec<GenerationItem>,
+        release: &str,
+        overlay_release: Option<&str>,
+    ) -> TokenStream {
         let mut generated_items = vec![];
 
         let lookup_xref = |xref: usize| items.iter().find(|&it| it.uid() == xref);
@@ -778,7 +1288,13 @@ pub fn generate(items: &Vec<GenerationItem>) -> TokenStream {
                 }
             }
         }
+        let overlay_release = if let Some(overlay_release) = overlay_release {
+            quote!(Some(#overlay_release))
+        } else {
+            quote!(None)
+        };
         quote!(
+            #[allow(clippy::cast_lossless)]
             #[allow(clippy::default_trait_access)]
             #[allow(clippy::identity_op)]
             #[allow(clippy::match_same_arms)]
@@ -786,18 +1302,76 @@ pub fn generate(items: &Vec<GenerationItem>) -> TokenStream {
             #[allow(clippy::struct_excessive_bools)]
             #[allow(clippy::too_many_lines)]
             #[allow(clippy::uninlined_format_args)]
+            #[allow(clippy::unnecessary_cast)]
             #[allow(clippy::unreadable_literal)]
             #[allow(clippy::write_literal)]
             pub mod enumerations {
-                use std::fmt::{Display, Formatter};
+                use core::fmt::{Display, Formatter};
                 #[cfg(feature = "serde")]
                 use serde::{Deserialize, Serialize};
 
+                /// The release of the SISO-REF-010 file from which the enumerations are generated.
+                pub const SISO_REF_010_RELEASE: &str = #release;
+                /// The release of the overlay file that is merged into the enumerations, if any.
+                pub const SISO_REF_010_OVERLAY_RELEASE: Option<&str> = #overlay_release;
+
                 #(#generated_items)*
             }
         )
     }
 
+    /// Generates the table of the entity type catalogue, which is included in the `entity_types` module.
+    /// The items must be sorted by entity type, as the catalogue uses binary search for lookups.
+    /// Items with values that do not fit the fields of an `EntityType` are skipped with a warning.
+    pub fn generate_entity_types(items: &[EntityTypeItem]) -> TokenStream {
+        let records: Vec<TokenStream> = items
+            .iter()
+            .filter_map(|item| {
+                let Some([kind, domain, country, category, subcategory, specific, extra]) =
+                    entity_type_literals(item)
+                else {
+                    println!(
+                        "cargo:warning=Skipped entity type '{}' of {}:{}:{}:{}:{}:{}:{}, a value is out of range",
+                        item.description,
+                        item.kind,
+                        item.domain,
+                        item.country,
+                        item.category,
+                        item.subcategory,
+                        item.specific,
+                        item.extra
+                    );
+                    return None;
+                };
+                let description = item.description.as_str();
+                Some(quote!(
+                    EntityTypeRecord::new(#kind, #domain, #country, #category, #subcategory, #specific, #extra, #description)
+                ))
+            })
+            .collect();
+        let count = Literal::usize_unsuffixed(records.len());
+        quote!(
+            /// The entity type records (UID 30) of SISO-REF-010, sorted by entity type.
+            static ENTITY_TYPE_RECORDS: [EntityTypeRecord; #count] = [
+                #(#records),*
+            ];
+        )
+    }
+
+    /// The literals of the fields of an entity type, or `None` when a value does not fit the size of its field.
+    fn entity_type_literals(item: &EntityTypeItem) -> Option<[Literal; 7]> {
+        let u8_literal = |value: usize| u8::try_from(value).ok().map(Literal::u8_suffixed);
+        Some([
+            u8_literal(item.kind)?,
+            u8_literal(item.domain)?,
+            Literal::u16_suffixed(u16::try_from(item.country).ok()?),
+            u8_literal(item.category)?,
+            u8_literal(item.subcategory)?,
+            u8_literal(item.specific)?,
+            u8_literal(item.extra)?,
+        ])
+    }
+
     fn generate_enum<'a, F>(item: &Enum, lookup_xref: F) -> TokenStream
     where
         F: Fn(usize) -> Option<&'a GenerationItem>,
@@ -1028,7 +1602,7 @@ fn quote_enum_display_impl(e: &Enum, name_ident: &Ident) -> TokenStream {
         let arms = quote_enum_display_arms(&e.items, name_ident, e.postfix_items);
         quote!(
             impl Display for #name_ident {
-                fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
                     match self {
                         #(#arms),*
                     }
@@ -1131,6 +1705,38 @@ pub struct #name_ident {
         )
     }
 
+    /// The type of a field in a generated bitfield struct.
+    enum BitfieldFieldType {
+        /// A single bit field, a `bool`.
+        Flag,
+        /// A multi-bit field without an enumeration, an unsigned integer that can hold the field.
+        Integer(&'static str),
+        /// A field referencing an enumeration, with the name of the enum and its underlying data type.
+        Enum {
+            name: String,
+            size_type: &'static str,
+        },
+    }
+
+    impl BitfieldFieldType {
+        fn of<'a, F>(field: &BitfieldItem, lookup_xref: F) -> Self
+        where
+            F: Fn(usize) -> Option<&'a GenerationItem>,
+        {
+            // A referenced enumeration that is not generated results in a plain integer field
+            if let Some(xref) = field.xref.and_then(lookup_xref) {
+                Self::Enum {
+                    name: format_name(xref.name(), xref.uid()),
+                    size_type: size_to_type(xref.size()),
+                }
+            } else if field.length == 1 {
+                Self::Flag
+            } else {
+                Self::Integer(length_to_type(field.length))
+            }
+        }
+    }
+
     fn quote_bitfield_decl_fields<'a, F>(
         fields: &[BitfieldItem],
         lookup_xref: F,
@@ -1143,11 +1749,10 @@ fn quote_bitfield_decl_fields<'a, F>(
             .map(|field| {
                 let field_name = format_field_name(field.name.as_str());
                 let field_ident = format_ident!("{}", field_name);
-                let type_literal = if let Some(xref_uid) = field.xref {
-                    let xref = lookup_xref(xref_uid).unwrap_or_else(|| panic!("{}", xref_uid));
-                    format_ident!("{}", format_name(xref.name(), xref.uid()))
-                } else {
-                    format_ident!("bool")
+                let type_literal = match BitfieldFieldType::of(field, &lookup_xref) {
+                    BitfieldFieldType::Flag => format_ident!("bool"),
+                    BitfieldFieldType::Integer(int_type) => format_ident!("{}", int_type),
+                    BitfieldFieldType::Enum { name, .. } => format_ident!("{}", name),
                 };
                 quote!(
                     pub #field_ident : #type_literal
@@ -1201,19 +1806,23 @@ fn quote_bitfield_from_fields<'a, F>(
             let position_shift_literal = Literal::usize_unsuffixed(data_size - field.length - field.bit_position);
             #[allow(clippy::cast_possible_truncation)]
             let bitmask = Literal::usize_unsuffixed(2usize.pow(field.length as u32) - 1);
-            if let Some(xref) = field.xref {
-                let xref = lookup_xref(xref).unwrap();
-                let xref_name = format_name(xref.name(), xref.uid());
-                let xref_ident = format_ident!("{}", xref_name);
-                let xref_data_size = size_to_type(xref.size());
-                let xref_size_ident = format_ident!("{}", xref_data_size);
-                quote!(
-                    let #field_ident = #xref_ident::from(((value >> #position_shift_literal) & #bitmask) as #xref_size_ident);
-                )
-            } else {
-                quote!(
+            match BitfieldFieldType::of(field, &lookup_xref) {
+                BitfieldFieldType::Flag => quote!(
                     let #field_ident = ((value >> #position_shift_literal) & #bitmask) != 0;
-                )
+                ),
+                BitfieldFieldType::Integer(int_type) => {
+                    let int_ident = format_ident!("{}", int_type);
+                    quote!(
+                        let #field_ident = ((value >> #position_shift_literal) & #bitmask) as #int_ident;
+                    )
+                }
+                BitfieldFieldType::Enum { name, size_type } => {
+                    let xref_ident = format_ident!("{}", name);
+                    let xref_size_ident = format_ident!("{}", size_type);
+                    quote!(
+                        let #field_ident = #xref_ident::from(((value >> #position_shift_literal) & #bitmask) as #xref_size_ident);
+                    )
+                }
             }
         }).collect()
     }
@@ -1262,16 +1871,22 @@ fn quote_bitfield_into_fields<'a, F>(
             let field_name = format_field_name(&field.name);
             let field_ident = format_ident!("{}", field_name);
             let position_shift_literal = data_size - field.length - field.bit_position;
-            if let Some(xref) = field.xref {
-                let xref_size_type = size_to_type(lookup_xref(xref).unwrap().size());
-                let xref_size_ident = format_ident!("{}", xref_size_type);
-                quote!(
-                    let #field_ident = #field_size_ident::from(#xref_size_ident::from(value.#field_ident)) << #position_shift_literal;
-                )
-            } else {
-                quote!(
+            #[allow(clippy::cast_possible_truncation)]
+            let bitmask = Literal::usize_unsuffixed(2usize.pow(field.length as u32) - 1);
+            match BitfieldFieldType::of(field, &lookup_xref) {
+                BitfieldFieldType::Flag => quote!(
                     let #field_ident = #field_size_ident::from( value.#field_ident) << #position_shift_literal;
-                )
+                ),
+                BitfieldFieldType::Integer(_) => quote!(
+                    let #field_ident = ((value.#field_ident as #field_size_ident) & #bitmask) << #position_shift_literal;
+                ),
+                BitfieldFieldType::Enum { size_type, .. } => {
+                    let xref_size_ident = format_ident!("{}", size_type);
+                    // The enum can be wider than the bitfield (e.g., a 16-bit enum in an 8-bit bitfield), hence the cast
+                    quote!(
+                        let #field_ident = ((#xref_size_ident::from(value.#field_ident) as #field_size_ident) & #bitmask) << #position_shift_literal;
+                    )
+                }
             }
         }).collect()
     }
@@ -1282,7 +1897,7 @@ fn quote_bitfield_display_impl(item: &Bitfield) -> TokenStream {
 
         quote!(
             impl Display for #name_ident {
-                fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
                     write!(f, #formatted_name)
                 }
             }
@@ -1300,6 +1915,16 @@ fn size_to_type(data_size: usize) -> &'static str {
         }
     }
 
+    /// Returns the smallest unsigned integer type that can hold a field of `length` bits.
+    fn length_to_type(length: usize) -> &'static str {
+        match length {
+            0..=8 => "u8",
+            9..=16 => "u16",
+            17..=32 => "u32",
+            _ => "u64",
+        }
+    }
+
     fn discriminant_literal(value: usize, data_size: usize) -> Literal {
         #[allow(clippy::match_same_arms)]
         #[allow(clippy::cast_possible_truncation)]
@@ -0,0 +1,145 @@
+//! Framing of DIS PDUs in a byte stream, such as a TCP connection or a file.
+//!
+//! Unlike UDP datagrams, a byte stream does not preserve the boundaries of PDUs:
+//! a read can contain a partial PDU, or multiple PDUs at once.
+//! The `DisCodec` buffers the incoming bytes and uses the PDU length field of the `PduHeader`
+//! to cut out complete PDUs, which are then parsed into `Pdu`s.
+//!
+//! The codec implements the `tokio_util::codec::{Decoder, Encoder}` traits,
+//! so it can be used with `Framed`, `FramedRead` and `FramedWrite` on sockets and (async) file readers.
+//! This module is available when the `codec` feature is enabled.
+use bytes::{Buf, BytesMut};
+use thiserror::Error;
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::common::errors::DisError;
+use crate::common::model::Pdu;
+use crate::common::parser::parse_pdu;
+use crate::constants::PDU_HEADER_LEN_BYTES;
+
+const PDU_LENGTH_OFFSET: usize = 8;
+
+/// Errors that can occur while decoding or encoding a stream of PDUs.
+#[derive(Debug, Error)]
+pub enum DisCodecError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Dis(#[from] DisError),
+    #[error("PDU length of {0} bytes in the header is invalid, stream is out of sync.")]
+    InvalidPduLength(u16), // the PDU length field is smaller than the header, so the next PDU cannot be located
+}
+
+/// Codec for reading and writing DIS PDUs from and to a byte stream.
+///
+/// When a PDU in the stream fails to parse, the decoder returns the `DisError` for that PDU.
+/// The bytes of the failed PDU are consumed, so calling `decode` again continues with the next PDU in the stream.
+/// Note that the `Framed` adapters of `tokio_util` end the stream after the first error.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct DisCodec;
+
+impl DisCodec {
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Decoder for DisCodec {
+    type Item = Pdu;
+    type Error = DisCodecError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        if src.len() < PDU_HEADER_LEN_BYTES as usize {
+            return Ok(None);
+        }
+
+        let pdu_length = (&src[PDU_LENGTH_OFFSET..]).get_u16();
+        if pdu_length < PDU_HEADER_LEN_BYTES {
+            return Err(DisCodecError::InvalidPduLength(pdu_length));
+        }
+
+        let pdu_length = pdu_length as usize;
+        if src.len() < pdu_length {
+            // wait for the rest of the PDU to arrive
+            src.reserve(pdu_length - src.len());
+            return Ok(None);
+        }
+
+        let frame = src.split_to(pdu_length);
+        let pdu = parse_pdu(&frame)?;
+        Ok(Some(pdu))
+    }
+}
+
+impl Encoder<Pdu> for DisCodec {
+    type Error = DisCodecError;
+
+    fn encode(&mut self, item: Pdu, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        self.encode(&item, dst)
+    }
+}
+
+impl Encoder<&Pdu> for DisCodec {
+    type Error = DisCodecError;
+
+    fn encode(&mut self, item: &Pdu, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        dst.reserve(item.pdu_length() as usize);
+        item.serialize(dst)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::model::{EntityId, PduHeader};
+    use crate::common::remove_entity::model::RemoveEntity;
+    use crate::enumerations::PduType;
+
+    fn remove_entity_pdu(entity_id: u16) -> Pdu {
+        let body = RemoveEntity::builder()
+            .with_origination_id(EntityId::new(1, 1, entity_id))
+            .with_request_id(entity_id.into())
+            .build()
+            .into_pdu_body();
+        Pdu::finalize_from_parts(PduHeader::new_v6(1, PduType::RemoveEntity), body, 0)
+    }
+
+    #[test]
+    fn decode_split_and_coalesced_pdus() {
+        let first = remove_entity_pdu(1);
+        let second = remove_entity_pdu(2);
+        let mut bytes = BytesMut::new();
+        let mut codec = DisCodec::new();
+        codec.encode(&first, &mut bytes).unwrap();
+        codec.encode(second.clone(), &mut bytes).unwrap();
+
+        // the first PDU and a part of the second arrive
+        let split_at = first.pdu_length() as usize + 5;
+        let mut src = BytesMut::from(&bytes[..split_at]);
+        assert_eq!(codec.decode(&mut src).unwrap(), Some(first));
+        assert_eq!(codec.decode(&mut src).unwrap(), None);
+
+        // the rest of the second PDU arrives
+        src.extend_from_slice(&bytes[split_at..]);
+        assert_eq!(codec.decode(&mut src).unwrap(), Some(second));
+        assert_eq!(codec.decode(&mut src).unwrap(), None);
+        assert!(src.is_empty());
+    }
+
+    #[test]
+    fn decode_invalid_pdu_length() {
+        let mut src = BytesMut::from(
+            &[
+                0x07, 0x01, 0x0c, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00,
+            ][..],
+        );
+        let mut codec = DisCodec::new();
+
+        assert!(matches!(
+            codec.decode(&mut src),
+            Err(DisCodecError::InvalidPduLength(4))
+        ));
+    }
+}
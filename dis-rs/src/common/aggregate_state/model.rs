@@ -12,10 +12,14 @@
     VectorF32, BASE_VARIABLE_DATUM_LENGTH,
 };
 use crate::DisError;
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::fmt::{Display, Formatter};
-use std::str::FromStr;
 
 pub(crate) const BASE_AGGREGATE_STATE_BODY_LENGTH: u16 = 124;
 
@@ -141,7 +145,7 @@ pub fn record_length(&self) -> u16 {
 }
 
 impl Display for AggregateMarking {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         f.write_str(self.marking_string.as_str())
     }
 }
@@ -227,7 +231,7 @@ pub fn record_length(&self) -> u16 {
 }
 
 impl Display for AggregateType {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
             "{}:{}:{}:{}:{}:{}:{}",
@@ -3,11 +3,12 @@
     SilentAggregateSystem, SilentEntitySystem,
 };
 use crate::common::BodyInfo;
+use crate::constants::THIRTY_ONE_OCTETS;
 use crate::{Serialize, SerializePdu, SupportedVersion};
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 
 impl SerializePdu for AggregateState {
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
         self.aggregate_id.serialize(buf);
         self.force_id.serialize(buf);
         buf.put_u8(self.aggregate_state.into());
@@ -59,7 +60,7 @@ fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for AggregateType {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u8(self.aggregate_kind.into());
         buf.put_u8(self.domain.into());
         buf.put_u16(self.country.into());
@@ -73,20 +74,22 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for AggregateMarking {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u8(self.marking_character_set.into());
-        let num_pad = 31 - self.marking_string.len();
-        let marking = self.marking_string.clone(); // clone necessary because into_bytes consumes self.
+        // markings that are too long are truncated to the length of the field
+        let marking = self.marking_string.as_bytes();
+        let marking = &marking[..marking.len().min(THIRTY_ONE_OCTETS)];
+        let num_pad = THIRTY_ONE_OCTETS - marking.len();
 
-        buf.put_slice(&marking.into_bytes()[..]);
-        (0..num_pad).for_each(|_i| buf.put_u8(0x20));
+        buf.put_slice(marking);
+        buf.put_bytes(0x20, num_pad);
 
         self.record_length()
     }
 }
 
 impl Serialize for SilentAggregateSystem {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u16(self.number_of_aggregates);
         buf.put_u16(0u16);
         self.aggregate_type.serialize(buf);
@@ -96,7 +99,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for SilentEntitySystem {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u16(self.number_of_entities);
         buf.put_u16(self.appearances.len() as u16);
         self.entity_type.serialize(buf);
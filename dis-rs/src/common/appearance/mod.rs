@@ -0,0 +1,58 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::appearance::model::Appearance;
+    use crate::common::model::DisTimeStamp;
+    use crate::common::model::{Pdu, PduHeader};
+    use crate::common::parser::parse_pdu;
+    use crate::entity_state::model::EntityMarking;
+    use crate::enumerations::{CoupledExtensionIndicator, ForceId, LvcIndicator, PduType};
+    use crate::model::{EntityType, LiveEntityId};
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+    use core::str::FromStr;
+
+    #[test]
+    fn appearance_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::Appearance).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::NoStatement)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = Appearance::builder()
+            .with_live_entity_id(LiveEntityId::new(1, 2, 3))
+            .with_force_id(ForceId::Friendly)
+            .with_entity_type(EntityType::from_str("1:1:225:1:1:0:0").unwrap())
+            .with_entity_marking(EntityMarking::new_ascii("LIVE1"))
+            .with_appearance_visual(0x0000_0100)
+            .with_appearance_audio(0x0000_0001)
+            .build()
+            .into_pdu_body();
+
+        let original_pdu =
+            Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+}
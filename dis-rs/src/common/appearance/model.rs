@@ -0,0 +1,194 @@
+use crate::appearance::builder::AppearanceBuilder;
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::{EIGHT_OCTETS, FOUR_OCTETS, ONE_OCTET, TWELVE_OCTETS};
+use crate::entity_state::model::{EntityAppearance, EntityMarking, EntityState};
+use crate::enumerations::{ForceId, PduType};
+use crate::model::{EntityId, EntityType, LiveEntityId, PduBody};
+use crate::v7::entity_state::entity_capabilities_from_bytes;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const BASE_APPEARANCE_BODY_LENGTH: u16 = 6;
+
+/// 9.4.3 Appearance PDU
+///
+/// The presence of the optional fields is signalled on the wire by the `AppearanceFlags` field,
+/// which is derived from the fields that are set.
+/// The capabilities and appearance fields are kept as raw values, because their layout
+/// depends on the entity type, which is not necessarily present in the same PDU.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Appearance {
+    pub live_entity_id: LiveEntityId,
+    pub force_id: Option<ForceId>,
+    pub entity_type: Option<EntityType>,
+    pub alternate_entity_type: Option<EntityType>,
+    pub entity_marking: Option<EntityMarking>,
+    pub capabilities: Option<u32>,
+    pub appearance_visual: Option<u32>,
+    pub appearance_ir: Option<u32>,
+    pub appearance_em: Option<u32>,
+    pub appearance_audio: Option<u32>,
+}
+
+impl Appearance {
+    #[must_use]
+    pub fn builder() -> AppearanceBuilder {
+        AppearanceBuilder::new()
+    }
+
+    #[must_use]
+    pub fn into_builder(self) -> AppearanceBuilder {
+        AppearanceBuilder::new_from_body(self)
+    }
+
+    #[must_use]
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::Appearance(self)
+    }
+
+    /// Determines the `AppearanceFlags` field based on the optional fields that are present.
+    #[must_use]
+    pub fn flags(&self) -> AppearanceFlags {
+        AppearanceFlags {
+            force_id_present: self.force_id.is_some(),
+            entity_type_present: self.entity_type.is_some(),
+            alternate_entity_type_present: self.alternate_entity_type.is_some(),
+            entity_marking_present: self.entity_marking.is_some(),
+            capabilities_present: self.capabilities.is_some(),
+            appearance_visual_present: self.appearance_visual.is_some(),
+            appearance_ir_present: self.appearance_ir.is_some(),
+            appearance_em_present: self.appearance_em.is_some(),
+            appearance_audio_present: self.appearance_audio.is_some(),
+        }
+    }
+
+    /// Creates an Appearance PDU from the identification and appearance information of an `EntityState`.
+    #[must_use]
+    pub fn from_entity_state(entity_state: &EntityState) -> Self {
+        Appearance::builder()
+            .with_live_entity_id(LiveEntityId::from(&entity_state.entity_id))
+            .with_force_id(entity_state.force_id)
+            .with_entity_type(entity_state.entity_type)
+            .with_alternate_entity_type(entity_state.alternative_entity_type)
+            .with_entity_marking(entity_state.entity_marking.clone())
+            .with_capabilities(entity_state.entity_capabilities.into())
+            .with_appearance_visual(u32::from(&entity_state.entity_appearance))
+            .build()
+    }
+
+    /// Updates the identification and appearance information of `entity_state` with the fields
+    /// present in this Appearance PDU.
+    /// The capabilities and visual appearance are interpreted using the (updated) entity type of `entity_state`.
+    /// The IR, EM and audio appearances have no counterpart in the Entity State PDU and are ignored.
+    pub fn apply_to_entity_state(&self, entity_state: &mut EntityState) {
+        entity_state.entity_id = EntityId::from(self.live_entity_id);
+        if let Some(force_id) = self.force_id {
+            entity_state.force_id = force_id;
+        }
+        if let Some(entity_type) = self.entity_type {
+            entity_state.entity_type = entity_type;
+        }
+        if let Some(alternate_entity_type) = self.alternate_entity_type {
+            entity_state.alternative_entity_type = alternate_entity_type;
+        }
+        if let Some(entity_marking) = &self.entity_marking {
+            entity_state.entity_marking = entity_marking.clone();
+        }
+        if let Some(capabilities) = self.capabilities {
+            entity_state.entity_capabilities =
+                entity_capabilities_from_bytes(capabilities, &entity_state.entity_type);
+        }
+        if let Some(appearance) = self.appearance_visual {
+            entity_state.entity_appearance =
+                EntityAppearance::from_bytes(appearance, &entity_state.entity_type);
+        }
+    }
+}
+
+impl BodyInfo for Appearance {
+    fn body_length(&self) -> u16 {
+        let four_octet_fields = [
+            self.capabilities,
+            self.appearance_visual,
+            self.appearance_ir,
+            self.appearance_em,
+            self.appearance_audio,
+        ]
+        .iter()
+        .flatten()
+        .count() as u16;
+
+        BASE_APPEARANCE_BODY_LENGTH
+            + self.force_id.map_or(0, |_| ONE_OCTET as u16)
+            + self.entity_type.map_or(0, |_| EIGHT_OCTETS as u16)
+            + self
+                .alternate_entity_type
+                .map_or(0, |_| EIGHT_OCTETS as u16)
+            + self
+                .entity_marking
+                .as_ref()
+                .map_or(0, |_| TWELVE_OCTETS as u16)
+            + four_octet_fields * FOUR_OCTETS as u16
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::Appearance
+    }
+}
+
+impl Interaction for Appearance {
+    fn originator(&self) -> Option<&EntityId> {
+        None
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// Appearance Flags field, indicating which optional fields are present in an Appearance PDU
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[allow(clippy::struct_excessive_bools)]
+pub struct AppearanceFlags {
+    pub force_id_present: bool,
+    pub entity_type_present: bool,
+    pub alternate_entity_type_present: bool,
+    pub entity_marking_present: bool,
+    pub capabilities_present: bool,
+    pub appearance_visual_present: bool,
+    pub appearance_ir_present: bool,
+    pub appearance_em_present: bool,
+    pub appearance_audio_present: bool,
+}
+
+impl From<u16> for AppearanceFlags {
+    fn from(value: u16) -> Self {
+        Self {
+            force_id_present: (value & 0x0001) != 0,
+            entity_type_present: ((value >> 1) & 0x0001) != 0,
+            alternate_entity_type_present: ((value >> 2) & 0x0001) != 0,
+            entity_marking_present: ((value >> 3) & 0x0001) != 0,
+            capabilities_present: ((value >> 4) & 0x0001) != 0,
+            appearance_visual_present: ((value >> 5) & 0x0001) != 0,
+            appearance_ir_present: ((value >> 6) & 0x0001) != 0,
+            appearance_em_present: ((value >> 7) & 0x0001) != 0,
+            appearance_audio_present: ((value >> 8) & 0x0001) != 0,
+        }
+    }
+}
+
+impl From<AppearanceFlags> for u16 {
+    fn from(value: AppearanceFlags) -> Self {
+        u16::from(value.force_id_present)
+            | u16::from(value.entity_type_present) << 1
+            | u16::from(value.alternate_entity_type_present) << 2
+            | u16::from(value.entity_marking_present) << 3
+            | u16::from(value.capabilities_present) << 4
+            | u16::from(value.appearance_visual_present) << 5
+            | u16::from(value.appearance_ir_present) << 6
+            | u16::from(value.appearance_em_present) << 7
+            | u16::from(value.appearance_audio_present) << 8
+    }
+}
@@ -0,0 +1,41 @@
+use crate::appearance::model::{Appearance, AppearanceFlags};
+use crate::common::parser::IResult;
+use crate::common::parser::{entity_type, live_entity_id};
+use crate::entity_state::parser::{entity_marking, force_id};
+use crate::model::PduBody;
+use nom::combinator::cond;
+use nom::error::context;
+use nom::number::complete::{be_u16, be_u32};
+
+pub(crate) fn appearance_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    context("Appearance Body", |input| {
+        let (input, live_entity_id) = live_entity_id(input)?;
+        let (input, flags) = be_u16(input)?;
+        let flags = AppearanceFlags::from(flags);
+        let (input, force_id) = cond(flags.force_id_present, force_id)(input)?;
+        let (input, entity_type_val) = cond(flags.entity_type_present, entity_type)(input)?;
+        let (input, alternate_entity_type) =
+            cond(flags.alternate_entity_type_present, entity_type)(input)?;
+        let (input, entity_marking) = cond(flags.entity_marking_present, entity_marking)(input)?;
+        let (input, capabilities) = cond(flags.capabilities_present, be_u32)(input)?;
+        let (input, appearance_visual) = cond(flags.appearance_visual_present, be_u32)(input)?;
+        let (input, appearance_ir) = cond(flags.appearance_ir_present, be_u32)(input)?;
+        let (input, appearance_em) = cond(flags.appearance_em_present, be_u32)(input)?;
+        let (input, appearance_audio) = cond(flags.appearance_audio_present, be_u32)(input)?;
+
+        let body = Appearance {
+            live_entity_id,
+            force_id,
+            entity_type: entity_type_val,
+            alternate_entity_type,
+            entity_marking,
+            capabilities,
+            appearance_visual,
+            appearance_ir,
+            appearance_em,
+            appearance_audio,
+        };
+
+        Ok((input, body.into_pdu_body()))
+    })(input)
+}
@@ -0,0 +1,37 @@
+use crate::appearance::model::Appearance;
+use crate::common::BodyInfo;
+use crate::{Serialize, SerializePdu, SupportedVersion};
+use bytes::BufMut;
+
+impl SerializePdu for Appearance {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
+        self.live_entity_id.serialize(buf);
+        buf.put_u16(self.flags().into());
+        if let Some(force_id) = &self.force_id {
+            force_id.serialize(buf);
+        }
+        if let Some(entity_type) = &self.entity_type {
+            entity_type.serialize(buf);
+        }
+        if let Some(entity_type) = &self.alternate_entity_type {
+            entity_type.serialize(buf);
+        }
+        if let Some(marking) = &self.entity_marking {
+            marking.serialize(buf);
+        }
+        for value in [
+            self.capabilities,
+            self.appearance_visual,
+            self.appearance_ir,
+            self.appearance_em,
+            self.appearance_audio,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            buf.put_u32(value);
+        }
+
+        self.body_length()
+    }
+}
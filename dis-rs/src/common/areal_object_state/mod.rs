@@ -0,0 +1,78 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::areal_object_state::model::ArealObjectState;
+    use crate::common::model::DisTimeStamp;
+    use crate::common::model::{Pdu, PduHeader};
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{
+        AppearanceObjectGeneralDamage, CoupledExtensionIndicator, ForceId, LvcIndicator, PduType,
+        PlatformDomain,
+    };
+    use crate::model::{
+        EntityId, GeneralObjectAppearance, Location, ObjectKind, ObjectStateModification,
+        ObjectType, SimulationAddress,
+    };
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+
+    #[test]
+    fn areal_object_state_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::ArealObjectState).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::NoStatement)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = ArealObjectState::builder()
+            .with_object_id(EntityId::new(10, 10, 300))
+            .with_referenced_object_id(EntityId::new(10, 10, 1))
+            .with_update_number(1)
+            .with_force_id(ForceId::Neutral)
+            .with_modifications(ObjectStateModification::default().with_location())
+            .with_object_type(ObjectType::new(
+                PlatformDomain::Land,
+                ObjectKind::Obstacle,
+                3,
+                2,
+            ))
+            .with_specific_appearance(0x0000_0101)
+            .with_general_appearance(
+                GeneralObjectAppearance::default()
+                    .with_damage(AppearanceObjectGeneralDamage::from(1u8))
+                    .with_flaming(true),
+            )
+            .with_requester_id(SimulationAddress::new(10, 10))
+            .with_receiving_id(SimulationAddress::new(20, 20))
+            .with_object_point(Location::new(0.0, 0.0, 0.0))
+            .with_object_point(Location::new(100.0, 0.0, 0.0))
+            .with_object_point(Location::new(100.0, 100.0, 0.0))
+            .build()
+            .into_pdu_body();
+
+        let original_pdu =
+            Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::areal_object_state::builder::ArealObjectStateBuilder;
+use crate::common::{BodyInfo, Interaction};
+use crate::enumerations::{ForceId, PduType};
+use crate::model::{
+    EntityId, GeneralObjectAppearance, Location, ObjectStateModification, ObjectType, PduBody,
+    SimulationAddress,
+};
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const BASE_AREAL_OBJECT_STATE_BODY_LENGTH: u16 = 36;
+const AREAL_OBJECT_POINT_LENGTH: u16 = 24;
+
+/// 7.10.6 Areal Object State PDU
+///
+/// The layout of the `specific_appearance` field depends on the `object_type` of the object.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ArealObjectState {
+    pub object_id: EntityId,
+    pub referenced_object_id: EntityId,
+    pub update_number: u16,
+    pub force_id: ForceId,
+    pub modifications: ObjectStateModification,
+    pub object_type: ObjectType,
+    pub specific_appearance: u32,
+    pub general_appearance: GeneralObjectAppearance,
+    pub requester_id: SimulationAddress,
+    pub receiving_id: SimulationAddress,
+    pub object_points: Vec<Location>,
+}
+
+impl ArealObjectState {
+    #[must_use]
+    pub fn builder() -> ArealObjectStateBuilder {
+        ArealObjectStateBuilder::new()
+    }
+
+    #[must_use]
+    pub fn into_builder(self) -> ArealObjectStateBuilder {
+        ArealObjectStateBuilder::new_from_body(self)
+    }
+
+    #[must_use]
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::ArealObjectState(self)
+    }
+}
+
+impl BodyInfo for ArealObjectState {
+    fn body_length(&self) -> u16 {
+        BASE_AREAL_OBJECT_STATE_BODY_LENGTH
+            + (self.object_points.len() as u16 * AREAL_OBJECT_POINT_LENGTH)
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::ArealObjectState
+    }
+}
+
+impl Interaction for ArealObjectState {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.object_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
@@ -0,0 +1,44 @@
+use crate::areal_object_state::model::ArealObjectState;
+use crate::common::parser::IResult;
+use crate::common::parser::{
+    entity_id, general_object_appearance, location, object_state_modification, object_type,
+    simulation_address,
+};
+use crate::entity_state::parser::force_id;
+use crate::model::PduBody;
+use nom::error::context;
+use nom::multi::count;
+use nom::number::complete::{be_u16, be_u32};
+
+pub(crate) fn areal_object_state_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    context("Areal Object State Body", |input| {
+        let (input, object_id) = entity_id(input)?;
+        let (input, referenced_object_id) = entity_id(input)?;
+        let (input, update_number) = be_u16(input)?;
+        let (input, force_id) = force_id(input)?;
+        let (input, modifications) = object_state_modification(input)?;
+        let (input, object_type) = object_type(input)?;
+        let (input, specific_appearance) = be_u32(input)?;
+        let (input, general_appearance) = general_object_appearance(input)?;
+        let (input, number_of_points) = be_u16(input)?;
+        let (input, requester_id) = simulation_address(input)?;
+        let (input, receiving_id) = simulation_address(input)?;
+        let (input, object_points) = count(location, number_of_points.into())(input)?;
+
+        let body = ArealObjectState::builder()
+            .with_object_id(object_id)
+            .with_referenced_object_id(referenced_object_id)
+            .with_update_number(update_number)
+            .with_force_id(force_id)
+            .with_modifications(modifications)
+            .with_object_type(object_type)
+            .with_specific_appearance(specific_appearance)
+            .with_general_appearance(general_appearance)
+            .with_requester_id(requester_id)
+            .with_receiving_id(receiving_id)
+            .with_object_points(object_points)
+            .build();
+
+        Ok((input, body.into_pdu_body()))
+    })(input)
+}
@@ -0,0 +1,25 @@
+use crate::areal_object_state::model::ArealObjectState;
+use crate::common::BodyInfo;
+use crate::{Serialize, SerializePdu, SupportedVersion};
+use bytes::BufMut;
+
+impl SerializePdu for ArealObjectState {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
+        self.object_id.serialize(buf);
+        self.referenced_object_id.serialize(buf);
+        buf.put_u16(self.update_number);
+        self.force_id.serialize(buf);
+        self.modifications.serialize(buf);
+        self.object_type.serialize(buf);
+        buf.put_u32(self.specific_appearance);
+        self.general_appearance.serialize(buf);
+        buf.put_u16(self.object_points.len() as u16);
+        self.requester_id.serialize(buf);
+        self.receiving_id.serialize(buf);
+        for point in &self.object_points {
+            point.serialize(buf);
+        }
+
+        self.body_length()
+    }
+}
@@ -0,0 +1,46 @@
+use crate::articulated_parts::model::ArticulatedParts;
+use crate::model::{LiveEntityId, VariableParameter};
+use alloc::vec::Vec;
+
+pub struct ArticulatedPartsBuilder(ArticulatedParts);
+
+impl Default for ArticulatedPartsBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ArticulatedPartsBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        ArticulatedPartsBuilder(ArticulatedParts::default())
+    }
+
+    #[must_use]
+    pub fn new_from_body(body: ArticulatedParts) -> Self {
+        ArticulatedPartsBuilder(body)
+    }
+
+    #[must_use]
+    pub fn build(self) -> ArticulatedParts {
+        self.0
+    }
+
+    #[must_use]
+    pub fn with_live_entity_id(mut self, live_entity_id: LiveEntityId) -> Self {
+        self.0.live_entity_id = live_entity_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_variable_parameter(mut self, variable_parameter: VariableParameter) -> Self {
+        self.0.variable_parameters.push(variable_parameter);
+        self
+    }
+
+    #[must_use]
+    pub fn with_variable_parameters(mut self, variable_parameters: Vec<VariableParameter>) -> Self {
+        self.0.variable_parameters = variable_parameters;
+        self
+    }
+}
@@ -0,0 +1,61 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::articulated_parts::model::ArticulatedParts;
+    use crate::common::model::DisTimeStamp;
+    use crate::common::model::{Pdu, PduHeader};
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{
+        ArticulatedPartsTypeClass, ArticulatedPartsTypeMetric, ChangeIndicator,
+        CoupledExtensionIndicator, LvcIndicator, PduType,
+    };
+    use crate::model::{ArticulatedPart, LiveEntityId, VariableParameter};
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+
+    #[test]
+    fn articulated_parts_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::ArticulatedParts).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::NoStatement)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = ArticulatedParts::builder()
+            .with_live_entity_id(LiveEntityId::new(1, 2, 3))
+            .with_variable_parameter(VariableParameter::Articulated(ArticulatedPart {
+                change_indicator: ChangeIndicator::from(1u8),
+                attachment_id: 0,
+                type_class: ArticulatedPartsTypeClass::PrimaryTurretNumber1,
+                type_metric: ArticulatedPartsTypeMetric::Azimuth,
+                parameter_value: 1.5,
+            }))
+            .build()
+            .into_pdu_body();
+
+        let original_pdu =
+            Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+}
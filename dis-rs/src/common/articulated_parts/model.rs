@@ -0,0 +1,74 @@
+use crate::articulated_parts::builder::ArticulatedPartsBuilder;
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::VARIABLE_PARAMETER_RECORD_LENGTH;
+use crate::entity_state::model::EntityState;
+use crate::enumerations::PduType;
+use crate::model::{EntityId, LiveEntityId, PduBody, VariableParameter};
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const BASE_ARTICULATED_PARTS_BODY_LENGTH: u16 = 5;
+
+/// 9.4.4 Articulated Parts PDU
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ArticulatedParts {
+    pub live_entity_id: LiveEntityId,
+    pub variable_parameters: Vec<VariableParameter>,
+}
+
+impl ArticulatedParts {
+    #[must_use]
+    pub fn builder() -> ArticulatedPartsBuilder {
+        ArticulatedPartsBuilder::new()
+    }
+
+    #[must_use]
+    pub fn into_builder(self) -> ArticulatedPartsBuilder {
+        ArticulatedPartsBuilder::new_from_body(self)
+    }
+
+    #[must_use]
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::ArticulatedParts(self)
+    }
+
+    /// Creates an Articulated Parts PDU from the variable parameters of an `EntityState`.
+    #[must_use]
+    pub fn from_entity_state(entity_state: &EntityState) -> Self {
+        ArticulatedParts::builder()
+            .with_live_entity_id(LiveEntityId::from(&entity_state.entity_id))
+            .with_variable_parameters(entity_state.variable_parameters.clone())
+            .build()
+    }
+
+    /// Replaces the variable parameters of `entity_state` with those of this Articulated Parts PDU.
+    pub fn apply_to_entity_state(&self, entity_state: &mut EntityState) {
+        entity_state.entity_id = EntityId::from(self.live_entity_id);
+        entity_state
+            .variable_parameters
+            .clone_from(&self.variable_parameters);
+    }
+}
+
+impl BodyInfo for ArticulatedParts {
+    fn body_length(&self) -> u16 {
+        BASE_ARTICULATED_PARTS_BODY_LENGTH
+            + (VARIABLE_PARAMETER_RECORD_LENGTH * (self.variable_parameters.len() as u16))
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::ArticulatedParts
+    }
+}
+
+impl Interaction for ArticulatedParts {
+    fn originator(&self) -> Option<&EntityId> {
+        None
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
@@ -0,0 +1,23 @@
+use crate::articulated_parts::model::ArticulatedParts;
+use crate::common::parser::IResult;
+use crate::common::parser::{live_entity_id, variable_parameter};
+use crate::model::PduBody;
+use nom::error::context;
+use nom::multi::count;
+use nom::number::complete::be_u8;
+
+pub(crate) fn articulated_parts_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    context("Articulated Parts Body", |input| {
+        let (input, live_entity_id) = live_entity_id(input)?;
+        let (input, number_of_parameter_records) = be_u8(input)?;
+        let (input, variable_parameters) =
+            count(variable_parameter, number_of_parameter_records.into())(input)?;
+
+        let body = ArticulatedParts::builder()
+            .with_live_entity_id(live_entity_id)
+            .with_variable_parameters(variable_parameters)
+            .build();
+
+        Ok((input, body.into_pdu_body()))
+    })(input)
+}
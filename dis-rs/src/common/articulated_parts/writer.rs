@@ -0,0 +1,16 @@
+use crate::articulated_parts::model::ArticulatedParts;
+use crate::common::BodyInfo;
+use crate::{Serialize, SerializePdu, SupportedVersion};
+use bytes::BufMut;
+
+impl SerializePdu for ArticulatedParts {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
+        self.live_entity_id.serialize(buf);
+        buf.put_u8(self.variable_parameters.len() as u8);
+        for parameter in &self.variable_parameters {
+            parameter.serialize(buf);
+        }
+
+        self.body_length()
+    }
+}
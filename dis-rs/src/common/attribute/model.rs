@@ -2,6 +2,8 @@
 use crate::common::model::{EntityId, PduBody, SimulationAddress};
 use crate::common::{BodyInfo, Interaction};
 use crate::enumerations::{AttributeActionCode, PduType, ProtocolVersion, VariableRecordType};
+use alloc::vec;
+use alloc::vec::Vec;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
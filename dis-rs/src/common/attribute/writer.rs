@@ -4,10 +4,10 @@
 use crate::common::model::length_padded_to_num;
 use crate::common::{Serialize, SerializePdu, SupportedVersion};
 use crate::constants::EIGHT_OCTETS;
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 
 impl SerializePdu for Attribute {
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
         let sim_address_bytes = self.originating_simulation_address.serialize(buf);
         buf.put_u32(0u32);
         buf.put_u16(0u16);
@@ -28,7 +28,7 @@ fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for AttributeRecordSet {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let entity_id_bytes = self.entity_id.serialize(buf);
         buf.put_u16(self.attribute_records.len() as u16);
         let records_bytes = self
@@ -42,7 +42,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for AttributeRecord {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let padded_record_lengths = length_padded_to_num(
             BASE_ATTRIBUTE_RECORD_LENGTH_OCTETS as usize + self.specific_fields.len(),
             EIGHT_OCTETS,
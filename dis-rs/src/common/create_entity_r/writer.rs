@@ -1,9 +1,9 @@
 use crate::common::create_entity_r::model::CreateEntityR;
 use crate::common::{Serialize, SerializePdu, SupportedVersion};
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 
 impl SerializePdu for CreateEntityR {
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
         let originating_id_bytes = self.originating_id.serialize(buf);
         let receiving_id_bytes = self.receiving_id.serialize(buf);
         buf.put_u8(self.required_reliability_service.into());
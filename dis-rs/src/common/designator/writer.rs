@@ -1,9 +1,9 @@
 use crate::common::designator::model::Designator;
 use crate::common::{Serialize, SerializePdu, SupportedVersion};
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 
 impl SerializePdu for Designator {
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
         let designating_id_bytes = self.designating_entity_id.serialize(buf);
         buf.put_u16(self.system_name.into());
         let designated_id_bytes = self.designated_entity_id.serialize(buf);
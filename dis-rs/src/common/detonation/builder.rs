@@ -4,6 +4,7 @@
     VariableParameter, VectorF32,
 };
 use crate::enumerations::{DetonationResult, ExplosiveMaterialCategories};
+use alloc::vec::Vec;
 
 pub struct DetonationBuilder(Detonation);
 
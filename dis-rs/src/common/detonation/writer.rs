@@ -1,9 +1,9 @@
 use crate::common::detonation::model::Detonation;
 use crate::common::{Serialize, SerializePdu, SupportedVersion};
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 
 impl SerializePdu for Detonation {
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
         let source_entity_id_bytes = self.source_entity_id.serialize(buf);
         let target_entity_id_bytes = self.target_entity_id.serialize(buf);
         let exploding_entity_id_bytes = self.exploding_entity_id.serialize(buf);
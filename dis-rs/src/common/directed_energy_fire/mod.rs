@@ -0,0 +1,88 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::model::DisTimeStamp;
+    use crate::common::model::{Pdu, PduHeader};
+    use crate::common::parser::parse_pdu;
+    use crate::directed_energy_fire::model::{
+        BeamSpotType, DEAreaAimpoint, DEFireFlags, DEFirePulseShape, DEPrecisionAimpoint,
+        DETargetEnergyDeposition, DirectedEnergyFire,
+    };
+    use crate::entity_damage_status::model::ComponentIdentification;
+    use crate::enumerations::{CoupledExtensionIndicator, LvcIndicator, PduType};
+    use crate::model::{ClockTime, EntityId, EventId, Location, SimulationAddress, VectorF32};
+    use crate::transmitter::model::BeamAntennaPattern;
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+
+    #[test]
+    fn directed_energy_fire_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::DirectedEnergyFire).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::NoStatement)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = DirectedEnergyFire::builder()
+            .with_firing_entity_id(EntityId::new(1, 1, 1))
+            .with_event_id(EventId::new(SimulationAddress::new(1, 1), 10))
+            .with_shot_start_time(ClockTime::new(10, 500))
+            .with_cumulative_shot_time(1.5)
+            .with_aperture_emitter_location(VectorF32::new(0.0, 0.5, -1.0))
+            .with_aperture_diameter(0.3)
+            .with_wavelength(1.064e-6)
+            .with_pulse_repetition_frequency(10.0)
+            .with_pulse_width(0.01)
+            .with_flags(DEFireFlags::default().with_weapon_on())
+            .with_pulse_shape(DEFirePulseShape::SquareWave)
+            .with_de_record(
+                DEPrecisionAimpoint::default()
+                    .with_target_spot_location(Location::new(1.0, 2.0, 3.0))
+                    .with_target_entity_id(EntityId::new(2, 2, 2))
+                    .with_target_component_id(ComponentIdentification::TrackingSensor)
+                    .with_beam_spot_type(BeamSpotType::Gaussian)
+                    .with_beam_spot_cross_section_semi_major_axis(0.2)
+                    .with_beam_spot_cross_section_semi_minor_axis(0.1)
+                    .with_peak_irradiance(1000.0)
+                    .into_record(),
+            )
+            .with_de_record(
+                DEAreaAimpoint::default()
+                    .with_beam_antenna_pattern(
+                        BeamAntennaPattern::new().with_azimuth_beamwidth(0.5),
+                    )
+                    .with_target_energy_deposition(DETargetEnergyDeposition::new(
+                        EntityId::new(3, 3, 3),
+                        250.0,
+                    ))
+                    .into_record(),
+            )
+            .build()
+            .into_pdu_body();
+
+        let original_pdu =
+            Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+}
@@ -0,0 +1,397 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::EIGHT_OCTETS;
+use crate::directed_energy_fire::builder::DirectedEnergyFireBuilder;
+use crate::entity_damage_status::model::ComponentIdentification;
+use crate::enumerations::PduType;
+use crate::model::{
+    length_padded_to_num, ClockTime, EntityId, EntityType, EventId, Location, PaddedRecordLengths,
+    PduBody, StandardVariableRecord, VectorF32,
+};
+use crate::transmitter::model::{BeamAntennaPattern, BEAM_ANTENNA_PATTERN_OCTETS};
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const BASE_DIRECTED_ENERGY_FIRE_BODY_LENGTH: u16 = 76;
+pub(crate) const PRECISION_AIMPOINT_RECORD_TYPE: u32 = 4000;
+pub(crate) const PRECISION_AIMPOINT_RECORD_LENGTH: u16 = 96;
+pub(crate) const AREA_AIMPOINT_RECORD_TYPE: u32 = 4001;
+const BASE_AREA_AIMPOINT_RECORD_LENGTH: u16 = 12;
+const TARGET_ENERGY_DEPOSITION_RECORD_LENGTH: u16 = 12;
+
+/// 7.3.4 Directed Energy Fire PDU
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DirectedEnergyFire {
+    pub firing_entity_id: EntityId,
+    pub event_id: EventId,
+    pub munition_type: EntityType,
+    pub shot_start_time: ClockTime,
+    pub cumulative_shot_time: f32,
+    pub aperture_emitter_location: VectorF32,
+    pub aperture_diameter: f32,
+    pub wavelength: f32,
+    pub pulse_repetition_frequency: f32,
+    pub pulse_width: f32,
+    pub flags: DEFireFlags,
+    pub pulse_shape: DEFirePulseShape,
+    pub de_records: Vec<DERecord>,
+}
+
+impl DirectedEnergyFire {
+    #[must_use]
+    pub fn builder() -> DirectedEnergyFireBuilder {
+        DirectedEnergyFireBuilder::new()
+    }
+
+    #[must_use]
+    pub fn into_builder(self) -> DirectedEnergyFireBuilder {
+        DirectedEnergyFireBuilder::new_from_body(self)
+    }
+
+    #[must_use]
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::DirectedEnergyFire(self)
+    }
+}
+
+impl BodyInfo for DirectedEnergyFire {
+    fn body_length(&self) -> u16 {
+        BASE_DIRECTED_ENERGY_FIRE_BODY_LENGTH
+            + self
+                .de_records
+                .iter()
+                .map(DERecord::record_length)
+                .sum::<u16>()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::DirectedEnergyFire
+    }
+}
+
+impl Interaction for DirectedEnergyFire {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.firing_entity_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// Flags field of the Directed Energy Fire PDU (SISO-REF-010)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DEFireFlags {
+    pub weapon_on: bool,
+    pub state_update: bool,
+}
+
+impl DEFireFlags {
+    #[must_use]
+    pub fn with_weapon_on(mut self) -> Self {
+        self.weapon_on = true;
+        self
+    }
+
+    #[must_use]
+    pub fn with_state_update(mut self) -> Self {
+        self.state_update = true;
+        self
+    }
+}
+
+impl From<u16> for DEFireFlags {
+    fn from(value: u16) -> Self {
+        Self {
+            weapon_on: (value & 0x0001) != 0,
+            state_update: (value & 0x0002) != 0,
+        }
+    }
+}
+
+impl From<DEFireFlags> for u16 {
+    fn from(value: DEFireFlags) -> Self {
+        u16::from(value.weapon_on) | u16::from(value.state_update) << 1
+    }
+}
+
+/// DE Fire Pulse Shape (SISO-REF-010)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DEFirePulseShape {
+    #[default]
+    Other,
+    SquareWave,
+    ContinuousWave,
+    Gaussian,
+    Unspecified(u8),
+}
+
+impl From<u8> for DEFirePulseShape {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Other,
+            1 => Self::SquareWave,
+            2 => Self::ContinuousWave,
+            3 => Self::Gaussian,
+            other => Self::Unspecified(other),
+        }
+    }
+}
+
+impl From<DEFirePulseShape> for u8 {
+    fn from(value: DEFirePulseShape) -> Self {
+        match value {
+            DEFirePulseShape::Other => 0,
+            DEFirePulseShape::SquareWave => 1,
+            DEFirePulseShape::ContinuousWave => 2,
+            DEFirePulseShape::Gaussian => 3,
+            DEFirePulseShape::Unspecified(value) => value,
+        }
+    }
+}
+
+/// DE records of the Directed Energy Fire PDU
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DERecord {
+    PrecisionAimpoint(DEPrecisionAimpoint),
+    AreaAimpoint(DEAreaAimpoint),
+    Unspecified(StandardVariableRecord),
+}
+
+impl DERecord {
+    #[must_use]
+    pub fn record_length(&self) -> u16 {
+        match self {
+            DERecord::PrecisionAimpoint(record) => record.record_length(),
+            DERecord::AreaAimpoint(record) => record.record_length(),
+            DERecord::Unspecified(record) => record.record_length(),
+        }
+    }
+}
+
+/// 6.2.20.3 DE Precision Aimpoint record
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DEPrecisionAimpoint {
+    pub target_spot_location: Location,
+    pub target_spot_entity_location: VectorF32,
+    pub target_spot_velocity: VectorF32,
+    pub target_spot_acceleration: VectorF32,
+    pub target_entity_id: EntityId,
+    pub target_component_id: ComponentIdentification,
+    pub beam_spot_type: BeamSpotType,
+    pub beam_spot_cross_section_semi_major_axis: f32,
+    pub beam_spot_cross_section_semi_minor_axis: f32,
+    pub beam_spot_cross_section_orientation_angle: f32,
+    pub peak_irradiance: f32,
+}
+
+impl DEPrecisionAimpoint {
+    #[must_use]
+    pub fn with_target_spot_location(mut self, target_spot_location: Location) -> Self {
+        self.target_spot_location = target_spot_location;
+        self
+    }
+
+    #[must_use]
+    pub fn with_target_spot_entity_location(
+        mut self,
+        target_spot_entity_location: VectorF32,
+    ) -> Self {
+        self.target_spot_entity_location = target_spot_entity_location;
+        self
+    }
+
+    #[must_use]
+    pub fn with_target_spot_velocity(mut self, target_spot_velocity: VectorF32) -> Self {
+        self.target_spot_velocity = target_spot_velocity;
+        self
+    }
+
+    #[must_use]
+    pub fn with_target_spot_acceleration(mut self, target_spot_acceleration: VectorF32) -> Self {
+        self.target_spot_acceleration = target_spot_acceleration;
+        self
+    }
+
+    #[must_use]
+    pub fn with_target_entity_id(mut self, target_entity_id: EntityId) -> Self {
+        self.target_entity_id = target_entity_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_target_component_id(
+        mut self,
+        target_component_id: ComponentIdentification,
+    ) -> Self {
+        self.target_component_id = target_component_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_beam_spot_type(mut self, beam_spot_type: BeamSpotType) -> Self {
+        self.beam_spot_type = beam_spot_type;
+        self
+    }
+
+    #[must_use]
+    pub fn with_beam_spot_cross_section_semi_major_axis(mut self, semi_major_axis: f32) -> Self {
+        self.beam_spot_cross_section_semi_major_axis = semi_major_axis;
+        self
+    }
+
+    #[must_use]
+    pub fn with_beam_spot_cross_section_semi_minor_axis(mut self, semi_minor_axis: f32) -> Self {
+        self.beam_spot_cross_section_semi_minor_axis = semi_minor_axis;
+        self
+    }
+
+    #[must_use]
+    pub fn with_beam_spot_cross_section_orientation_angle(
+        mut self,
+        orientation_angle: f32,
+    ) -> Self {
+        self.beam_spot_cross_section_orientation_angle = orientation_angle;
+        self
+    }
+
+    #[must_use]
+    pub fn with_peak_irradiance(mut self, peak_irradiance: f32) -> Self {
+        self.peak_irradiance = peak_irradiance;
+        self
+    }
+
+    #[must_use]
+    pub fn into_record(self) -> DERecord {
+        DERecord::PrecisionAimpoint(self)
+    }
+
+    #[must_use]
+    pub const fn record_length(&self) -> u16 {
+        PRECISION_AIMPOINT_RECORD_LENGTH
+    }
+}
+
+/// Beam Spot Type (SISO-REF-010)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BeamSpotType {
+    #[default]
+    Other,
+    Gaussian,
+    TopHat,
+    Unspecified(u8),
+}
+
+impl From<u8> for BeamSpotType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Other,
+            1 => Self::Gaussian,
+            2 => Self::TopHat,
+            other => Self::Unspecified(other),
+        }
+    }
+}
+
+impl From<BeamSpotType> for u8 {
+    fn from(value: BeamSpotType) -> Self {
+        match value {
+            BeamSpotType::Other => 0,
+            BeamSpotType::Gaussian => 1,
+            BeamSpotType::TopHat => 2,
+            BeamSpotType::Unspecified(value) => value,
+        }
+    }
+}
+
+/// 6.2.20.2 DE Area Aimpoint record
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DEAreaAimpoint {
+    pub beam_antenna_patterns: Vec<BeamAntennaPattern>,
+    pub target_energy_depositions: Vec<DETargetEnergyDeposition>,
+}
+
+impl DEAreaAimpoint {
+    #[must_use]
+    pub fn with_beam_antenna_pattern(mut self, beam_antenna_pattern: BeamAntennaPattern) -> Self {
+        self.beam_antenna_patterns.push(beam_antenna_pattern);
+        self
+    }
+
+    #[must_use]
+    pub fn with_beam_antenna_patterns(
+        mut self,
+        beam_antenna_patterns: Vec<BeamAntennaPattern>,
+    ) -> Self {
+        self.beam_antenna_patterns = beam_antenna_patterns;
+        self
+    }
+
+    #[must_use]
+    pub fn with_target_energy_deposition(
+        mut self,
+        target_energy_deposition: DETargetEnergyDeposition,
+    ) -> Self {
+        self.target_energy_depositions
+            .push(target_energy_deposition);
+        self
+    }
+
+    #[must_use]
+    pub fn with_target_energy_depositions(
+        mut self,
+        target_energy_depositions: Vec<DETargetEnergyDeposition>,
+    ) -> Self {
+        self.target_energy_depositions = target_energy_depositions;
+        self
+    }
+
+    #[must_use]
+    pub fn into_record(self) -> DERecord {
+        DERecord::AreaAimpoint(self)
+    }
+
+    pub(crate) fn padded_record_lengths(&self) -> PaddedRecordLengths {
+        let data_length = BASE_AREA_AIMPOINT_RECORD_LENGTH as usize
+            + self.beam_antenna_patterns.len() * BEAM_ANTENNA_PATTERN_OCTETS as usize
+            + self.target_energy_depositions.len()
+                * TARGET_ENERGY_DEPOSITION_RECORD_LENGTH as usize;
+        length_padded_to_num(data_length, EIGHT_OCTETS)
+    }
+
+    /// Returns the length of the record, including the padding to a 64-bit boundary.
+    #[must_use]
+    pub fn record_length(&self) -> u16 {
+        self.padded_record_lengths().record_length as u16
+    }
+}
+
+/// 6.2.21 Directed Energy Target Energy Deposition record
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DETargetEnergyDeposition {
+    pub target_entity_id: EntityId,
+    pub peak_irradiance: f32,
+}
+
+impl DETargetEnergyDeposition {
+    #[must_use]
+    pub fn new(target_entity_id: EntityId, peak_irradiance: f32) -> Self {
+        Self {
+            target_entity_id,
+            peak_irradiance,
+        }
+    }
+
+    #[must_use]
+    pub const fn record_length(&self) -> u16 {
+        TARGET_ENERGY_DEPOSITION_RECORD_LENGTH
+    }
+}
@@ -0,0 +1,151 @@
+use crate::common::parser::IResult;
+use crate::common::parser::{
+    clock_time, entity_id, entity_type, event_id, location, standard_variable_record, vec3_f32,
+};
+use crate::directed_energy_fire::model::{
+    BeamSpotType, DEAreaAimpoint, DEFireFlags, DEFirePulseShape, DEPrecisionAimpoint, DERecord,
+    DETargetEnergyDeposition, DirectedEnergyFire, AREA_AIMPOINT_RECORD_TYPE,
+    PRECISION_AIMPOINT_RECORD_TYPE,
+};
+use crate::entity_damage_status::model::ComponentIdentification;
+use crate::model::PduBody;
+use crate::transmitter::parser::beam_antenna_pattern;
+use nom::bytes::complete::take;
+use nom::combinator::peek;
+use nom::error::context;
+use nom::multi::count;
+use nom::number::complete::{be_f32, be_u16, be_u32, be_u8};
+
+pub(crate) fn directed_energy_fire_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    context("Directed Energy Fire Body", |input| {
+        let (input, firing_entity_id) = entity_id(input)?;
+        let (input, event_id) = event_id(input)?;
+        let (input, munition_type) = entity_type(input)?;
+        let (input, shot_start_time) = clock_time(input)?;
+        let (input, cumulative_shot_time) = be_f32(input)?;
+        let (input, aperture_emitter_location) = vec3_f32(input)?;
+        let (input, aperture_diameter) = be_f32(input)?;
+        let (input, wavelength) = be_f32(input)?;
+        let (input, _padding) = be_u32(input)?;
+        let (input, pulse_repetition_frequency) = be_f32(input)?;
+        let (input, pulse_width) = be_f32(input)?;
+        let (input, flags) = be_u16(input)?;
+        let (input, pulse_shape) = be_u8(input)?;
+        let (input, _padding) = be_u8(input)?;
+        let (input, _padding) = be_u32(input)?;
+        let (input, _padding) = be_u16(input)?;
+        let (input, number_of_de_records) = be_u16(input)?;
+        let (input, de_records) = count(de_record, number_of_de_records.into())(input)?;
+
+        let body = DirectedEnergyFire::builder()
+            .with_firing_entity_id(firing_entity_id)
+            .with_event_id(event_id)
+            .with_munition_type(munition_type)
+            .with_shot_start_time(shot_start_time)
+            .with_cumulative_shot_time(cumulative_shot_time)
+            .with_aperture_emitter_location(aperture_emitter_location)
+            .with_aperture_diameter(aperture_diameter)
+            .with_wavelength(wavelength)
+            .with_pulse_repetition_frequency(pulse_repetition_frequency)
+            .with_pulse_width(pulse_width)
+            .with_flags(DEFireFlags::from(flags))
+            .with_pulse_shape(DEFirePulseShape::from(pulse_shape))
+            .with_de_records(de_records)
+            .build();
+
+        Ok((input, body.into_pdu_body()))
+    })(input)
+}
+
+fn de_record(input: &[u8]) -> IResult<&[u8], DERecord> {
+    context("DE Record", |input| {
+        let (_, record_type) = peek(be_u32)(input)?;
+
+        match record_type {
+            PRECISION_AIMPOINT_RECORD_TYPE => {
+                let (input, record) = de_precision_aimpoint(input)?;
+                Ok((input, DERecord::PrecisionAimpoint(record)))
+            }
+            AREA_AIMPOINT_RECORD_TYPE => {
+                let (input, record) = de_area_aimpoint(input)?;
+                Ok((input, DERecord::AreaAimpoint(record)))
+            }
+            _ => {
+                let (input, record) = standard_variable_record(input)?;
+                Ok((input, DERecord::Unspecified(record)))
+            }
+        }
+    })(input)
+}
+
+fn de_precision_aimpoint(input: &[u8]) -> IResult<&[u8], DEPrecisionAimpoint> {
+    context("DE Precision Aimpoint", |input| {
+        let (input, _record_type) = be_u32(input)?;
+        let (input, _record_length) = be_u16(input)?;
+        let (input, _padding) = be_u16(input)?;
+        let (input, target_spot_location) = location(input)?;
+        let (input, target_spot_entity_location) = vec3_f32(input)?;
+        let (input, target_spot_velocity) = vec3_f32(input)?;
+        let (input, target_spot_acceleration) = vec3_f32(input)?;
+        let (input, target_entity_id) = entity_id(input)?;
+        let (input, target_component_id) = be_u8(input)?;
+        let (input, beam_spot_type) = be_u8(input)?;
+        let (input, semi_major_axis) = be_f32(input)?;
+        let (input, semi_minor_axis) = be_f32(input)?;
+        let (input, orientation_angle) = be_f32(input)?;
+        let (input, peak_irradiance) = be_f32(input)?;
+        let (input, _padding) = be_u32(input)?;
+
+        Ok((
+            input,
+            DEPrecisionAimpoint::default()
+                .with_target_spot_location(target_spot_location)
+                .with_target_spot_entity_location(target_spot_entity_location)
+                .with_target_spot_velocity(target_spot_velocity)
+                .with_target_spot_acceleration(target_spot_acceleration)
+                .with_target_entity_id(target_entity_id)
+                .with_target_component_id(ComponentIdentification::from(target_component_id))
+                .with_beam_spot_type(BeamSpotType::from(beam_spot_type))
+                .with_beam_spot_cross_section_semi_major_axis(semi_major_axis)
+                .with_beam_spot_cross_section_semi_minor_axis(semi_minor_axis)
+                .with_beam_spot_cross_section_orientation_angle(orientation_angle)
+                .with_peak_irradiance(peak_irradiance),
+        ))
+    })(input)
+}
+
+fn de_area_aimpoint(input: &[u8]) -> IResult<&[u8], DEAreaAimpoint> {
+    context("DE Area Aimpoint", |input| {
+        let (input, _record_type) = be_u32(input)?;
+        let (input, _record_length) = be_u16(input)?;
+        let (input, _padding) = be_u16(input)?;
+        let (input, number_of_beam_antenna_patterns) = be_u16(input)?;
+        let (input, number_of_target_energy_depositions) = be_u16(input)?;
+        let (input, beam_antenna_patterns) =
+            count(beam_antenna_pattern, number_of_beam_antenna_patterns.into())(input)?;
+        let (input, target_energy_depositions) = count(
+            de_target_energy_deposition,
+            number_of_target_energy_depositions.into(),
+        )(input)?;
+
+        let record = DEAreaAimpoint::default()
+            .with_beam_antenna_patterns(beam_antenna_patterns)
+            .with_target_energy_depositions(target_energy_depositions);
+        let (input, _padding) = take(record.padded_record_lengths().padding_length)(input)?;
+
+        Ok((input, record))
+    })(input)
+}
+
+fn de_target_energy_deposition(input: &[u8]) -> IResult<&[u8], DETargetEnergyDeposition> {
+    context("DE Target Energy Deposition", |input| {
+        let (input, target_entity_id) = entity_id(input)?;
+        let (input, _padding) = be_u16(input)?;
+        let (input, peak_irradiance) = be_f32(input)?;
+
+        Ok((
+            input,
+            DETargetEnergyDeposition::new(target_entity_id, peak_irradiance),
+        ))
+    })(input)
+}
@@ -0,0 +1,97 @@
+use crate::common::BodyInfo;
+use crate::directed_energy_fire::model::{
+    DEAreaAimpoint, DEPrecisionAimpoint, DERecord, DETargetEnergyDeposition, DirectedEnergyFire,
+    AREA_AIMPOINT_RECORD_TYPE, PRECISION_AIMPOINT_RECORD_TYPE,
+};
+use crate::{Serialize, SerializePdu, SupportedVersion};
+use bytes::BufMut;
+
+impl SerializePdu for DirectedEnergyFire {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
+        self.firing_entity_id.serialize(buf);
+        self.event_id.serialize(buf);
+        self.munition_type.serialize(buf);
+        self.shot_start_time.serialize(buf);
+        buf.put_f32(self.cumulative_shot_time);
+        self.aperture_emitter_location.serialize(buf);
+        buf.put_f32(self.aperture_diameter);
+        buf.put_f32(self.wavelength);
+        buf.put_u32(0u32);
+        buf.put_f32(self.pulse_repetition_frequency);
+        buf.put_f32(self.pulse_width);
+        buf.put_u16(self.flags.into());
+        buf.put_u8(self.pulse_shape.into());
+        buf.put_u8(0u8);
+        buf.put_u32(0u32);
+        buf.put_u16(0u16);
+        buf.put_u16(self.de_records.len() as u16);
+        for record in &self.de_records {
+            record.serialize(buf);
+        }
+
+        self.body_length()
+    }
+}
+
+impl Serialize for DERecord {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        match self {
+            DERecord::PrecisionAimpoint(record) => record.serialize(buf),
+            DERecord::AreaAimpoint(record) => record.serialize(buf),
+            DERecord::Unspecified(record) => record.serialize(buf),
+        }
+    }
+}
+
+impl Serialize for DEPrecisionAimpoint {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        buf.put_u32(PRECISION_AIMPOINT_RECORD_TYPE);
+        buf.put_u16(self.record_length());
+        buf.put_u16(0u16);
+        self.target_spot_location.serialize(buf);
+        self.target_spot_entity_location.serialize(buf);
+        self.target_spot_velocity.serialize(buf);
+        self.target_spot_acceleration.serialize(buf);
+        self.target_entity_id.serialize(buf);
+        buf.put_u8(self.target_component_id.into());
+        buf.put_u8(self.beam_spot_type.into());
+        buf.put_f32(self.beam_spot_cross_section_semi_major_axis);
+        buf.put_f32(self.beam_spot_cross_section_semi_minor_axis);
+        buf.put_f32(self.beam_spot_cross_section_orientation_angle);
+        buf.put_f32(self.peak_irradiance);
+        buf.put_u32(0u32);
+
+        self.record_length()
+    }
+}
+
+impl Serialize for DEAreaAimpoint {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        let lengths = self.padded_record_lengths();
+
+        buf.put_u32(AREA_AIMPOINT_RECORD_TYPE);
+        buf.put_u16(lengths.record_length as u16);
+        buf.put_u16(0u16);
+        buf.put_u16(self.beam_antenna_patterns.len() as u16);
+        buf.put_u16(self.target_energy_depositions.len() as u16);
+        for pattern in &self.beam_antenna_patterns {
+            pattern.serialize(buf);
+        }
+        for deposition in &self.target_energy_depositions {
+            deposition.serialize(buf);
+        }
+        buf.put_bytes(0u8, lengths.padding_length);
+
+        lengths.record_length as u16
+    }
+}
+
+impl Serialize for DETargetEnergyDeposition {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        self.target_entity_id.serialize(buf);
+        buf.put_u16(0u16);
+        buf.put_f32(self.peak_irradiance);
+
+        self.record_length()
+    }
+}
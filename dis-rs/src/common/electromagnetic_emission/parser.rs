@@ -4,130 +4,144 @@
 };
 use crate::common::model::{PduBody, PduHeader};
 use crate::common::parser;
+use crate::common::parser::IResult;
 use crate::common::parser::{entity_id, event_id, vec3_f32};
 use crate::enumerations::{
     BeamStatusBeamState, ElectromagneticEmissionBeamFunction,
     ElectromagneticEmissionStateUpdateIndicator, EmitterName, EmitterSystemFunction,
     HighDensityTrackJam,
 };
+use nom::error::context;
 use nom::multi::count;
 use nom::number::complete::{be_f32, be_u16, be_u8};
-use nom::IResult;
 
 pub(crate) fn emission_body(_header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[u8], PduBody> + '_ {
     move |input| {
-        let (input, emitting_entity_id) = entity_id(input)?;
-        let (input, event_id) = event_id(input)?;
-        let (input, status_update_indicator) = be_u8(input)?;
-        let (input, no_of_systems) = be_u8(input)?;
-        let (input, _pad_16) = be_u16(input)?;
+        context("Emission Body", |input| {
+            let (input, emitting_entity_id) = entity_id(input)?;
+            let (input, event_id) = event_id(input)?;
+            let (input, status_update_indicator) = be_u8(input)?;
+            let (input, no_of_systems) = be_u8(input)?;
+            let (input, _pad_16) = be_u16(input)?;
 
-        let (input, mut emitter_systems) = count(emitter_system, no_of_systems as usize)(input)?;
+            let (input, mut emitter_systems) =
+                count(emitter_system, no_of_systems as usize)(input)?;
 
-        let body = ElectromagneticEmission::builder()
-            .with_emitting_entity_id(emitting_entity_id)
-            .with_event_id(event_id)
-            .with_state_update_indicator(ElectromagneticEmissionStateUpdateIndicator::from(
-                status_update_indicator,
-            ))
-            .with_emitter_systems(&mut emitter_systems)
-            .build();
+            let body = ElectromagneticEmission::builder()
+                .with_emitting_entity_id(emitting_entity_id)
+                .with_event_id(event_id)
+                .with_state_update_indicator(ElectromagneticEmissionStateUpdateIndicator::from(
+                    status_update_indicator,
+                ))
+                .with_emitter_systems(&mut emitter_systems)
+                .build();
 
-        Ok((input, body.into_pdu_body()))
+            Ok((input, body.into_pdu_body()))
+        })(input)
     }
 }
 
 pub(crate) fn emitter_system(input: &[u8]) -> IResult<&[u8], EmitterSystem> {
-    let (input, _system_data_length) = be_u8(input)?;
-    let (input, no_of_beams) = be_u8(input)?;
-    let (input, _pad_16) = be_u16(input)?;
-    let (input, name) = be_u16(input)?;
-    let (input, function) = be_u8(input)?;
-    let (input, number) = be_u8(input)?;
-    let (input, location) = vec3_f32(input)?;
+    context("Emitter System", |input| {
+        let (input, _system_data_length) = be_u8(input)?;
+        let (input, no_of_beams) = be_u8(input)?;
+        let (input, _pad_16) = be_u16(input)?;
+        let (input, name) = be_u16(input)?;
+        let (input, function) = be_u8(input)?;
+        let (input, number) = be_u8(input)?;
+        let (input, location) = vec3_f32(input)?;
 
-    let (input, mut beams) = count(beam, no_of_beams as usize)(input)?;
+        let (input, mut beams) = count(beam, no_of_beams as usize)(input)?;
 
-    let system = EmitterSystem::new()
-        .with_name(EmitterName::from(name))
-        .with_function(EmitterSystemFunction::from(function))
-        .with_number(number)
-        .with_location(location)
-        .with_beams(&mut beams);
+        let system = EmitterSystem::new()
+            .with_name(EmitterName::from(name))
+            .with_function(EmitterSystemFunction::from(function))
+            .with_number(number)
+            .with_location(location)
+            .with_beams(&mut beams);
 
-    Ok((input, system))
+        Ok((input, system))
+    })(input)
 }
 
 pub(crate) fn beam(input: &[u8]) -> IResult<&[u8], Beam> {
-    let (input, _data_length) = be_u8(input)?;
-    let (input, number) = be_u8(input)?;
-    let (input, parameter_index) = be_u16(input)?;
-    let (input, fundamental_parameter_data) = fundamental_parameter_data(input)?;
-    let (input, beam_data) = parser::beam_data(input)?;
-    let (input, function) = be_u8(input)?;
-    let (input, no_of_targets) = be_u8(input)?;
-    let (input, high_density_track_jam) = be_u8(input)?;
-    let (input, status) = be_u8(input)?;
-    let (input, jamming_technique) = jamming_technique(input)?;
-    let (input, mut track_jams) = count(track_jam, no_of_targets as usize)(input)?;
+    context("Beam", |input| {
+        let (input, _data_length) = be_u8(input)?;
+        let (input, number) = be_u8(input)?;
+        let (input, parameter_index) = be_u16(input)?;
+        let (input, fundamental_parameter_data) = fundamental_parameter_data(input)?;
+        let (input, beam_data) = parser::beam_data(input)?;
+        let (input, function) = be_u8(input)?;
+        let (input, no_of_targets) = be_u8(input)?;
+        let (input, high_density_track_jam) = be_u8(input)?;
+        let (input, status) = be_u8(input)?;
+        let (input, jamming_technique) = jamming_technique(input)?;
+        let (input, mut track_jams) = count(track_jam, no_of_targets as usize)(input)?;
 
-    let beam = Beam::new()
-        .with_number(number)
-        .with_parameter_index(parameter_index)
-        .with_parameter_data(fundamental_parameter_data)
-        .with_beam_data(beam_data)
-        .with_beam_function(ElectromagneticEmissionBeamFunction::from(function))
-        .with_high_density_track_jam(HighDensityTrackJam::from(high_density_track_jam))
-        .with_beam_status(BeamStatusBeamState::from(status))
-        .with_jamming_technique(jamming_technique)
-        .with_track_jams(&mut track_jams);
+        let beam = Beam::new()
+            .with_number(number)
+            .with_parameter_index(parameter_index)
+            .with_parameter_data(fundamental_parameter_data)
+            .with_beam_data(beam_data)
+            .with_beam_function(ElectromagneticEmissionBeamFunction::from(function))
+            .with_high_density_track_jam(HighDensityTrackJam::from(high_density_track_jam))
+            .with_beam_status(BeamStatusBeamState::from(status))
+            .with_jamming_technique(jamming_technique)
+            .with_track_jams(&mut track_jams);
 
-    Ok((input, beam))
+        Ok((input, beam))
+    })(input)
 }
 
 pub(crate) fn fundamental_parameter_data(input: &[u8]) -> IResult<&[u8], FundamentalParameterData> {
-    let (input, frequency) = be_f32(input)?;
-    let (input, frequency_range) = be_f32(input)?;
-    let (input, effective_power) = be_f32(input)?;
-    let (input, pulse_repetition_frequency) = be_f32(input)?;
-    let (input, pulse_width) = be_f32(input)?;
+    context("Fundamental Parameter Data", |input| {
+        let (input, frequency) = be_f32(input)?;
+        let (input, frequency_range) = be_f32(input)?;
+        let (input, effective_power) = be_f32(input)?;
+        let (input, pulse_repetition_frequency) = be_f32(input)?;
+        let (input, pulse_width) = be_f32(input)?;
 
-    let data = FundamentalParameterData::new()
-        .with_frequency(frequency)
-        .with_frequency_range(frequency_range)
-        .with_effective_power(effective_power)
-        .with_pulse_repetition_frequency(pulse_repetition_frequency)
-        .with_pulse_width(pulse_width);
+        let data = FundamentalParameterData::new()
+            .with_frequency(frequency)
+            .with_frequency_range(frequency_range)
+            .with_effective_power(effective_power)
+            .with_pulse_repetition_frequency(pulse_repetition_frequency)
+            .with_pulse_width(pulse_width);
 
-    Ok((input, data))
+        Ok((input, data))
+    })(input)
 }
 
 pub(crate) fn jamming_technique(input: &[u8]) -> IResult<&[u8], JammingTechnique> {
-    let (input, kind) = be_u8(input)?;
-    let (input, category) = be_u8(input)?;
-    let (input, subcategory) = be_u8(input)?;
-    let (input, specific) = be_u8(input)?;
+    context("Jamming Technique", |input| {
+        let (input, kind) = be_u8(input)?;
+        let (input, category) = be_u8(input)?;
+        let (input, subcategory) = be_u8(input)?;
+        let (input, specific) = be_u8(input)?;
 
-    let technique = JammingTechnique::new()
-        .with_kind(kind)
-        .with_category(category)
-        .with_subcategory(subcategory)
-        .with_specific(specific);
+        let technique = JammingTechnique::new()
+            .with_kind(kind)
+            .with_category(category)
+            .with_subcategory(subcategory)
+            .with_specific(specific);
 
-    Ok((input, technique))
+        Ok((input, technique))
+    })(input)
 }
 
 pub(crate) fn track_jam(input: &[u8]) -> IResult<&[u8], TrackJam> {
-    let (input, entity_id) = entity_id(input)?;
-    let (input, emitter_number) = be_u8(input)?;
-    let (input, beam_number) = be_u8(input)?;
+    context("Track Jam", |input| {
+        let (input, entity_id) = entity_id(input)?;
+        let (input, emitter_number) = be_u8(input)?;
+        let (input, beam_number) = be_u8(input)?;
 
-    let track = TrackJam::new()
-        .with_entity_id(entity_id)
-        .with_emitter(emitter_number)
-        .with_beam(beam_number);
+        let track = TrackJam::new()
+            .with_entity_id(entity_id)
+            .with_emitter(emitter_number)
+            .with_beam(beam_number);
 
-    Ok((input, track))
+        Ok((input, track))
+    })(input)
 }
 
 #[cfg(test)]
@@ -3,10 +3,10 @@
     TrackJam,
 };
 use crate::common::{Serialize, SerializePdu, SupportedVersion};
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 
 impl SerializePdu for ElectromagneticEmission {
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
         let entity_bytes = self.emitting_entity_id.serialize(buf);
         let event_bytes = self.event_id.serialize(buf);
         buf.put_u8(self.state_update_indicator.into());
@@ -23,7 +23,7 @@ fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for EmitterSystem {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let system_length_in_words = self.system_data_length_bytes() / 4;
         buf.put_u8(system_length_in_words as u8);
         buf.put_u8(self.beams.len() as u8);
@@ -44,7 +44,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for Beam {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let beam_length_in_words = self.beam_data_length_bytes() / 4;
         buf.put_u8(beam_length_in_words as u8);
         buf.put_u8(self.number);
@@ -68,7 +68,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for FundamentalParameterData {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_f32(self.frequency);
         buf.put_f32(self.frequency_range);
         buf.put_f32(self.effective_power);
@@ -80,7 +80,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for JammingTechnique {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u8(self.kind);
         buf.put_u8(self.category);
         buf.put_u8(self.subcategory);
@@ -91,7 +91,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for TrackJam {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let entity_bytes = self.entity_id.serialize(buf);
         buf.put_u8(self.emitter);
         buf.put_u8(self.beam);
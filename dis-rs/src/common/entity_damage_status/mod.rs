@@ -0,0 +1,73 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::model::DisTimeStamp;
+    use crate::common::model::{Pdu, PduHeader};
+    use crate::common::parser::parse_pdu;
+    use crate::entity_damage_status::model::{
+        ComponentDamageStatus, ComponentIdentification, ComponentVisualDamageStatus,
+        ComponentVisualSmokeColor, DamageDescriptionRecord, DirectedEnergyDamage,
+        EntityDamageStatus, SurfaceDamage,
+    };
+    use crate::enumerations::{CoupledExtensionIndicator, LvcIndicator, PduType};
+    use crate::model::{EntityId, EventId, SimulationAddress, StandardVariableRecord, VectorF32};
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+
+    #[test]
+    fn entity_damage_status_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::EntityDamageStatus).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::NoStatement)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = EntityDamageStatus::builder()
+            .with_damaged_entity_id(EntityId::new(10, 10, 10))
+            .with_damage_description_record(
+                DirectedEnergyDamage::default()
+                    .with_damage_location(VectorF32::new(1.0, 0.5, -0.5))
+                    .with_damage_diameter(0.1)
+                    .with_temperature(450.0)
+                    .with_component_identification(ComponentIdentification::AcquisitionSensor)
+                    .with_component_damage_status(ComponentDamageStatus::MajorDamage)
+                    .with_component_visual_damage_status(
+                        ComponentVisualDamageStatus::default()
+                            .with_surface_damage(SurfaceDamage::HeavyCharring),
+                    )
+                    .with_component_visual_smoke_color(ComponentVisualSmokeColor::Gray)
+                    .with_fire_event_id(EventId::new(SimulationAddress::new(20, 20), 5))
+                    .into_record(),
+            )
+            .with_damage_description_record(DamageDescriptionRecord::Unspecified(
+                StandardVariableRecord::new(5000, vec![1, 2]),
+            ))
+            .build()
+            .into_pdu_body();
+
+        let original_pdu =
+            Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+}
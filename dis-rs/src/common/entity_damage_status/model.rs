@@ -0,0 +1,364 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::entity_damage_status::builder::EntityDamageStatusBuilder;
+use crate::enumerations::PduType;
+use crate::model::{EntityId, EventId, PduBody, StandardVariableRecord, VectorF32};
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const BASE_ENTITY_DAMAGE_STATUS_BODY_LENGTH: u16 = 12;
+pub(crate) const DIRECTED_ENERGY_DAMAGE_RECORD_TYPE: u32 = 4500;
+pub(crate) const DIRECTED_ENERGY_DAMAGE_RECORD_LENGTH: u16 = 40;
+
+/// 7.3.5 Entity Damage Status PDU
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EntityDamageStatus {
+    pub damaged_entity_id: EntityId,
+    pub damage_description_records: Vec<DamageDescriptionRecord>,
+}
+
+impl EntityDamageStatus {
+    #[must_use]
+    pub fn builder() -> EntityDamageStatusBuilder {
+        EntityDamageStatusBuilder::new()
+    }
+
+    #[must_use]
+    pub fn into_builder(self) -> EntityDamageStatusBuilder {
+        EntityDamageStatusBuilder::new_from_body(self)
+    }
+
+    #[must_use]
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::EntityDamageStatus(self)
+    }
+}
+
+impl BodyInfo for EntityDamageStatus {
+    fn body_length(&self) -> u16 {
+        BASE_ENTITY_DAMAGE_STATUS_BODY_LENGTH
+            + self
+                .damage_description_records
+                .iter()
+                .map(DamageDescriptionRecord::record_length)
+                .sum::<u16>()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::EntityDamageStatus
+    }
+}
+
+impl Interaction for EntityDamageStatus {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.damaged_entity_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// Damage Description records of the Entity Damage Status PDU
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DamageDescriptionRecord {
+    DirectedEnergy(DirectedEnergyDamage),
+    Unspecified(StandardVariableRecord),
+}
+
+impl DamageDescriptionRecord {
+    #[must_use]
+    pub fn record_length(&self) -> u16 {
+        match self {
+            DamageDescriptionRecord::DirectedEnergy(record) => record.record_length(),
+            DamageDescriptionRecord::Unspecified(record) => record.record_length(),
+        }
+    }
+}
+
+/// 6.2.15 Directed Energy Damage record
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DirectedEnergyDamage {
+    pub damage_location: VectorF32,
+    pub damage_diameter: f32,
+    pub temperature: f32,
+    pub component_identification: ComponentIdentification,
+    pub component_damage_status: ComponentDamageStatus,
+    pub component_visual_damage_status: ComponentVisualDamageStatus,
+    pub component_visual_smoke_color: ComponentVisualSmokeColor,
+    pub fire_event_id: EventId,
+}
+
+impl DirectedEnergyDamage {
+    #[must_use]
+    pub fn with_damage_location(mut self, damage_location: VectorF32) -> Self {
+        self.damage_location = damage_location;
+        self
+    }
+
+    #[must_use]
+    pub fn with_damage_diameter(mut self, damage_diameter: f32) -> Self {
+        self.damage_diameter = damage_diameter;
+        self
+    }
+
+    #[must_use]
+    pub fn with_temperature(mut self, temperature: f32) -> Self {
+        self.temperature = temperature;
+        self
+    }
+
+    #[must_use]
+    pub fn with_component_identification(
+        mut self,
+        component_identification: ComponentIdentification,
+    ) -> Self {
+        self.component_identification = component_identification;
+        self
+    }
+
+    #[must_use]
+    pub fn with_component_damage_status(
+        mut self,
+        component_damage_status: ComponentDamageStatus,
+    ) -> Self {
+        self.component_damage_status = component_damage_status;
+        self
+    }
+
+    #[must_use]
+    pub fn with_component_visual_damage_status(
+        mut self,
+        component_visual_damage_status: ComponentVisualDamageStatus,
+    ) -> Self {
+        self.component_visual_damage_status = component_visual_damage_status;
+        self
+    }
+
+    #[must_use]
+    pub fn with_component_visual_smoke_color(
+        mut self,
+        component_visual_smoke_color: ComponentVisualSmokeColor,
+    ) -> Self {
+        self.component_visual_smoke_color = component_visual_smoke_color;
+        self
+    }
+
+    #[must_use]
+    pub fn with_fire_event_id(mut self, fire_event_id: EventId) -> Self {
+        self.fire_event_id = fire_event_id;
+        self
+    }
+
+    #[must_use]
+    pub fn into_record(self) -> DamageDescriptionRecord {
+        DamageDescriptionRecord::DirectedEnergy(self)
+    }
+
+    #[must_use]
+    pub const fn record_length(&self) -> u16 {
+        DIRECTED_ENERGY_DAMAGE_RECORD_LENGTH
+    }
+}
+
+/// Component Identification (SISO-REF-010), identifying the damaged or targeted component of an entity
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ComponentIdentification {
+    #[default]
+    EntityCenter,
+    EntityStructure,
+    ControlSystem,
+    ControlSurface,
+    EnginePropulsionSystem,
+    CrewMember,
+    Fuse,
+    AcquisitionSensor,
+    TrackingSensor,
+    FuelTankSolidRocketMotor,
+    Unspecified(u8),
+}
+
+impl From<u8> for ComponentIdentification {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::EntityCenter,
+            1 => Self::EntityStructure,
+            2 => Self::ControlSystem,
+            3 => Self::ControlSurface,
+            4 => Self::EnginePropulsionSystem,
+            5 => Self::CrewMember,
+            6 => Self::Fuse,
+            7 => Self::AcquisitionSensor,
+            8 => Self::TrackingSensor,
+            9 => Self::FuelTankSolidRocketMotor,
+            other => Self::Unspecified(other),
+        }
+    }
+}
+
+impl From<ComponentIdentification> for u8 {
+    fn from(value: ComponentIdentification) -> Self {
+        match value {
+            ComponentIdentification::EntityCenter => 0,
+            ComponentIdentification::EntityStructure => 1,
+            ComponentIdentification::ControlSystem => 2,
+            ComponentIdentification::ControlSurface => 3,
+            ComponentIdentification::EnginePropulsionSystem => 4,
+            ComponentIdentification::CrewMember => 5,
+            ComponentIdentification::Fuse => 6,
+            ComponentIdentification::AcquisitionSensor => 7,
+            ComponentIdentification::TrackingSensor => 8,
+            ComponentIdentification::FuelTankSolidRocketMotor => 9,
+            ComponentIdentification::Unspecified(value) => value,
+        }
+    }
+}
+
+/// Component Damage Status (SISO-REF-010)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ComponentDamageStatus {
+    #[default]
+    NoDamage,
+    MinorDamage,
+    MediumDamage,
+    MajorDamage,
+    Destroyed,
+    Unspecified(u8),
+}
+
+impl From<u8> for ComponentDamageStatus {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::NoDamage,
+            1 => Self::MinorDamage,
+            2 => Self::MediumDamage,
+            3 => Self::MajorDamage,
+            4 => Self::Destroyed,
+            other => Self::Unspecified(other),
+        }
+    }
+}
+
+impl From<ComponentDamageStatus> for u8 {
+    fn from(value: ComponentDamageStatus) -> Self {
+        match value {
+            ComponentDamageStatus::NoDamage => 0,
+            ComponentDamageStatus::MinorDamage => 1,
+            ComponentDamageStatus::MediumDamage => 2,
+            ComponentDamageStatus::MajorDamage => 3,
+            ComponentDamageStatus::Destroyed => 4,
+            ComponentDamageStatus::Unspecified(value) => value,
+        }
+    }
+}
+
+/// Component Visual Smoke Color (SISO-REF-010)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ComponentVisualSmokeColor {
+    #[default]
+    NoSmoke,
+    White,
+    Gray,
+    Black,
+    Unspecified(u8),
+}
+
+impl From<u8> for ComponentVisualSmokeColor {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::NoSmoke,
+            1 => Self::White,
+            2 => Self::Gray,
+            3 => Self::Black,
+            other => Self::Unspecified(other),
+        }
+    }
+}
+
+impl From<ComponentVisualSmokeColor> for u8 {
+    fn from(value: ComponentVisualSmokeColor) -> Self {
+        match value {
+            ComponentVisualSmokeColor::NoSmoke => 0,
+            ComponentVisualSmokeColor::White => 1,
+            ComponentVisualSmokeColor::Gray => 2,
+            ComponentVisualSmokeColor::Black => 3,
+            ComponentVisualSmokeColor::Unspecified(value) => value,
+        }
+    }
+}
+
+/// Component Visual Damage Status (SISO-REF-010)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ComponentVisualDamageStatus {
+    pub is_firing: bool,
+    pub surface_damage: SurfaceDamage,
+}
+
+impl ComponentVisualDamageStatus {
+    #[must_use]
+    pub fn with_firing(mut self, is_firing: bool) -> Self {
+        self.is_firing = is_firing;
+        self
+    }
+
+    #[must_use]
+    pub fn with_surface_damage(mut self, surface_damage: SurfaceDamage) -> Self {
+        self.surface_damage = surface_damage;
+        self
+    }
+}
+
+impl From<u8> for ComponentVisualDamageStatus {
+    fn from(value: u8) -> Self {
+        Self {
+            is_firing: (value & 0x01) != 0,
+            surface_damage: SurfaceDamage::from((value >> 1) & 0x03),
+        }
+    }
+}
+
+impl From<ComponentVisualDamageStatus> for u8 {
+    fn from(value: ComponentVisualDamageStatus) -> Self {
+        u8::from(value.is_firing) | (u8::from(value.surface_damage) & 0x03) << 1
+    }
+}
+
+/// Surface Damage field of the Component Visual Damage Status
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SurfaceDamage {
+    #[default]
+    NormalAppearance,
+    LightCharring,
+    HeavyCharring,
+    OneOrMoreHolesBurned,
+}
+
+impl From<u8> for SurfaceDamage {
+    fn from(value: u8) -> Self {
+        match value & 0x03 {
+            0 => Self::NormalAppearance,
+            1 => Self::LightCharring,
+            2 => Self::HeavyCharring,
+            _ => Self::OneOrMoreHolesBurned,
+        }
+    }
+}
+
+impl From<SurfaceDamage> for u8 {
+    fn from(value: SurfaceDamage) -> Self {
+        match value {
+            SurfaceDamage::NormalAppearance => 0,
+            SurfaceDamage::LightCharring => 1,
+            SurfaceDamage::HeavyCharring => 2,
+            SurfaceDamage::OneOrMoreHolesBurned => 3,
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::common::parser::IResult;
+use crate::common::parser::{entity_id, event_id, standard_variable_record, vec3_f32};
+use crate::entity_damage_status::model::{
+    ComponentDamageStatus, ComponentIdentification, ComponentVisualDamageStatus,
+    ComponentVisualSmokeColor, DamageDescriptionRecord, DirectedEnergyDamage, EntityDamageStatus,
+    DIRECTED_ENERGY_DAMAGE_RECORD_TYPE,
+};
+use crate::model::PduBody;
+use nom::combinator::peek;
+use nom::error::context;
+use nom::multi::count;
+use nom::number::complete::{be_f32, be_u16, be_u32, be_u8};
+
+pub(crate) fn entity_damage_status_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    context("Entity Damage Status Body", |input| {
+        let (input, damaged_entity_id) = entity_id(input)?;
+        let (input, _padding) = be_u16(input)?;
+        let (input, _padding) = be_u16(input)?;
+        let (input, number_of_records) = be_u16(input)?;
+        let (input, damage_description_records) =
+            count(damage_description_record, number_of_records.into())(input)?;
+
+        let body = EntityDamageStatus::builder()
+            .with_damaged_entity_id(damaged_entity_id)
+            .with_damage_description_records(damage_description_records)
+            .build();
+
+        Ok((input, body.into_pdu_body()))
+    })(input)
+}
+
+fn damage_description_record(input: &[u8]) -> IResult<&[u8], DamageDescriptionRecord> {
+    context("Damage Description Record", |input| {
+        let (_, record_type) = peek(be_u32)(input)?;
+
+        if record_type == DIRECTED_ENERGY_DAMAGE_RECORD_TYPE {
+            let (input, record) = directed_energy_damage(input)?;
+            Ok((input, DamageDescriptionRecord::DirectedEnergy(record)))
+        } else {
+            let (input, record) = standard_variable_record(input)?;
+            Ok((input, DamageDescriptionRecord::Unspecified(record)))
+        }
+    })(input)
+}
+
+fn directed_energy_damage(input: &[u8]) -> IResult<&[u8], DirectedEnergyDamage> {
+    context("Directed Energy Damage", |input| {
+        let (input, _record_type) = be_u32(input)?;
+        let (input, _record_length) = be_u16(input)?;
+        let (input, _padding) = be_u16(input)?;
+        let (input, damage_location) = vec3_f32(input)?;
+        let (input, damage_diameter) = be_f32(input)?;
+        let (input, temperature) = be_f32(input)?;
+        let (input, component_identification) = be_u8(input)?;
+        let (input, component_damage_status) = be_u8(input)?;
+        let (input, component_visual_damage_status) = be_u8(input)?;
+        let (input, component_visual_smoke_color) = be_u8(input)?;
+        let (input, fire_event_id) = event_id(input)?;
+        let (input, _padding) = be_u16(input)?;
+
+        Ok((
+            input,
+            DirectedEnergyDamage::default()
+                .with_damage_location(damage_location)
+                .with_damage_diameter(damage_diameter)
+                .with_temperature(temperature)
+                .with_component_identification(ComponentIdentification::from(
+                    component_identification,
+                ))
+                .with_component_damage_status(ComponentDamageStatus::from(component_damage_status))
+                .with_component_visual_damage_status(ComponentVisualDamageStatus::from(
+                    component_visual_damage_status,
+                ))
+                .with_component_visual_smoke_color(ComponentVisualSmokeColor::from(
+                    component_visual_smoke_color,
+                ))
+                .with_fire_event_id(fire_event_id),
+        ))
+    })(input)
+}
@@ -0,0 +1,49 @@
+use crate::common::BodyInfo;
+use crate::entity_damage_status::model::{
+    DamageDescriptionRecord, DirectedEnergyDamage, EntityDamageStatus,
+    DIRECTED_ENERGY_DAMAGE_RECORD_TYPE,
+};
+use crate::{Serialize, SerializePdu, SupportedVersion};
+use bytes::BufMut;
+
+impl SerializePdu for EntityDamageStatus {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
+        self.damaged_entity_id.serialize(buf);
+        buf.put_u16(0u16);
+        buf.put_u16(0u16);
+        buf.put_u16(self.damage_description_records.len() as u16);
+        for record in &self.damage_description_records {
+            record.serialize(buf);
+        }
+
+        self.body_length()
+    }
+}
+
+impl Serialize for DamageDescriptionRecord {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        match self {
+            DamageDescriptionRecord::DirectedEnergy(record) => record.serialize(buf),
+            DamageDescriptionRecord::Unspecified(record) => record.serialize(buf),
+        }
+    }
+}
+
+impl Serialize for DirectedEnergyDamage {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        buf.put_u32(DIRECTED_ENERGY_DAMAGE_RECORD_TYPE);
+        buf.put_u16(self.record_length());
+        buf.put_u16(0u16);
+        self.damage_location.serialize(buf);
+        buf.put_f32(self.damage_diameter);
+        buf.put_f32(self.temperature);
+        buf.put_u8(self.component_identification.into());
+        buf.put_u8(self.component_damage_status.into());
+        buf.put_u8(self.component_visual_damage_status.into());
+        buf.put_u8(self.component_visual_smoke_color.into());
+        self.fire_event_id.serialize(buf);
+        buf.put_u16(0u16);
+
+        self.record_length()
+    }
+}
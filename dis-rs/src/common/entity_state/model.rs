@@ -13,18 +13,16 @@
     SupplyAppearance, SurfacePlatformAppearance,
 };
 use crate::DisError;
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::str::FromStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::str::FromStr;
 
 const BASE_ENTITY_STATE_BODY_LENGTH: u16 = 132;
 
-// TODO sensible errors for EntityState
-#[allow(dead_code)]
-pub enum EntityStateValidationError {
-    SomeFieldNotOkError,
-}
-
 /// 5.3.2 Entity State PDU
 ///
 /// 7.2.2 Entity State PDU
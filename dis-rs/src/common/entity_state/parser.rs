@@ -4,82 +4,95 @@
 };
 use crate::common::model::{EntityType, PduBody, PduHeader};
 use crate::common::parser;
+use crate::common::parser::IResult;
 use crate::common::parser::{entity_id, entity_type, sanitize_marking, vec3_f32};
-use crate::enumerations::{
-    DeadReckoningAlgorithm, EntityMarkingCharacterSet, ForceId, ProtocolVersion,
-};
+use crate::common::SupportedVersion;
+use crate::enumerations::{DeadReckoningAlgorithm, EntityMarkingCharacterSet, ForceId};
 use crate::v6::entity_state::parser::entity_capabilities;
+use alloc::vec;
 use nom::bytes::complete::take;
+use nom::error::context;
 use nom::multi::count;
 use nom::number::complete::{be_f32, be_u16, be_u32, be_u8};
-use nom::IResult;
 
 pub(crate) fn entity_state_body(
     header: &PduHeader,
 ) -> impl Fn(&[u8]) -> IResult<&[u8], PduBody> + '_ {
     move |input: &[u8]| {
-        let (input, entity_id_val) = entity_id(input)?;
-        let (input, force_id_val) = force_id(input)?;
-        let (input, variable_parameters_no) = be_u8(input)?;
-        let (input, entity_type_val) = entity_type(input)?;
-        let (input, alternative_entity_type) = entity_type(input)?;
-        let (input, entity_linear_velocity) = vec3_f32(input)?;
-        let (input, entity_location) = parser::location(input)?;
-        let (input, entity_orientation) = parser::orientation(input)?;
-        let (input, entity_appearance) = entity_appearance(entity_type_val)(input)?;
-        let (input, dead_reckoning_parameters) = dr_parameters(input)?;
-        let (input, entity_marking) = entity_marking(input)?;
-        #[allow(clippy::wildcard_in_or_patterns)]
-        let (input, entity_capabilities) =
-            if header.protocol_version == ProtocolVersion::IEEE1278_12012 {
-                crate::v7::entity_state::parser::entity_capabilities(entity_type_val)(input)?
+        context("Entity State Body", |input| {
+            let (input, entity_id_val) = entity_id(input)?;
+            let (input, force_id_val) = force_id(input)?;
+            let (input, variable_parameters_no) = be_u8(input)?;
+            let (input, entity_type_val) = entity_type(input)?;
+            let (input, alternative_entity_type) = entity_type(input)?;
+            let (input, entity_linear_velocity) = vec3_f32(input)?;
+            let (input, entity_location) = parser::location(input)?;
+            let (input, entity_orientation) = parser::orientation(input)?;
+            let (input, entity_appearance) = entity_appearance(entity_type_val)(input)?;
+            let (input, dead_reckoning_parameters) = dr_parameters(input)?;
+            let (input, entity_marking) = entity_marking(input)?;
+            // Versions 4 and 5 use the Entity Capabilities record of version 6
+            let (input, entity_capabilities) = match SupportedVersion::from(header.protocol_version)
+            {
+                SupportedVersion::V7 => {
+                    crate::v7::entity_state::parser::entity_capabilities(entity_type_val)(input)?
+                }
+                SupportedVersion::V4
+                | SupportedVersion::V5
+                | SupportedVersion::V6
+                | SupportedVersion::Unsupported => {
+                    let (input, entity_capabilities) = entity_capabilities(input)?;
+                    (
+                        input,
+                        crate::enumerations::EntityCapabilities::from(entity_capabilities),
+                    )
+                }
+            };
+            let (input, variable_parameters) = if variable_parameters_no > 0 {
+                count(parser::variable_parameter, variable_parameters_no as usize)(input)?
             } else {
-                let (input, entity_capabilities) = entity_capabilities(input)?;
-                (
-                    input,
-                    crate::enumerations::EntityCapabilities::from(entity_capabilities),
-                )
+                (input, vec![])
             };
-        let (input, variable_parameters) = if variable_parameters_no > 0 {
-            count(parser::variable_parameter, variable_parameters_no as usize)(input)?
-        } else {
-            (input, vec![])
-        };
 
-        let body = EntityState::builder()
-            .with_entity_id(entity_id_val)
-            .with_force_id(force_id_val)
-            .with_entity_type(entity_type_val)
-            .with_alternative_entity_type(alternative_entity_type)
-            .with_velocity(entity_linear_velocity)
-            .with_location(entity_location)
-            .with_orientation(entity_orientation)
-            .with_appearance(entity_appearance)
-            .with_dead_reckoning_parameters(dead_reckoning_parameters)
-            .with_marking(entity_marking)
-            .with_capabilities(entity_capabilities)
-            .with_variable_parameters(variable_parameters)
-            .build();
-
-        Ok((input, body.into_pdu_body()))
+            let body = EntityState::builder()
+                .with_entity_id(entity_id_val)
+                .with_force_id(force_id_val)
+                .with_entity_type(entity_type_val)
+                .with_alternative_entity_type(alternative_entity_type)
+                .with_velocity(entity_linear_velocity)
+                .with_location(entity_location)
+                .with_orientation(entity_orientation)
+                .with_appearance(entity_appearance)
+                .with_dead_reckoning_parameters(dead_reckoning_parameters)
+                .with_marking(entity_marking)
+                .with_capabilities(entity_capabilities)
+                .with_variable_parameters(variable_parameters)
+                .build();
+
+            Ok((input, body.into_pdu_body()))
+        })(input)
     }
 }
 
 pub(crate) fn force_id(input: &[u8]) -> IResult<&[u8], ForceId> {
-    let (input, force_id) = be_u8(input)?;
-    Ok((input, ForceId::from(force_id)))
+    context("Force ID", |input| {
+        let (input, force_id) = be_u8(input)?;
+        Ok((input, ForceId::from(force_id)))
+    })(input)
 }
 
 pub(crate) fn entity_appearance(
     entity_type: EntityType,
 ) -> impl Fn(&[u8]) -> IResult<&[u8], EntityAppearance> {
     move |input: &[u8]| {
-        let (input, appearance) = be_u32(input)?;
-
-        Ok((
-            input,
-            EntityAppearance::from_bytes(appearance, &entity_type),
-        ))
+        context("Entity Appearance", |input| {
+            let (input, appearance) = be_u32(input)?;
+
+            Ok((
+                input,
+                EntityAppearance::from_bytes(appearance, &entity_type),
+            ))
+        })(input)
     }
 }
 
@@ -88,63 +101,67 @@ pub(crate) fn entity_appearance(
 /// strip trailing whitespace and any trailing non-alphanumeric characters. In case the marking is less
 /// than 11 characters, the trailing bytes are typically 0x00 in the PDU, which in UTF-8 is a control character.
 pub(crate) fn entity_marking(input: &[u8]) -> IResult<&[u8], EntityMarking> {
-    let mut buf: [u8; 11] = [0; 11];
-    let (input, marking_character_set) = be_u8(input)?;
-    let (input, ()) = nom::multi::fill(be_u8, &mut buf)(input)?;
-
-    let marking_character_set = EntityMarkingCharacterSet::from(marking_character_set);
-    let marking_string = sanitize_marking(&buf[..]);
-
-    Ok((
-        input,
-        EntityMarking {
-            marking_character_set,
-            marking_string,
-        },
-    ))
+    context("Entity Marking", |input| {
+        let mut buf: [u8; 11] = [0; 11];
+        let (input, marking_character_set) = be_u8(input)?;
+        let (input, ()) = nom::multi::fill(be_u8, &mut buf)(input)?;
+
+        let marking_character_set = EntityMarkingCharacterSet::from(marking_character_set);
+        let marking_string = sanitize_marking(&buf[..]);
+
+        Ok((
+            input,
+            EntityMarking {
+                marking_character_set,
+                marking_string,
+            },
+        ))
+    })(input)
 }
 
 pub(crate) fn dr_parameters(input: &[u8]) -> IResult<&[u8], DrParameters> {
-    let (input, algorithm) = be_u8(input)?;
-    let algorithm = DeadReckoningAlgorithm::from(algorithm);
-
-    let (input, other_parameters) = dr_other_parameters(input, algorithm)?;
-
-    // // This match statement basically determines the value of the DrParametersType field for Euler and Quaternion variants
-    // let (input, other_parameters) = match algorithm {
-    //     DeadReckoningAlgorithm::StaticNonmovingEntity |
-    //         DeadReckoningAlgorithm::DRM_FPW_ConstantVelocityLowAccelerationLinearMotionEntity |
-    //         DeadReckoningAlgorithm::DRM_FVW_HighSpeedorManeuveringEntity |
-    //         DeadReckoningAlgorithm::DRM_FPB_SimilartoFPWexceptinBodyCoordinates |
-    //         DeadReckoningAlgorithm::DRM_FVB_SimilartoFVWexceptinBodyCoordinates => {
-    //         dr_other_parameters_euler(input)?
-    //     }
-    //     DeadReckoningAlgorithm::DRM_RPW_ConstantVelocityLowAccelerationLinearMotionEntitywithExtrapolationofOrientation |
-    //         DeadReckoningAlgorithm::DRM_RVW_HighSpeedorManeuveringEntitywithExtrapolationofOrientation |
-    //         DeadReckoningAlgorithm::DRM_RPB_SimilartoRPWexceptinBodyCoordinates |
-    //         DeadReckoningAlgorithm::DRM_RVB_SimilartoRVWexceptinBodyCoordinates => {
-    //         dr_other_parameters_quaternion(input)?
-    //     }
-    //     DeadReckoningAlgorithm::Other => {
-    //         dr_other_parameters_none(input)?
-    //     }
-    //     _ => {
-    //         dr_other_parameters_none(input)?
-    //     }
-    // };
-
-    let (input, acceleration) = vec3_f32(input)?;
-    let (input, velocity) = vec3_f32(input)?;
-
-    Ok((
-        input,
-        DrParameters {
-            algorithm,
-            other_parameters,
-            linear_acceleration: acceleration,
-            angular_velocity: velocity,
-        },
-    ))
+    context("Dead Reckoning Parameters", |input| {
+        let (input, algorithm) = be_u8(input)?;
+        let algorithm = DeadReckoningAlgorithm::from(algorithm);
+
+        let (input, other_parameters) = dr_other_parameters(input, algorithm)?;
+
+        // // This match statement basically determines the value of the DrParametersType field for Euler and Quaternion variants
+        // let (input, other_parameters) = match algorithm {
+        //     DeadReckoningAlgorithm::StaticNonmovingEntity |
+        //         DeadReckoningAlgorithm::DRM_FPW_ConstantVelocityLowAccelerationLinearMotionEntity |
+        //         DeadReckoningAlgorithm::DRM_FVW_HighSpeedorManeuveringEntity |
+        //         DeadReckoningAlgorithm::DRM_FPB_SimilartoFPWexceptinBodyCoordinates |
+        //         DeadReckoningAlgorithm::DRM_FVB_SimilartoFVWexceptinBodyCoordinates => {
+        //         dr_other_parameters_euler(input)?
+        //     }
+        //     DeadReckoningAlgorithm::DRM_RPW_ConstantVelocityLowAccelerationLinearMotionEntitywithExtrapolationofOrientation |
+        //         DeadReckoningAlgorithm::DRM_RVW_HighSpeedorManeuveringEntitywithExtrapolationofOrientation |
+        //         DeadReckoningAlgorithm::DRM_RPB_SimilartoRPWexceptinBodyCoordinates |
+        //         DeadReckoningAlgorithm::DRM_RVB_SimilartoRVWexceptinBodyCoordinates => {
+        //         dr_other_parameters_quaternion(input)?
+        //     }
+        //     DeadReckoningAlgorithm::Other => {
+        //         dr_other_parameters_none(input)?
+        //     }
+        //     _ => {
+        //         dr_other_parameters_none(input)?
+        //     }
+        // };
+
+        let (input, acceleration) = vec3_f32(input)?;
+        let (input, velocity) = vec3_f32(input)?;
+
+        Ok((
+            input,
+            DrParameters {
+                algorithm,
+                other_parameters,
+                linear_acceleration: acceleration,
+                angular_velocity: velocity,
+            },
+        ))
+    })(input)
 }
 
 #[allow(clippy::missing_errors_doc)]
@@ -152,68 +169,76 @@ pub fn dr_other_parameters(
     input: &[u8],
     algorithm: DeadReckoningAlgorithm,
 ) -> IResult<&[u8], DrOtherParameters> {
-    // This match statement basically determines the value of the DrParametersType field for Euler and Quaternion variants
-    let (input, other_parameters) = match algorithm {
-        DeadReckoningAlgorithm::StaticNonmovingEntity |
-        DeadReckoningAlgorithm::DRM_FPW_ConstantVelocityLowAccelerationLinearMotionEntity |
-        DeadReckoningAlgorithm::DRM_FVW_HighSpeedOrManeuveringEntity |
-        DeadReckoningAlgorithm::DRM_FPB_SimilarToFPWExceptInBodyCoordinates |
-        DeadReckoningAlgorithm::DRM_FVB_SimilarToFVWExceptInBodyCoordinates => {
-            dr_other_parameters_euler(input)?
-        }
-        DeadReckoningAlgorithm::DRM_RPW_ConstantVelocityLowAccelerationLinearMotionEntityWithExtrapolationOfOrientation |
-        DeadReckoningAlgorithm::DRM_RVW_HighSpeedOrManeuveringEntityWithExtrapolationOfOrientation |
-        DeadReckoningAlgorithm::DRM_RPB_SimilarToRPWExceptInBodyCoordinates |
-        DeadReckoningAlgorithm::DRM_RVB_SimilarToRVWExceptInBodyCoordinates => {
-            dr_other_parameters_quaternion(input)?
-        }
-        DeadReckoningAlgorithm::Other => {
-            dr_other_parameters_none(input)?
-        }
-        DeadReckoningAlgorithm::Unspecified(_) => {
-            dr_other_parameters_none(input)?
-        }
-    };
+    context("Dead Reckoning Other Parameters", |input| {
+        // This match statement basically determines the value of the DrParametersType field for Euler and Quaternion variants
+        let (input, other_parameters) = match algorithm {
+            DeadReckoningAlgorithm::StaticNonmovingEntity |
+            DeadReckoningAlgorithm::DRM_FPW_ConstantVelocityLowAccelerationLinearMotionEntity |
+            DeadReckoningAlgorithm::DRM_FVW_HighSpeedOrManeuveringEntity |
+            DeadReckoningAlgorithm::DRM_FPB_SimilarToFPWExceptInBodyCoordinates |
+            DeadReckoningAlgorithm::DRM_FVB_SimilarToFVWExceptInBodyCoordinates => {
+                dr_other_parameters_euler(input)?
+            }
+            DeadReckoningAlgorithm::DRM_RPW_ConstantVelocityLowAccelerationLinearMotionEntityWithExtrapolationOfOrientation |
+            DeadReckoningAlgorithm::DRM_RVW_HighSpeedOrManeuveringEntityWithExtrapolationOfOrientation |
+            DeadReckoningAlgorithm::DRM_RPB_SimilarToRPWExceptInBodyCoordinates |
+            DeadReckoningAlgorithm::DRM_RVB_SimilarToRVWExceptInBodyCoordinates => {
+                dr_other_parameters_quaternion(input)?
+            }
+            DeadReckoningAlgorithm::Other => {
+                dr_other_parameters_none(input)?
+            }
+            DeadReckoningAlgorithm::Unspecified(_) => {
+                dr_other_parameters_none(input)?
+            }
+        };
 
-    Ok((input, other_parameters))
+        Ok((input, other_parameters))
+    })(input)
 }
 
 pub(crate) fn dr_other_parameters_none(input: &[u8]) -> IResult<&[u8], DrOtherParameters> {
-    let (input, params) = take(15usize)(input)?;
-    Ok((input, DrOtherParameters::None(params.try_into().unwrap())))
+    context("DR Other Parameters None", |input| {
+        let (input, params): (_, &[u8]) = take(15usize)(input)?;
+        Ok((input, DrOtherParameters::None(params.try_into().unwrap())))
+    })(input)
 }
 
 pub(crate) fn dr_other_parameters_euler(input: &[u8]) -> IResult<&[u8], DrOtherParameters> {
-    let (input, _param_type) = be_u8(input)?;
-    let (input, _unused) = be_u16(input)?;
-    let (input, local_yaw) = be_f32(input)?;
-    let (input, local_pitch) = be_f32(input)?;
-    let (input, local_roll) = be_f32(input)?;
-    Ok((
-        input,
-        DrOtherParameters::LocalEulerAngles(DrEulerAngles {
-            local_yaw,
-            local_pitch,
-            local_roll,
-        }),
-    ))
+    context("DR Other Parameters Euler", |input| {
+        let (input, _param_type) = be_u8(input)?;
+        let (input, _unused) = be_u16(input)?;
+        let (input, local_yaw) = be_f32(input)?;
+        let (input, local_pitch) = be_f32(input)?;
+        let (input, local_roll) = be_f32(input)?;
+        Ok((
+            input,
+            DrOtherParameters::LocalEulerAngles(DrEulerAngles {
+                local_yaw,
+                local_pitch,
+                local_roll,
+            }),
+        ))
+    })(input)
 }
 
 pub(crate) fn dr_other_parameters_quaternion(input: &[u8]) -> IResult<&[u8], DrOtherParameters> {
-    let (input, _param_type) = be_u8(input)?;
-    let (input, nil) = be_u16(input)?;
-    let (input, x) = be_f32(input)?;
-    let (input, y) = be_f32(input)?;
-    let (input, z) = be_f32(input)?;
-    Ok((
-        input,
-        DrOtherParameters::WorldOrientationQuaternion(DrWorldOrientationQuaternion {
-            nil,
-            x,
-            y,
-            z,
-        }),
-    ))
+    context("DR Other Parameters Quaternion", |input| {
+        let (input, _param_type) = be_u8(input)?;
+        let (input, nil) = be_u16(input)?;
+        let (input, x) = be_f32(input)?;
+        let (input, y) = be_f32(input)?;
+        let (input, z) = be_f32(input)?;
+        Ok((
+            input,
+            DrOtherParameters::WorldOrientationQuaternion(DrWorldOrientationQuaternion {
+                nil,
+                x,
+                y,
+                z,
+            }),
+        ))
+    })(input)
 }
 
 #[cfg(test)]
@@ -225,6 +250,7 @@ mod tests {
     use crate::common::parser::{location, parse_pdu, variable_parameter};
     use crate::enumerations::*;
     use crate::v6::entity_state::parser::entity_capabilities;
+    use bytes::BytesMut;
 
     #[test]
     fn parse_pdu_entity_state() {
@@ -319,6 +345,69 @@ fn parse_pdu_entity_state() {
         }
     }
 
+    /// An Entity State PDU of version 5 (IEEE 1278.1-1995), with the Entity Capabilities record of version 6.
+    const ENTITY_STATE_V5_BYTES: [u8; 144] = [
+        0x05, 0x01, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x90, 0x00, 0x00, 0x01, 0xf4, 0x03,
+        0x84, 0x00, 0x0e, 0x01, 0x00, 0x01, 0x01, 0x00, 0xe1, 0x01, 0x01, 0x03, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x01,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x54, 0x41, 0x4e, 0x4b, 0x20, 0x20,
+        0x20, 0x20, 0x20, 0x20, 0x20, 0x80, 0x00, 0x00, 0x00,
+    ];
+
+    #[test]
+    fn parse_pdu_entity_state_v5() {
+        let bytes = ENTITY_STATE_V5_BYTES;
+
+        let pdu = parse_pdu(&bytes).unwrap();
+        assert_eq!(pdu.header.protocol_version, ProtocolVersion::IEEE1278_11995);
+        assert_eq!(pdu.header.pdu_type, PduType::EntityState);
+        assert_eq!(pdu.header.pdu_length, 144u16);
+        if let PduBody::EntityState(body) = &pdu.body {
+            assert_eq!(body.entity_id.simulation_address.site_id, 500u16);
+            assert_eq!(body.entity_id.simulation_address.application_id, 900u16);
+            assert_eq!(body.entity_id.entity_id, 14u16);
+            assert_eq!(body.force_id, ForceId::Friendly);
+            assert!(body.variable_parameters.is_empty());
+            assert_eq!(body.entity_marking.marking_string, String::from("TANK"));
+        } else {
+            panic!();
+        }
+
+        // The version 6 Entity Capabilities record is written back unchanged
+        let mut buf = BytesMut::with_capacity(144);
+        pdu.serialize(&mut buf).unwrap();
+        assert_eq!(buf.as_ref(), bytes.as_ref());
+    }
+
+    #[test]
+    fn parse_pdu_entity_state_v4() {
+        let mut bytes = ENTITY_STATE_V5_BYTES;
+        bytes[0] = 0x04;
+
+        let pdu = parse_pdu(&bytes).unwrap();
+        assert_eq!(
+            pdu.header.protocol_version,
+            ProtocolVersion::DISApplicationProtocolsVersion2_0FourthDraft
+        );
+        assert_eq!(pdu.header.pdu_type, PduType::EntityState);
+        assert_eq!(pdu.header.pdu_length, 144u16);
+        if let PduBody::EntityState(body) = &pdu.body {
+            assert_eq!(body.entity_id.entity_id, 14u16);
+            assert_eq!(body.entity_marking.marking_string, String::from("TANK"));
+        } else {
+            panic!();
+        }
+
+        let mut buf = BytesMut::with_capacity(144);
+        pdu.serialize(&mut buf).unwrap();
+        assert_eq!(buf.as_ref(), bytes.as_ref());
+    }
+
     #[test]
     fn parse_entity_location() {
         let bytes: [u8; 24] = [
@@ -4,12 +4,13 @@
 use crate::common::entity_state::model::{DrParameters, EntityMarking, EntityState};
 use crate::common::model::EntityType;
 use crate::common::{Serialize, SerializePdu, SupportedVersion};
+use crate::constants::ELEVEN_OCTETS;
 use crate::enumerations::{DrParametersType, ForceId};
 use crate::v6::entity_state::model::EntityCapabilities;
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 
 impl SerializePdu for EntityState {
-    fn serialize_pdu(&self, version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu(&self, version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
         let entity_id_bytes = self.entity_id.serialize(buf);
         let force_id_bytes = self.force_id.serialize(buf);
         buf.put_u8(self.variable_parameters.len() as u8);
@@ -26,7 +27,7 @@ fn serialize_pdu(&self, version: SupportedVersion, buf: &mut BytesMut) -> u16 {
 
         let marking_bytes = self.entity_marking.serialize(buf);
         let capabilities_bytes = match version {
-            SupportedVersion::V6 => {
+            SupportedVersion::V4 | SupportedVersion::V5 | SupportedVersion::V6 => {
                 let capabilities: EntityCapabilities = self.entity_capabilities.into();
                 capabilities.serialize(buf)
             }
@@ -63,7 +64,7 @@ fn serialize_pdu(&self, version: SupportedVersion, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for EntityAppearance {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let appearance: u32 = u32::from(self);
         buf.put_u32(appearance);
         4
@@ -71,7 +72,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for DrParameters {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u8(self.algorithm.into());
         let other_parameters_bytes = self.other_parameters.serialize(buf);
         let lin_acc_bytes = self.linear_acceleration.serialize(buf);
@@ -81,7 +82,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for DrOtherParameters {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         match self {
             DrOtherParameters::None(bytes) => {
                 for x in bytes {
@@ -96,7 +97,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for DrEulerAngles {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u8(DrParametersType::LocalEulerAngles_Yaw_Pitch_Roll_.into());
         buf.put_u16(0u16);
         buf.put_f32(self.local_yaw);
@@ -107,7 +108,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for DrWorldOrientationQuaternion {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u8(DrParametersType::WorldOrientationQuaternion.into());
         buf.put_u16(self.nil);
         buf.put_f32(self.x);
@@ -118,7 +119,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for ForceId {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let force_id = *self;
         buf.put_u8(force_id.into());
         1
@@ -126,7 +127,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for EntityType {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u8(self.kind.into());
         buf.put_u8(self.domain.into());
         buf.put_u16(self.country.into());
@@ -139,13 +140,15 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for EntityMarking {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u8(self.marking_character_set.into());
-        let num_pad = 11 - self.marking_string.len();
-        let marking = self.marking_string.clone(); // clone necessary because into_bytes consumes self.
+        // markings that are too long are truncated to the length of the field
+        let marking = self.marking_string.as_bytes();
+        let marking = &marking[..marking.len().min(ELEVEN_OCTETS)];
+        let num_pad = ELEVEN_OCTETS - marking.len();
 
-        buf.put_slice(&marking.into_bytes()[..]);
-        (0..num_pad).for_each(|_i| buf.put_u8(0x20));
+        buf.put_slice(marking);
+        buf.put_bytes(0x20, num_pad);
         12
     }
 }
@@ -0,0 +1,76 @@
+use crate::enumerations::EnvironmentalProcessModelType;
+use crate::environmental_process::model::{
+    EnvironmentRecord, EnvironmentalProcess, EnvironmentalProcessStatus,
+};
+use crate::model::{EntityId, EntityType};
+use alloc::vec::Vec;
+
+pub struct EnvironmentalProcessBuilder(EnvironmentalProcess);
+
+impl Default for EnvironmentalProcessBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EnvironmentalProcessBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        EnvironmentalProcessBuilder(EnvironmentalProcess::default())
+    }
+
+    #[must_use]
+    pub fn new_from_body(body: EnvironmentalProcess) -> Self {
+        EnvironmentalProcessBuilder(body)
+    }
+
+    #[must_use]
+    pub fn build(self) -> EnvironmentalProcess {
+        self.0
+    }
+
+    #[must_use]
+    pub fn with_environmental_process_id(mut self, environmental_process_id: EntityId) -> Self {
+        self.0.environmental_process_id = environmental_process_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_environment_type(mut self, environment_type: EntityType) -> Self {
+        self.0.environment_type = environment_type;
+        self
+    }
+
+    #[must_use]
+    pub fn with_model_type(mut self, model_type: EnvironmentalProcessModelType) -> Self {
+        self.0.model_type = model_type;
+        self
+    }
+
+    #[must_use]
+    pub fn with_environment_status(
+        mut self,
+        environment_status: EnvironmentalProcessStatus,
+    ) -> Self {
+        self.0.environment_status = environment_status;
+        self
+    }
+
+    #[must_use]
+    pub fn with_sequence_number(mut self, sequence_number: u16) -> Self {
+        self.0.sequence_number = sequence_number;
+        self
+    }
+
+    #[must_use]
+    pub fn with_environment_record(mut self, record: EnvironmentRecord) -> Self {
+        self.0.environment_records.push(record);
+        self
+    }
+
+    #[must_use]
+    pub fn with_environment_records(mut self, records: Vec<EnvironmentRecord>) -> Self {
+        self.0.environment_records = records;
+        self
+    }
+}
@@ -0,0 +1,466 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::{EIGHT_OCTETS, ONE_BYTE_IN_BITS};
+use crate::enumerations::{EnvironmentRecordType, EnvironmentalProcessModelType, PduType};
+use crate::environmental_process::builder::EnvironmentalProcessBuilder;
+use crate::model::{
+    length_padded_to_num, EntityId, EntityType, Location, Orientation, PaddedRecordLengths,
+    PduBody, VectorF32,
+};
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const BASE_ENVIRONMENTAL_PROCESS_BODY_LENGTH: u16 = 20;
+pub(crate) const BASE_ENVIRONMENT_RECORD_LENGTH: u16 = 8;
+/// The maximum length of the data of an Environment record, in octets, as the length is given in bits by a 16-bit field.
+pub(crate) const MAX_ENVIRONMENT_RECORD_DATA_LENGTH: usize = u16::MAX as usize / ONE_BYTE_IN_BITS;
+
+/// 7.10.2 Environmental Process PDU
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EnvironmentalProcess {
+    pub environmental_process_id: EntityId,
+    pub environment_type: EntityType,
+    pub model_type: EnvironmentalProcessModelType,
+    pub environment_status: EnvironmentalProcessStatus,
+    pub sequence_number: u16,
+    pub environment_records: Vec<EnvironmentRecord>,
+}
+
+impl EnvironmentalProcess {
+    #[must_use]
+    pub fn builder() -> EnvironmentalProcessBuilder {
+        EnvironmentalProcessBuilder::new()
+    }
+
+    #[must_use]
+    pub fn into_builder(self) -> EnvironmentalProcessBuilder {
+        EnvironmentalProcessBuilder::new_from_body(self)
+    }
+
+    #[must_use]
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::EnvironmentalProcess(self)
+    }
+}
+
+impl BodyInfo for EnvironmentalProcess {
+    /// The length saturates at `u16::MAX` for records that do not fit a PDU.
+    fn body_length(&self) -> u16 {
+        let records_length: usize = self
+            .environment_records
+            .iter()
+            .map(|record| record.padded_lengths().record_length)
+            .sum();
+        u16::try_from(BASE_ENVIRONMENTAL_PROCESS_BODY_LENGTH as usize + records_length)
+            .unwrap_or(u16::MAX)
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::EnvironmentalProcess
+    }
+}
+
+impl Interaction for EnvironmentalProcess {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.environmental_process_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// Environment Status field of the Environmental Process PDU
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EnvironmentalProcessStatus {
+    pub is_last: bool,
+    pub is_active: bool,
+}
+
+impl EnvironmentalProcessStatus {
+    #[must_use]
+    pub fn with_last(mut self, is_last: bool) -> Self {
+        self.is_last = is_last;
+        self
+    }
+
+    #[must_use]
+    pub fn with_active(mut self, is_active: bool) -> Self {
+        self.is_active = is_active;
+        self
+    }
+}
+
+impl From<u8> for EnvironmentalProcessStatus {
+    fn from(value: u8) -> Self {
+        Self {
+            is_last: (value & 0x01) != 0,
+            is_active: (value & 0x02) != 0,
+        }
+    }
+}
+
+impl From<EnvironmentalProcessStatus> for u8 {
+    fn from(value: EnvironmentalProcessStatus) -> Self {
+        u8::from(value.is_last) | u8::from(value.is_active) << 1
+    }
+}
+
+/// Values of the Environmental Process-Record Type enumeration (SISO-REF-010) of the records
+/// that are parsed into typed `EnvironmentRecordData`.
+pub(crate) mod record_types {
+    pub(crate) const COMBIC_STATE: u32 = 256;
+    pub(crate) const FLARE_STATE: u32 = 259;
+    pub(crate) const BOUNDING_SPHERE_RECORD: u32 = 65_536;
+    pub(crate) const UNIFORM_GEOMETRY_RECORD: u32 = 327_680;
+    pub(crate) const POINT_RECORD_1: u32 = 655_360;
+    pub(crate) const LINE_RECORD_1: u32 = 786_432;
+    pub(crate) const SPHERE_RECORD_1: u32 = 851_968;
+    pub(crate) const ELLIPSOID_RECORD_1: u32 = 1_048_576;
+    pub(crate) const CONE_RECORD_1: u32 = 3_145_728;
+    pub(crate) const RECTANGULAR_VOLUME_RECORD_1: u32 = 5_242_880;
+    pub(crate) const RECTANGULAR_VOLUME_RECORD_3: u32 = 83_886_080;
+    pub(crate) const POINT_RECORD_2: u32 = 167_772_160;
+    pub(crate) const LINE_RECORD_2: u32 = 201_326_592;
+    pub(crate) const SPHERE_RECORD_2: u32 = 218_103_808;
+    pub(crate) const ELLIPSOID_RECORD_2: u32 = 268_435_456;
+    pub(crate) const CONE_RECORD_2: u32 = 805_306_368;
+    pub(crate) const RECTANGULAR_VOLUME_RECORD_2: u32 = 1_342_177_280;
+    pub(crate) const GAUSSIAN_PLUME_RECORD: u32 = 1_610_612_736;
+    pub(crate) const GAUSSIAN_PUFF_RECORD: u32 = 1_879_048_192;
+}
+
+/// 6.2.31 Environment record
+///
+/// The record is padded to a 64-bit boundary on the wire.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EnvironmentRecord {
+    pub index: u8,
+    pub data: EnvironmentRecordData,
+}
+
+impl EnvironmentRecord {
+    #[must_use]
+    pub fn new(index: u8, data: EnvironmentRecordData) -> Self {
+        Self { index, data }
+    }
+
+    /// Length of the record on the wire, including the padding to a 64-bit boundary, in octets.
+    #[must_use]
+    pub fn record_length(&self) -> usize {
+        self.padded_lengths().record_length
+    }
+
+    pub(crate) fn padded_lengths(&self) -> PaddedRecordLengths {
+        length_padded_to_num(
+            BASE_ENVIRONMENT_RECORD_LENGTH as usize + self.data.data_length(),
+            EIGHT_OCTETS,
+        )
+    }
+}
+
+/// The type specific data of an Environment record (Annex E of IEEE 1278.1-2012).
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EnvironmentRecordData {
+    CombicState(CombicState),
+    FlareState(FlareState),
+    BoundingSphere(BoundingSphere),
+    UniformGeometry(UniformGeometry),
+    PointRecord1(PointRecord1),
+    LineRecord1(LineRecord1),
+    SphereRecord1(SphereRecord1),
+    EllipsoidRecord1(EllipsoidRecord1),
+    ConeRecord1(ConeRecord1),
+    RectangularVolumeRecord1(RectangularVolumeRecord1),
+    RectangularVolumeRecord3(RectangularVolumeRecord3),
+    PointRecord2(PointRecord2),
+    LineRecord2(LineRecord2),
+    SphereRecord2(SphereRecord2),
+    EllipsoidRecord2(EllipsoidRecord2),
+    ConeRecord2(ConeRecord2),
+    RectangularVolumeRecord2(RectangularVolumeRecord2),
+    GaussianPlume(GaussianPlume),
+    GaussianPuff(GaussianPuff),
+    Unspecified(UnspecifiedEnvironmentRecord),
+}
+
+impl Default for EnvironmentRecordData {
+    fn default() -> Self {
+        Self::Unspecified(UnspecifiedEnvironmentRecord::default())
+    }
+}
+
+impl EnvironmentRecordData {
+    #[must_use]
+    pub fn record_type(&self) -> EnvironmentRecordType {
+        EnvironmentRecordType::from(self.record_type_value())
+    }
+
+    fn record_type_value(&self) -> u32 {
+        match self {
+            EnvironmentRecordData::CombicState(_) => record_types::COMBIC_STATE,
+            EnvironmentRecordData::FlareState(_) => record_types::FLARE_STATE,
+            EnvironmentRecordData::BoundingSphere(_) => record_types::BOUNDING_SPHERE_RECORD,
+            EnvironmentRecordData::UniformGeometry(_) => record_types::UNIFORM_GEOMETRY_RECORD,
+            EnvironmentRecordData::PointRecord1(_) => record_types::POINT_RECORD_1,
+            EnvironmentRecordData::LineRecord1(_) => record_types::LINE_RECORD_1,
+            EnvironmentRecordData::SphereRecord1(_) => record_types::SPHERE_RECORD_1,
+            EnvironmentRecordData::EllipsoidRecord1(_) => record_types::ELLIPSOID_RECORD_1,
+            EnvironmentRecordData::ConeRecord1(_) => record_types::CONE_RECORD_1,
+            EnvironmentRecordData::RectangularVolumeRecord1(_) => {
+                record_types::RECTANGULAR_VOLUME_RECORD_1
+            }
+            EnvironmentRecordData::RectangularVolumeRecord3(_) => {
+                record_types::RECTANGULAR_VOLUME_RECORD_3
+            }
+            EnvironmentRecordData::PointRecord2(_) => record_types::POINT_RECORD_2,
+            EnvironmentRecordData::LineRecord2(_) => record_types::LINE_RECORD_2,
+            EnvironmentRecordData::SphereRecord2(_) => record_types::SPHERE_RECORD_2,
+            EnvironmentRecordData::EllipsoidRecord2(_) => record_types::ELLIPSOID_RECORD_2,
+            EnvironmentRecordData::ConeRecord2(_) => record_types::CONE_RECORD_2,
+            EnvironmentRecordData::RectangularVolumeRecord2(_) => {
+                record_types::RECTANGULAR_VOLUME_RECORD_2
+            }
+            EnvironmentRecordData::GaussianPlume(_) => record_types::GAUSSIAN_PLUME_RECORD,
+            EnvironmentRecordData::GaussianPuff(_) => record_types::GAUSSIAN_PUFF_RECORD,
+            EnvironmentRecordData::Unspecified(record) => record.record_type,
+        }
+    }
+
+    /// Length of the type specific data, excluding the record header and padding, in octets.
+    #[must_use]
+    pub fn data_length(&self) -> usize {
+        match self {
+            EnvironmentRecordData::CombicState(_) => 52,
+            EnvironmentRecordData::FlareState(_) | EnvironmentRecordData::PointRecord1(_) => 24,
+            EnvironmentRecordData::BoundingSphere(_) | EnvironmentRecordData::SphereRecord1(_) => {
+                28
+            }
+            EnvironmentRecordData::UniformGeometry(_) => 2,
+            EnvironmentRecordData::LineRecord1(_)
+            | EnvironmentRecordData::EllipsoidRecord1(_)
+            | EnvironmentRecordData::RectangularVolumeRecord2(_) => 48,
+            EnvironmentRecordData::ConeRecord1(_) => 44,
+            EnvironmentRecordData::RectangularVolumeRecord1(_)
+            | EnvironmentRecordData::PointRecord2(_) => 36,
+            EnvironmentRecordData::RectangularVolumeRecord3(_)
+            | EnvironmentRecordData::LineRecord2(_) => 72,
+            EnvironmentRecordData::SphereRecord2(_) => 56,
+            EnvironmentRecordData::EllipsoidRecord2(_) => 84,
+            EnvironmentRecordData::ConeRecord2(_) => 76,
+            EnvironmentRecordData::GaussianPlume(_) => 80,
+            EnvironmentRecordData::GaussianPuff(_) => 112,
+            EnvironmentRecordData::Unspecified(record) => record.data.len(),
+        }
+    }
+}
+
+/// Environment record of a type that is not modelled by this crate, holding the raw data.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UnspecifiedEnvironmentRecord {
+    pub record_type: u32,
+    pub data: Vec<u8>,
+}
+
+/// E.4.2.1 COMBIC State record
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CombicState {
+    pub time_since_creation: u32,
+    pub munition_source: EntityType,
+    pub number_of_sources: u32,
+    pub geometry_index: u16,
+    pub source_type: u32,
+    pub barrage_rate: f32,
+    pub barrage_duration: f32,
+    pub barrage_crosswind_length: f32,
+    pub barrage_downwind_length: f32,
+    pub detonation_velocity: VectorF32,
+}
+
+/// E.4.2.3 Flare State record
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FlareState {
+    pub time_since_creation: u32,
+    pub munition_source: EntityType,
+    pub number_of_intensity: u32,
+    pub number_of_sources: u32,
+    pub geometry_index: u16,
+}
+
+/// E.5.2 Bounding Sphere record
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BoundingSphere {
+    pub centroid: Location,
+    pub radius: f32,
+}
+
+/// E.5.3 Uniform Geometry record
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UniformGeometry {
+    pub geometry_index: u16,
+}
+
+/// E.5.4 Point record 1
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PointRecord1 {
+    pub location: Location,
+}
+
+/// E.5.5 Line record 1
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LineRecord1 {
+    pub start_point: Location,
+    pub end_point: Location,
+}
+
+/// E.5.6 Sphere record 1
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SphereRecord1 {
+    pub centroid: Location,
+    pub radius: f32,
+}
+
+/// E.5.7 Ellipsoid record 1
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EllipsoidRecord1 {
+    pub centroid: Location,
+    pub sigma: VectorF32,
+    pub orientation: Orientation,
+}
+
+/// E.5.8 Cone record 1
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConeRecord1 {
+    pub vertex: Location,
+    pub orientation: Orientation,
+    pub height: f32,
+    pub peak_angle: f32,
+}
+
+/// E.5.9 Rectangular Volume record 1
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RectangularVolumeRecord1 {
+    pub corner: Location,
+    pub length: VectorF32,
+}
+
+/// E.5.10 Rectangular Volume record 3
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RectangularVolumeRecord3 {
+    pub center: Location,
+    pub length: VectorF32,
+    pub velocity: VectorF32,
+    pub orientation: Orientation,
+    pub angular_velocity: VectorF32,
+}
+
+/// E.5.11 Point record 2
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PointRecord2 {
+    pub location: Location,
+    pub velocity: VectorF32,
+}
+
+/// E.5.12 Line record 2
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LineRecord2 {
+    pub start_point: Location,
+    pub end_point: Location,
+    pub start_point_velocity: VectorF32,
+    pub end_point_velocity: VectorF32,
+}
+
+/// E.5.13 Sphere record 2
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SphereRecord2 {
+    pub centroid: Location,
+    pub radius: f32,
+    pub radius_rate: f32,
+    pub velocity: VectorF32,
+    pub angular_velocity: VectorF32,
+}
+
+/// E.5.14 Ellipsoid record 2
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EllipsoidRecord2 {
+    pub centroid: Location,
+    pub sigma: VectorF32,
+    pub sigma_rate: VectorF32,
+    pub velocity: VectorF32,
+    pub orientation: Orientation,
+    pub angular_velocity: VectorF32,
+}
+
+/// E.5.15 Cone record 2
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ConeRecord2 {
+    pub vertex: Location,
+    pub orientation: Orientation,
+    pub velocity: VectorF32,
+    pub angular_velocity: VectorF32,
+    pub height: f32,
+    pub height_rate: f32,
+    pub peak_angle: f32,
+    pub peak_angle_rate: f32,
+}
+
+/// E.5.16 Rectangular Volume record 2
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RectangularVolumeRecord2 {
+    pub corner: Location,
+    pub length: VectorF32,
+    pub orientation: Orientation,
+}
+
+/// E.5.17 Gaussian Plume record
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GaussianPlume {
+    pub source_location: Location,
+    pub orientation: Orientation,
+    pub plume_length: f32,
+    pub plume_width: f32,
+    pub plume_top_height: f32,
+    pub plume_length_rate: f32,
+    pub plume_width_rate: f32,
+    pub plume_top_height_rate: f32,
+    pub leading_edge_centroid_height: f32,
+    pub centroid_velocity: VectorF32,
+    pub leading_edge_velocity: f32,
+}
+
+/// E.5.18 Gaussian Puff record
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GaussianPuff {
+    pub puff_location: Location,
+    pub origination_location: Location,
+    pub sigma: VectorF32,
+    pub sigma_rate: VectorF32,
+    pub orientation: Orientation,
+    pub velocity: VectorF32,
+    pub angular_velocity: VectorF32,
+    pub centroid_height: f32,
+}
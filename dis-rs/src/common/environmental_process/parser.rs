@@ -0,0 +1,412 @@
+use crate::common::parser::IResult;
+use crate::common::parser::{entity_id, entity_type, location, orientation, vec3_f32};
+use crate::constants::{EIGHT_OCTETS, ONE_BYTE_IN_BITS};
+use crate::enumerations::EnvironmentalProcessModelType;
+use crate::environmental_process::model::{
+    record_types, BoundingSphere, CombicState, ConeRecord1, ConeRecord2, EllipsoidRecord1,
+    EllipsoidRecord2, EnvironmentRecord, EnvironmentRecordData, EnvironmentalProcess,
+    EnvironmentalProcessStatus, FlareState, GaussianPlume, GaussianPuff, LineRecord1, LineRecord2,
+    PointRecord1, PointRecord2, RectangularVolumeRecord1, RectangularVolumeRecord2,
+    RectangularVolumeRecord3, SphereRecord1, SphereRecord2, UniformGeometry,
+    UnspecifiedEnvironmentRecord, BASE_ENVIRONMENT_RECORD_LENGTH,
+};
+use crate::model::{length_padded_to_num, PduBody};
+use nom::bytes::complete::take;
+use nom::error::context;
+use nom::multi::count;
+use nom::number::complete::{be_f32, be_u16, be_u32, be_u8};
+
+pub(crate) fn environmental_process_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    context("Environmental Process Body", |input| {
+        let (input, environmental_process_id) = entity_id(input)?;
+        let (input, environment_type) = entity_type(input)?;
+        let (input, model_type) = be_u8(input)?;
+        let (input, environment_status) = be_u8(input)?;
+        let (input, number_of_records) = be_u16(input)?;
+        let (input, sequence_number) = be_u16(input)?;
+        let (input, environment_records) =
+            count(environment_record, number_of_records.into())(input)?;
+
+        let body = EnvironmentalProcess::builder()
+            .with_environmental_process_id(environmental_process_id)
+            .with_environment_type(environment_type)
+            .with_model_type(EnvironmentalProcessModelType::from(model_type))
+            .with_environment_status(EnvironmentalProcessStatus::from(environment_status))
+            .with_sequence_number(sequence_number)
+            .with_environment_records(environment_records)
+            .build();
+
+        Ok((input, body.into_pdu_body()))
+    })(input)
+}
+
+pub(crate) fn environment_record(input: &[u8]) -> IResult<&[u8], EnvironmentRecord> {
+    context("Environment Record", |input| {
+        let (input, record_type) = be_u32(input)?;
+        let (input, record_length_bits) = be_u16(input)?;
+        let (input, index) = be_u8(input)?;
+        let (input, _padding) = be_u8(input)?;
+
+        let data_length = usize::from(record_length_bits) / ONE_BYTE_IN_BITS;
+        let padded_record = length_padded_to_num(
+            BASE_ENVIRONMENT_RECORD_LENGTH as usize + data_length,
+            EIGHT_OCTETS,
+        );
+        let (input, data_bytes) = take(data_length)(input)?;
+        let (input, _padding) = take(padded_record.padding_length)(input)?;
+
+        let (_, data) = environment_record_data(record_type, data_bytes)?;
+
+        Ok((input, EnvironmentRecord::new(index, data)))
+    })(input)
+}
+
+#[allow(clippy::too_many_lines)]
+fn environment_record_data(
+    record_type: u32,
+    input: &[u8],
+) -> IResult<&[u8], EnvironmentRecordData> {
+    context("Environment Record Data", |input| {
+        let (input, data) = match record_type {
+            record_types::COMBIC_STATE => {
+                let (input, record) = combic_state(input)?;
+                (input, EnvironmentRecordData::CombicState(record))
+            }
+            record_types::FLARE_STATE => {
+                let (input, record) = flare_state(input)?;
+                (input, EnvironmentRecordData::FlareState(record))
+            }
+            record_types::BOUNDING_SPHERE_RECORD => {
+                let (input, centroid) = location(input)?;
+                let (input, radius) = be_f32(input)?;
+                (
+                    input,
+                    EnvironmentRecordData::BoundingSphere(BoundingSphere { centroid, radius }),
+                )
+            }
+            record_types::UNIFORM_GEOMETRY_RECORD => {
+                let (input, geometry_index) = be_u16(input)?;
+                (
+                    input,
+                    EnvironmentRecordData::UniformGeometry(UniformGeometry { geometry_index }),
+                )
+            }
+            record_types::POINT_RECORD_1 => {
+                let (input, location) = location(input)?;
+                (
+                    input,
+                    EnvironmentRecordData::PointRecord1(PointRecord1 { location }),
+                )
+            }
+            record_types::LINE_RECORD_1 => {
+                let (input, start_point) = location(input)?;
+                let (input, end_point) = location(input)?;
+                (
+                    input,
+                    EnvironmentRecordData::LineRecord1(LineRecord1 {
+                        start_point,
+                        end_point,
+                    }),
+                )
+            }
+            record_types::SPHERE_RECORD_1 => {
+                let (input, centroid) = location(input)?;
+                let (input, radius) = be_f32(input)?;
+                (
+                    input,
+                    EnvironmentRecordData::SphereRecord1(SphereRecord1 { centroid, radius }),
+                )
+            }
+            record_types::ELLIPSOID_RECORD_1 => {
+                let (input, centroid) = location(input)?;
+                let (input, sigma) = vec3_f32(input)?;
+                let (input, orientation) = orientation(input)?;
+                (
+                    input,
+                    EnvironmentRecordData::EllipsoidRecord1(EllipsoidRecord1 {
+                        centroid,
+                        sigma,
+                        orientation,
+                    }),
+                )
+            }
+            record_types::CONE_RECORD_1 => {
+                let (input, vertex) = location(input)?;
+                let (input, orientation) = orientation(input)?;
+                let (input, height) = be_f32(input)?;
+                let (input, peak_angle) = be_f32(input)?;
+                (
+                    input,
+                    EnvironmentRecordData::ConeRecord1(ConeRecord1 {
+                        vertex,
+                        orientation,
+                        height,
+                        peak_angle,
+                    }),
+                )
+            }
+            record_types::RECTANGULAR_VOLUME_RECORD_1 => {
+                let (input, corner) = location(input)?;
+                let (input, length) = vec3_f32(input)?;
+                (
+                    input,
+                    EnvironmentRecordData::RectangularVolumeRecord1(RectangularVolumeRecord1 {
+                        corner,
+                        length,
+                    }),
+                )
+            }
+            record_types::RECTANGULAR_VOLUME_RECORD_3 => {
+                let (input, center) = location(input)?;
+                let (input, length) = vec3_f32(input)?;
+                let (input, velocity) = vec3_f32(input)?;
+                let (input, orientation) = orientation(input)?;
+                let (input, angular_velocity) = vec3_f32(input)?;
+                (
+                    input,
+                    EnvironmentRecordData::RectangularVolumeRecord3(RectangularVolumeRecord3 {
+                        center,
+                        length,
+                        velocity,
+                        orientation,
+                        angular_velocity,
+                    }),
+                )
+            }
+            record_types::POINT_RECORD_2 => {
+                let (input, location) = location(input)?;
+                let (input, velocity) = vec3_f32(input)?;
+                (
+                    input,
+                    EnvironmentRecordData::PointRecord2(PointRecord2 { location, velocity }),
+                )
+            }
+            record_types::LINE_RECORD_2 => {
+                let (input, start_point) = location(input)?;
+                let (input, end_point) = location(input)?;
+                let (input, start_point_velocity) = vec3_f32(input)?;
+                let (input, end_point_velocity) = vec3_f32(input)?;
+                (
+                    input,
+                    EnvironmentRecordData::LineRecord2(LineRecord2 {
+                        start_point,
+                        end_point,
+                        start_point_velocity,
+                        end_point_velocity,
+                    }),
+                )
+            }
+            record_types::SPHERE_RECORD_2 => {
+                let (input, centroid) = location(input)?;
+                let (input, radius) = be_f32(input)?;
+                let (input, radius_rate) = be_f32(input)?;
+                let (input, velocity) = vec3_f32(input)?;
+                let (input, angular_velocity) = vec3_f32(input)?;
+                (
+                    input,
+                    EnvironmentRecordData::SphereRecord2(SphereRecord2 {
+                        centroid,
+                        radius,
+                        radius_rate,
+                        velocity,
+                        angular_velocity,
+                    }),
+                )
+            }
+            record_types::ELLIPSOID_RECORD_2 => {
+                let (input, centroid) = location(input)?;
+                let (input, sigma) = vec3_f32(input)?;
+                let (input, sigma_rate) = vec3_f32(input)?;
+                let (input, velocity) = vec3_f32(input)?;
+                let (input, orientation) = orientation(input)?;
+                let (input, angular_velocity) = vec3_f32(input)?;
+                (
+                    input,
+                    EnvironmentRecordData::EllipsoidRecord2(EllipsoidRecord2 {
+                        centroid,
+                        sigma,
+                        sigma_rate,
+                        velocity,
+                        orientation,
+                        angular_velocity,
+                    }),
+                )
+            }
+            record_types::CONE_RECORD_2 => {
+                let (input, record) = cone_record_2(input)?;
+                (input, EnvironmentRecordData::ConeRecord2(record))
+            }
+            record_types::RECTANGULAR_VOLUME_RECORD_2 => {
+                let (input, corner) = location(input)?;
+                let (input, length) = vec3_f32(input)?;
+                let (input, orientation) = orientation(input)?;
+                (
+                    input,
+                    EnvironmentRecordData::RectangularVolumeRecord2(RectangularVolumeRecord2 {
+                        corner,
+                        length,
+                        orientation,
+                    }),
+                )
+            }
+            record_types::GAUSSIAN_PLUME_RECORD => {
+                let (input, record) = gaussian_plume(input)?;
+                (input, EnvironmentRecordData::GaussianPlume(record))
+            }
+            record_types::GAUSSIAN_PUFF_RECORD => {
+                let (input, record) = gaussian_puff(input)?;
+                (input, EnvironmentRecordData::GaussianPuff(record))
+            }
+            record_type => (
+                &input[input.len()..],
+                EnvironmentRecordData::Unspecified(UnspecifiedEnvironmentRecord {
+                    record_type,
+                    data: input.to_vec(),
+                }),
+            ),
+        };
+
+        Ok((input, data))
+    })(input)
+}
+
+fn combic_state(input: &[u8]) -> IResult<&[u8], CombicState> {
+    context("Combic State", |input| {
+        let (input, time_since_creation) = be_u32(input)?;
+        let (input, munition_source) = entity_type(input)?;
+        let (input, number_of_sources) = be_u32(input)?;
+        let (input, geometry_index) = be_u16(input)?;
+        let (input, _padding) = be_u16(input)?;
+        let (input, source_type) = be_u32(input)?;
+        let (input, barrage_rate) = be_f32(input)?;
+        let (input, barrage_duration) = be_f32(input)?;
+        let (input, barrage_crosswind_length) = be_f32(input)?;
+        let (input, barrage_downwind_length) = be_f32(input)?;
+        let (input, detonation_velocity) = vec3_f32(input)?;
+
+        Ok((
+            input,
+            CombicState {
+                time_since_creation,
+                munition_source,
+                number_of_sources,
+                geometry_index,
+                source_type,
+                barrage_rate,
+                barrage_duration,
+                barrage_crosswind_length,
+                barrage_downwind_length,
+                detonation_velocity,
+            },
+        ))
+    })(input)
+}
+
+fn flare_state(input: &[u8]) -> IResult<&[u8], FlareState> {
+    context("Flare State", |input| {
+        let (input, time_since_creation) = be_u32(input)?;
+        let (input, munition_source) = entity_type(input)?;
+        let (input, number_of_intensity) = be_u32(input)?;
+        let (input, number_of_sources) = be_u32(input)?;
+        let (input, geometry_index) = be_u16(input)?;
+        let (input, _padding) = be_u16(input)?;
+
+        Ok((
+            input,
+            FlareState {
+                time_since_creation,
+                munition_source,
+                number_of_intensity,
+                number_of_sources,
+                geometry_index,
+            },
+        ))
+    })(input)
+}
+
+fn cone_record_2(input: &[u8]) -> IResult<&[u8], ConeRecord2> {
+    context("Cone Record 2", |input| {
+        let (input, vertex) = location(input)?;
+        let (input, orientation) = orientation(input)?;
+        let (input, velocity) = vec3_f32(input)?;
+        let (input, angular_velocity) = vec3_f32(input)?;
+        let (input, height) = be_f32(input)?;
+        let (input, height_rate) = be_f32(input)?;
+        let (input, peak_angle) = be_f32(input)?;
+        let (input, peak_angle_rate) = be_f32(input)?;
+
+        Ok((
+            input,
+            ConeRecord2 {
+                vertex,
+                orientation,
+                velocity,
+                angular_velocity,
+                height,
+                height_rate,
+                peak_angle,
+                peak_angle_rate,
+            },
+        ))
+    })(input)
+}
+
+fn gaussian_plume(input: &[u8]) -> IResult<&[u8], GaussianPlume> {
+    context("Gaussian Plume", |input| {
+        let (input, source_location) = location(input)?;
+        let (input, orientation) = orientation(input)?;
+        let (input, plume_length) = be_f32(input)?;
+        let (input, plume_width) = be_f32(input)?;
+        let (input, plume_top_height) = be_f32(input)?;
+        let (input, plume_length_rate) = be_f32(input)?;
+        let (input, plume_width_rate) = be_f32(input)?;
+        let (input, plume_top_height_rate) = be_f32(input)?;
+        let (input, leading_edge_centroid_height) = be_f32(input)?;
+        let (input, centroid_velocity) = vec3_f32(input)?;
+        let (input, leading_edge_velocity) = be_f32(input)?;
+
+        Ok((
+            input,
+            GaussianPlume {
+                source_location,
+                orientation,
+                plume_length,
+                plume_width,
+                plume_top_height,
+                plume_length_rate,
+                plume_width_rate,
+                plume_top_height_rate,
+                leading_edge_centroid_height,
+                centroid_velocity,
+                leading_edge_velocity,
+            },
+        ))
+    })(input)
+}
+
+fn gaussian_puff(input: &[u8]) -> IResult<&[u8], GaussianPuff> {
+    context("Gaussian Puff", |input| {
+        let (input, puff_location) = location(input)?;
+        let (input, origination_location) = location(input)?;
+        let (input, sigma) = vec3_f32(input)?;
+        let (input, sigma_rate) = vec3_f32(input)?;
+        let (input, orientation) = orientation(input)?;
+        let (input, velocity) = vec3_f32(input)?;
+        let (input, angular_velocity) = vec3_f32(input)?;
+        let (input, centroid_height) = be_f32(input)?;
+
+        Ok((
+            input,
+            GaussianPuff {
+                puff_location,
+                origination_location,
+                sigma,
+                sigma_rate,
+                orientation,
+                velocity,
+                angular_velocity,
+                centroid_height,
+            },
+        ))
+    })(input)
+}
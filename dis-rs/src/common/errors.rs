@@ -1,12 +1,20 @@
+use alloc::string::String;
+use core::fmt::{Display, Formatter};
+
+use nom::error::{ContextError, ErrorKind, FromExternalError, ParseError};
+use nom::ErrorConvert;
 use thiserror::Error;
 
 use crate::constants::PDU_HEADER_LEN_BYTES;
+use crate::enumerations::{PduType, ProtocolVersion};
 
 #[derive(Debug, PartialEq, Eq, Error)]
 pub enum DisError {
     // UnsupportedProtocolVersion,
     #[error("{0}")]
     ParseError(String), // the parsing of a PDU resulted in an error
+    #[error("{0}")]
+    PduParseError(PduParseError), // the parsing of a PDU resulted in an error, located at a specific record or field
     #[error("The buffer does not contain enough bytes for a valid DIS header. {0} bytes available, needed {PDU_HEADER_LEN_BYTES}")]
     InsufficientHeaderLength(u16), // the input was too small to contain a valid DIS header; (u16 found)
     #[error("PDU has insufficient length. Expected {0}, found {1}")]
@@ -17,8 +25,106 @@ pub enum DisError {
     StringNotAsciiError, // the String value to serialize is not valid ASCII encoded
     #[error("Provided String is too long.")]
     StringTooLongError, // the String value to serialize is too large for the field specification
+    #[error("Record data is too long for its length field. Length {0} octets, maximum {1} octets")]
+    DataTooLongError(usize, usize), // the data of a record to serialize does not fit the length field of the record; (usize length, usize maximum length)
     #[error("IFF PDU - Incorrect System Time provided.")]
     IffIncorrectSystemType, // the System Type in an IFF PDU is incorrect (to determine the type for parsing the basic data)
     #[error("IFF PDU - Undetermined System Time.")]
     IffUndeterminedSystemType, // the System Type in an IFF PDU does not determine whether it is an Interrogator or a Transponder
 }
+
+/// Details on where and why the parsing of a PDU failed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PduParseError {
+    /// The type of the PDU, when the header of the PDU could be parsed.
+    pub pdu_type: Option<PduType>,
+    /// The protocol version of the PDU, when the header of the PDU could be parsed.
+    pub protocol_version: Option<ProtocolVersion>,
+    /// Offset in bytes, from the start of the input buffer (e.g., the datagram), at which parsing failed.
+    /// For a buffer holding multiple PDUs, this includes the offset of the failed PDU in the buffer.
+    pub offset: usize,
+    /// Name of the (innermost) record or field that failed to parse.
+    pub field: &'static str,
+    /// Description of the cause of the failure.
+    pub reason: String,
+}
+
+impl Display for PduParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Failed to parse {}", self.field)?;
+        match (self.pdu_type, self.protocol_version) {
+            (Some(pdu_type), Some(protocol_version)) => {
+                write!(f, " of {pdu_type:?} PDU ({protocol_version:?})")?;
+            }
+            (Some(pdu_type), None) => write!(f, " of {pdu_type:?} PDU")?,
+            _ => {}
+        }
+        write!(f, " at byte offset {}: {}", self.offset, self.reason)
+    }
+}
+
+/// Error type of the parsers in this crate.
+///
+/// Next to the remaining input and the kind of error reported by `nom`,
+/// it keeps track of the innermost record or field that was being parsed when the error occurred.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParserError<I> {
+    pub input: I,
+    pub code: ErrorKind,
+    pub context: Option<&'static str>,
+}
+
+impl<I> ParserError<I> {
+    pub fn new(input: I, code: ErrorKind) -> Self {
+        Self {
+            input,
+            code,
+            context: None,
+        }
+    }
+}
+
+impl<I> ParseError<I> for ParserError<I> {
+    fn from_error_kind(input: I, kind: ErrorKind) -> Self {
+        Self::new(input, kind)
+    }
+
+    fn append(_input: I, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl<I> ContextError<I> for ParserError<I> {
+    fn add_context(_input: I, ctx: &'static str, mut other: Self) -> Self {
+        // keep the innermost context, which is the most specific
+        if other.context.is_none() {
+            other.context = Some(ctx);
+        }
+        other
+    }
+}
+
+impl<I, E> FromExternalError<I, E> for ParserError<I> {
+    fn from_external_error(input: I, kind: ErrorKind, _e: E) -> Self {
+        Self::new(input, kind)
+    }
+}
+
+impl<I> ErrorConvert<ParserError<I>> for ParserError<(I, usize)> {
+    fn convert(self) -> ParserError<I> {
+        ParserError {
+            input: self.input.0,
+            code: self.code,
+            context: self.context,
+        }
+    }
+}
+
+impl<I> Display for ParserError<I> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self.context {
+            Some(context) => write!(f, "error {:?} while parsing {context}", self.code),
+            None => write!(f, "error {:?}", self.code),
+        }
+    }
+}
@@ -7,6 +7,7 @@
 use crate::enumerations::EventType;
 use crate::enumerations::PduType;
 use crate::event_report_r::builder::EventReportRBuilder;
+use alloc::vec::Vec;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
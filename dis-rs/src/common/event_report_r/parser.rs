@@ -1,25 +1,28 @@
 use crate::common::model::PduBody;
+use crate::common::parser::IResult;
 use crate::common::parser::{datum_specification, entity_id};
 use crate::enumerations::EventType;
 use crate::event_report_r::model::EventReportR;
+use nom::error::context;
 use nom::number::complete::be_u32;
-use nom::IResult;
 
 pub(crate) fn event_report_r_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, originating_id) = entity_id(input)?;
-    let (input, receiving_id) = entity_id(input)?;
-    let (input, event_type) = be_u32(input)?;
-    let event_type = EventType::from(event_type);
-    let (input, _padding) = be_u32(input)?;
-    let (input, datums) = datum_specification(input)?;
+    context("Event Report-R Body", |input| {
+        let (input, originating_id) = entity_id(input)?;
+        let (input, receiving_id) = entity_id(input)?;
+        let (input, event_type) = be_u32(input)?;
+        let event_type = EventType::from(event_type);
+        let (input, _padding) = be_u32(input)?;
+        let (input, datums) = datum_specification(input)?;
 
-    let body = EventReportR::builder()
-        .with_origination_id(originating_id)
-        .with_receiving_id(receiving_id)
-        .with_event_type(event_type)
-        .with_fixed_datums(datums.fixed_datum_records)
-        .with_variable_datums(datums.variable_datum_records)
-        .build();
+        let body = EventReportR::builder()
+            .with_origination_id(originating_id)
+            .with_receiving_id(receiving_id)
+            .with_event_type(event_type)
+            .with_fixed_datums(datums.fixed_datum_records)
+            .with_variable_datums(datums.variable_datum_records)
+            .build();
 
-    Ok((input, body.into_pdu_body()))
+        Ok((input, body.into_pdu_body()))
+    })(input)
 }
@@ -1,9 +1,9 @@
 use crate::common::{Serialize, SerializePdu, SupportedVersion};
 use crate::event_report_r::model::EventReportR;
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 
 impl SerializePdu for EventReportR {
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
         let originating_bytes = self.originating_id.serialize(buf);
         let receiving_bytes = self.receiving_id.serialize(buf);
         buf.put_u32(self.event_type.into());
@@ -0,0 +1,4 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
@@ -0,0 +1,221 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::{EIGHT_OCTETS, FOUR_OCTETS, TWO_OCTETS};
+use crate::enumerations::{
+    GriddedDataConstantGrid, GriddedDataCoordinateSystem, GriddedDataSampleType, PduType,
+};
+use crate::gridded_data::builder::GriddedDataBuilder;
+use crate::model::{
+    length_padded_to_num, ClockTime, EntityId, EntityType, Orientation, PaddedRecordLengths,
+    PduBody,
+};
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const BASE_GRIDDED_DATA_BODY_LENGTH: u16 = 52;
+const GRID_AXIS_REGULAR_LENGTH: usize = 24;
+const BASE_GRID_AXIS_IRREGULAR_LENGTH: usize = 40;
+const BASE_GRID_DATA_TYPE_0_LENGTH: usize = 6;
+const BASE_GRID_DATA_TYPE_1_LENGTH: usize = 14;
+const BASE_GRID_DATA_TYPE_2_LENGTH: usize = 8;
+
+/// 7.10.3 Gridded Data PDU
+///
+/// The Vector Dimension field of the PDU is derived from the number of `grid_data` records.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GriddedData {
+    pub environmental_simulation_id: EntityId,
+    pub field_number: u16,
+    pub pdu_number: u16,
+    pub pdu_total: u16,
+    pub coordinate_system: GriddedDataCoordinateSystem,
+    pub constant_grid: GriddedDataConstantGrid,
+    pub environment_type: EntityType,
+    pub orientation: Orientation,
+    pub sample_time: ClockTime,
+    pub total_values: u32,
+    pub grid_axis_descriptors: Vec<GridAxisDescriptor>,
+    pub grid_data: Vec<GridData>,
+}
+
+impl GriddedData {
+    #[must_use]
+    pub fn builder() -> GriddedDataBuilder {
+        GriddedDataBuilder::new()
+    }
+
+    #[must_use]
+    pub fn into_builder(self) -> GriddedDataBuilder {
+        GriddedDataBuilder::new_from_body(self)
+    }
+
+    #[must_use]
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::GriddedData(self)
+    }
+}
+
+impl BodyInfo for GriddedData {
+    fn body_length(&self) -> u16 {
+        BASE_GRIDDED_DATA_BODY_LENGTH
+            + self
+                .grid_axis_descriptors
+                .iter()
+                .map(GridAxisDescriptor::record_length)
+                .sum::<u16>()
+            + self
+                .grid_data
+                .iter()
+                .map(GridData::record_length)
+                .sum::<u16>()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::GriddedData
+    }
+}
+
+impl Interaction for GriddedData {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.environmental_simulation_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// 6.2.40 Grid Axis Descriptor record
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GridAxisDescriptor {
+    Regular(GridAxisRegular),
+    Irregular(GridAxisIrregular),
+}
+
+impl Default for GridAxisDescriptor {
+    fn default() -> Self {
+        Self::Regular(GridAxisRegular::default())
+    }
+}
+
+impl GridAxisDescriptor {
+    /// Length of the record on the wire, including padding to a 64-bit boundary, in octets.
+    #[must_use]
+    pub fn record_length(&self) -> u16 {
+        match self {
+            GridAxisDescriptor::Regular(_) => GRID_AXIS_REGULAR_LENGTH as u16,
+            GridAxisDescriptor::Irregular(axis) => {
+                irregular_axis_lengths(axis.x_values.len()).record_length as u16
+            }
+        }
+    }
+}
+
+pub(crate) fn irregular_axis_lengths(number_of_values: usize) -> PaddedRecordLengths {
+    length_padded_to_num(
+        BASE_GRID_AXIS_IRREGULAR_LENGTH + number_of_values * TWO_OCTETS,
+        EIGHT_OCTETS,
+    )
+}
+
+/// 6.2.40.2 Grid Axis Descriptor Fixed record, for regularly spaced axes.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GridAxisRegular {
+    pub domain_initial: f64,
+    pub domain_final: f64,
+    pub domain_points: u16,
+    pub interleaf_factor: u8,
+    pub number_of_points_on_axis: u16,
+    pub initial_index: u16,
+}
+
+/// 6.2.40.3 Grid Axis Descriptor Variable record, for irregularly spaced axes.
+///
+/// The Number of Points on Axis field is derived from the number of `x_values`.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GridAxisIrregular {
+    pub domain_initial: f64,
+    pub domain_final: f64,
+    pub domain_points: u16,
+    pub interleaf_factor: u8,
+    pub initial_index: u16,
+    pub coordinate_scale: f64,
+    pub coordinate_offset: f64,
+    pub x_values: Vec<u16>,
+}
+
+/// 6.2.41 Grid Data record
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GridData {
+    pub sample_type: GriddedDataSampleType,
+    pub data: GridDataRepresentation,
+}
+
+impl GridData {
+    #[must_use]
+    pub fn new(sample_type: GriddedDataSampleType, data: GridDataRepresentation) -> Self {
+        Self { sample_type, data }
+    }
+
+    /// Length of the record on the wire, including padding to a 32-bit boundary, in octets.
+    #[must_use]
+    pub fn record_length(&self) -> u16 {
+        self.padded_lengths().record_length as u16
+    }
+
+    pub(crate) fn padded_lengths(&self) -> PaddedRecordLengths {
+        self.data.padded_lengths()
+    }
+}
+
+/// The data values of a Grid Data record, in one of the three data representations.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GridDataRepresentation {
+    /// Data Representation Type 0: raw octets
+    Type0 { values: Vec<u8> },
+    /// Data Representation Type 1: scaled 16-bit unsigned integers
+    Type1 {
+        field_scale: f32,
+        field_offset: f32,
+        values: Vec<u16>,
+    },
+    /// Data Representation Type 2: 32-bit floating point values
+    Type2 { values: Vec<f32> },
+}
+
+impl Default for GridDataRepresentation {
+    fn default() -> Self {
+        Self::Type2 { values: vec![] }
+    }
+}
+
+impl GridDataRepresentation {
+    #[must_use]
+    pub fn data_representation(&self) -> u16 {
+        match self {
+            GridDataRepresentation::Type0 { .. } => 0,
+            GridDataRepresentation::Type1 { .. } => 1,
+            GridDataRepresentation::Type2 { .. } => 2,
+        }
+    }
+
+    pub(crate) fn padded_lengths(&self) -> PaddedRecordLengths {
+        let data_length = match self {
+            GridDataRepresentation::Type0 { values } => BASE_GRID_DATA_TYPE_0_LENGTH + values.len(),
+            GridDataRepresentation::Type1 { values, .. } => {
+                BASE_GRID_DATA_TYPE_1_LENGTH + values.len() * TWO_OCTETS
+            }
+            GridDataRepresentation::Type2 { values } => {
+                BASE_GRID_DATA_TYPE_2_LENGTH + values.len() * FOUR_OCTETS
+            }
+        };
+        length_padded_to_num(data_length, FOUR_OCTETS)
+    }
+}
@@ -0,0 +1,141 @@
+use crate::common::errors::ParserError;
+use crate::common::parser::IResult;
+use crate::common::parser::{clock_time, entity_id, entity_type, orientation};
+use crate::enumerations::{
+    GriddedDataConstantGrid, GriddedDataCoordinateSystem, GriddedDataSampleType,
+};
+use crate::gridded_data::model::{
+    irregular_axis_lengths, GridAxisDescriptor, GridAxisIrregular, GridAxisRegular, GridData,
+    GridDataRepresentation, GriddedData,
+};
+use crate::model::PduBody;
+use nom::bytes::complete::take;
+use nom::error::context;
+use nom::error::ErrorKind;
+use nom::multi::count;
+use nom::number::complete::{be_f32, be_f64, be_u16, be_u32, be_u8};
+
+pub(crate) fn gridded_data_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    context("Gridded Data Body", |input| {
+        let (input, environmental_simulation_id) = entity_id(input)?;
+        let (input, field_number) = be_u16(input)?;
+        let (input, pdu_number) = be_u16(input)?;
+        let (input, pdu_total) = be_u16(input)?;
+        let (input, coordinate_system) = be_u16(input)?;
+        let (input, number_of_grid_axes) = be_u8(input)?;
+        let (input, constant_grid) = be_u8(input)?;
+        let (input, environment_type) = entity_type(input)?;
+        let (input, orientation) = orientation(input)?;
+        let (input, sample_time) = clock_time(input)?;
+        let (input, total_values) = be_u32(input)?;
+        let (input, vector_dimension) = be_u8(input)?;
+        let (input, _padding) = take(3usize)(input)?;
+        let (input, grid_axis_descriptors) =
+            count(grid_axis_descriptor, number_of_grid_axes.into())(input)?;
+        let (input, grid_data) = count(grid_data, vector_dimension.into())(input)?;
+
+        let body = GriddedData::builder()
+            .with_environmental_simulation_id(environmental_simulation_id)
+            .with_field_number(field_number)
+            .with_pdu_number(pdu_number)
+            .with_pdu_total(pdu_total)
+            .with_coordinate_system(GriddedDataCoordinateSystem::from(coordinate_system))
+            .with_constant_grid(GriddedDataConstantGrid::from(constant_grid))
+            .with_environment_type(environment_type)
+            .with_orientation(orientation)
+            .with_sample_time(sample_time)
+            .with_total_values(total_values)
+            .with_grid_axis_descriptors(grid_axis_descriptors)
+            .with_grid_data_records(grid_data)
+            .build();
+
+        Ok((input, body.into_pdu_body()))
+    })(input)
+}
+
+fn grid_axis_descriptor(input: &[u8]) -> IResult<&[u8], GridAxisDescriptor> {
+    context("Grid Axis Descriptor", |input| {
+        let (input, domain_initial) = be_f64(input)?;
+        let (input, domain_final) = be_f64(input)?;
+        let (input, domain_points) = be_u16(input)?;
+        let (input, interleaf_factor) = be_u8(input)?;
+        let (input, axis_type) = be_u8(input)?;
+        let (input, number_of_points_on_axis) = be_u16(input)?;
+        let (input, initial_index) = be_u16(input)?;
+
+        if axis_type == 0 {
+            return Ok((
+                input,
+                GridAxisDescriptor::Regular(GridAxisRegular {
+                    domain_initial,
+                    domain_final,
+                    domain_points,
+                    interleaf_factor,
+                    number_of_points_on_axis,
+                    initial_index,
+                }),
+            ));
+        }
+
+        let (input, coordinate_scale) = be_f64(input)?;
+        let (input, coordinate_offset) = be_f64(input)?;
+        let (input, x_values) = count(be_u16, number_of_points_on_axis.into())(input)?;
+        let (input, _padding) = take(irregular_axis_lengths(x_values.len()).padding_length)(input)?;
+
+        Ok((
+            input,
+            GridAxisDescriptor::Irregular(GridAxisIrregular {
+                domain_initial,
+                domain_final,
+                domain_points,
+                interleaf_factor,
+                initial_index,
+                coordinate_scale,
+                coordinate_offset,
+                x_values,
+            }),
+        ))
+    })(input)
+}
+
+fn grid_data(input: &[u8]) -> IResult<&[u8], GridData> {
+    context("Grid Data", |input| {
+        let (input, sample_type) = be_u16(input)?;
+        let (input, data_representation) = be_u16(input)?;
+
+        let (input, data) = match data_representation {
+            0 => {
+                let (input, number_of_octets) = be_u16(input)?;
+                let (input, values) = count(be_u8, number_of_octets.into())(input)?;
+                (input, GridDataRepresentation::Type0 { values })
+            }
+            1 => {
+                let (input, field_scale) = be_f32(input)?;
+                let (input, field_offset) = be_f32(input)?;
+                let (input, number_of_values) = be_u16(input)?;
+                let (input, values) = count(be_u16, number_of_values.into())(input)?;
+                (
+                    input,
+                    GridDataRepresentation::Type1 {
+                        field_scale,
+                        field_offset,
+                        values,
+                    },
+                )
+            }
+            2 => {
+                let (input, number_of_values) = be_u16(input)?;
+                let (input, _padding) = be_u16(input)?;
+                let (input, values) = count(be_f32, number_of_values.into())(input)?;
+                (input, GridDataRepresentation::Type2 { values })
+            }
+            _ => return Err(nom::Err::Error(ParserError::new(input, ErrorKind::Switch))),
+        };
+        let (input, _padding) = take(data.padded_lengths().padding_length)(input)?;
+
+        Ok((
+            input,
+            GridData::new(GriddedDataSampleType::from(sample_type), data),
+        ))
+    })(input)
+}
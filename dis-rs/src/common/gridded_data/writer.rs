@@ -0,0 +1,105 @@
+use crate::common::BodyInfo;
+use crate::gridded_data::model::{
+    irregular_axis_lengths, GridAxisDescriptor, GridData, GridDataRepresentation, GriddedData,
+};
+use crate::{Serialize, SerializePdu, SupportedVersion};
+use bytes::BufMut;
+
+impl SerializePdu for GriddedData {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
+        self.environmental_simulation_id.serialize(buf);
+        buf.put_u16(self.field_number);
+        buf.put_u16(self.pdu_number);
+        buf.put_u16(self.pdu_total);
+        buf.put_u16(self.coordinate_system.into());
+        buf.put_u8(self.grid_axis_descriptors.len() as u8);
+        buf.put_u8(self.constant_grid.into());
+        self.environment_type.serialize(buf);
+        self.orientation.serialize(buf);
+        self.sample_time.serialize(buf);
+        buf.put_u32(self.total_values);
+        buf.put_u8(self.grid_data.len() as u8);
+        buf.put_bytes(0u8, 3);
+        self.grid_axis_descriptors
+            .iter()
+            .map(|descriptor| descriptor.serialize(buf))
+            .sum::<u16>();
+        self.grid_data
+            .iter()
+            .map(|grid_data| grid_data.serialize(buf))
+            .sum::<u16>();
+
+        self.body_length()
+    }
+}
+
+impl Serialize for GridAxisDescriptor {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        match self {
+            GridAxisDescriptor::Regular(axis) => {
+                buf.put_f64(axis.domain_initial);
+                buf.put_f64(axis.domain_final);
+                buf.put_u16(axis.domain_points);
+                buf.put_u8(axis.interleaf_factor);
+                buf.put_u8(0u8);
+                buf.put_u16(axis.number_of_points_on_axis);
+                buf.put_u16(axis.initial_index);
+            }
+            GridAxisDescriptor::Irregular(axis) => {
+                buf.put_f64(axis.domain_initial);
+                buf.put_f64(axis.domain_final);
+                buf.put_u16(axis.domain_points);
+                buf.put_u8(axis.interleaf_factor);
+                buf.put_u8(1u8);
+                buf.put_u16(axis.x_values.len() as u16);
+                buf.put_u16(axis.initial_index);
+                buf.put_f64(axis.coordinate_scale);
+                buf.put_f64(axis.coordinate_offset);
+                for &value in &axis.x_values {
+                    buf.put_u16(value);
+                }
+                buf.put_bytes(
+                    0u8,
+                    irregular_axis_lengths(axis.x_values.len()).padding_length,
+                );
+            }
+        }
+
+        self.record_length()
+    }
+}
+
+impl Serialize for GridData {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        buf.put_u16(self.sample_type.into());
+        buf.put_u16(self.data.data_representation());
+        match &self.data {
+            GridDataRepresentation::Type0 { values } => {
+                buf.put_u16(values.len() as u16);
+                buf.put_slice(values);
+            }
+            GridDataRepresentation::Type1 {
+                field_scale,
+                field_offset,
+                values,
+            } => {
+                buf.put_f32(*field_scale);
+                buf.put_f32(*field_offset);
+                buf.put_u16(values.len() as u16);
+                for &value in values {
+                    buf.put_u16(value);
+                }
+            }
+            GridDataRepresentation::Type2 { values } => {
+                buf.put_u16(values.len() as u16);
+                buf.put_u16(0u16);
+                for &value in values {
+                    buf.put_f32(value);
+                }
+            }
+        }
+        buf.put_bytes(0u8, self.padded_lengths().padding_length);
+
+        self.record_length()
+    }
+}
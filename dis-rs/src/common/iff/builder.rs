@@ -19,6 +19,8 @@
     ModeSSquitterRecordSource, ModeSSquitterType, ModeSTransmitState, NavigationSource,
     VariableRecordType,
 };
+use alloc::string::String;
+use alloc::vec::Vec;
 
 pub struct IffBuilder(Iff);
 
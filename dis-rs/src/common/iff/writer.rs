@@ -16,10 +16,10 @@
     EIGHT_OCTETS, FOUR_OCTETS, ONE_OCTET, SIX_OCTETS, THREE_OCTETS, TWO_OCTETS,
 };
 use crate::DisError;
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 
 impl SerializePdu for Iff {
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
         let entity_id_bytes = self.emitting_entity_id.serialize(buf);
         let event_id_bytes = self.event_id.serialize(buf);
         let antenna_location_bytes = self.relative_antenna_location.serialize(buf);
@@ -63,7 +63,7 @@ fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for IffLayer2 {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let layer_header_bytes = self.layer_header.serialize(buf);
         let beam_data_bytes = self.beam_data.serialize(buf);
         buf.put_u8(self.operational_parameter_1);
@@ -80,7 +80,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for IffLayer3 {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let layer_header_bytes = self.layer_header.serialize(buf);
         let reporting_simulation_bytes = self.reporting_simulation.serialize(buf);
         let basic_data_bytes = match &self.mode_5_basic_data {
@@ -99,7 +99,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for IffLayer4 {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let layer_header_bytes = self.layer_header.serialize(buf);
         let reporting_simulation_bytes = self.reporting_simulation.serialize(buf);
         let basic_data_bytes = match &self.mode_s_basic_data {
@@ -118,7 +118,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for IffLayer5 {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let layer_header_bytes = self.layer_header.serialize(buf);
         let reporting_simulation_bytes = self.reporting_simulation.serialize(buf);
         buf.put_u16(0u16);
@@ -137,7 +137,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for ChangeOptionsRecord {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let byte: u8 = self.into();
         buf.put_u8(byte);
 
@@ -146,7 +146,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for FundamentalOperationalData {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let system_status_bytes = self.system_status.serialize(buf);
         buf.put_u8(self.data_field_1);
         let information_layers_bytes = self.information_layers.serialize(buf);
@@ -190,7 +190,7 @@ fn from(value: &LayersPresenceApplicability) -> Self {
 }
 
 impl Serialize for IffDataRecord {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let padded_record_lengths =
             length_padded_to_num(SIX_OCTETS + self.record_specific_fields.len(), FOUR_OCTETS);
         let record_length_bytes = padded_record_lengths.record_length as u16;
@@ -205,7 +205,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for IffDataSpecification {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u16(self.iff_data_records.len() as u16);
         let records_bytes: u16 = self
             .iff_data_records
@@ -218,7 +218,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for InformationLayers {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let byte = u8::from(self);
         buf.put_u8(byte);
 
@@ -227,7 +227,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for IffFundamentalParameterData {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_f32(self.erp);
         buf.put_f32(self.frequency);
         buf.put_f32(self.pgrf);
@@ -241,7 +241,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for LayerHeader {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u8(self.layer_number);
         buf.put_u8(self.layer_specific_information);
         buf.put_u16(self.length);
@@ -251,7 +251,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for SystemSpecificData {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u8(self.part_1);
         buf.put_u8(self.part_2);
         buf.put_u8(self.part_3);
@@ -261,7 +261,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for SystemId {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u16(self.system_type.into());
         buf.put_u16(self.system_name.into());
         buf.put_u8(self.system_mode.into());
@@ -272,7 +272,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for DapSource {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let indicated_air_speed = u8::from(&self.indicated_air_speed) << 7;
         let mach_number = u8::from(&self.mach_number) << 6;
         let ground_speed = u8::from(&self.ground_speed) << 5;
@@ -307,7 +307,7 @@ fn from(value: &DapValue) -> Self {
 }
 
 impl Serialize for EnhancedMode1Code {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let bytes = u16::from(self);
         buf.put_u16(bytes);
 
@@ -316,7 +316,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for Mode5InterrogatorBasicData {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let _status_bytes = self.status.serialize(buf);
         buf.put_u8(0u8);
         buf.put_u16(0u16);
@@ -329,7 +329,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for Mode5InterrogatorStatus {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let byte = u8::from(self);
         buf.put_u8(byte);
 
@@ -338,7 +338,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for Mode5MessageFormats {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let value = u32::from(self);
         buf.put_u32(value);
 
@@ -347,7 +347,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for Mode5TransponderBasicData {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let status_bytes = self.status.serialize(buf);
         buf.put_u16(self.pin);
         let formats_present_bytes = self.mode_5_message_formats_present.serialize(buf);
@@ -408,7 +408,7 @@ fn from(value: &LatLonAltSource) -> Self {
 }
 
 impl Serialize for Mode5TransponderSupplementalData {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let byte = u8::from(self);
         buf.put_u8(byte);
 
@@ -417,7 +417,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for Mode5TransponderStatus {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u16(self.into());
 
         TWO_OCTETS as u16
@@ -425,7 +425,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for ModeSAltitude {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u16(self.into());
 
         TWO_OCTETS as u16
@@ -433,7 +433,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for ModeSInterrogatorBasicData {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         const PAD_168_BITS_IN_OCTETS: usize = 21;
         let _status_bytes = self.mode_s_interrogator_status.serialize(buf);
         buf.put_u8(0u8);
@@ -445,7 +445,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for ModeSInterrogatorStatus {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u8(self.into());
 
         ONE_OCTET as u16
@@ -453,7 +453,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for ModeSLevelsPresent {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u8(self.into());
 
         ONE_OCTET as u16
@@ -479,7 +479,7 @@ fn from(value: &IffPresence) -> Self {
 }
 
 impl Serialize for ModeSTransponderBasicData {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let _status_bytes = self.status.serialize(buf);
         let _levels_present_bytes = self.levels_present.serialize(buf);
         buf.put_u8(self.aircraft_present_domain.into());
@@ -502,7 +502,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 fn put_ascii_string_with_length(
-    buf: &mut BytesMut,
+    buf: &mut impl BufMut,
     value: &str,
     length: usize,
 ) -> Result<u16, DisError> {
@@ -518,7 +518,7 @@ fn put_ascii_string_with_length(
 }
 
 impl Serialize for ModeSTransponderStatus {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let squitter_status: u8 = u8::from(&self.squitter_status) << 7;
         let squitter_type: u8 = u8::from(self.squitter_type) << 4;
         let squitter_record_source: u8 = u8::from(self.squitter_record_source) << 3;
@@ -555,7 +555,7 @@ fn from(value: &SquitterStatus) -> Self {
 }
 
 impl Serialize for SystemStatus {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let byte = u8::from(self);
         buf.put_u8(byte);
 
@@ -0,0 +1,98 @@
+use crate::enumerations::{IOActionPhase, IOActionType, IOSimulationSource, IOWarfareType};
+use crate::information_operations_action::model::InformationOperationsAction;
+use crate::model::{EntityId, IORecord};
+use alloc::vec::Vec;
+
+pub struct InformationOperationsActionBuilder(InformationOperationsAction);
+
+impl Default for InformationOperationsActionBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InformationOperationsActionBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        InformationOperationsActionBuilder(InformationOperationsAction::default())
+    }
+
+    #[must_use]
+    pub fn new_from_body(body: InformationOperationsAction) -> Self {
+        InformationOperationsActionBuilder(body)
+    }
+
+    #[must_use]
+    pub fn build(self) -> InformationOperationsAction {
+        self.0
+    }
+
+    #[must_use]
+    pub fn with_originating_simulation_id(mut self, originating_simulation_id: EntityId) -> Self {
+        self.0.originating_simulation_id = originating_simulation_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_receiving_simulation_id(mut self, receiving_simulation_id: EntityId) -> Self {
+        self.0.receiving_simulation_id = receiving_simulation_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_request_id(mut self, request_id: u32) -> Self {
+        self.0.request_id = request_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_io_warfare_type(mut self, io_warfare_type: IOWarfareType) -> Self {
+        self.0.io_warfare_type = io_warfare_type;
+        self
+    }
+
+    #[must_use]
+    pub fn with_io_simulation_source(mut self, io_simulation_source: IOSimulationSource) -> Self {
+        self.0.io_simulation_source = io_simulation_source;
+        self
+    }
+
+    #[must_use]
+    pub fn with_io_action_type(mut self, io_action_type: IOActionType) -> Self {
+        self.0.io_action_type = io_action_type;
+        self
+    }
+
+    #[must_use]
+    pub fn with_io_action_phase(mut self, io_action_phase: IOActionPhase) -> Self {
+        self.0.io_action_phase = io_action_phase;
+        self
+    }
+
+    #[must_use]
+    pub fn with_io_attacker_entity_id(mut self, io_attacker_entity_id: EntityId) -> Self {
+        self.0.io_attacker_entity_id = io_attacker_entity_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_io_primary_target_entity_id(
+        mut self,
+        io_primary_target_entity_id: EntityId,
+    ) -> Self {
+        self.0.io_primary_target_entity_id = io_primary_target_entity_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_io_record(mut self, io_record: IORecord) -> Self {
+        self.0.io_records.push(io_record);
+        self
+    }
+
+    #[must_use]
+    pub fn with_io_records(mut self, io_records: Vec<IORecord>) -> Self {
+        self.0.io_records = io_records;
+        self
+    }
+}
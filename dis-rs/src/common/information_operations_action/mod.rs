@@ -0,0 +1,87 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::model::DisTimeStamp;
+    use crate::common::model::{Pdu, PduHeader};
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{
+        CoupledExtensionIndicator, IOActionPhase, IOActionType, IOCommsNodeType, IOEffectLinkType,
+        IOEffectProcess, IOEffectStatus, IOEffectType, IOSimulationSource, IOWarfareType,
+        LvcIndicator, PduType,
+    };
+    use crate::information_operations_action::model::InformationOperationsAction;
+    use crate::model::{
+        CommunicationsNodeId, EntityId, IOCommunicationsNode, IOEffect, IORecord,
+        StandardVariableRecord,
+    };
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+
+    #[test]
+    fn information_operations_action_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::InformationOperationsAction).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::NoStatement)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = InformationOperationsAction::builder()
+            .with_originating_simulation_id(EntityId::new(1, 1, 0))
+            .with_receiving_simulation_id(EntityId::new(2, 2, 0))
+            .with_request_id(42)
+            .with_io_warfare_type(IOWarfareType::from(1u16))
+            .with_io_simulation_source(IOSimulationSource::from(1u16))
+            .with_io_action_type(IOActionType::from(2u16))
+            .with_io_action_phase(IOActionPhase::from(3u16))
+            .with_io_attacker_entity_id(EntityId::new(1, 1, 10))
+            .with_io_primary_target_entity_id(EntityId::new(2, 2, 20))
+            .with_io_record(
+                IOCommunicationsNode::new(
+                    IOCommsNodeType::from(1u8),
+                    CommunicationsNodeId::new(EntityId::new(2, 2, 20), 1),
+                )
+                .into_record(),
+            )
+            .with_io_record(
+                IOEffect::default()
+                    .with_status(IOEffectStatus::from(1u8))
+                    .with_link_type(IOEffectLinkType::from(2u8))
+                    .with_effect(IOEffectType::from(1u8))
+                    .with_effect_duty_cycle(50)
+                    .with_effect_duration(120)
+                    .with_process(IOEffectProcess::from(1u16))
+                    .into_record(),
+            )
+            .with_io_record(IORecord::Unspecified(StandardVariableRecord::new(
+                5600,
+                vec![1, 2],
+            )))
+            .build()
+            .into_pdu_body();
+
+        let original_pdu =
+            Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+}
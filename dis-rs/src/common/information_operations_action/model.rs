@@ -0,0 +1,69 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::enumerations::{
+    IOActionPhase, IOActionType, IOSimulationSource, IOWarfareType, PduType,
+};
+use crate::information_operations_action::builder::InformationOperationsActionBuilder;
+use crate::model::{EntityId, IORecord, PduBody};
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const BASE_INFORMATION_OPERATIONS_ACTION_BODY_LENGTH: u16 = 44;
+
+/// 7.12.2 Information Operations Action PDU
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InformationOperationsAction {
+    pub originating_simulation_id: EntityId,
+    pub receiving_simulation_id: EntityId,
+    pub request_id: u32,
+    pub io_warfare_type: IOWarfareType,
+    pub io_simulation_source: IOSimulationSource,
+    pub io_action_type: IOActionType,
+    pub io_action_phase: IOActionPhase,
+    pub io_attacker_entity_id: EntityId,
+    pub io_primary_target_entity_id: EntityId,
+    pub io_records: Vec<IORecord>,
+}
+
+impl InformationOperationsAction {
+    #[must_use]
+    pub fn builder() -> InformationOperationsActionBuilder {
+        InformationOperationsActionBuilder::new()
+    }
+
+    #[must_use]
+    pub fn into_builder(self) -> InformationOperationsActionBuilder {
+        InformationOperationsActionBuilder::new_from_body(self)
+    }
+
+    #[must_use]
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::InformationOperationsAction(self)
+    }
+}
+
+impl BodyInfo for InformationOperationsAction {
+    fn body_length(&self) -> u16 {
+        BASE_INFORMATION_OPERATIONS_ACTION_BODY_LENGTH
+            + self
+                .io_records
+                .iter()
+                .map(IORecord::record_length)
+                .sum::<u16>()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::InformationOperationsAction
+    }
+}
+
+impl Interaction for InformationOperationsAction {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.originating_simulation_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        Some(&self.receiving_simulation_id)
+    }
+}
@@ -0,0 +1,41 @@
+use crate::common::parser::IResult;
+use crate::common::parser::{entity_id, io_record};
+use crate::enumerations::{IOActionPhase, IOActionType, IOSimulationSource, IOWarfareType};
+use crate::information_operations_action::model::InformationOperationsAction;
+use crate::model::PduBody;
+use nom::error::context;
+use nom::multi::count;
+use nom::number::complete::{be_u16, be_u32};
+
+pub(crate) fn information_operations_action_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    context("Information Operations Action Body", |input| {
+        let (input, originating_simulation_id) = entity_id(input)?;
+        let (input, receiving_simulation_id) = entity_id(input)?;
+        let (input, request_id) = be_u32(input)?;
+        let (input, io_warfare_type) = be_u16(input)?;
+        let (input, io_simulation_source) = be_u16(input)?;
+        let (input, io_action_type) = be_u16(input)?;
+        let (input, io_action_phase) = be_u16(input)?;
+        let (input, _padding) = be_u32(input)?;
+        let (input, io_attacker_entity_id) = entity_id(input)?;
+        let (input, io_primary_target_entity_id) = entity_id(input)?;
+        let (input, _padding) = be_u16(input)?;
+        let (input, number_of_io_records) = be_u16(input)?;
+        let (input, io_records) = count(io_record, number_of_io_records.into())(input)?;
+
+        let body = InformationOperationsAction::builder()
+            .with_originating_simulation_id(originating_simulation_id)
+            .with_receiving_simulation_id(receiving_simulation_id)
+            .with_request_id(request_id)
+            .with_io_warfare_type(IOWarfareType::from(io_warfare_type))
+            .with_io_simulation_source(IOSimulationSource::from(io_simulation_source))
+            .with_io_action_type(IOActionType::from(io_action_type))
+            .with_io_action_phase(IOActionPhase::from(io_action_phase))
+            .with_io_attacker_entity_id(io_attacker_entity_id)
+            .with_io_primary_target_entity_id(io_primary_target_entity_id)
+            .with_io_records(io_records)
+            .build();
+
+        Ok((input, body.into_pdu_body()))
+    })(input)
+}
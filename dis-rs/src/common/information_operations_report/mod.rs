@@ -0,0 +1,69 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::model::DisTimeStamp;
+    use crate::common::model::{Pdu, PduHeader};
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{
+        CoupledExtensionIndicator, IOEffectLinkType, IOEffectProcess, IOEffectStatus, IOEffectType,
+        IOReportType, IOSimulationSource, LvcIndicator, PduType,
+    };
+    use crate::information_operations_report::model::InformationOperationsReport;
+    use crate::model::{EntityId, IOEffect};
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+
+    #[test]
+    fn information_operations_report_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::InformationOperationsReport).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::NoStatement)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = InformationOperationsReport::builder()
+            .with_originating_simulation_id(EntityId::new(2, 2, 0))
+            .with_receiving_simulation_id(EntityId::new(1, 1, 0))
+            .with_io_simulation_source(IOSimulationSource::from(1u16))
+            .with_io_report_type(IOReportType::from(1u8))
+            .with_io_attacker_entity_id(EntityId::new(1, 1, 10))
+            .with_io_primary_target_entity_id(EntityId::new(2, 2, 20))
+            .with_io_record(
+                IOEffect::default()
+                    .with_status(IOEffectStatus::from(2u8))
+                    .with_link_type(IOEffectLinkType::from(1u8))
+                    .with_effect(IOEffectType::from(2u8))
+                    .with_effect_duty_cycle(100)
+                    .with_effect_duration(30)
+                    .with_process(IOEffectProcess::from(2u16))
+                    .into_record(),
+            )
+            .build()
+            .into_pdu_body();
+
+        let original_pdu =
+            Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+}
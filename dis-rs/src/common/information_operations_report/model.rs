@@ -0,0 +1,64 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::enumerations::{IOReportType, IOSimulationSource, PduType};
+use crate::information_operations_report::builder::InformationOperationsReportBuilder;
+use crate::model::{EntityId, IORecord, PduBody};
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const BASE_INFORMATION_OPERATIONS_REPORT_BODY_LENGTH: u16 = 36;
+
+/// 7.12.3 Information Operations Report PDU
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InformationOperationsReport {
+    pub originating_simulation_id: EntityId,
+    pub receiving_simulation_id: EntityId,
+    pub io_simulation_source: IOSimulationSource,
+    pub io_report_type: IOReportType,
+    pub io_attacker_entity_id: EntityId,
+    pub io_primary_target_entity_id: EntityId,
+    pub io_records: Vec<IORecord>,
+}
+
+impl InformationOperationsReport {
+    #[must_use]
+    pub fn builder() -> InformationOperationsReportBuilder {
+        InformationOperationsReportBuilder::new()
+    }
+
+    #[must_use]
+    pub fn into_builder(self) -> InformationOperationsReportBuilder {
+        InformationOperationsReportBuilder::new_from_body(self)
+    }
+
+    #[must_use]
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::InformationOperationsReport(self)
+    }
+}
+
+impl BodyInfo for InformationOperationsReport {
+    fn body_length(&self) -> u16 {
+        BASE_INFORMATION_OPERATIONS_REPORT_BODY_LENGTH
+            + self
+                .io_records
+                .iter()
+                .map(IORecord::record_length)
+                .sum::<u16>()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::InformationOperationsReport
+    }
+}
+
+impl Interaction for InformationOperationsReport {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.originating_simulation_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        Some(&self.receiving_simulation_id)
+    }
+}
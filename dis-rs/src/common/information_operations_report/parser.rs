@@ -0,0 +1,36 @@
+use crate::common::parser::IResult;
+use crate::common::parser::{entity_id, io_record};
+use crate::enumerations::{IOReportType, IOSimulationSource};
+use crate::information_operations_report::model::InformationOperationsReport;
+use crate::model::PduBody;
+use nom::error::context;
+use nom::multi::count;
+use nom::number::complete::{be_u16, be_u32, be_u8};
+
+pub(crate) fn information_operations_report_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    context("Information Operations Report Body", |input| {
+        let (input, originating_simulation_id) = entity_id(input)?;
+        let (input, receiving_simulation_id) = entity_id(input)?;
+        let (input, io_simulation_source) = be_u16(input)?;
+        let (input, io_report_type) = be_u8(input)?;
+        let (input, _padding) = be_u8(input)?;
+        let (input, io_attacker_entity_id) = entity_id(input)?;
+        let (input, io_primary_target_entity_id) = entity_id(input)?;
+        let (input, _padding) = be_u16(input)?;
+        let (input, _padding) = be_u32(input)?;
+        let (input, number_of_io_records) = be_u16(input)?;
+        let (input, io_records) = count(io_record, number_of_io_records.into())(input)?;
+
+        let body = InformationOperationsReport::builder()
+            .with_originating_simulation_id(originating_simulation_id)
+            .with_receiving_simulation_id(receiving_simulation_id)
+            .with_io_simulation_source(IOSimulationSource::from(io_simulation_source))
+            .with_io_report_type(IOReportType::from(io_report_type))
+            .with_io_attacker_entity_id(io_attacker_entity_id)
+            .with_io_primary_target_entity_id(io_primary_target_entity_id)
+            .with_io_records(io_records)
+            .build();
+
+        Ok((input, body.into_pdu_body()))
+    })(input)
+}
@@ -0,0 +1,24 @@
+use crate::common::BodyInfo;
+use crate::information_operations_report::model::InformationOperationsReport;
+use crate::{Serialize, SerializePdu, SupportedVersion};
+use bytes::BufMut;
+
+impl SerializePdu for InformationOperationsReport {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
+        self.originating_simulation_id.serialize(buf);
+        self.receiving_simulation_id.serialize(buf);
+        buf.put_u16(self.io_simulation_source.into());
+        buf.put_u8(self.io_report_type.into());
+        buf.put_u8(0u8);
+        self.io_attacker_entity_id.serialize(buf);
+        self.io_primary_target_entity_id.serialize(buf);
+        buf.put_u16(0u16);
+        buf.put_u32(0u32);
+        buf.put_u16(self.io_records.len() as u16);
+        for record in &self.io_records {
+            record.serialize(buf);
+        }
+
+        self.body_length()
+    }
+}
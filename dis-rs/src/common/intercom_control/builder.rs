@@ -0,0 +1,127 @@
+use crate::intercom_control::model::{
+    CommunicationsChannelType, IntercomCommand, IntercomCommunicationsParameters, IntercomControl,
+    IntercomControlType, IntercomTransmitLineState,
+};
+use crate::model::EntityId;
+use alloc::vec::Vec;
+
+pub struct IntercomControlBuilder(IntercomControl);
+
+impl Default for IntercomControlBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IntercomControlBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        IntercomControlBuilder(IntercomControl::default())
+    }
+
+    #[must_use]
+    pub fn new_from_body(body: IntercomControl) -> Self {
+        IntercomControlBuilder(body)
+    }
+
+    #[must_use]
+    pub fn build(self) -> IntercomControl {
+        self.0
+    }
+
+    #[must_use]
+    pub fn with_control_type(mut self, control_type: IntercomControlType) -> Self {
+        self.0.control_type = control_type;
+        self
+    }
+
+    #[must_use]
+    pub fn with_communications_channel_type(
+        mut self,
+        communications_channel_type: CommunicationsChannelType,
+    ) -> Self {
+        self.0.communications_channel_type = communications_channel_type;
+        self
+    }
+
+    #[must_use]
+    pub fn with_source_entity_id(mut self, source_entity_id: EntityId) -> Self {
+        self.0.source_entity_id = source_entity_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_source_communications_device_id(
+        mut self,
+        source_communications_device_id: u16,
+    ) -> Self {
+        self.0.source_communications_device_id = source_communications_device_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_source_line_id(mut self, source_line_id: u8) -> Self {
+        self.0.source_line_id = source_line_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_transmit_priority(mut self, transmit_priority: u8) -> Self {
+        self.0.transmit_priority = transmit_priority;
+        self
+    }
+
+    #[must_use]
+    pub fn with_transmit_line_state(
+        mut self,
+        transmit_line_state: IntercomTransmitLineState,
+    ) -> Self {
+        self.0.transmit_line_state = transmit_line_state;
+        self
+    }
+
+    #[must_use]
+    pub fn with_command(mut self, command: IntercomCommand) -> Self {
+        self.0.command = command;
+        self
+    }
+
+    #[must_use]
+    pub fn with_master_intercom_reference_id(
+        mut self,
+        master_intercom_reference_id: EntityId,
+    ) -> Self {
+        self.0.master_intercom_reference_id = master_intercom_reference_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_master_intercom_number(mut self, master_intercom_number: u16) -> Self {
+        self.0.master_intercom_number = master_intercom_number;
+        self
+    }
+
+    #[must_use]
+    pub fn with_master_channel_id(mut self, master_channel_id: u16) -> Self {
+        self.0.master_channel_id = master_channel_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_intercom_parameter(
+        mut self,
+        intercom_parameter: IntercomCommunicationsParameters,
+    ) -> Self {
+        self.0.intercom_parameters.push(intercom_parameter);
+        self
+    }
+
+    #[must_use]
+    pub fn with_intercom_parameters(
+        mut self,
+        intercom_parameters: Vec<IntercomCommunicationsParameters>,
+    ) -> Self {
+        self.0.intercom_parameters = intercom_parameters;
+        self
+    }
+}
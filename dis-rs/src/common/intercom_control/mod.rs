@@ -0,0 +1,66 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::model::DisTimeStamp;
+    use crate::common::model::{Pdu, PduHeader};
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::PduType;
+    use crate::intercom_control::model::{
+        CommunicationsChannelType, IntercomCommand, IntercomCommunicationsClass,
+        IntercomCommunicationsParameters, IntercomCommunicationsType, IntercomControl,
+        IntercomControlType, IntercomParametersRecordType, IntercomTransmitLineState,
+    };
+    use crate::model::EntityId;
+    use bytes::BytesMut;
+
+    #[test]
+    fn intercom_control_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::IntercomControl);
+
+        let body = IntercomControl::builder()
+            .with_control_type(IntercomControlType::RequestAcknowledgeRequired)
+            .with_communications_channel_type(CommunicationsChannelType::new(
+                IntercomCommunicationsType::ConnectionFdx,
+                IntercomCommunicationsClass::SimulationSupportCommunicationsChannel,
+            ))
+            .with_source_entity_id(EntityId::new(1, 1, 1))
+            .with_source_communications_device_id(3)
+            .with_source_line_id(1)
+            .with_transmit_priority(2)
+            .with_transmit_line_state(IntercomTransmitLineState::Transmitting)
+            .with_command(IntercomCommand::Connect)
+            .with_master_intercom_reference_id(EntityId::new(1, 1, 2))
+            .with_master_intercom_number(4)
+            .with_master_channel_id(5)
+            .with_intercom_parameter(IntercomCommunicationsParameters::new(
+                IntercomParametersRecordType::SpecificDestinationRecord,
+                vec![0, 1, 0, 1, 0, 3, 0, 4, 1],
+            ))
+            .build()
+            .into_pdu_body();
+        let original_pdu =
+            Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+}
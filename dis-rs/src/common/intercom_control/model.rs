@@ -0,0 +1,357 @@
+use crate::common::model::{length_padded_to_num, EntityId, PduBody};
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::FOUR_OCTETS;
+use crate::enumerations::PduType;
+use crate::intercom_control::builder::IntercomControlBuilder;
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const BASE_INTERCOM_CONTROL_BODY_LENGTH: u16 = 28;
+const BASE_INTERCOM_COMMUNICATIONS_PARAMETERS_LENGTH: usize = 4;
+
+/// 7.7.6 Intercom Control PDU
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IntercomControl {
+    pub control_type: IntercomControlType,
+    pub communications_channel_type: CommunicationsChannelType,
+    pub source_entity_id: EntityId,
+    pub source_communications_device_id: u16,
+    pub source_line_id: u8,
+    pub transmit_priority: u8,
+    pub transmit_line_state: IntercomTransmitLineState,
+    pub command: IntercomCommand,
+    pub master_intercom_reference_id: EntityId,
+    pub master_intercom_number: u16,
+    pub master_channel_id: u16,
+    pub intercom_parameters: Vec<IntercomCommunicationsParameters>,
+}
+
+impl IntercomControl {
+    #[must_use]
+    pub fn builder() -> IntercomControlBuilder {
+        IntercomControlBuilder::new()
+    }
+
+    #[must_use]
+    pub fn into_builder(self) -> IntercomControlBuilder {
+        IntercomControlBuilder::new_from_body(self)
+    }
+
+    #[must_use]
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::IntercomControl(self)
+    }
+}
+
+impl BodyInfo for IntercomControl {
+    fn body_length(&self) -> u16 {
+        BASE_INTERCOM_CONTROL_BODY_LENGTH
+            + self
+                .intercom_parameters
+                .iter()
+                .map(IntercomCommunicationsParameters::record_length)
+                .sum::<u16>()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::IntercomControl
+    }
+}
+
+impl Interaction for IntercomControl {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.source_entity_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// 6.2.47 Intercom Communications Parameters record
+///
+/// The record-specific field is kept as raw bytes, and is padded to a 32-bit boundary on the wire.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IntercomCommunicationsParameters {
+    pub record_type: IntercomParametersRecordType,
+    pub record_specific_field: Vec<u8>,
+}
+
+impl IntercomCommunicationsParameters {
+    #[must_use]
+    pub fn new(record_type: IntercomParametersRecordType, record_specific_field: Vec<u8>) -> Self {
+        Self {
+            record_type,
+            record_specific_field,
+        }
+    }
+
+    #[must_use]
+    pub fn record_length(&self) -> u16 {
+        length_padded_to_num(
+            BASE_INTERCOM_COMMUNICATIONS_PARAMETERS_LENGTH + self.record_specific_field.len(),
+            FOUR_OCTETS,
+        )
+        .record_length as u16
+    }
+}
+
+/// Intercom Control-Control Type (SISO-REF-010)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IntercomControlType {
+    #[default]
+    Reserved,
+    Status,
+    RequestAcknowledgeRequired,
+    RequestNoAcknowledge,
+    AckRequestGranted,
+    NackRequestDenied,
+    Unspecified(u8),
+}
+
+impl From<u8> for IntercomControlType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Reserved,
+            1 => Self::Status,
+            2 => Self::RequestAcknowledgeRequired,
+            3 => Self::RequestNoAcknowledge,
+            4 => Self::AckRequestGranted,
+            5 => Self::NackRequestDenied,
+            other => Self::Unspecified(other),
+        }
+    }
+}
+
+impl From<IntercomControlType> for u8 {
+    fn from(value: IntercomControlType) -> Self {
+        match value {
+            IntercomControlType::Reserved => 0,
+            IntercomControlType::Status => 1,
+            IntercomControlType::RequestAcknowledgeRequired => 2,
+            IntercomControlType::RequestNoAcknowledge => 3,
+            IntercomControlType::AckRequestGranted => 4,
+            IntercomControlType::NackRequestDenied => 5,
+            IntercomControlType::Unspecified(value) => value,
+        }
+    }
+}
+
+/// Communications Channel Type record, combining the Communications Type (bits 0-6)
+/// and the Communications Class (bit 7).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CommunicationsChannelType {
+    pub communications_type: IntercomCommunicationsType,
+    pub communications_class: IntercomCommunicationsClass,
+}
+
+impl CommunicationsChannelType {
+    #[must_use]
+    pub fn new(
+        communications_type: IntercomCommunicationsType,
+        communications_class: IntercomCommunicationsClass,
+    ) -> Self {
+        Self {
+            communications_type,
+            communications_class,
+        }
+    }
+}
+
+impl From<u8> for CommunicationsChannelType {
+    fn from(value: u8) -> Self {
+        Self {
+            communications_type: IntercomCommunicationsType::from(value & 0x7F),
+            communications_class: IntercomCommunicationsClass::from(value >> 7),
+        }
+    }
+}
+
+impl From<CommunicationsChannelType> for u8 {
+    fn from(value: CommunicationsChannelType) -> Self {
+        (u8::from(value.communications_type) & 0x7F) | u8::from(value.communications_class) << 7
+    }
+}
+
+/// Intercom Control-Communications Type (SISO-REF-010)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IntercomCommunicationsType {
+    #[default]
+    Reserved,
+    ConnectionFdx,
+    ConnectionHdxDestinationIsReceiveOnly,
+    ConnectionHdxDestinationIsTransmitOnly,
+    ConnectionHdx,
+    Unspecified(u8),
+}
+
+impl From<u8> for IntercomCommunicationsType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Reserved,
+            1 => Self::ConnectionFdx,
+            2 => Self::ConnectionHdxDestinationIsReceiveOnly,
+            3 => Self::ConnectionHdxDestinationIsTransmitOnly,
+            4 => Self::ConnectionHdx,
+            other => Self::Unspecified(other),
+        }
+    }
+}
+
+impl From<IntercomCommunicationsType> for u8 {
+    fn from(value: IntercomCommunicationsType) -> Self {
+        match value {
+            IntercomCommunicationsType::Reserved => 0,
+            IntercomCommunicationsType::ConnectionFdx => 1,
+            IntercomCommunicationsType::ConnectionHdxDestinationIsReceiveOnly => 2,
+            IntercomCommunicationsType::ConnectionHdxDestinationIsTransmitOnly => 3,
+            IntercomCommunicationsType::ConnectionHdx => 4,
+            IntercomCommunicationsType::Unspecified(value) => value,
+        }
+    }
+}
+
+/// Intercom Control-Communications Class (SISO-REF-010)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IntercomCommunicationsClass {
+    #[default]
+    SimulatedCommunicationsChannel,
+    SimulationSupportCommunicationsChannel,
+}
+
+impl From<u8> for IntercomCommunicationsClass {
+    fn from(value: u8) -> Self {
+        if value & 0x01 == 0 {
+            Self::SimulatedCommunicationsChannel
+        } else {
+            Self::SimulationSupportCommunicationsChannel
+        }
+    }
+}
+
+impl From<IntercomCommunicationsClass> for u8 {
+    fn from(value: IntercomCommunicationsClass) -> Self {
+        match value {
+            IntercomCommunicationsClass::SimulatedCommunicationsChannel => 0,
+            IntercomCommunicationsClass::SimulationSupportCommunicationsChannel => 1,
+        }
+    }
+}
+
+/// Intercom Control-Transmit Line State (SISO-REF-010)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IntercomTransmitLineState {
+    #[default]
+    TransmitLineStateNotApplicable,
+    NotTransmitting,
+    Transmitting,
+    Unspecified(u8),
+}
+
+impl From<u8> for IntercomTransmitLineState {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::TransmitLineStateNotApplicable,
+            1 => Self::NotTransmitting,
+            2 => Self::Transmitting,
+            other => Self::Unspecified(other),
+        }
+    }
+}
+
+impl From<IntercomTransmitLineState> for u8 {
+    fn from(value: IntercomTransmitLineState) -> Self {
+        match value {
+            IntercomTransmitLineState::TransmitLineStateNotApplicable => 0,
+            IntercomTransmitLineState::NotTransmitting => 1,
+            IntercomTransmitLineState::Transmitting => 2,
+            IntercomTransmitLineState::Unspecified(value) => value,
+        }
+    }
+}
+
+/// Intercom Control-Command (SISO-REF-010)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IntercomCommand {
+    #[default]
+    NoCommand,
+    Status,
+    Connect,
+    Disconnect,
+    Reset,
+    On,
+    Off,
+    Unspecified(u8),
+}
+
+impl From<u8> for IntercomCommand {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::NoCommand,
+            1 => Self::Status,
+            2 => Self::Connect,
+            3 => Self::Disconnect,
+            4 => Self::Reset,
+            5 => Self::On,
+            6 => Self::Off,
+            other => Self::Unspecified(other),
+        }
+    }
+}
+
+impl From<IntercomCommand> for u8 {
+    fn from(value: IntercomCommand) -> Self {
+        match value {
+            IntercomCommand::NoCommand => 0,
+            IntercomCommand::Status => 1,
+            IntercomCommand::Connect => 2,
+            IntercomCommand::Disconnect => 3,
+            IntercomCommand::Reset => 4,
+            IntercomCommand::On => 5,
+            IntercomCommand::Off => 6,
+            IntercomCommand::Unspecified(value) => value,
+        }
+    }
+}
+
+/// Intercom Control-Record Type (SISO-REF-010)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IntercomParametersRecordType {
+    #[default]
+    SpecificDestinationRecord,
+    GroupDestinationRecord,
+    GroupAssignmentRecord,
+    Unspecified(u16),
+}
+
+impl From<u16> for IntercomParametersRecordType {
+    fn from(value: u16) -> Self {
+        match value {
+            1 => Self::SpecificDestinationRecord,
+            2 => Self::GroupDestinationRecord,
+            3 => Self::GroupAssignmentRecord,
+            other => Self::Unspecified(other),
+        }
+    }
+}
+
+impl From<IntercomParametersRecordType> for u16 {
+    fn from(value: IntercomParametersRecordType) -> Self {
+        match value {
+            IntercomParametersRecordType::SpecificDestinationRecord => 1,
+            IntercomParametersRecordType::GroupDestinationRecord => 2,
+            IntercomParametersRecordType::GroupAssignmentRecord => 3,
+            IntercomParametersRecordType::Unspecified(value) => value,
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::common::model::{length_padded_to_num, PduBody};
+use crate::common::parser::entity_id;
+use crate::common::parser::IResult;
+use crate::constants::FOUR_OCTETS;
+use crate::intercom_control::model::{
+    CommunicationsChannelType, IntercomCommand, IntercomCommunicationsParameters, IntercomControl,
+    IntercomControlType, IntercomParametersRecordType, IntercomTransmitLineState,
+};
+use nom::bytes::complete::take;
+use nom::error::context;
+use nom::multi::count;
+use nom::number::complete::{be_u16, be_u32, be_u8};
+
+pub(crate) fn intercom_control_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    context("Intercom Control Body", |input| {
+        let (input, control_type) = be_u8(input)?;
+        let (input, communications_channel_type) = be_u8(input)?;
+        let (input, source_entity_id) = entity_id(input)?;
+        let (input, source_communications_device_id) = be_u16(input)?;
+        let (input, source_line_id) = be_u8(input)?;
+        let (input, transmit_priority) = be_u8(input)?;
+        let (input, transmit_line_state) = be_u8(input)?;
+        let (input, command) = be_u8(input)?;
+        let (input, master_intercom_reference_id) = entity_id(input)?;
+        let (input, master_intercom_number) = be_u16(input)?;
+        let (input, master_channel_id) = be_u16(input)?;
+        let (input, number_of_intercom_parameters) = be_u32(input)?;
+        let (input, intercom_parameters) = count(
+            intercom_communications_parameters,
+            number_of_intercom_parameters as usize,
+        )(input)?;
+
+        let body = IntercomControl::builder()
+            .with_control_type(IntercomControlType::from(control_type))
+            .with_communications_channel_type(CommunicationsChannelType::from(
+                communications_channel_type,
+            ))
+            .with_source_entity_id(source_entity_id)
+            .with_source_communications_device_id(source_communications_device_id)
+            .with_source_line_id(source_line_id)
+            .with_transmit_priority(transmit_priority)
+            .with_transmit_line_state(IntercomTransmitLineState::from(transmit_line_state))
+            .with_command(IntercomCommand::from(command))
+            .with_master_intercom_reference_id(master_intercom_reference_id)
+            .with_master_intercom_number(master_intercom_number)
+            .with_master_channel_id(master_channel_id)
+            .with_intercom_parameters(intercom_parameters)
+            .build();
+
+        Ok((input, body.into_pdu_body()))
+    })(input)
+}
+
+fn intercom_communications_parameters(
+    input: &[u8],
+) -> IResult<&[u8], IntercomCommunicationsParameters> {
+    context("Intercom Communications Parameters", |input| {
+        let (input, record_type) = be_u16(input)?;
+        let (input, record_length) = be_u16(input)?;
+        let (input, record_specific_field): (_, &[u8]) = take(record_length)(input)?;
+        let padded_record_lengths = length_padded_to_num(record_length as usize, FOUR_OCTETS);
+        let (input, _padding) = take(padded_record_lengths.padding_length)(input)?;
+
+        Ok((
+            input,
+            IntercomCommunicationsParameters::new(
+                IntercomParametersRecordType::from(record_type),
+                record_specific_field.to_vec(),
+            ),
+        ))
+    })(input)
+}
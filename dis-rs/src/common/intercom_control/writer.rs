@@ -0,0 +1,41 @@
+use crate::common::model::length_padded_to_num;
+use crate::common::{BodyInfo, Serialize, SerializePdu, SupportedVersion};
+use crate::constants::FOUR_OCTETS;
+use crate::intercom_control::model::{IntercomCommunicationsParameters, IntercomControl};
+use bytes::BufMut;
+
+impl SerializePdu for IntercomControl {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
+        buf.put_u8(self.control_type.into());
+        buf.put_u8(self.communications_channel_type.into());
+        self.source_entity_id.serialize(buf);
+        buf.put_u16(self.source_communications_device_id);
+        buf.put_u8(self.source_line_id);
+        buf.put_u8(self.transmit_priority);
+        buf.put_u8(self.transmit_line_state.into());
+        buf.put_u8(self.command.into());
+        self.master_intercom_reference_id.serialize(buf);
+        buf.put_u16(self.master_intercom_number);
+        buf.put_u16(self.master_channel_id);
+        buf.put_u32(self.intercom_parameters.len() as u32);
+        for parameter in &self.intercom_parameters {
+            parameter.serialize(buf);
+        }
+
+        self.body_length()
+    }
+}
+
+impl Serialize for IntercomCommunicationsParameters {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        let padded_record_lengths =
+            length_padded_to_num(self.record_specific_field.len(), FOUR_OCTETS);
+
+        buf.put_u16(self.record_type.into());
+        buf.put_u16(self.record_specific_field.len() as u16);
+        buf.put(&self.record_specific_field[..]);
+        buf.put_bytes(0u8, padded_record_lengths.padding_length);
+
+        self.record_length()
+    }
+}
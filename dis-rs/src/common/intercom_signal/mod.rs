@@ -0,0 +1,54 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::model::DisTimeStamp;
+    use crate::common::model::{Pdu, PduHeader};
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{PduType, SignalEncodingClass, SignalEncodingType};
+    use crate::intercom_signal::model::IntercomSignal;
+    use crate::model::EntityId;
+    use crate::signal::model::EncodingScheme;
+    use bytes::BytesMut;
+
+    #[test]
+    fn intercom_signal_internal_consistency() {
+        let header = PduHeader::new_v6(1, PduType::IntercomSignal);
+
+        let body = IntercomSignal::builder()
+            .with_intercom_reference_id(EntityId::new(10, 10, 123))
+            .with_intercom_number(2)
+            .with_encoding_scheme(EncodingScheme::EncodedAudio {
+                encoding_class: SignalEncodingClass::EncodedAudio,
+                encoding_type: SignalEncodingType::_16bitLinearPCM2sComplement_BigEndian_4,
+            })
+            .with_sample_rate(8000)
+            .with_samples(3)
+            .with_data(vec![0x10, 0x20, 0x30, 0x40, 0x50])
+            .build()
+            .into_pdu_body();
+        let original_pdu =
+            Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+}
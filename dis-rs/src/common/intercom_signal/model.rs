@@ -0,0 +1,64 @@
+use crate::common::model::{length_padded_to_num, EntityId, PduBody};
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::FOUR_OCTETS;
+use crate::enumerations::{PduType, SignalTdlType};
+use crate::intercom_signal::builder::IntercomSignalBuilder;
+use crate::signal::model::EncodingScheme;
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const BASE_INTERCOM_SIGNAL_BODY_LENGTH: u16 = 20;
+
+/// 7.7.5 Intercom Signal PDU
+///
+/// The Intercom Signal PDU shares the `EncodingScheme` of the Signal PDU.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IntercomSignal {
+    pub intercom_reference_id: EntityId,
+    pub intercom_number: u16,
+    pub encoding_scheme: EncodingScheme,
+    pub tdl_type: SignalTdlType,
+    pub sample_rate: u32,
+    pub samples: u16,
+    pub data: Vec<u8>,
+}
+
+impl IntercomSignal {
+    #[must_use]
+    pub fn builder() -> IntercomSignalBuilder {
+        IntercomSignalBuilder::new()
+    }
+
+    #[must_use]
+    pub fn into_builder(self) -> IntercomSignalBuilder {
+        IntercomSignalBuilder::new_from_body(self)
+    }
+
+    #[must_use]
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::IntercomSignal(self)
+    }
+}
+
+impl BodyInfo for IntercomSignal {
+    fn body_length(&self) -> u16 {
+        BASE_INTERCOM_SIGNAL_BODY_LENGTH
+            + length_padded_to_num(self.data.len(), FOUR_OCTETS).record_length as u16
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::IntercomSignal
+    }
+}
+
+impl Interaction for IntercomSignal {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.intercom_reference_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
@@ -0,0 +1,38 @@
+use crate::common::model::PduBody;
+use crate::common::parser::entity_id;
+use crate::common::parser::IResult;
+use crate::constants::ONE_BYTE_IN_BITS;
+use crate::enumerations::SignalTdlType;
+use crate::intercom_signal::model::IntercomSignal;
+use crate::signal::parser::parse_encoding_scheme;
+use nom::bytes::complete::take;
+use nom::error::context;
+use nom::number::complete::{be_u16, be_u32};
+
+pub(crate) fn intercom_signal_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    context("Intercom Signal Body", |input| {
+        let (input, intercom_reference_id) = entity_id(input)?;
+        let (input, intercom_number) = be_u16(input)?;
+        let (input, encoding_scheme) = be_u16(input)?;
+        let (input, tdl_type) = be_u16(input)?;
+        let tdl_type = SignalTdlType::from(tdl_type);
+        let (input, sample_rate) = be_u32(input)?;
+        let (input, data_length_in_bits) = be_u16(input)?;
+        let (input, samples) = be_u16(input)?;
+        let (input, data) = take(data_length_in_bits / ONE_BYTE_IN_BITS as u16)(input)?;
+
+        let encoding_scheme = parse_encoding_scheme(encoding_scheme, data);
+
+        let body = IntercomSignal::builder()
+            .with_intercom_reference_id(intercom_reference_id)
+            .with_intercom_number(intercom_number)
+            .with_encoding_scheme(encoding_scheme)
+            .with_tdl_type(tdl_type)
+            .with_sample_rate(sample_rate)
+            .with_samples(samples)
+            .with_data(data.to_vec())
+            .build();
+
+        Ok((input, body.into_pdu_body()))
+    })(input)
+}
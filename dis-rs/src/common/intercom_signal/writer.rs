@@ -0,0 +1,22 @@
+use crate::common::model::length_padded_to_num;
+use crate::common::{BodyInfo, Serialize, SerializePdu, SupportedVersion};
+use crate::constants::{FOUR_OCTETS, ONE_BYTE_IN_BITS};
+use crate::intercom_signal::model::IntercomSignal;
+use bytes::BufMut;
+
+impl SerializePdu for IntercomSignal {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
+        self.intercom_reference_id.serialize(buf);
+        buf.put_u16(self.intercom_number);
+        self.encoding_scheme.serialize(buf);
+        buf.put_u16(self.tdl_type.into());
+        buf.put_u32(self.sample_rate);
+        buf.put_u16((self.data.len() * ONE_BYTE_IN_BITS) as u16);
+        buf.put_u16(self.samples);
+        buf.put(&self.data[..]);
+        let padded_record_lengths = length_padded_to_num(self.data.len(), FOUR_OCTETS);
+        buf.put_bytes(0u8, padded_record_lengths.padding_length);
+
+        self.body_length()
+    }
+}
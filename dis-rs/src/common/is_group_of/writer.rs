@@ -4,10 +4,10 @@
     GroupReferencePoint, IsGroupOf,
 };
 use crate::{Serialize, SerializePdu, SupportedVersion};
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 
 impl SerializePdu for IsGroupOf {
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
         let group_id_bytes = self.group_id.serialize(buf);
         buf.put_u8(self.grouped_entity_category.into());
         buf.put_u8(self.descriptions.len() as u8);
@@ -24,7 +24,7 @@ fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for GroupReferencePoint {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_f64(self.latitude);
         buf.put_f64(self.longitude);
 
@@ -33,7 +33,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for GroupEntityDescription {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         match self {
             GroupEntityDescription::Undefined => 0,
             GroupEntityDescription::BasicGroundCombatVehicle(ged_record) => {
@@ -62,7 +62,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for GEDEntityLocation {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u16(self.x_offset);
         buf.put_u16(self.y_offset);
         buf.put_u16(self.z_offset);
@@ -72,7 +72,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for GEDEntityOrientation {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u8(self.psi);
         buf.put_u8(self.theta);
         buf.put_u8(self.phi);
@@ -82,7 +82,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for GEDRecord1 {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u16(self.entity_id);
         self.location.serialize(buf);
         self.appearance.serialize(buf);
@@ -98,7 +98,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for GEDRecord2 {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         self.basic_ground_combat_vehicle.serialize(buf);
         buf.put_u8(self.fuel_status);
         buf.put_u8(self.ground_maintenance_status);
@@ -110,7 +110,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for GEDRecord3 {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u16(self.entity_id);
         self.location.serialize(buf);
         self.appearance.serialize(buf);
@@ -126,7 +126,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for GEDRecord4 {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         self.basic_ground_combat_soldier.serialize(buf);
         buf.put_u8(self.water_status);
         buf.put_u8(self.reset_status);
@@ -138,7 +138,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for GEDRecord5 {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u16(self.entity_id);
         self.location.serialize(buf);
         self.appearance.serialize(buf);
@@ -157,7 +157,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for GEDRecord6 {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         self.basic_rotor_wing_aircraft.serialize(buf);
         buf.put_u8(self.supplemental_fuel_status);
         buf.put_u8(self.air_maintenance_status);
@@ -169,7 +169,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for GEDRecord7 {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u16(self.entity_id);
         self.location.serialize(buf);
         self.appearance.serialize(buf);
@@ -184,7 +184,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for GEDRecord8 {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         self.basic_fixed_wing_aircraft.serialize(buf);
         buf.put_u8(self.supplemental_fuel_status);
         buf.put_u8(self.air_maintenance_status);
@@ -196,7 +196,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for GEDRecord9 {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u16(self.entity_id);
         self.location.serialize(buf);
         self.appearance.serialize(buf);
@@ -1,9 +1,9 @@
 use crate::is_part_of::model::{IsPartOf, NamedLocationId, Relationship};
 use crate::{Serialize, SerializePdu, SupportedVersion};
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 
 impl SerializePdu for IsPartOf {
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
         let originating_bytes = self.originating_simulation_id.serialize(buf);
         let receiving_bytes = self.receiving_entity_id.serialize(buf);
         let relationship_bytes = self.relationship.serialize(buf);
@@ -21,7 +21,7 @@ fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for Relationship {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u16(self.nature.into());
         buf.put_u16(self.position.into());
 
@@ -30,7 +30,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for NamedLocationId {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u16(self.station_name.into());
         buf.put_u16(self.station_number);
 
@@ -0,0 +1,94 @@
+use crate::enumerations::DetonationResult;
+use crate::le_detonation::model::{LEDetonation, LiveEntityRelativeLocation};
+use crate::model::{
+    LiveEntityId, LiveEntityLinearVelocity, LiveEntityOrientation, LiveEventId,
+    LiveMunitionDescriptor, RelativeWorldCoordinates,
+};
+
+pub struct LEDetonationBuilder(LEDetonation);
+
+impl Default for LEDetonationBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LEDetonationBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        LEDetonationBuilder(LEDetonation::default())
+    }
+
+    #[must_use]
+    pub fn new_from_body(body: LEDetonation) -> Self {
+        LEDetonationBuilder(body)
+    }
+
+    #[must_use]
+    pub fn build(self) -> LEDetonation {
+        self.0
+    }
+
+    #[must_use]
+    pub fn with_firing_live_entity_id(mut self, firing_live_entity_id: LiveEntityId) -> Self {
+        self.0.firing_live_entity_id = firing_live_entity_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_target_live_entity_id(mut self, target_live_entity_id: LiveEntityId) -> Self {
+        self.0.target_live_entity_id = Some(target_live_entity_id);
+        self
+    }
+
+    #[must_use]
+    pub fn with_munition_live_entity_id(mut self, munition_live_entity_id: LiveEntityId) -> Self {
+        self.0.munition_live_entity_id = Some(munition_live_entity_id);
+        self
+    }
+
+    #[must_use]
+    pub fn with_event_id(mut self, event_id: LiveEventId) -> Self {
+        self.0.event_id = Some(event_id);
+        self
+    }
+
+    #[must_use]
+    pub fn with_world_location(mut self, world_location: RelativeWorldCoordinates) -> Self {
+        self.0.world_location = world_location;
+        self
+    }
+
+    #[must_use]
+    pub fn with_velocity(mut self, velocity: LiveEntityLinearVelocity) -> Self {
+        self.0.velocity = velocity;
+        self
+    }
+
+    #[must_use]
+    pub fn with_munition_orientation(
+        mut self,
+        munition_orientation: LiveEntityOrientation,
+    ) -> Self {
+        self.0.munition_orientation = Some(munition_orientation);
+        self
+    }
+
+    #[must_use]
+    pub fn with_munition_descriptor(mut self, munition_descriptor: LiveMunitionDescriptor) -> Self {
+        self.0.munition_descriptor = munition_descriptor;
+        self
+    }
+
+    #[must_use]
+    pub fn with_entity_location(mut self, entity_location: LiveEntityRelativeLocation) -> Self {
+        self.0.entity_location = Some(entity_location);
+        self
+    }
+
+    #[must_use]
+    pub fn with_detonation_result(mut self, detonation_result: DetonationResult) -> Self {
+        self.0.detonation_result = detonation_result;
+        self
+    }
+}
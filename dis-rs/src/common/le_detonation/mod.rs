@@ -0,0 +1,117 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::model::DisTimeStamp;
+    use crate::common::model::{Pdu, PduHeader};
+    use crate::common::parser::parse_pdu;
+    use crate::detonation::model::Detonation;
+    use crate::enumerations::{CoupledExtensionIndicator, DetonationResult, LvcIndicator, PduType};
+    use crate::le_detonation::model::{LEDetonation, LiveEntityRelativeLocation};
+    use crate::model::{
+        EntityId, EntityType, EventId, LiveEntityId, LiveEntityLinearVelocity,
+        LiveEntityOrientation, LiveEventId, LiveMunitionDescriptor, LiveReferencePoint, Location,
+        MunitionDescriptor, RelativeWorldCoordinates, SimulationAddress, VectorF32,
+    };
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+    use core::str::FromStr;
+
+    #[test]
+    fn le_detonation_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::LEDetonation).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::NoStatement)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = LEDetonation::builder()
+            .with_firing_live_entity_id(LiveEntityId::new(1, 2, 3))
+            .with_munition_live_entity_id(LiveEntityId::new(1, 2, 5))
+            .with_event_id(LiveEventId::new(1, 2, 10))
+            .with_world_location(RelativeWorldCoordinates::new(1, 1500, 20, -5))
+            .with_velocity(LiveEntityLinearVelocity::new(2500, 0, -100))
+            .with_munition_orientation(LiveEntityOrientation::new(0, 250, 0))
+            .with_munition_descriptor(
+                LiveMunitionDescriptor::default()
+                    .with_munition_type(EntityType::from_str("2:2:225:2:1:0:0").unwrap())
+                    .with_quantity(1),
+            )
+            .with_entity_location(LiveEntityRelativeLocation::new(1, 0, -1))
+            .with_detonation_result(DetonationResult::from(1u8))
+            .build()
+            .into_pdu_body();
+
+        let original_pdu =
+            Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+
+    #[test]
+    fn le_detonation_detonation_conversion() {
+        let reference_point = LiveReferencePoint::new(1, Location::new(1000.0, 2000.0, 3000.0));
+        let detonation = Detonation::builder()
+            .with_source_entity_id(EntityId::new(1, 2, 3))
+            .with_target_entity_id(EntityId::new(1, 2, 4))
+            .with_event_id(EventId::new(SimulationAddress::new(1, 2), 10))
+            .with_velocity(VectorF32::new(250.0, 0.0, -10.0))
+            .with_world_location(Location::new(2500.0, 2020.0, 2995.0))
+            .with_munition_descriptor(
+                EntityType::from_str("2:2:225:2:1:0:0").unwrap(),
+                MunitionDescriptor::default().with_quantity(1),
+            )
+            .with_entity_location(VectorF32::new(1.0, 0.0, -1.0))
+            .with_detonation_result(DetonationResult::from(1u8))
+            .build();
+
+        let le_detonation = LEDetonation::from_detonation(&detonation, &reference_point);
+        assert_eq!(
+            le_detonation.target_live_entity_id,
+            Some(LiveEntityId::new(1, 2, 4))
+        );
+        assert_eq!(le_detonation.munition_live_entity_id, None);
+        assert_eq!(
+            le_detonation.world_location,
+            RelativeWorldCoordinates::new(1, 1500, 20, -5)
+        );
+        assert_eq!(
+            le_detonation.entity_location,
+            Some(LiveEntityRelativeLocation::new(1, 0, -1))
+        );
+
+        let converted = le_detonation.to_detonation(&reference_point);
+        assert_eq!(converted.source_entity_id, detonation.source_entity_id);
+        assert_eq!(converted.target_entity_id, detonation.target_entity_id);
+        assert_eq!(converted.event_id, detonation.event_id);
+        assert_eq!(converted.velocity, detonation.velocity);
+        assert_eq!(
+            converted.location_in_world_coordinates,
+            detonation.location_in_world_coordinates
+        );
+        assert_eq!(
+            converted.location_in_entity_coordinates,
+            detonation.location_in_entity_coordinates
+        );
+        assert_eq!(converted.detonation_result, detonation.detonation_result);
+    }
+}
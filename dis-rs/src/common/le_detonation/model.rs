@@ -0,0 +1,227 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::SIX_OCTETS;
+use crate::detonation::model::Detonation;
+use crate::enumerations::{DetonationResult, PduType};
+use crate::le_detonation::builder::LEDetonationBuilder;
+use crate::le_fire::model::optional_live_entity_id;
+use crate::model::{
+    round, EntityId, LiveEntityId, LiveEntityLinearVelocity, LiveEntityOrientation, LiveEventId,
+    LiveMunitionDescriptor, LiveReferencePoint, PduBody, RelativeWorldCoordinates, VectorF32,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const BASE_LE_DETONATION_BODY_LENGTH: u16 = 35;
+
+/// 9.4.6 LE Detonation PDU
+///
+/// The presence of the optional fields is signalled on the wire by the `LEDetonationFlags` field,
+/// which is derived from the fields that are set.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LEDetonation {
+    pub firing_live_entity_id: LiveEntityId,
+    pub target_live_entity_id: Option<LiveEntityId>,
+    pub munition_live_entity_id: Option<LiveEntityId>,
+    pub event_id: Option<LiveEventId>,
+    pub world_location: RelativeWorldCoordinates,
+    pub velocity: LiveEntityLinearVelocity,
+    pub munition_orientation: Option<LiveEntityOrientation>,
+    pub munition_descriptor: LiveMunitionDescriptor,
+    pub entity_location: Option<LiveEntityRelativeLocation>,
+    pub detonation_result: DetonationResult,
+}
+
+impl LEDetonation {
+    #[must_use]
+    pub fn builder() -> LEDetonationBuilder {
+        LEDetonationBuilder::new()
+    }
+
+    #[must_use]
+    pub fn into_builder(self) -> LEDetonationBuilder {
+        LEDetonationBuilder::new_from_body(self)
+    }
+
+    #[must_use]
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::LEDetonation(self)
+    }
+
+    /// Determines the `LEDetonationFlags` field based on the optional fields that are present.
+    #[must_use]
+    pub fn flags(&self) -> LEDetonationFlags {
+        LEDetonationFlags {
+            target_id_present: self.target_live_entity_id.is_some(),
+            munition_id_present: self.munition_live_entity_id.is_some(),
+            event_id_present: self.event_id.is_some(),
+            munition_orientation_present: self.munition_orientation.is_some(),
+            entity_location_present: self.entity_location.is_some(),
+        }
+    }
+
+    /// Creates an LE Detonation PDU from a Detonation PDU, with the location expressed relative to `reference_point`.
+    /// Target and munition ids that are not set (`EntityId::default()`) are left out.
+    /// The variable parameters have no counterpart in the LE Detonation PDU and are not carried over.
+    #[must_use]
+    pub fn from_detonation(detonation: &Detonation, reference_point: &LiveReferencePoint) -> Self {
+        LEDetonation {
+            firing_live_entity_id: LiveEntityId::from(&detonation.source_entity_id),
+            target_live_entity_id: optional_live_entity_id(detonation.target_entity_id),
+            munition_live_entity_id: optional_live_entity_id(detonation.exploding_entity_id),
+            event_id: Some(LiveEventId::from(&detonation.event_id)),
+            world_location: RelativeWorldCoordinates::from_location(
+                reference_point,
+                &detonation.location_in_world_coordinates,
+            ),
+            velocity: LiveEntityLinearVelocity::from_velocity(&detonation.velocity),
+            munition_orientation: None,
+            munition_descriptor: LiveMunitionDescriptor::from_descriptor(&detonation.descriptor),
+            entity_location: Some(LiveEntityRelativeLocation::from_location(
+                &detonation.location_in_entity_coordinates,
+            )),
+            detonation_result: detonation.detonation_result,
+        }
+    }
+
+    /// Converts to a Detonation PDU, with the location converted to world coordinates using `reference_point`.
+    #[must_use]
+    pub fn to_detonation(&self, reference_point: &LiveReferencePoint) -> Detonation {
+        Detonation::builder()
+            .with_source_entity_id(self.firing_live_entity_id.into())
+            .with_target_entity_id(
+                self.target_live_entity_id
+                    .map(Into::into)
+                    .unwrap_or_default(),
+            )
+            .with_exploding_entity_id(
+                self.munition_live_entity_id
+                    .map(Into::into)
+                    .unwrap_or_default(),
+            )
+            .with_event_id(self.event_id.map(Into::into).unwrap_or_default())
+            .with_velocity(self.velocity.to_velocity())
+            .with_world_location(self.world_location.to_location(reference_point))
+            .with_descriptor(self.munition_descriptor.to_descriptor())
+            .with_entity_location(
+                self.entity_location
+                    .map(|location| location.to_location())
+                    .unwrap_or_default(),
+            )
+            .with_detonation_result(self.detonation_result)
+            .build()
+    }
+}
+
+impl BodyInfo for LEDetonation {
+    fn body_length(&self) -> u16 {
+        BASE_LE_DETONATION_BODY_LENGTH
+            + self
+                .target_live_entity_id
+                .map_or(0, |id| id.record_length())
+            + self
+                .munition_live_entity_id
+                .map_or(0, |id| id.record_length())
+            + self.event_id.map_or(0, |id| id.record_length())
+            + self
+                .munition_orientation
+                .map_or(0, |orientation| orientation.record_length())
+            + self
+                .entity_location
+                .map_or(0, |location| location.record_length())
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::LEDetonation
+    }
+}
+
+impl Interaction for LEDetonation {
+    fn originator(&self) -> Option<&EntityId> {
+        None
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// LE Detonation Flags fields, indicating which optional fields are present in an LE Detonation PDU
+///
+/// The flags are transmitted as two octets, of which the second is currently unused.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[allow(clippy::struct_excessive_bools)]
+pub struct LEDetonationFlags {
+    pub target_id_present: bool,
+    pub munition_id_present: bool,
+    pub event_id_present: bool,
+    pub munition_orientation_present: bool,
+    pub entity_location_present: bool,
+}
+
+impl From<u8> for LEDetonationFlags {
+    fn from(value: u8) -> Self {
+        Self {
+            target_id_present: (value & 0x01) != 0,
+            munition_id_present: ((value >> 1) & 0x01) != 0,
+            event_id_present: ((value >> 2) & 0x01) != 0,
+            munition_orientation_present: ((value >> 3) & 0x01) != 0,
+            entity_location_present: ((value >> 4) & 0x01) != 0,
+        }
+    }
+}
+
+impl From<LEDetonationFlags> for u8 {
+    fn from(value: LEDetonationFlags) -> Self {
+        u8::from(value.target_id_present)
+            | u8::from(value.munition_id_present) << 1
+            | u8::from(value.event_id_present) << 2
+            | u8::from(value.munition_orientation_present) << 3
+            | u8::from(value.entity_location_present) << 4
+    }
+}
+
+/// Location of the detonation relative to the target entity, in meters
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LiveEntityRelativeLocation {
+    pub x_component: i16,
+    pub y_component: i16,
+    pub z_component: i16,
+}
+
+impl LiveEntityRelativeLocation {
+    #[must_use]
+    pub fn new(x_component: i16, y_component: i16, z_component: i16) -> Self {
+        Self {
+            x_component,
+            y_component,
+            z_component,
+        }
+    }
+
+    /// Converts a location in entity coordinates, rounding to whole meters.
+    #[must_use]
+    pub fn from_location(location: &VectorF32) -> Self {
+        Self {
+            x_component: round(f64::from(location.first_vector_component)) as i16,
+            y_component: round(f64::from(location.second_vector_component)) as i16,
+            z_component: round(f64::from(location.third_vector_component)) as i16,
+        }
+    }
+
+    #[must_use]
+    pub fn to_location(&self) -> VectorF32 {
+        VectorF32::new(
+            f32::from(self.x_component),
+            f32::from(self.y_component),
+            f32::from(self.z_component),
+        )
+    }
+
+    #[must_use]
+    pub const fn record_length(&self) -> u16 {
+        SIX_OCTETS as u16
+    }
+}
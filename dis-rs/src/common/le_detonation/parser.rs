@@ -0,0 +1,60 @@
+use crate::common::parser::IResult;
+use crate::common::parser::{
+    live_entity_id, live_entity_linear_velocity, live_entity_orientation, live_event_id,
+    live_munition_descriptor, relative_world_coordinates,
+};
+use crate::enumerations::DetonationResult;
+use crate::le_detonation::model::{LEDetonation, LEDetonationFlags, LiveEntityRelativeLocation};
+use crate::model::PduBody;
+use nom::combinator::cond;
+use nom::error::context;
+use nom::number::complete::{be_i16, be_u8};
+
+pub(crate) fn le_detonation_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    context("LE Detonation Body", |input| {
+        let (input, firing_live_entity_id) = live_entity_id(input)?;
+        let (input, flags) = be_u8(input)?;
+        let flags = LEDetonationFlags::from(flags);
+        let (input, _flags_2) = be_u8(input)?;
+        let (input, target_live_entity_id) = cond(flags.target_id_present, live_entity_id)(input)?;
+        let (input, munition_live_entity_id) =
+            cond(flags.munition_id_present, live_entity_id)(input)?;
+        let (input, event_id) = cond(flags.event_id_present, live_event_id)(input)?;
+        let (input, world_location) = relative_world_coordinates(input)?;
+        let (input, velocity) = live_entity_linear_velocity(input)?;
+        let (input, munition_orientation) =
+            cond(flags.munition_orientation_present, live_entity_orientation)(input)?;
+        let (input, munition_descriptor) = live_munition_descriptor(input)?;
+        let (input, entity_location) =
+            cond(flags.entity_location_present, live_entity_relative_location)(input)?;
+        let (input, detonation_result) = be_u8(input)?;
+
+        let body = LEDetonation {
+            firing_live_entity_id,
+            target_live_entity_id,
+            munition_live_entity_id,
+            event_id,
+            world_location,
+            velocity,
+            munition_orientation,
+            munition_descriptor,
+            entity_location,
+            detonation_result: DetonationResult::from(detonation_result),
+        };
+
+        Ok((input, body.into_pdu_body()))
+    })(input)
+}
+
+fn live_entity_relative_location(input: &[u8]) -> IResult<&[u8], LiveEntityRelativeLocation> {
+    context("Live Entity Relative Location", |input| {
+        let (input, x_component) = be_i16(input)?;
+        let (input, y_component) = be_i16(input)?;
+        let (input, z_component) = be_i16(input)?;
+
+        Ok((
+            input,
+            LiveEntityRelativeLocation::new(x_component, y_component, z_component),
+        ))
+    })(input)
+}
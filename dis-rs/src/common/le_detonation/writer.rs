@@ -0,0 +1,43 @@
+use crate::common::BodyInfo;
+use crate::le_detonation::model::{LEDetonation, LiveEntityRelativeLocation};
+use crate::{Serialize, SerializePdu, SupportedVersion};
+use bytes::BufMut;
+
+impl SerializePdu for LEDetonation {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
+        self.firing_live_entity_id.serialize(buf);
+        buf.put_u8(self.flags().into());
+        buf.put_u8(0u8);
+        if let Some(id) = &self.target_live_entity_id {
+            id.serialize(buf);
+        }
+        if let Some(id) = &self.munition_live_entity_id {
+            id.serialize(buf);
+        }
+        if let Some(id) = &self.event_id {
+            id.serialize(buf);
+        }
+        self.world_location.serialize(buf);
+        self.velocity.serialize(buf);
+        if let Some(orientation) = &self.munition_orientation {
+            orientation.serialize(buf);
+        }
+        self.munition_descriptor.serialize(buf);
+        if let Some(location) = &self.entity_location {
+            location.serialize(buf);
+        }
+        buf.put_u8(self.detonation_result.into());
+
+        self.body_length()
+    }
+}
+
+impl Serialize for LiveEntityRelativeLocation {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        buf.put_i16(self.x_component);
+        buf.put_i16(self.y_component);
+        buf.put_i16(self.z_component);
+
+        self.record_length()
+    }
+}
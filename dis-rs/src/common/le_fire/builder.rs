@@ -0,0 +1,78 @@
+use crate::le_fire::model::LEFire;
+use crate::model::{
+    LiveEntityId, LiveEntityLinearVelocity, LiveEventId, LiveMunitionDescriptor,
+    RelativeWorldCoordinates,
+};
+
+pub struct LEFireBuilder(LEFire);
+
+impl Default for LEFireBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LEFireBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        LEFireBuilder(LEFire::default())
+    }
+
+    #[must_use]
+    pub fn new_from_body(body: LEFire) -> Self {
+        LEFireBuilder(body)
+    }
+
+    #[must_use]
+    pub fn build(self) -> LEFire {
+        self.0
+    }
+
+    #[must_use]
+    pub fn with_firing_live_entity_id(mut self, firing_live_entity_id: LiveEntityId) -> Self {
+        self.0.firing_live_entity_id = firing_live_entity_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_target_live_entity_id(mut self, target_live_entity_id: LiveEntityId) -> Self {
+        self.0.target_live_entity_id = Some(target_live_entity_id);
+        self
+    }
+
+    #[must_use]
+    pub fn with_munition_live_entity_id(mut self, munition_live_entity_id: LiveEntityId) -> Self {
+        self.0.munition_live_entity_id = Some(munition_live_entity_id);
+        self
+    }
+
+    #[must_use]
+    pub fn with_event_id(mut self, event_id: LiveEventId) -> Self {
+        self.0.event_id = Some(event_id);
+        self
+    }
+
+    #[must_use]
+    pub fn with_location(mut self, location: RelativeWorldCoordinates) -> Self {
+        self.0.location = location;
+        self
+    }
+
+    #[must_use]
+    pub fn with_munition_descriptor(mut self, munition_descriptor: LiveMunitionDescriptor) -> Self {
+        self.0.munition_descriptor = munition_descriptor;
+        self
+    }
+
+    #[must_use]
+    pub fn with_velocity(mut self, velocity: LiveEntityLinearVelocity) -> Self {
+        self.0.velocity = velocity;
+        self
+    }
+
+    #[must_use]
+    pub fn with_range(mut self, range: u16) -> Self {
+        self.0.range = range;
+        self
+    }
+}
@@ -0,0 +1,112 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::model::DisTimeStamp;
+    use crate::common::model::{Pdu, PduHeader};
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{
+        CoupledExtensionIndicator, LvcIndicator, MunitionDescriptorFuse, MunitionDescriptorWarhead,
+        PduType,
+    };
+    use crate::fire::model::Fire;
+    use crate::le_fire::model::LEFire;
+    use crate::model::{
+        DescriptorRecord, EntityId, EntityType, EventId, LiveEntityId, LiveEntityLinearVelocity,
+        LiveEventId, LiveMunitionDescriptor, LiveReferencePoint, Location, MunitionDescriptor,
+        RelativeWorldCoordinates, SimulationAddress, VectorF32,
+    };
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+    use core::str::FromStr;
+
+    #[test]
+    fn le_fire_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::LEFire).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::NoStatement)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = LEFire::builder()
+            .with_firing_live_entity_id(LiveEntityId::new(1, 2, 3))
+            .with_target_live_entity_id(LiveEntityId::new(1, 2, 4))
+            .with_event_id(LiveEventId::new(1, 2, 10))
+            .with_location(RelativeWorldCoordinates::new(1, 10, 20, -5))
+            .with_munition_descriptor(
+                LiveMunitionDescriptor::default()
+                    .with_munition_type(EntityType::from_str("2:2:225:2:1:0:0").unwrap())
+                    .with_warhead(MunitionDescriptorWarhead::from(1000u16))
+                    .with_fuse(MunitionDescriptorFuse::from(1000u16))
+                    .with_quantity(1)
+                    .with_rate(0),
+            )
+            .with_velocity(LiveEntityLinearVelocity::new(3000, 0, 0))
+            .with_range(1500)
+            .build()
+            .into_pdu_body();
+
+        let original_pdu =
+            Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+
+    #[test]
+    fn le_fire_fire_conversion() {
+        let reference_point = LiveReferencePoint::new(1, Location::new(1000.0, 2000.0, 3000.0));
+        let fire = Fire::builder()
+            .with_firing_entity_id(EntityId::new(1, 2, 3))
+            .with_event_id(EventId::new(SimulationAddress::new(1, 2), 10))
+            .with_location_in_world(Location::new(1010.0, 2020.0, 2995.0))
+            .with_munition_descriptor(
+                EntityType::from_str("2:2:225:2:1:0:0").unwrap(),
+                MunitionDescriptor::default().with_quantity(1),
+            )
+            .with_velocity(VectorF32::new(300.0, 0.0, 0.0))
+            .with_range(1500.0)
+            .build();
+
+        let le_fire = LEFire::from_fire(&fire, &reference_point);
+        assert_eq!(le_fire.target_live_entity_id, None);
+        assert_eq!(le_fire.munition_live_entity_id, None);
+        assert_eq!(le_fire.event_id, Some(LiveEventId::new(1, 2, 10)));
+        assert_eq!(
+            le_fire.location,
+            RelativeWorldCoordinates::new(1, 10, 20, -5)
+        );
+        assert_eq!(le_fire.velocity, LiveEntityLinearVelocity::new(3000, 0, 0));
+        assert_eq!(le_fire.range, 1500);
+
+        let converted = le_fire.to_fire(&reference_point);
+        assert_eq!(converted.firing_entity_id, fire.firing_entity_id);
+        assert_eq!(converted.target_entity_id, EntityId::default());
+        assert_eq!(converted.event_id, fire.event_id);
+        assert_eq!(converted.location_in_world, fire.location_in_world);
+        assert!(matches!(
+            converted.descriptor,
+            DescriptorRecord::Munition { .. }
+        ));
+        assert_eq!(converted.velocity, fire.velocity);
+        assert_eq!(converted.range, fire.range);
+    }
+}
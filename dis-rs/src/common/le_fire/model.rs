@@ -0,0 +1,161 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::enumerations::PduType;
+use crate::fire::model::Fire;
+use crate::le_fire::builder::LEFireBuilder;
+use crate::model::{
+    round, EntityId, LiveEntityId, LiveEntityLinearVelocity, LiveEventId, LiveMunitionDescriptor,
+    LiveReferencePoint, PduBody, RelativeWorldCoordinates,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const BASE_LE_FIRE_BODY_LENGTH: u16 = 36;
+
+/// 9.4.5 LE Fire PDU
+///
+/// The presence of the optional fields is signalled on the wire by the `LEFireFlags` field,
+/// which is derived from the fields that are set.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LEFire {
+    pub firing_live_entity_id: LiveEntityId,
+    pub target_live_entity_id: Option<LiveEntityId>,
+    pub munition_live_entity_id: Option<LiveEntityId>,
+    pub event_id: Option<LiveEventId>,
+    pub location: RelativeWorldCoordinates,
+    pub munition_descriptor: LiveMunitionDescriptor,
+    pub velocity: LiveEntityLinearVelocity,
+    pub range: u16,
+}
+
+impl LEFire {
+    #[must_use]
+    pub fn builder() -> LEFireBuilder {
+        LEFireBuilder::new()
+    }
+
+    #[must_use]
+    pub fn into_builder(self) -> LEFireBuilder {
+        LEFireBuilder::new_from_body(self)
+    }
+
+    #[must_use]
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::LEFire(self)
+    }
+
+    /// Determines the `LEFireFlags` field based on the optional fields that are present.
+    #[must_use]
+    pub fn flags(&self) -> LEFireFlags {
+        LEFireFlags {
+            target_id_present: self.target_live_entity_id.is_some(),
+            munition_id_present: self.munition_live_entity_id.is_some(),
+            event_id_present: self.event_id.is_some(),
+        }
+    }
+
+    /// Creates an LE Fire PDU from a Fire PDU, with the location expressed relative to `reference_point`.
+    /// Target and munition ids that are not set (`EntityId::default()`) are left out.
+    /// The fire mission index has no counterpart in the LE Fire PDU and is not carried over.
+    #[must_use]
+    pub fn from_fire(fire: &Fire, reference_point: &LiveReferencePoint) -> Self {
+        LEFire {
+            firing_live_entity_id: LiveEntityId::from(&fire.firing_entity_id),
+            target_live_entity_id: optional_live_entity_id(fire.target_entity_id),
+            munition_live_entity_id: optional_live_entity_id(fire.entity_id),
+            event_id: Some(LiveEventId::from(&fire.event_id)),
+            location: RelativeWorldCoordinates::from_location(
+                reference_point,
+                &fire.location_in_world,
+            ),
+            munition_descriptor: LiveMunitionDescriptor::from_descriptor(&fire.descriptor),
+            velocity: LiveEntityLinearVelocity::from_velocity(&fire.velocity),
+            range: round(f64::from(fire.range)) as u16,
+        }
+    }
+
+    /// Converts to a Fire PDU, with the location converted to world coordinates using `reference_point`.
+    #[must_use]
+    pub fn to_fire(&self, reference_point: &LiveReferencePoint) -> Fire {
+        Fire::builder()
+            .with_firing_entity_id(self.firing_live_entity_id.into())
+            .with_target_entity_id(
+                self.target_live_entity_id
+                    .map(Into::into)
+                    .unwrap_or_default(),
+            )
+            .with_entity_id(
+                self.munition_live_entity_id
+                    .map(Into::into)
+                    .unwrap_or_default(),
+            )
+            .with_event_id(self.event_id.map(Into::into).unwrap_or_default())
+            .with_location_in_world(self.location.to_location(reference_point))
+            .with_descriptor(self.munition_descriptor.to_descriptor())
+            .with_velocity(self.velocity.to_velocity())
+            .with_range(f32::from(self.range))
+            .build()
+    }
+}
+
+pub(crate) fn optional_live_entity_id(entity_id: EntityId) -> Option<LiveEntityId> {
+    if entity_id == EntityId::default() {
+        None
+    } else {
+        Some(LiveEntityId::from(&entity_id))
+    }
+}
+
+impl BodyInfo for LEFire {
+    fn body_length(&self) -> u16 {
+        BASE_LE_FIRE_BODY_LENGTH
+            + self
+                .target_live_entity_id
+                .map_or(0, |id| id.record_length())
+            + self
+                .munition_live_entity_id
+                .map_or(0, |id| id.record_length())
+            + self.event_id.map_or(0, |id| id.record_length())
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::LEFire
+    }
+}
+
+impl Interaction for LEFire {
+    fn originator(&self) -> Option<&EntityId> {
+        None
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// LE Fire Flags field, indicating which optional fields are present in an LE Fire PDU
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LEFireFlags {
+    pub target_id_present: bool,
+    pub munition_id_present: bool,
+    pub event_id_present: bool,
+}
+
+impl From<u8> for LEFireFlags {
+    fn from(value: u8) -> Self {
+        Self {
+            target_id_present: (value & 0x01) != 0,
+            munition_id_present: ((value >> 1) & 0x01) != 0,
+            event_id_present: ((value >> 2) & 0x01) != 0,
+        }
+    }
+}
+
+impl From<LEFireFlags> for u8 {
+    fn from(value: LEFireFlags) -> Self {
+        u8::from(value.target_id_present)
+            | u8::from(value.munition_id_present) << 1
+            | u8::from(value.event_id_present) << 2
+    }
+}
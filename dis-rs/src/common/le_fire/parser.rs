@@ -0,0 +1,40 @@
+use crate::common::parser::IResult;
+use crate::common::parser::{
+    live_entity_id, live_entity_linear_velocity, live_event_id, live_munition_descriptor,
+    relative_world_coordinates,
+};
+use crate::le_fire::model::{LEFire, LEFireFlags};
+use crate::model::PduBody;
+use nom::combinator::cond;
+use nom::error::context;
+use nom::number::complete::{be_u16, be_u8};
+
+pub(crate) fn le_fire_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    context("LE Fire Body", |input| {
+        let (input, firing_live_entity_id) = live_entity_id(input)?;
+        let (input, flags) = be_u8(input)?;
+        let flags = LEFireFlags::from(flags);
+        let (input, _padding) = be_u8(input)?;
+        let (input, target_live_entity_id) = cond(flags.target_id_present, live_entity_id)(input)?;
+        let (input, munition_live_entity_id) =
+            cond(flags.munition_id_present, live_entity_id)(input)?;
+        let (input, event_id) = cond(flags.event_id_present, live_event_id)(input)?;
+        let (input, location) = relative_world_coordinates(input)?;
+        let (input, munition_descriptor) = live_munition_descriptor(input)?;
+        let (input, velocity) = live_entity_linear_velocity(input)?;
+        let (input, range) = be_u16(input)?;
+
+        let body = LEFire {
+            firing_live_entity_id,
+            target_live_entity_id,
+            munition_live_entity_id,
+            event_id,
+            location,
+            munition_descriptor,
+            velocity,
+            range,
+        };
+
+        Ok((input, body.into_pdu_body()))
+    })(input)
+}
@@ -0,0 +1,27 @@
+use crate::common::BodyInfo;
+use crate::le_fire::model::LEFire;
+use crate::{Serialize, SerializePdu, SupportedVersion};
+use bytes::BufMut;
+
+impl SerializePdu for LEFire {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
+        self.firing_live_entity_id.serialize(buf);
+        buf.put_u8(self.flags().into());
+        buf.put_u8(0u8);
+        if let Some(id) = &self.target_live_entity_id {
+            id.serialize(buf);
+        }
+        if let Some(id) = &self.munition_live_entity_id {
+            id.serialize(buf);
+        }
+        if let Some(id) = &self.event_id {
+            id.serialize(buf);
+        }
+        self.location.serialize(buf);
+        self.munition_descriptor.serialize(buf);
+        self.velocity.serialize(buf);
+        buf.put_u16(self.range);
+
+        self.body_length()
+    }
+}
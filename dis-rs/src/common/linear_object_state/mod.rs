@@ -0,0 +1,128 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::model::DisTimeStamp;
+    use crate::common::model::{Pdu, PduHeader};
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{
+        CoupledExtensionIndicator, ForceId, LaneMarkerAppearance, LvcIndicator, PduType,
+        PlatformDomain, TankDitchAppearance,
+    };
+    use crate::linear_object_state::model::{
+        LinearObjectAppearance, LinearObjectState, LinearSegmentParameter,
+    };
+    use crate::model::{
+        EntityId, GeneralObjectAppearance, Location, ObjectKind, ObjectStateModification,
+        ObjectType, Orientation, SimulationAddress,
+    };
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+
+    #[test]
+    fn linear_object_state_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::LinearObjectState).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::NoStatement)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = LinearObjectState::builder()
+            .with_object_id(EntityId::new(10, 10, 200))
+            .with_referenced_object_id(EntityId::new(10, 10, 1))
+            .with_update_number(5)
+            .with_force_id(ForceId::Friendly)
+            .with_requester_id(SimulationAddress::new(10, 10))
+            .with_receiving_id(SimulationAddress::new(20, 20))
+            .with_object_type(ObjectType::new(
+                PlatformDomain::Land,
+                ObjectKind::ObstacleMarker,
+                1,
+                0,
+            ))
+            .with_segment(
+                LinearSegmentParameter::default()
+                    .with_segment_number(1)
+                    .with_segment_modification(
+                        ObjectStateModification::default()
+                            .with_location()
+                            .with_orientation(),
+                    )
+                    .with_general_segment_appearance(
+                        GeneralObjectAppearance::default().with_percent_complete(100),
+                    )
+                    .with_segment_location(Location::new(100.0, 200.0, 300.0))
+                    .with_segment_orientation(Orientation::new(0.5, 0.0, 0.0))
+                    .with_segment_length(50.0)
+                    .with_segment_width(2.0)
+                    .with_segment_height(1.5),
+            )
+            .with_segment(
+                LinearSegmentParameter::default()
+                    .with_segment_number(2)
+                    .with_segment_location(Location::new(150.0, 250.0, 300.0))
+                    .with_segment_length(25.0)
+                    .with_segment_depth(0.5),
+            )
+            .build()
+            .into_pdu_body();
+
+        let original_pdu =
+            Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+
+    #[test]
+    fn linear_object_appearance_from_object_type() {
+        let appearance = 0x4000_0000u32;
+
+        let tank_ditch = ObjectType::new(PlatformDomain::Land, ObjectKind::Obstacle, 1, 0);
+        let typed = LinearObjectAppearance::from_bytes(appearance, &tank_ditch);
+        assert_eq!(
+            typed,
+            LinearObjectAppearance::TankDitch(TankDitchAppearance::from(appearance))
+        );
+        assert_eq!(u32::from(&typed), appearance);
+
+        let other = ObjectType::new(PlatformDomain::Land, ObjectKind::TacticalSmoke, 1, 0);
+        assert_eq!(
+            LinearObjectAppearance::from_bytes(appearance, &other),
+            LinearObjectAppearance::Unspecified(appearance)
+        );
+    }
+
+    #[test]
+    fn linear_segment_typed_specific_appearance() {
+        let lane_marker = LaneMarkerAppearance::from(0x4000_0000);
+        let object_type = ObjectType::new(PlatformDomain::Land, ObjectKind::ObstacleMarker, 1, 0);
+        let segment = LinearSegmentParameter::default().with_typed_specific_segment_appearance(
+            LinearObjectAppearance::LaneMarker(lane_marker),
+        );
+
+        assert_eq!(segment.specific_segment_appearance, u32::from(lane_marker));
+        assert_eq!(
+            segment.typed_specific_segment_appearance(&object_type),
+            LinearObjectAppearance::LaneMarker(lane_marker)
+        );
+    }
+}
@@ -0,0 +1,219 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::enumerations::{ForceId, LaneMarkerAppearance, PduType, TankDitchAppearance};
+use crate::linear_object_state::builder::LinearObjectStateBuilder;
+use crate::model::{
+    EntityId, GeneralObjectAppearance, Location, ObjectKind, ObjectStateModification, ObjectType,
+    Orientation, PduBody, SimulationAddress,
+};
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const BASE_LINEAR_OBJECT_STATE_BODY_LENGTH: u16 = 28;
+const LINEAR_SEGMENT_PARAMETER_LENGTH: u16 = 64;
+
+/// 7.10.5 Linear Object State PDU
+///
+/// The layout of the `specific_appearance` field of each segment depends on the `object_type` of the object.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LinearObjectState {
+    pub object_id: EntityId,
+    pub referenced_object_id: EntityId,
+    pub update_number: u16,
+    pub force_id: ForceId,
+    pub requester_id: SimulationAddress,
+    pub receiving_id: SimulationAddress,
+    pub object_type: ObjectType,
+    pub segments: Vec<LinearSegmentParameter>,
+}
+
+impl LinearObjectState {
+    #[must_use]
+    pub fn builder() -> LinearObjectStateBuilder {
+        LinearObjectStateBuilder::new()
+    }
+
+    #[must_use]
+    pub fn into_builder(self) -> LinearObjectStateBuilder {
+        LinearObjectStateBuilder::new_from_body(self)
+    }
+
+    #[must_use]
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::LinearObjectState(self)
+    }
+}
+
+impl BodyInfo for LinearObjectState {
+    fn body_length(&self) -> u16 {
+        BASE_LINEAR_OBJECT_STATE_BODY_LENGTH
+            + self
+                .segments
+                .iter()
+                .map(LinearSegmentParameter::record_length)
+                .sum::<u16>()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::LinearObjectState
+    }
+}
+
+impl Interaction for LinearObjectState {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.object_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// 6.2.52 Linear Segment Parameter record
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LinearSegmentParameter {
+    pub segment_number: u8,
+    pub segment_modification: ObjectStateModification,
+    pub general_segment_appearance: GeneralObjectAppearance,
+    pub specific_segment_appearance: u32,
+    pub segment_location: Location,
+    pub segment_orientation: Orientation,
+    pub segment_length: f32,
+    pub segment_width: f32,
+    pub segment_height: f32,
+    pub segment_depth: f32,
+}
+
+impl LinearSegmentParameter {
+    #[must_use]
+    pub fn record_length(&self) -> u16 {
+        LINEAR_SEGMENT_PARAMETER_LENGTH
+    }
+
+    #[must_use]
+    pub fn with_segment_number(mut self, segment_number: u8) -> Self {
+        self.segment_number = segment_number;
+        self
+    }
+
+    #[must_use]
+    pub fn with_segment_modification(
+        mut self,
+        segment_modification: ObjectStateModification,
+    ) -> Self {
+        self.segment_modification = segment_modification;
+        self
+    }
+
+    #[must_use]
+    pub fn with_general_segment_appearance(
+        mut self,
+        general_segment_appearance: GeneralObjectAppearance,
+    ) -> Self {
+        self.general_segment_appearance = general_segment_appearance;
+        self
+    }
+
+    #[must_use]
+    pub fn with_specific_segment_appearance(mut self, specific_segment_appearance: u32) -> Self {
+        self.specific_segment_appearance = specific_segment_appearance;
+        self
+    }
+
+    /// Sets the specific segment appearance from a Linear Object Appearance record.
+    #[must_use]
+    pub fn with_typed_specific_segment_appearance(
+        mut self,
+        specific_segment_appearance: LinearObjectAppearance,
+    ) -> Self {
+        self.specific_segment_appearance = u32::from(&specific_segment_appearance);
+        self
+    }
+
+    /// Returns the `specific_segment_appearance` as the Linear Object Appearance record
+    /// matching the `object_type` of the object the segment belongs to.
+    #[must_use]
+    pub fn typed_specific_segment_appearance(
+        &self,
+        object_type: &ObjectType,
+    ) -> LinearObjectAppearance {
+        LinearObjectAppearance::from_bytes(self.specific_segment_appearance, object_type)
+    }
+
+    #[must_use]
+    pub fn with_segment_location(mut self, segment_location: Location) -> Self {
+        self.segment_location = segment_location;
+        self
+    }
+
+    #[must_use]
+    pub fn with_segment_orientation(mut self, segment_orientation: Orientation) -> Self {
+        self.segment_orientation = segment_orientation;
+        self
+    }
+
+    #[must_use]
+    pub fn with_segment_length(mut self, segment_length: f32) -> Self {
+        self.segment_length = segment_length;
+        self
+    }
+
+    #[must_use]
+    pub fn with_segment_width(mut self, segment_width: f32) -> Self {
+        self.segment_width = segment_width;
+        self
+    }
+
+    #[must_use]
+    pub fn with_segment_height(mut self, segment_height: f32) -> Self {
+        self.segment_height = segment_height;
+        self
+    }
+
+    #[must_use]
+    pub fn with_segment_depth(mut self, segment_depth: f32) -> Self {
+        self.segment_depth = segment_depth;
+        self
+    }
+}
+
+/// Specific appearance of a segment of a Linear Object State PDU, being one of the
+/// Linear Object Appearance records (UIDs 488-489)
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LinearObjectAppearance {
+    TankDitch(TankDitchAppearance),
+    LaneMarker(LaneMarkerAppearance),
+    Unspecified(u32),
+}
+
+impl Default for LinearObjectAppearance {
+    fn default() -> Self {
+        Self::Unspecified(0)
+    }
+}
+
+impl LinearObjectAppearance {
+    /// Interprets the specific segment appearance `appearance` according to the `object_type` of the object.
+    /// Object types without a specific appearance record result in `LinearObjectAppearance::Unspecified`.
+    #[must_use]
+    pub fn from_bytes(appearance: u32, object_type: &ObjectType) -> Self {
+        match object_type.kind {
+            ObjectKind::Obstacle => Self::TankDitch(TankDitchAppearance::from(appearance)),
+            ObjectKind::ObstacleMarker => Self::LaneMarker(LaneMarkerAppearance::from(appearance)),
+            _ => Self::Unspecified(appearance),
+        }
+    }
+}
+
+impl From<&LinearObjectAppearance> for u32 {
+    fn from(value: &LinearObjectAppearance) -> Self {
+        match value {
+            LinearObjectAppearance::TankDitch(appearance) => u32::from(*appearance),
+            LinearObjectAppearance::LaneMarker(appearance) => u32::from(*appearance),
+            LinearObjectAppearance::Unspecified(appearance) => *appearance,
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use crate::common::parser::IResult;
+use crate::common::parser::{
+    entity_id, general_object_appearance, location, object_state_modification, object_type,
+    orientation, simulation_address,
+};
+use crate::entity_state::parser::force_id;
+use crate::linear_object_state::model::{LinearObjectState, LinearSegmentParameter};
+use crate::model::PduBody;
+use nom::error::context;
+use nom::multi::count;
+use nom::number::complete::{be_f32, be_u16, be_u32, be_u8};
+
+pub(crate) fn linear_object_state_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    context("Linear Object State Body", |input| {
+        let (input, object_id) = entity_id(input)?;
+        let (input, referenced_object_id) = entity_id(input)?;
+        let (input, update_number) = be_u16(input)?;
+        let (input, force_id) = force_id(input)?;
+        let (input, number_of_segments) = be_u8(input)?;
+        let (input, requester_id) = simulation_address(input)?;
+        let (input, receiving_id) = simulation_address(input)?;
+        let (input, object_type) = object_type(input)?;
+        let (input, segments) = count(linear_segment_parameter, number_of_segments.into())(input)?;
+
+        let body = LinearObjectState::builder()
+            .with_object_id(object_id)
+            .with_referenced_object_id(referenced_object_id)
+            .with_update_number(update_number)
+            .with_force_id(force_id)
+            .with_requester_id(requester_id)
+            .with_receiving_id(receiving_id)
+            .with_object_type(object_type)
+            .with_segments(segments)
+            .build();
+
+        Ok((input, body.into_pdu_body()))
+    })(input)
+}
+
+pub(crate) fn linear_segment_parameter(input: &[u8]) -> IResult<&[u8], LinearSegmentParameter> {
+    context("Linear Segment Parameter", |input| {
+        let (input, segment_number) = be_u8(input)?;
+        let (input, segment_modification) = object_state_modification(input)?;
+        let (input, general_segment_appearance) = general_object_appearance(input)?;
+        let (input, specific_segment_appearance) = be_u32(input)?;
+        let (input, segment_location) = location(input)?;
+        let (input, segment_orientation) = orientation(input)?;
+        let (input, segment_length) = be_f32(input)?;
+        let (input, segment_width) = be_f32(input)?;
+        let (input, segment_height) = be_f32(input)?;
+        let (input, segment_depth) = be_f32(input)?;
+        let (input, _padding) = be_u32(input)?;
+
+        Ok((
+            input,
+            LinearSegmentParameter::default()
+                .with_segment_number(segment_number)
+                .with_segment_modification(segment_modification)
+                .with_general_segment_appearance(general_segment_appearance)
+                .with_specific_segment_appearance(specific_segment_appearance)
+                .with_segment_location(segment_location)
+                .with_segment_orientation(segment_orientation)
+                .with_segment_length(segment_length)
+                .with_segment_width(segment_width)
+                .with_segment_height(segment_height)
+                .with_segment_depth(segment_depth),
+        ))
+    })(input)
+}
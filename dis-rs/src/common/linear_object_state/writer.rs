@@ -0,0 +1,40 @@
+use crate::common::BodyInfo;
+use crate::linear_object_state::model::{LinearObjectState, LinearSegmentParameter};
+use crate::{Serialize, SerializePdu, SupportedVersion};
+use bytes::BufMut;
+
+impl SerializePdu for LinearObjectState {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
+        self.object_id.serialize(buf);
+        self.referenced_object_id.serialize(buf);
+        buf.put_u16(self.update_number);
+        self.force_id.serialize(buf);
+        buf.put_u8(self.segments.len() as u8);
+        self.requester_id.serialize(buf);
+        self.receiving_id.serialize(buf);
+        self.object_type.serialize(buf);
+        for segment in &self.segments {
+            segment.serialize(buf);
+        }
+
+        self.body_length()
+    }
+}
+
+impl Serialize for LinearSegmentParameter {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        buf.put_u8(self.segment_number);
+        self.segment_modification.serialize(buf);
+        self.general_segment_appearance.serialize(buf);
+        buf.put_u32(self.specific_segment_appearance);
+        self.segment_location.serialize(buf);
+        self.segment_orientation.serialize(buf);
+        buf.put_f32(self.segment_length);
+        buf.put_f32(self.segment_width);
+        buf.put_f32(self.segment_height);
+        buf.put_f32(self.segment_depth);
+        buf.put_u32(0u32);
+
+        self.record_length()
+    }
+}
@@ -0,0 +1,100 @@
+use crate::minefield_data::model::{Mine, MinefieldData};
+use crate::model::{EntityId, EntityType, MinefieldDataFilter, MinefieldSensorType};
+use alloc::vec::Vec;
+
+pub struct MinefieldDataBuilder(MinefieldData);
+
+impl Default for MinefieldDataBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MinefieldDataBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        MinefieldDataBuilder(MinefieldData::default())
+    }
+
+    #[must_use]
+    pub fn new_from_body(body: MinefieldData) -> Self {
+        MinefieldDataBuilder(body)
+    }
+
+    #[must_use]
+    pub fn build(self) -> MinefieldData {
+        self.0
+    }
+
+    #[must_use]
+    pub fn with_minefield_id(mut self, minefield_id: EntityId) -> Self {
+        self.0.minefield_id = minefield_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_requesting_entity_id(mut self, requesting_entity_id: EntityId) -> Self {
+        self.0.requesting_entity_id = requesting_entity_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_minefield_sequence_number(mut self, minefield_sequence_number: u16) -> Self {
+        self.0.minefield_sequence_number = minefield_sequence_number;
+        self
+    }
+
+    #[must_use]
+    pub fn with_request_id(mut self, request_id: u8) -> Self {
+        self.0.request_id = request_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_pdu_sequence_number(mut self, pdu_sequence_number: u8) -> Self {
+        self.0.pdu_sequence_number = pdu_sequence_number;
+        self
+    }
+
+    #[must_use]
+    pub fn with_number_of_pdus(mut self, number_of_pdus: u8) -> Self {
+        self.0.number_of_pdus = number_of_pdus;
+        self
+    }
+
+    #[must_use]
+    pub fn with_data_filter(mut self, data_filter: MinefieldDataFilter) -> Self {
+        self.0.data_filter = data_filter;
+        self
+    }
+
+    #[must_use]
+    pub fn with_mine_type(mut self, mine_type: EntityType) -> Self {
+        self.0.mine_type = mine_type;
+        self
+    }
+
+    #[must_use]
+    pub fn with_sensor_type(mut self, sensor_type: MinefieldSensorType) -> Self {
+        self.0.sensor_types.push(sensor_type);
+        self
+    }
+
+    #[must_use]
+    pub fn with_sensor_types(mut self, sensor_types: Vec<MinefieldSensorType>) -> Self {
+        self.0.sensor_types = sensor_types;
+        self
+    }
+
+    #[must_use]
+    pub fn with_mine(mut self, mine: Mine) -> Self {
+        self.0.mines.push(mine);
+        self
+    }
+
+    #[must_use]
+    pub fn with_mines(mut self, mines: Vec<Mine>) -> Self {
+        self.0.mines = mines;
+        self
+    }
+}
@@ -0,0 +1,100 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::model::DisTimeStamp;
+    use crate::common::model::{Pdu, PduHeader};
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{CoupledExtensionIndicator, LvcIndicator, PduType};
+    use crate::minefield_data::model::{
+        Mine, MineFusing, MinePaintScheme, MinefieldData, TripDetonationWire,
+    };
+    use crate::model::{
+        ClockTime, EntityId, EntityType, MinefieldDataFilter, MinefieldSensorType, Orientation,
+        VectorF32,
+    };
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+    use core::str::FromStr;
+
+    #[test]
+    fn minefield_data_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::MinefieldData).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::NoStatement)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = MinefieldData::builder()
+            .with_minefield_id(EntityId::new(10, 10, 1))
+            .with_requesting_entity_id(EntityId::new(20, 20, 2))
+            .with_minefield_sequence_number(3)
+            .with_request_id(4)
+            .with_pdu_sequence_number(1)
+            .with_number_of_pdus(1)
+            .with_data_filter(
+                MinefieldDataFilter::default()
+                    .with_snow_depth_offset()
+                    .with_mine_orientation()
+                    .with_mine_emplacement_time()
+                    .with_fusing()
+                    .with_scalar_detection_coefficient()
+                    .with_paint_scheme()
+                    .with_number_of_trip_detonation_wires()
+                    .with_number_of_vertices(),
+            )
+            .with_mine_type(EntityType::from_str("2:9:1:1:1:1:0").unwrap())
+            .with_sensor_type(MinefieldSensorType::new(1, 2))
+            .with_mine(
+                Mine::default()
+                    .with_location(VectorF32::new(1.0, 2.0, 0.0))
+                    .with_snow_depth_offset(0.5)
+                    .with_orientation(Orientation::new(0.0, 0.0, 1.0))
+                    .with_emplacement_time(ClockTime::new(1, 1000))
+                    .with_fusing(MineFusing::default().with_primary(2).with_secondary(3))
+                    .with_scalar_detection_coefficient(10)
+                    .with_paint_scheme(MinePaintScheme::default().with_paint_scheme(5)),
+            )
+            .with_mine(
+                Mine::default()
+                    .with_location(VectorF32::new(5.0, 6.0, 0.0))
+                    .with_snow_depth_offset(0.25)
+                    .with_orientation(Orientation::new(0.0, 0.0, 2.0))
+                    .with_emplacement_time(ClockTime::new(1, 2000))
+                    .with_fusing(MineFusing::default().with_anti_handling_device(true))
+                    .with_scalar_detection_coefficient(20)
+                    .with_paint_scheme(MinePaintScheme::default().with_algae(1))
+                    .with_trip_detonation_wire(
+                        TripDetonationWire::default()
+                            .with_vertex(VectorF32::new(0.0, 0.0, 0.0))
+                            .with_vertex(VectorF32::new(1.0, 1.0, 0.0)),
+                    ),
+            )
+            .build()
+            .into_pdu_body();
+
+        let original_pdu =
+            Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+}
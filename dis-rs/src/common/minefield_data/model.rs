@@ -0,0 +1,389 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::{EIGHT_OCTETS, FOUR_OCTETS, ONE_OCTET, TWELVE_OCTETS, TWO_OCTETS};
+use crate::enumerations::PduType;
+use crate::minefield_data::builder::MinefieldDataBuilder;
+use crate::minefield_query::model::sensor_types_lengths;
+use crate::model::{
+    length_padded_to_num, ClockTime, EntityId, EntityType, MinefieldDataFilter,
+    MinefieldSensorType, Orientation, PaddedRecordLengths, PduBody, VectorF32,
+};
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const BASE_MINEFIELD_DATA_BODY_LENGTH: u16 = 32;
+
+/// 7.9.4 Minefield Data PDU
+///
+/// The optional per-mine fields are encoded on the wire as consecutive lists (all mine locations,
+/// then all ground burial depth offsets, etc.). The `data_filter` field determines which
+/// of the optional fields are present for the mines in the PDU.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MinefieldData {
+    pub minefield_id: EntityId,
+    pub requesting_entity_id: EntityId,
+    pub minefield_sequence_number: u16,
+    pub request_id: u8,
+    pub pdu_sequence_number: u8,
+    pub number_of_pdus: u8,
+    pub data_filter: MinefieldDataFilter,
+    pub mine_type: EntityType,
+    pub sensor_types: Vec<MinefieldSensorType>,
+    pub mines: Vec<Mine>,
+}
+
+impl MinefieldData {
+    #[must_use]
+    pub fn builder() -> MinefieldDataBuilder {
+        MinefieldDataBuilder::new()
+    }
+
+    #[must_use]
+    pub fn into_builder(self) -> MinefieldDataBuilder {
+        MinefieldDataBuilder::new_from_body(self)
+    }
+
+    #[must_use]
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::MinefieldData(self)
+    }
+}
+
+impl BodyInfo for MinefieldData {
+    fn body_length(&self) -> u16 {
+        let number_of_mines = self.mines.len();
+        let filter = &self.data_filter;
+
+        let f32_fields = [
+            filter.ground_burial_depth_offset,
+            filter.water_depth_offset,
+            filter.snow_depth_offset,
+            filter.thermal_contrast,
+            filter.reflectance,
+        ]
+        .iter()
+        .filter(|&&present| present)
+        .count();
+        let mine_fields_length = number_of_mines
+            * (TWELVE_OCTETS
+                + f32_fields * FOUR_OCTETS
+                + usize::from(filter.mine_orientation) * TWELVE_OCTETS
+                + usize::from(filter.mine_emplacement_time) * EIGHT_OCTETS);
+
+        let number_of_wires = self
+            .mines
+            .iter()
+            .map(|mine| mine.trip_detonation_wires.len())
+            .sum::<usize>();
+        let vertices_length = if has_vertices(filter) {
+            self.mines
+                .iter()
+                .flat_map(|mine| mine.trip_detonation_wires.iter())
+                .map(|wire| wire.vertices.len() * TWELVE_OCTETS)
+                .sum::<usize>()
+        } else {
+            0
+        };
+
+        BASE_MINEFIELD_DATA_BODY_LENGTH
+            + sensor_types_lengths(self.sensor_types.len()).record_length as u16
+            + mine_fields_length as u16
+            + mine_attributes_lengths(filter, number_of_mines, self.sensor_types.len())
+                .record_length as u16
+            + wire_counts_lengths(filter, number_of_mines).record_length as u16
+            + vertex_counts_lengths(filter, number_of_wires).record_length as u16
+            + vertices_length as u16
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::MinefieldData
+    }
+}
+
+impl Interaction for MinefieldData {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.minefield_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        Some(&self.requesting_entity_id)
+    }
+}
+
+/// The vertices of trip/detonation wires are only present when the data filter
+/// indicates both the number of wires and the number of vertices are present.
+pub(crate) fn has_vertices(filter: &MinefieldDataFilter) -> bool {
+    filter.number_of_trip_detonation_wires && filter.number_of_vertices
+}
+
+/// Lengths of the Mine Entity Number, Fusing, Scalar Detection Coefficient and Paint Scheme lists,
+/// padded to a 32-bit boundary.
+pub(crate) fn mine_attributes_lengths(
+    filter: &MinefieldDataFilter,
+    number_of_mines: usize,
+    number_of_sensor_types: usize,
+) -> PaddedRecordLengths {
+    let data_length = number_of_mines
+        * (usize::from(filter.mine_entity_number) * TWO_OCTETS
+            + usize::from(filter.fusing) * TWO_OCTETS
+            + usize::from(filter.scalar_detection_coefficient)
+                * number_of_sensor_types
+                * ONE_OCTET
+            + usize::from(filter.paint_scheme) * ONE_OCTET);
+    length_padded_to_num(data_length, FOUR_OCTETS)
+}
+
+/// Lengths of the Number of Trip/Detonation Wires list, padded to a 32-bit boundary.
+pub(crate) fn wire_counts_lengths(
+    filter: &MinefieldDataFilter,
+    number_of_mines: usize,
+) -> PaddedRecordLengths {
+    let data_length = usize::from(filter.number_of_trip_detonation_wires) * number_of_mines;
+    length_padded_to_num(data_length, FOUR_OCTETS)
+}
+
+/// Lengths of the Number of Vertices list, padded to a 32-bit boundary.
+pub(crate) fn vertex_counts_lengths(
+    filter: &MinefieldDataFilter,
+    number_of_wires: usize,
+) -> PaddedRecordLengths {
+    let data_length = usize::from(has_vertices(filter)) * number_of_wires;
+    length_padded_to_num(data_length, FOUR_OCTETS)
+}
+
+/// The data of a single mine in a Minefield Data PDU.
+///
+/// Optional fields are only encoded when the corresponding flag
+/// in the Data Filter of the PDU is set.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Mine {
+    pub location: VectorF32,
+    pub ground_burial_depth_offset: Option<f32>,
+    pub water_depth_offset: Option<f32>,
+    pub snow_depth_offset: Option<f32>,
+    pub orientation: Option<Orientation>,
+    pub thermal_contrast: Option<f32>,
+    pub reflectance: Option<f32>,
+    pub emplacement_time: Option<ClockTime>,
+    pub entity_number: Option<u16>,
+    pub fusing: Option<MineFusing>,
+    pub scalar_detection_coefficients: Vec<u8>,
+    pub paint_scheme: Option<MinePaintScheme>,
+    pub trip_detonation_wires: Vec<TripDetonationWire>,
+}
+
+impl Mine {
+    #[must_use]
+    pub fn with_location(mut self, location: VectorF32) -> Self {
+        self.location = location;
+        self
+    }
+
+    #[must_use]
+    pub fn with_ground_burial_depth_offset(mut self, offset: f32) -> Self {
+        self.ground_burial_depth_offset = Some(offset);
+        self
+    }
+
+    #[must_use]
+    pub fn with_water_depth_offset(mut self, offset: f32) -> Self {
+        self.water_depth_offset = Some(offset);
+        self
+    }
+
+    #[must_use]
+    pub fn with_snow_depth_offset(mut self, offset: f32) -> Self {
+        self.snow_depth_offset = Some(offset);
+        self
+    }
+
+    #[must_use]
+    pub fn with_orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = Some(orientation);
+        self
+    }
+
+    #[must_use]
+    pub fn with_thermal_contrast(mut self, thermal_contrast: f32) -> Self {
+        self.thermal_contrast = Some(thermal_contrast);
+        self
+    }
+
+    #[must_use]
+    pub fn with_reflectance(mut self, reflectance: f32) -> Self {
+        self.reflectance = Some(reflectance);
+        self
+    }
+
+    #[must_use]
+    pub fn with_emplacement_time(mut self, emplacement_time: ClockTime) -> Self {
+        self.emplacement_time = Some(emplacement_time);
+        self
+    }
+
+    #[must_use]
+    pub fn with_entity_number(mut self, entity_number: u16) -> Self {
+        self.entity_number = Some(entity_number);
+        self
+    }
+
+    #[must_use]
+    pub fn with_fusing(mut self, fusing: MineFusing) -> Self {
+        self.fusing = Some(fusing);
+        self
+    }
+
+    /// Adds a scalar detection coefficient; one coefficient is expected per sensor type in the PDU.
+    #[must_use]
+    pub fn with_scalar_detection_coefficient(mut self, coefficient: u8) -> Self {
+        self.scalar_detection_coefficients.push(coefficient);
+        self
+    }
+
+    #[must_use]
+    pub fn with_scalar_detection_coefficients(mut self, coefficients: Vec<u8>) -> Self {
+        self.scalar_detection_coefficients = coefficients;
+        self
+    }
+
+    #[must_use]
+    pub fn with_paint_scheme(mut self, paint_scheme: MinePaintScheme) -> Self {
+        self.paint_scheme = Some(paint_scheme);
+        self
+    }
+
+    #[must_use]
+    pub fn with_trip_detonation_wire(mut self, wire: TripDetonationWire) -> Self {
+        self.trip_detonation_wires.push(wire);
+        self
+    }
+
+    #[must_use]
+    pub fn with_trip_detonation_wires(mut self, wires: Vec<TripDetonationWire>) -> Self {
+        self.trip_detonation_wires = wires;
+        self
+    }
+}
+
+/// Fusing field of the Minefield Data PDU
+///
+/// The primary and secondary fuse hold the values of the Fuse Type enumeration (SISO-REF-010).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MineFusing {
+    pub primary: u8,
+    pub secondary: u8,
+    pub has_anti_handling_device: bool,
+}
+
+impl MineFusing {
+    #[must_use]
+    pub fn with_primary(mut self, primary: u8) -> Self {
+        self.primary = primary;
+        self
+    }
+
+    #[must_use]
+    pub fn with_secondary(mut self, secondary: u8) -> Self {
+        self.secondary = secondary;
+        self
+    }
+
+    #[must_use]
+    pub fn with_anti_handling_device(mut self, has_anti_handling_device: bool) -> Self {
+        self.has_anti_handling_device = has_anti_handling_device;
+        self
+    }
+
+    #[must_use]
+    pub const fn record_length(&self) -> u16 {
+        TWO_OCTETS as u16
+    }
+}
+
+impl From<u16> for MineFusing {
+    fn from(value: u16) -> Self {
+        Self {
+            primary: (value & 0x007F) as u8,
+            secondary: ((value >> 7) & 0x007F) as u8,
+            has_anti_handling_device: ((value >> 14) & 0x0001) != 0,
+        }
+    }
+}
+
+impl From<MineFusing> for u16 {
+    fn from(value: MineFusing) -> Self {
+        (u16::from(value.primary) & 0x007F)
+            | (u16::from(value.secondary) & 0x007F) << 7
+            | u16::from(value.has_anti_handling_device) << 14
+    }
+}
+
+/// Paint Scheme field of the Minefield Data PDU
+///
+/// Both sub-fields hold values of the corresponding enumerations (SISO-REF-010).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MinePaintScheme {
+    pub algae: u8,
+    pub paint_scheme: u8,
+}
+
+impl MinePaintScheme {
+    #[must_use]
+    pub fn with_algae(mut self, algae: u8) -> Self {
+        self.algae = algae;
+        self
+    }
+
+    #[must_use]
+    pub fn with_paint_scheme(mut self, paint_scheme: u8) -> Self {
+        self.paint_scheme = paint_scheme;
+        self
+    }
+
+    #[must_use]
+    pub const fn record_length(&self) -> u16 {
+        ONE_OCTET as u16
+    }
+}
+
+impl From<u8> for MinePaintScheme {
+    fn from(value: u8) -> Self {
+        Self {
+            algae: value & 0x03,
+            paint_scheme: value >> 2,
+        }
+    }
+}
+
+impl From<MinePaintScheme> for u8 {
+    fn from(value: MinePaintScheme) -> Self {
+        (value.algae & 0x03) | (value.paint_scheme << 2)
+    }
+}
+
+/// A trip or detonation wire of a mine, described by its vertices.
+///
+/// The vertices are relative to the location of the mine.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TripDetonationWire {
+    pub vertices: Vec<VectorF32>,
+}
+
+impl TripDetonationWire {
+    #[must_use]
+    pub fn with_vertex(mut self, vertex: VectorF32) -> Self {
+        self.vertices.push(vertex);
+        self
+    }
+
+    #[must_use]
+    pub fn with_vertices(mut self, vertices: Vec<VectorF32>) -> Self {
+        self.vertices = vertices;
+        self
+    }
+}
@@ -0,0 +1,180 @@
+use crate::common::errors::ParserError;
+use crate::common::parser::IResult;
+use crate::common::parser::{
+    clock_time, entity_id, entity_type, minefield_data_filter, minefield_sensor_type, orientation,
+    vec3_f32,
+};
+use crate::minefield_data::model::{
+    has_vertices, mine_attributes_lengths, vertex_counts_lengths, wire_counts_lengths, Mine,
+    MineFusing, MinePaintScheme, MinefieldData, TripDetonationWire,
+};
+use crate::minefield_query::model::sensor_types_lengths;
+use crate::model::PduBody;
+use alloc::vec;
+use alloc::vec::Vec;
+use nom::bytes::complete::take;
+use nom::error::context;
+use nom::multi::count;
+use nom::number::complete::{be_f32, be_u16, be_u8};
+use nom::Parser;
+
+#[allow(clippy::too_many_lines)]
+pub(crate) fn minefield_data_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    context("Minefield Data Body", |input| {
+        let (input, minefield_id) = entity_id(input)?;
+        let (input, requesting_entity_id) = entity_id(input)?;
+        let (input, minefield_sequence_number) = be_u16(input)?;
+        let (input, request_id) = be_u8(input)?;
+        let (input, pdu_sequence_number) = be_u8(input)?;
+        let (input, number_of_pdus) = be_u8(input)?;
+        let (input, number_of_mines) = be_u8(input)?;
+        let (input, number_of_sensor_types) = be_u8(input)?;
+        let (input, _padding) = be_u8(input)?;
+        let (input, data_filter) = minefield_data_filter(input)?;
+        let (input, mine_type) = entity_type(input)?;
+
+        let number_of_mines = usize::from(number_of_mines);
+        let number_of_sensor_types = usize::from(number_of_sensor_types);
+
+        let (input, sensor_types) = count(minefield_sensor_type, number_of_sensor_types)(input)?;
+        let (input, _padding) =
+            take(sensor_types_lengths(number_of_sensor_types).padding_length)(input)?;
+
+        let (input, locations) = count(vec3_f32, number_of_mines)(input)?;
+        let (input, ground_burial_depth_offsets) = optional_fields(
+            data_filter.ground_burial_depth_offset,
+            be_f32,
+            number_of_mines,
+        )(input)?;
+        let (input, water_depth_offsets) =
+            optional_fields(data_filter.water_depth_offset, be_f32, number_of_mines)(input)?;
+        let (input, snow_depth_offsets) =
+            optional_fields(data_filter.snow_depth_offset, be_f32, number_of_mines)(input)?;
+        let (input, orientations) =
+            optional_fields(data_filter.mine_orientation, orientation, number_of_mines)(input)?;
+        let (input, thermal_contrasts) =
+            optional_fields(data_filter.thermal_contrast, be_f32, number_of_mines)(input)?;
+        let (input, reflectances) =
+            optional_fields(data_filter.reflectance, be_f32, number_of_mines)(input)?;
+        let (input, emplacement_times) = optional_fields(
+            data_filter.mine_emplacement_time,
+            clock_time,
+            number_of_mines,
+        )(input)?;
+        let (input, entity_numbers) =
+            optional_fields(data_filter.mine_entity_number, be_u16, number_of_mines)(input)?;
+        let (input, fusings) =
+            optional_fields(data_filter.fusing, mine_fusing, number_of_mines)(input)?;
+        let (input, scalar_detection_coefficients) = if data_filter.scalar_detection_coefficient {
+            count(count(be_u8, number_of_sensor_types), number_of_mines)(input)?
+        } else {
+            (input, vec![vec![]; number_of_mines])
+        };
+        let (input, paint_schemes) =
+            optional_fields(data_filter.paint_scheme, mine_paint_scheme, number_of_mines)(input)?;
+        let (input, _padding) = take(
+            mine_attributes_lengths(&data_filter, number_of_mines, number_of_sensor_types)
+                .padding_length,
+        )(input)?;
+
+        let (input, wire_counts) = if data_filter.number_of_trip_detonation_wires {
+            count(be_u8, number_of_mines)(input)?
+        } else {
+            (input, vec![0; number_of_mines])
+        };
+        let (input, _padding) =
+            take(wire_counts_lengths(&data_filter, number_of_mines).padding_length)(input)?;
+
+        let number_of_wires = wire_counts.iter().map(|&wires| usize::from(wires)).sum();
+        let (input, vertex_counts) = if has_vertices(&data_filter) {
+            count(be_u8, number_of_wires)(input)?
+        } else {
+            (input, vec![0; number_of_wires])
+        };
+        let (input, _padding) =
+            take(vertex_counts_lengths(&data_filter, number_of_wires).padding_length)(input)?;
+
+        let mut input = input;
+        let mut wires = Vec::with_capacity(number_of_wires);
+        for vertex_count in vertex_counts {
+            let (remainder, vertices) = count(vec3_f32, usize::from(vertex_count))(input)?;
+            wires.push(TripDetonationWire::default().with_vertices(vertices));
+            input = remainder;
+        }
+        let mut wires = wires.into_iter();
+
+        let mines = locations
+            .into_iter()
+            .zip(scalar_detection_coefficients)
+            .zip(wire_counts)
+            .enumerate()
+            .map(|(index, ((location, coefficients), wire_count))| Mine {
+                location,
+                ground_burial_depth_offset: ground_burial_depth_offsets[index],
+                water_depth_offset: water_depth_offsets[index],
+                snow_depth_offset: snow_depth_offsets[index],
+                orientation: orientations[index],
+                thermal_contrast: thermal_contrasts[index],
+                reflectance: reflectances[index],
+                emplacement_time: emplacement_times[index],
+                entity_number: entity_numbers[index],
+                fusing: fusings[index],
+                scalar_detection_coefficients: coefficients,
+                paint_scheme: paint_schemes[index],
+                trip_detonation_wires: wires.by_ref().take(usize::from(wire_count)).collect(),
+            })
+            .collect();
+
+        let body = MinefieldData::builder()
+            .with_minefield_id(minefield_id)
+            .with_requesting_entity_id(requesting_entity_id)
+            .with_minefield_sequence_number(minefield_sequence_number)
+            .with_request_id(request_id)
+            .with_pdu_sequence_number(pdu_sequence_number)
+            .with_number_of_pdus(number_of_pdus)
+            .with_data_filter(data_filter)
+            .with_mine_type(mine_type)
+            .with_sensor_types(sensor_types)
+            .with_mines(mines)
+            .build();
+
+        Ok((input, body.into_pdu_body()))
+    })(input)
+}
+
+/// Parses a list of `number_of_mines` values of an optional field when `present`,
+/// or yields a list of `None` values when the field is not present in the PDU.
+fn optional_fields<'a, O, F>(
+    present: bool,
+    parser: F,
+    number_of_mines: usize,
+) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], Vec<Option<O>>>
+where
+    F: Parser<&'a [u8], O, ParserError<&'a [u8]>>,
+{
+    let mut parser = count(parser, number_of_mines);
+    move |input: &'a [u8]| {
+        if present {
+            let (input, values) = parser(input)?;
+            Ok((input, values.into_iter().map(Some).collect()))
+        } else {
+            Ok((input, (0..number_of_mines).map(|_| None).collect()))
+        }
+    }
+}
+
+fn mine_fusing(input: &[u8]) -> IResult<&[u8], MineFusing> {
+    context("Mine Fusing", |input| {
+        let (input, fusing) = be_u16(input)?;
+
+        Ok((input, MineFusing::from(fusing)))
+    })(input)
+}
+
+fn mine_paint_scheme(input: &[u8]) -> IResult<&[u8], MinePaintScheme> {
+    context("Mine Paint Scheme", |input| {
+        let (input, paint_scheme) = be_u8(input)?;
+
+        Ok((input, MinePaintScheme::from(paint_scheme)))
+    })(input)
+}
@@ -0,0 +1,159 @@
+use crate::common::BodyInfo;
+use crate::minefield_data::model::{
+    has_vertices, mine_attributes_lengths, vertex_counts_lengths, wire_counts_lengths, MineFusing,
+    MinePaintScheme, MinefieldData,
+};
+use crate::minefield_query::model::sensor_types_lengths;
+use crate::{Serialize, SerializePdu, SupportedVersion};
+use bytes::BufMut;
+
+impl SerializePdu for MinefieldData {
+    #[allow(clippy::too_many_lines)]
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
+        let filter = &self.data_filter;
+
+        self.minefield_id.serialize(buf);
+        self.requesting_entity_id.serialize(buf);
+        buf.put_u16(self.minefield_sequence_number);
+        buf.put_u8(self.request_id);
+        buf.put_u8(self.pdu_sequence_number);
+        buf.put_u8(self.number_of_pdus);
+        buf.put_u8(self.mines.len() as u8);
+        buf.put_u8(self.sensor_types.len() as u8);
+        buf.put_u8(0u8);
+        filter.serialize(buf);
+        self.mine_type.serialize(buf);
+
+        self.sensor_types
+            .iter()
+            .map(|sensor_type| sensor_type.serialize(buf))
+            .sum::<u16>();
+        buf.put_bytes(
+            0u8,
+            sensor_types_lengths(self.sensor_types.len()).padding_length,
+        );
+
+        self.mines
+            .iter()
+            .map(|mine| mine.location.serialize(buf))
+            .sum::<u16>();
+        if filter.ground_burial_depth_offset {
+            self.mines.iter().for_each(|mine| {
+                buf.put_f32(mine.ground_burial_depth_offset.unwrap_or_default());
+            });
+        }
+        if filter.water_depth_offset {
+            self.mines.iter().for_each(|mine| {
+                buf.put_f32(mine.water_depth_offset.unwrap_or_default());
+            });
+        }
+        if filter.snow_depth_offset {
+            self.mines.iter().for_each(|mine| {
+                buf.put_f32(mine.snow_depth_offset.unwrap_or_default());
+            });
+        }
+        if filter.mine_orientation {
+            self.mines.iter().for_each(|mine| {
+                mine.orientation.unwrap_or_default().serialize(buf);
+            });
+        }
+        if filter.thermal_contrast {
+            self.mines.iter().for_each(|mine| {
+                buf.put_f32(mine.thermal_contrast.unwrap_or_default());
+            });
+        }
+        if filter.reflectance {
+            self.mines.iter().for_each(|mine| {
+                buf.put_f32(mine.reflectance.unwrap_or_default());
+            });
+        }
+        if filter.mine_emplacement_time {
+            self.mines.iter().for_each(|mine| {
+                mine.emplacement_time.unwrap_or_default().serialize(buf);
+            });
+        }
+        if filter.mine_entity_number {
+            self.mines.iter().for_each(|mine| {
+                buf.put_u16(mine.entity_number.unwrap_or_default());
+            });
+        }
+        if filter.fusing {
+            self.mines.iter().for_each(|mine| {
+                mine.fusing.unwrap_or_default().serialize(buf);
+            });
+        }
+        if filter.scalar_detection_coefficient {
+            self.mines.iter().for_each(|mine| {
+                (0..self.sensor_types.len()).for_each(|sensor_index| {
+                    buf.put_u8(
+                        mine.scalar_detection_coefficients
+                            .get(sensor_index)
+                            .copied()
+                            .unwrap_or_default(),
+                    );
+                });
+            });
+        }
+        if filter.paint_scheme {
+            self.mines.iter().for_each(|mine| {
+                mine.paint_scheme.unwrap_or_default().serialize(buf);
+            });
+        }
+        buf.put_bytes(
+            0u8,
+            mine_attributes_lengths(filter, self.mines.len(), self.sensor_types.len())
+                .padding_length,
+        );
+
+        if filter.number_of_trip_detonation_wires {
+            self.mines.iter().for_each(|mine| {
+                buf.put_u8(mine.trip_detonation_wires.len() as u8);
+            });
+        }
+        buf.put_bytes(
+            0u8,
+            wire_counts_lengths(filter, self.mines.len()).padding_length,
+        );
+
+        if filter.number_of_trip_detonation_wires {
+            let wires = self
+                .mines
+                .iter()
+                .flat_map(|mine| mine.trip_detonation_wires.iter());
+            let number_of_wires = wires.clone().count();
+            if has_vertices(filter) {
+                wires.clone().for_each(|wire| {
+                    buf.put_u8(wire.vertices.len() as u8);
+                });
+            }
+            buf.put_bytes(
+                0u8,
+                vertex_counts_lengths(filter, number_of_wires).padding_length,
+            );
+            if has_vertices(filter) {
+                wires
+                    .flat_map(|wire| wire.vertices.iter())
+                    .map(|vertex| vertex.serialize(buf))
+                    .sum::<u16>();
+            }
+        }
+
+        self.body_length()
+    }
+}
+
+impl Serialize for MineFusing {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        buf.put_u16((*self).into());
+
+        self.record_length()
+    }
+}
+
+impl Serialize for MinePaintScheme {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        buf.put_u8((*self).into());
+
+        self.record_length()
+    }
+}
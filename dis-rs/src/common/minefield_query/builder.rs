@@ -0,0 +1,87 @@
+use crate::minefield_query::model::MinefieldQuery;
+use crate::model::{
+    EntityId, EntityType, MinefieldDataFilter, MinefieldSensorType, PerimeterPoint,
+};
+use alloc::vec::Vec;
+
+pub struct MinefieldQueryBuilder(MinefieldQuery);
+
+impl Default for MinefieldQueryBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MinefieldQueryBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        MinefieldQueryBuilder(MinefieldQuery::default())
+    }
+
+    #[must_use]
+    pub fn new_from_body(body: MinefieldQuery) -> Self {
+        MinefieldQueryBuilder(body)
+    }
+
+    #[must_use]
+    pub fn build(self) -> MinefieldQuery {
+        self.0
+    }
+
+    #[must_use]
+    pub fn with_minefield_id(mut self, minefield_id: EntityId) -> Self {
+        self.0.minefield_id = minefield_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_requesting_entity_id(mut self, requesting_entity_id: EntityId) -> Self {
+        self.0.requesting_entity_id = requesting_entity_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_request_id(mut self, request_id: u8) -> Self {
+        self.0.request_id = request_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_data_filter(mut self, data_filter: MinefieldDataFilter) -> Self {
+        self.0.data_filter = data_filter;
+        self
+    }
+
+    #[must_use]
+    pub fn with_requested_mine_type(mut self, requested_mine_type: EntityType) -> Self {
+        self.0.requested_mine_type = requested_mine_type;
+        self
+    }
+
+    #[must_use]
+    pub fn with_requested_perimeter_point(mut self, perimeter_point: PerimeterPoint) -> Self {
+        self.0.requested_perimeter_points.push(perimeter_point);
+        self
+    }
+
+    #[must_use]
+    pub fn with_requested_perimeter_points(
+        mut self,
+        perimeter_points: Vec<PerimeterPoint>,
+    ) -> Self {
+        self.0.requested_perimeter_points = perimeter_points;
+        self
+    }
+
+    #[must_use]
+    pub fn with_sensor_type(mut self, sensor_type: MinefieldSensorType) -> Self {
+        self.0.sensor_types.push(sensor_type);
+        self
+    }
+
+    #[must_use]
+    pub fn with_sensor_types(mut self, sensor_types: Vec<MinefieldSensorType>) -> Self {
+        self.0.sensor_types = sensor_types;
+        self
+    }
+}
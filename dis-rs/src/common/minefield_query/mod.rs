@@ -0,0 +1,65 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::model::DisTimeStamp;
+    use crate::common::model::{Pdu, PduHeader};
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{CoupledExtensionIndicator, LvcIndicator, PduType};
+    use crate::minefield_query::model::MinefieldQuery;
+    use crate::model::{
+        EntityId, EntityType, MinefieldDataFilter, MinefieldSensorType, PerimeterPoint,
+    };
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+    use core::str::FromStr;
+
+    #[test]
+    fn minefield_query_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::MinefieldQuery).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::NoStatement)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = MinefieldQuery::builder()
+            .with_minefield_id(EntityId::new(10, 10, 1))
+            .with_requesting_entity_id(EntityId::new(20, 20, 20))
+            .with_request_id(5)
+            .with_data_filter(
+                MinefieldDataFilter::default()
+                    .with_mine_orientation()
+                    .with_fusing(),
+            )
+            .with_requested_mine_type(EntityType::from_str("2:9:1:1:1:1:0").unwrap())
+            .with_requested_perimeter_point(PerimeterPoint::new(0.0, 0.0))
+            .with_requested_perimeter_point(PerimeterPoint::new(50.0, 50.0))
+            .with_sensor_type(MinefieldSensorType::new(1, 2))
+            .build()
+            .into_pdu_body();
+
+        let original_pdu =
+            Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::{FOUR_OCTETS, TWO_OCTETS};
+use crate::enumerations::PduType;
+use crate::minefield_query::builder::MinefieldQueryBuilder;
+use crate::model::{
+    length_padded_to_num, EntityId, EntityType, MinefieldDataFilter, MinefieldSensorType,
+    PaddedRecordLengths, PduBody, PerimeterPoint,
+};
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const BASE_MINEFIELD_QUERY_BODY_LENGTH: u16 = 28;
+
+/// 7.9.3 Minefield Query PDU
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MinefieldQuery {
+    pub minefield_id: EntityId,
+    pub requesting_entity_id: EntityId,
+    pub request_id: u8,
+    pub data_filter: MinefieldDataFilter,
+    pub requested_mine_type: EntityType,
+    pub requested_perimeter_points: Vec<PerimeterPoint>,
+    pub sensor_types: Vec<MinefieldSensorType>,
+}
+
+impl MinefieldQuery {
+    #[must_use]
+    pub fn builder() -> MinefieldQueryBuilder {
+        MinefieldQueryBuilder::new()
+    }
+
+    #[must_use]
+    pub fn into_builder(self) -> MinefieldQueryBuilder {
+        MinefieldQueryBuilder::new_from_body(self)
+    }
+
+    #[must_use]
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::MinefieldQuery(self)
+    }
+}
+
+impl BodyInfo for MinefieldQuery {
+    fn body_length(&self) -> u16 {
+        BASE_MINEFIELD_QUERY_BODY_LENGTH
+            + self
+                .requested_perimeter_points
+                .iter()
+                .map(PerimeterPoint::record_length)
+                .sum::<u16>()
+            + sensor_types_lengths(self.sensor_types.len()).record_length as u16
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::MinefieldQuery
+    }
+}
+
+/// Lengths of the Sensor Types list, padded to a 32-bit boundary.
+pub(crate) fn sensor_types_lengths(number_of_sensor_types: usize) -> PaddedRecordLengths {
+    length_padded_to_num(number_of_sensor_types * TWO_OCTETS, FOUR_OCTETS)
+}
+
+impl Interaction for MinefieldQuery {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.requesting_entity_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        Some(&self.minefield_id)
+    }
+}
@@ -0,0 +1,42 @@
+use crate::common::parser::IResult;
+use crate::common::parser::{
+    entity_id, entity_type, minefield_data_filter, minefield_sensor_type, perimeter_point,
+};
+use crate::minefield_query::model::{sensor_types_lengths, MinefieldQuery};
+use crate::model::PduBody;
+use nom::bytes::complete::take;
+use nom::error::context;
+use nom::multi::count;
+use nom::number::complete::be_u8;
+
+pub(crate) fn minefield_query_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    context("Minefield Query Body", |input| {
+        let (input, minefield_id) = entity_id(input)?;
+        let (input, requesting_entity_id) = entity_id(input)?;
+        let (input, request_id) = be_u8(input)?;
+        let (input, number_of_perimeter_points) = be_u8(input)?;
+        let (input, _padding) = be_u8(input)?;
+        let (input, number_of_sensor_types) = be_u8(input)?;
+        let (input, data_filter) = minefield_data_filter(input)?;
+        let (input, requested_mine_type) = entity_type(input)?;
+
+        let (input, requested_perimeter_points) =
+            count(perimeter_point, number_of_perimeter_points.into())(input)?;
+        let (input, sensor_types) =
+            count(minefield_sensor_type, number_of_sensor_types.into())(input)?;
+        let (input, _padding) =
+            take(sensor_types_lengths(number_of_sensor_types.into()).padding_length)(input)?;
+
+        let body = MinefieldQuery::builder()
+            .with_minefield_id(minefield_id)
+            .with_requesting_entity_id(requesting_entity_id)
+            .with_request_id(request_id)
+            .with_data_filter(data_filter)
+            .with_requested_mine_type(requested_mine_type)
+            .with_requested_perimeter_points(requested_perimeter_points)
+            .with_sensor_types(sensor_types)
+            .build();
+
+        Ok((input, body.into_pdu_body()))
+    })(input)
+}
@@ -0,0 +1,32 @@
+use crate::common::BodyInfo;
+use crate::minefield_query::model::{sensor_types_lengths, MinefieldQuery};
+use crate::{Serialize, SerializePdu, SupportedVersion};
+use bytes::BufMut;
+
+impl SerializePdu for MinefieldQuery {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
+        self.minefield_id.serialize(buf);
+        self.requesting_entity_id.serialize(buf);
+        buf.put_u8(self.request_id);
+        buf.put_u8(self.requested_perimeter_points.len() as u8);
+        buf.put_u8(0u8);
+        buf.put_u8(self.sensor_types.len() as u8);
+        self.data_filter.serialize(buf);
+        self.requested_mine_type.serialize(buf);
+
+        self.requested_perimeter_points
+            .iter()
+            .map(|point| point.serialize(buf))
+            .sum::<u16>();
+        self.sensor_types
+            .iter()
+            .map(|sensor_type| sensor_type.serialize(buf))
+            .sum::<u16>();
+        buf.put_bytes(
+            0u8,
+            sensor_types_lengths(self.sensor_types.len()).padding_length,
+        );
+
+        self.body_length()
+    }
+}
@@ -0,0 +1,55 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::model::DisTimeStamp;
+    use crate::common::model::{Pdu, PduHeader};
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{CoupledExtensionIndicator, LvcIndicator, PduType};
+    use crate::minefield_response_nack::model::MinefieldResponseNack;
+    use crate::model::EntityId;
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+
+    #[test]
+    fn minefield_response_nack_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::MinefieldResponseNACK).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::NoStatement)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = MinefieldResponseNack::builder()
+            .with_minefield_id(EntityId::new(10, 10, 1))
+            .with_requesting_entity_id(EntityId::new(20, 20, 2))
+            .with_request_id(4)
+            .with_missing_pdu_sequence_number(2)
+            .with_missing_pdu_sequence_number(5)
+            .build()
+            .into_pdu_body();
+
+        let original_pdu =
+            Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+}
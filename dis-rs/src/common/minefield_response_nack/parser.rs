@@ -0,0 +1,27 @@
+use crate::common::parser::entity_id;
+use crate::common::parser::IResult;
+use crate::minefield_response_nack::model::MinefieldResponseNack;
+use crate::model::PduBody;
+use nom::error::context;
+use nom::multi::count;
+use nom::number::complete::be_u8;
+
+pub(crate) fn minefield_response_nack_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    context("Minefield Response NACK Body", |input| {
+        let (input, minefield_id) = entity_id(input)?;
+        let (input, requesting_entity_id) = entity_id(input)?;
+        let (input, request_id) = be_u8(input)?;
+        let (input, number_of_missing_pdus) = be_u8(input)?;
+        let (input, missing_pdu_sequence_numbers) =
+            count(be_u8, number_of_missing_pdus.into())(input)?;
+
+        let body = MinefieldResponseNack::builder()
+            .with_minefield_id(minefield_id)
+            .with_requesting_entity_id(requesting_entity_id)
+            .with_request_id(request_id)
+            .with_missing_pdu_sequence_numbers(missing_pdu_sequence_numbers)
+            .build();
+
+        Ok((input, body.into_pdu_body()))
+    })(input)
+}
@@ -0,0 +1,18 @@
+use crate::common::BodyInfo;
+use crate::minefield_response_nack::model::MinefieldResponseNack;
+use crate::{Serialize, SerializePdu, SupportedVersion};
+use bytes::BufMut;
+
+impl SerializePdu for MinefieldResponseNack {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
+        self.minefield_id.serialize(buf);
+        self.requesting_entity_id.serialize(buf);
+        buf.put_u8(self.request_id);
+        buf.put_u8(self.missing_pdu_sequence_numbers.len() as u8);
+        self.missing_pdu_sequence_numbers
+            .iter()
+            .for_each(|&sequence_number| buf.put_u8(sequence_number));
+
+        self.body_length()
+    }
+}
@@ -0,0 +1,95 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::model::DisTimeStamp;
+    use crate::common::model::{Pdu, PduHeader};
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{CoupledExtensionIndicator, ForceId, LvcIndicator, PduType};
+    use crate::minefield_state::model::{
+        MinefieldAppearance, MinefieldProtocolMode, MinefieldState, MinefieldType,
+    };
+    use crate::model::{EntityId, EntityType, Location, Orientation, PerimeterPoint};
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+    use core::str::FromStr;
+
+    #[test]
+    fn minefield_state_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::MinefieldState).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::NoStatement)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = MinefieldState::builder()
+            .with_minefield_id(EntityId::new(10, 10, 1))
+            .with_minefield_sequence_number(3)
+            .with_force_id(ForceId::Friendly)
+            .with_minefield_type(EntityType::from_str("2:9:1:1:1:0:0").unwrap())
+            .with_minefield_location(Location::new(1.0, 2.0, 3.0))
+            .with_minefield_orientation(Orientation::new(0.0, 0.0, 1.5))
+            .with_appearance(
+                MinefieldAppearance::default()
+                    .with_minefield_type(MinefieldType::PureAntiTankMines)
+                    .with_inactive_lane(true),
+            )
+            .with_protocol_mode(MinefieldProtocolMode::QRPMode)
+            .with_perimeter_point(PerimeterPoint::new(0.0, 0.0))
+            .with_perimeter_point(PerimeterPoint::new(100.0, 0.0))
+            .with_perimeter_point(PerimeterPoint::new(100.0, 50.0))
+            .with_mine_type(EntityType::from_str("2:9:1:1:1:1:0").unwrap())
+            .build()
+            .into_pdu_body();
+
+        let original_pdu =
+            Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+
+    #[test]
+    fn minefield_appearance_from_wire() {
+        let appearance = MinefieldAppearance::from(0b0000_0000_0000_1000u16);
+        assert_eq!(
+            appearance.minefield_type,
+            MinefieldType::MixedAntiPersonnelAndAntiTankMines
+        );
+        assert!(!appearance.is_inactive);
+        assert!(appearance.has_inactive_lane);
+        assert!(!appearance.is_deactivated);
+
+        let appearance = MinefieldAppearance::from(0b0010_0000_0000_0110u16);
+        assert_eq!(appearance.minefield_type, MinefieldType::PureAntiTankMines);
+        assert!(appearance.is_inactive);
+        assert!(!appearance.has_inactive_lane);
+        assert!(appearance.is_deactivated);
+    }
+
+    #[test]
+    fn minefield_appearance_to_wire() {
+        let appearance = MinefieldAppearance::default()
+            .with_minefield_type(MinefieldType::PureAntiPersonnelMines)
+            .with_inactive_lane(true);
+        assert_eq!(u16::from(appearance), 0b0000_0000_0000_1001u16);
+    }
+}
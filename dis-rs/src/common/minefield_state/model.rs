@@ -0,0 +1,212 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::TWO_OCTETS;
+use crate::enumerations::{ForceId, PduType};
+use crate::minefield_state::builder::MinefieldStateBuilder;
+use crate::model::{EntityId, EntityType, Location, Orientation, PduBody, PerimeterPoint};
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const BASE_MINEFIELD_STATE_BODY_LENGTH: u16 = 60;
+
+/// 7.9.2 Minefield State PDU
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MinefieldState {
+    pub minefield_id: EntityId,
+    pub minefield_sequence_number: u16,
+    pub force_id: ForceId,
+    pub minefield_type: EntityType,
+    pub minefield_location: Location,
+    pub minefield_orientation: Orientation,
+    pub appearance: MinefieldAppearance,
+    pub protocol_mode: MinefieldProtocolMode,
+    pub perimeter_points: Vec<PerimeterPoint>,
+    pub mine_types: Vec<EntityType>,
+}
+
+impl MinefieldState {
+    #[must_use]
+    pub fn builder() -> MinefieldStateBuilder {
+        MinefieldStateBuilder::new()
+    }
+
+    #[must_use]
+    pub fn into_builder(self) -> MinefieldStateBuilder {
+        MinefieldStateBuilder::new_from_body(self)
+    }
+
+    #[must_use]
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::MinefieldState(self)
+    }
+}
+
+impl BodyInfo for MinefieldState {
+    fn body_length(&self) -> u16 {
+        BASE_MINEFIELD_STATE_BODY_LENGTH
+            + self
+                .perimeter_points
+                .iter()
+                .map(PerimeterPoint::record_length)
+                .sum::<u16>()
+            + self
+                .mine_types
+                .iter()
+                .map(EntityType::record_length)
+                .sum::<u16>()
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::MinefieldState
+    }
+}
+
+impl Interaction for MinefieldState {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.minefield_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// Minefield State Appearance bit field
+///
+/// 7.9.2 Minefield State PDU
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MinefieldAppearance {
+    pub minefield_type: MinefieldType,
+    pub is_inactive: bool,
+    pub has_inactive_lane: bool,
+    pub is_deactivated: bool,
+}
+
+impl MinefieldAppearance {
+    #[must_use]
+    pub fn with_minefield_type(mut self, minefield_type: MinefieldType) -> Self {
+        self.minefield_type = minefield_type;
+        self
+    }
+
+    #[must_use]
+    pub fn with_inactive(mut self, is_inactive: bool) -> Self {
+        self.is_inactive = is_inactive;
+        self
+    }
+
+    #[must_use]
+    pub fn with_inactive_lane(mut self, has_inactive_lane: bool) -> Self {
+        self.has_inactive_lane = has_inactive_lane;
+        self
+    }
+
+    #[must_use]
+    pub fn with_deactivated(mut self, is_deactivated: bool) -> Self {
+        self.is_deactivated = is_deactivated;
+        self
+    }
+
+    #[must_use]
+    pub const fn record_length(&self) -> u16 {
+        TWO_OCTETS as u16
+    }
+}
+
+impl From<u16> for MinefieldAppearance {
+    fn from(value: u16) -> Self {
+        Self {
+            minefield_type: MinefieldType::from((value & 0x0003) as u8),
+            is_inactive: ((value >> 2) & 0x0001) != 0,
+            has_inactive_lane: ((value >> 3) & 0x0001) != 0,
+            is_deactivated: ((value >> 13) & 0x0001) != 0,
+        }
+    }
+}
+
+impl From<MinefieldAppearance> for u16 {
+    fn from(value: MinefieldAppearance) -> Self {
+        let minefield_type: u8 = value.minefield_type.into();
+        (u16::from(minefield_type) & 0x0003)
+            | u16::from(value.is_inactive) << 2
+            | u16::from(value.has_inactive_lane) << 3
+            | u16::from(value.is_deactivated) << 13
+    }
+}
+
+/// Minefield Type field of the Minefield State Appearance bit field
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MinefieldType {
+    #[default]
+    MixedAntiPersonnelAndAntiTankMines,
+    PureAntiPersonnelMines,
+    PureAntiTankMines,
+    Unspecified(u8),
+}
+
+impl From<u8> for MinefieldType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => MinefieldType::MixedAntiPersonnelAndAntiTankMines,
+            1 => MinefieldType::PureAntiPersonnelMines,
+            2 => MinefieldType::PureAntiTankMines,
+            unspecified_value => MinefieldType::Unspecified(unspecified_value),
+        }
+    }
+}
+
+impl From<MinefieldType> for u8 {
+    fn from(value: MinefieldType) -> Self {
+        match value {
+            MinefieldType::MixedAntiPersonnelAndAntiTankMines => 0,
+            MinefieldType::PureAntiPersonnelMines => 1,
+            MinefieldType::PureAntiTankMines => 2,
+            MinefieldType::Unspecified(unspecified_value) => unspecified_value,
+        }
+    }
+}
+
+/// Protocol Mode record, as used by the Minefield State PDU
+///
+/// The protocol mode is stored in the two most significant bits of the 16-bit record.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MinefieldProtocolMode {
+    #[default]
+    HeartbeatMode,
+    QRPMode,
+    Unspecified(u8),
+}
+
+impl MinefieldProtocolMode {
+    #[must_use]
+    pub const fn record_length(&self) -> u16 {
+        TWO_OCTETS as u16
+    }
+}
+
+impl From<u16> for MinefieldProtocolMode {
+    fn from(value: u16) -> Self {
+        match (value >> 14) as u8 {
+            0 => MinefieldProtocolMode::HeartbeatMode,
+            1 => MinefieldProtocolMode::QRPMode,
+            unspecified_value => MinefieldProtocolMode::Unspecified(unspecified_value),
+        }
+    }
+}
+
+impl From<MinefieldProtocolMode> for u16 {
+    fn from(value: MinefieldProtocolMode) -> Self {
+        let mode = match value {
+            MinefieldProtocolMode::HeartbeatMode => 0u16,
+            MinefieldProtocolMode::QRPMode => 1u16,
+            MinefieldProtocolMode::Unspecified(unspecified_value) => {
+                u16::from(unspecified_value) & 0x0003
+            }
+        };
+        mode << 14
+    }
+}
@@ -0,0 +1,44 @@
+use crate::common::parser::IResult;
+use crate::common::parser::{entity_id, entity_type, location, orientation, perimeter_point};
+use crate::entity_state::parser::force_id;
+use crate::minefield_state::model::{MinefieldAppearance, MinefieldProtocolMode, MinefieldState};
+use crate::model::PduBody;
+use nom::error::context;
+use nom::multi::count;
+use nom::number::complete::{be_u16, be_u8};
+
+pub(crate) fn minefield_state_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    context("Minefield State Body", |input| {
+        let (input, minefield_id) = entity_id(input)?;
+        let (input, minefield_sequence_number) = be_u16(input)?;
+        let (input, force_id) = force_id(input)?;
+        let (input, number_of_perimeter_points) = be_u8(input)?;
+        let (input, minefield_type) = entity_type(input)?;
+        let (input, number_of_mine_types) = be_u16(input)?;
+        let (input, minefield_location) = location(input)?;
+        let (input, minefield_orientation) = orientation(input)?;
+        let (input, appearance) = be_u16(input)?;
+        let appearance = MinefieldAppearance::from(appearance);
+        let (input, protocol_mode) = be_u16(input)?;
+        let protocol_mode = MinefieldProtocolMode::from(protocol_mode);
+
+        let (input, perimeter_points) =
+            count(perimeter_point, number_of_perimeter_points.into())(input)?;
+        let (input, mine_types) = count(entity_type, number_of_mine_types.into())(input)?;
+
+        let body = MinefieldState::builder()
+            .with_minefield_id(minefield_id)
+            .with_minefield_sequence_number(minefield_sequence_number)
+            .with_force_id(force_id)
+            .with_minefield_type(minefield_type)
+            .with_minefield_location(minefield_location)
+            .with_minefield_orientation(minefield_orientation)
+            .with_appearance(appearance)
+            .with_protocol_mode(protocol_mode)
+            .with_perimeter_points(perimeter_points)
+            .with_mine_types(mine_types)
+            .build();
+
+        Ok((input, body.into_pdu_body()))
+    })(input)
+}
@@ -0,0 +1,46 @@
+use crate::common::BodyInfo;
+use crate::minefield_state::model::{MinefieldAppearance, MinefieldProtocolMode, MinefieldState};
+use crate::{Serialize, SerializePdu, SupportedVersion};
+use bytes::BufMut;
+
+impl SerializePdu for MinefieldState {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
+        self.minefield_id.serialize(buf);
+        buf.put_u16(self.minefield_sequence_number);
+        self.force_id.serialize(buf);
+        buf.put_u8(self.perimeter_points.len() as u8);
+        self.minefield_type.serialize(buf);
+        buf.put_u16(self.mine_types.len() as u16);
+        self.minefield_location.serialize(buf);
+        self.minefield_orientation.serialize(buf);
+        self.appearance.serialize(buf);
+        self.protocol_mode.serialize(buf);
+
+        self.perimeter_points
+            .iter()
+            .map(|point| point.serialize(buf))
+            .sum::<u16>();
+        self.mine_types
+            .iter()
+            .map(|mine_type| mine_type.serialize(buf))
+            .sum::<u16>();
+
+        self.body_length()
+    }
+}
+
+impl Serialize for MinefieldAppearance {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        buf.put_u16((*self).into());
+
+        self.record_length()
+    }
+}
+
+impl Serialize for MinefieldProtocolMode {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        buf.put_u16((*self).into());
+
+        self.record_length()
+    }
+}
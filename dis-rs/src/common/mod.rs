@@ -8,6 +8,9 @@
 pub mod action_response;
 pub mod action_response_r;
 pub mod aggregate_state;
+pub mod appearance;
+pub mod areal_object_state;
+pub mod articulated_parts;
 pub mod attribute;
 pub mod collision;
 pub mod collision_elastic;
@@ -21,16 +24,32 @@
 pub mod data_r;
 pub mod designator;
 pub mod detonation;
+pub mod directed_energy_fire;
 pub mod electromagnetic_emission;
+pub mod entity_damage_status;
 pub mod entity_state;
 pub mod entity_state_update;
+pub mod environmental_process;
 pub mod event_report;
 pub mod event_report_r;
 pub mod fire;
+pub mod gridded_data;
 pub mod iff;
+pub mod information_operations_action;
+pub mod information_operations_report;
+pub mod intercom_control;
+pub mod intercom_signal;
 pub mod is_group_of;
 pub mod is_part_of;
+pub mod le_detonation;
+pub mod le_fire;
+pub mod linear_object_state;
+pub mod minefield_data;
+pub mod minefield_query;
+pub mod minefield_response_nack;
+pub mod minefield_state;
 pub mod other;
+pub mod point_object_state;
 pub mod receiver;
 pub mod record_query_r;
 pub mod record_r;
@@ -53,19 +72,31 @@
 pub mod stop_freeze_r;
 pub mod transfer_ownership;
 pub mod transmitter;
+pub mod tspi;
 pub mod underwater_acoustic;
+pub mod validation;
+pub mod version_conversion;
+pub mod view;
 
 pub mod errors;
 mod writer;
 
 use crate::common::errors::DisError;
 use crate::common::model::Pdu;
-use crate::common::parser::parse_multiple_pdu;
+use crate::common::model::PduHeader;
+use crate::common::parser::{
+    parse_multiple_header, parse_multiple_pdu, parse_multiple_pdu_lenient,
+};
+use crate::common::view::PduSplitter;
 use crate::enumerations::{PduType, ProtocolVersion};
-use bytes::BytesMut;
+use alloc::vec;
+use alloc::vec::Vec;
+use bytes::BufMut;
 
 #[allow(dead_code)]
 pub enum SupportedVersion {
+    V4,
+    V5,
     V6,
     V7,
     Unsupported,
@@ -76,6 +107,8 @@ fn from(version: ProtocolVersion) -> Self {
         match version {
             ProtocolVersion::IEEE1278_1A1998 => SupportedVersion::V6,
             ProtocolVersion::IEEE1278_12012 => SupportedVersion::V7,
+            ProtocolVersion::IEEE1278_11995 => SupportedVersion::V5,
+            ProtocolVersion::DISApplicationProtocolsVersion2_0FourthDraft => SupportedVersion::V4,
             _ => SupportedVersion::Unsupported,
         }
     }
@@ -85,6 +118,8 @@ fn from(version: ProtocolVersion) -> Self {
 #[must_use]
 pub fn supported_protocol_versions() -> Vec<ProtocolVersion> {
     vec![
+        ProtocolVersion::DISApplicationProtocolsVersion2_0FourthDraft,
+        ProtocolVersion::IEEE1278_11995,
         ProtocolVersion::IEEE1278_1A1998,
         ProtocolVersion::IEEE1278_12012,
     ]
@@ -108,14 +143,14 @@ pub trait Interaction {
 /// based on the protocol version of the PDU.
 /// Returns the number of bytes written to the buffer.
 pub trait SerializePdu {
-    fn serialize_pdu(&self, version: SupportedVersion, buf: &mut BytesMut) -> u16;
+    fn serialize_pdu(&self, version: SupportedVersion, buf: &mut impl BufMut) -> u16;
 }
 
 /// Trait that implements writing data structures to a buffer.
 /// This serialize must be independent of protocol version differences for the data structure.
 /// Returns the number of bytes written to the buffer.
 pub trait Serialize {
-    fn serialize(&self, buf: &mut BytesMut) -> u16;
+    fn serialize(&self, buf: &mut impl BufMut) -> u16;
 }
 
 /// Parses the contents of the input, determining the DIS version by itself.
@@ -130,6 +165,70 @@ pub fn parse(input: &[u8]) -> Result<Vec<Pdu>, DisError> {
     parse_multiple_pdu(input)
 }
 
+/// The outcome of parsing a buffer with `parse_lenient()`.
+#[derive(Debug, Default, PartialEq)]
+pub struct LenientParseResult {
+    /// All PDUs in the buffer that were parsed successfully, in order of appearance.
+    pub pdus: Vec<Pdu>,
+    /// The errors for the PDUs that could not be parsed, paired with the byte offset of the PDU in the buffer.
+    pub errors: Vec<(usize, DisError)>,
+}
+
+impl LenientParseResult {
+    /// Returns true when all PDUs in the buffer were parsed successfully.
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Parses the contents of the input, determining the DIS version by itself,
+/// while keeping all PDUs that can be parsed when other PDUs in the buffer are malformed.
+///
+/// After a PDU fails to parse, parsing continues at the next PDU, which is located using the PDU length field
+/// of the header of the failed PDU. Parsing stops when the header of a PDU cannot be parsed,
+/// or when the buffer is shorter than the PDU length indicated by the header.
+/// The errors are returned together with the successfully parsed PDUs.
+#[must_use]
+pub fn parse_lenient(input: &[u8]) -> LenientParseResult {
+    parse_multiple_pdu_lenient(input)
+}
+
+/// Parses the contents of the input as DIS version 4.
+/// This function tries to parse as many PDUs as there are in the buffer,
+/// assuming there are only complete PDUs present in the input.
+///
+/// This function will filter out any non-v4 PDUs in a buffer (packet).
+///
+/// # Errors
+/// Returns a `DisError` when parsing fails
+pub fn parse_v4(input: &[u8]) -> Result<Vec<Pdu>, DisError> {
+    let pdus = parse_multiple_pdu(input)?
+        .into_iter()
+        .filter(|pdu| {
+            pdu.header.protocol_version
+                == ProtocolVersion::DISApplicationProtocolsVersion2_0FourthDraft
+        })
+        .collect();
+    Ok(pdus)
+}
+
+/// Parses the contents of the input as DIS version 5.
+/// This function tries to parse as many PDUs as there are in the buffer,
+/// assuming there are only complete PDUs present in the input.
+///
+/// This function will filter out any non-v5 PDUs in a buffer (packet).
+///
+/// # Errors
+/// Returns a `DisError` when parsing fails
+pub fn parse_v5(input: &[u8]) -> Result<Vec<Pdu>, DisError> {
+    let pdus = parse_multiple_pdu(input)?
+        .into_iter()
+        .filter(|pdu| pdu.header.protocol_version == ProtocolVersion::IEEE1278_11995)
+        .collect();
+    Ok(pdus)
+}
+
 /// Parses the contents of the input as DIS version 6.
 /// This function tries to parse as many PDUs as there are in the buffer,
 /// assuming there are only complete PDUs present in the input.
@@ -161,3 +260,28 @@ pub fn parse_v7(input: &[u8]) -> Result<Vec<Pdu>, DisError> {
         .collect();
     Ok(pdus)
 }
+
+/// Parses the header of the first PDU in the input, without parsing the body.
+///
+/// # Errors
+/// Returns a `DisError` when the input does not contain a complete header,
+/// or is smaller than the PDU length indicated in the header.
+pub fn parse_header(input: &[u8]) -> Result<PduHeader, DisError> {
+    parser::parse_header(input)
+}
+
+/// Parses the headers of all PDUs in the input, skipping the bodies.
+///
+/// # Errors
+/// Returns a `DisError` when the input does not contain at least one complete PDU.
+pub fn parse_headers(input: &[u8]) -> Result<Vec<PduHeader>, DisError> {
+    parse_multiple_header(input)
+}
+
+/// Splits the input (e.g., a datagram) into the PDUs it contains, yielding the `PduHeader` and the raw body bytes
+/// of each PDU. This allows routing, counting and filtering PDUs based on type, exercise and version
+/// without parsing the bodies.
+#[must_use]
+pub fn split_pdus(input: &[u8]) -> PduSplitter<'_> {
+    PduSplitter::new(input)
+}
@@ -2,6 +2,9 @@
 use crate::action_request_r::model::ActionRequestR;
 use crate::action_response_r::model::ActionResponseR;
 use crate::aggregate_state::model::AggregateState;
+use crate::appearance::model::Appearance;
+use crate::areal_object_state::model::ArealObjectState;
+use crate::articulated_parts::model::ArticulatedParts;
 use crate::comment_r::model::CommentR;
 use crate::common::acknowledge::model::Acknowledge;
 use crate::common::action_request::model::ActionRequest;
@@ -31,28 +34,48 @@
 use crate::common::transmitter::model::Transmitter;
 use crate::common::{BodyInfo, Interaction};
 use crate::constants::{
-    EIGHT_OCTETS, FIFTEEN_OCTETS, LEAST_SIGNIFICANT_BIT, NANOSECONDS_PER_TIME_UNIT, NO_REMAINDER,
-    PDU_HEADER_LEN_BYTES, SIX_OCTETS,
+    EIGHT_OCTETS, FIFTEEN_OCTETS, FOURTEEN_OCTETS, FOUR_OCTETS, LEAST_SIGNIFICANT_BIT,
+    NANOSECONDS_PER_TIME_UNIT, NO_REMAINDER, PDU_HEADER_LEN_BYTES, SIX_OCTETS, THREE_OCTETS,
+    TWO_OCTETS,
 };
 use crate::create_entity_r::model::CreateEntityR;
 use crate::data_query_r::model::DataQueryR;
 use crate::data_r::model::DataR;
+use crate::directed_energy_fire::model::DirectedEnergyFire;
+use crate::entity_damage_status::model::EntityDamageStatus;
 use crate::enumerations::{
+    AppearanceEntityOrObjectState, AppearanceObjectGeneralDamage,
+    AppearanceObjectGeneralIEDPresent, AppearanceObjectGeneralPredistributed,
     ArticulatedPartsTypeClass, ArticulatedPartsTypeMetric, AttachedPartDetachedIndicator,
     AttachedParts, ChangeIndicator, EntityAssociationAssociationStatus,
     EntityAssociationGroupMemberType, EntityAssociationPhysicalAssociationType,
-    EntityAssociationPhysicalConnectionType, SeparationPreEntityIndicator,
-    SeparationReasonForSeparation, StationName,
+    EntityAssociationPhysicalConnectionType, IOCommsNodeType, IOEffectLinkType, IOEffectProcess,
+    IOEffectStatus, IOEffectType, SeparationPreEntityIndicator, SeparationReasonForSeparation,
+    StationName,
 };
 use crate::enumerations::{
     Country, EntityKind, ExplosiveMaterialCategories, MunitionDescriptorFuse,
     MunitionDescriptorWarhead, PduType, PlatformDomain, ProtocolFamily, ProtocolVersion,
     VariableRecordType,
 };
+use crate::environmental_process::model::EnvironmentalProcess;
 use crate::event_report_r::model::EventReportR;
 use crate::fixed_parameters::{NO_APPLIC, NO_ENTITY, NO_SITE};
+use crate::gridded_data::model::GriddedData;
+use crate::information_operations_action::model::InformationOperationsAction;
+use crate::information_operations_report::model::InformationOperationsReport;
+use crate::intercom_control::model::IntercomControl;
+use crate::intercom_signal::model::IntercomSignal;
 use crate::is_group_of::model::IsGroupOf;
 use crate::is_part_of::model::IsPartOf;
+use crate::le_detonation::model::LEDetonation;
+use crate::le_fire::model::LEFire;
+use crate::linear_object_state::model::LinearObjectState;
+use crate::minefield_data::model::MinefieldData;
+use crate::minefield_query::model::MinefieldQuery;
+use crate::minefield_response_nack::model::MinefieldResponseNack;
+use crate::minefield_state::model::MinefieldState;
+use crate::point_object_state::model::PointObjectState;
 use crate::record_query_r::model::RecordQueryR;
 use crate::record_r::model::RecordR;
 use crate::remove_entity_r::model::RemoveEntityR;
@@ -68,12 +91,22 @@
 use crate::start_resume_r::model::StartResumeR;
 use crate::stop_freeze_r::model::StopFreezeR;
 use crate::transfer_ownership::model::TransferOwnership;
+use crate::tspi::model::TSPI;
 use crate::underwater_acoustic::model::UnderwaterAcoustic;
+#[cfg(feature = "std")]
+use crate::utils::{
+    euler_angles_to_matrix, matrix_to_euler_angles, multiply_matrices, transpose,
+    LocalTangentPlane, Quaternion, RotationMatrix,
+};
 use crate::DisError;
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt::Display;
+use core::str::FromStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::fmt::Display;
-use std::str::FromStr;
 
 pub use crate::v7::model::PduStatus;
 
@@ -146,6 +179,20 @@ pub fn new(protocol_version: ProtocolVersion, exercise_id: u8, pdu_type: PduType
         }
     }
 
+    #[must_use]
+    pub fn new_v4(exercise_id: u8, pdu_type: PduType) -> Self {
+        PduHeader::new(
+            ProtocolVersion::DISApplicationProtocolsVersion2_0FourthDraft,
+            exercise_id,
+            pdu_type,
+        )
+    }
+
+    #[must_use]
+    pub fn new_v5(exercise_id: u8, pdu_type: PduType) -> Self {
+        PduHeader::new(ProtocolVersion::IEEE1278_11995, exercise_id, pdu_type)
+    }
+
     #[must_use]
     pub fn new_v6(exercise_id: u8, pdu_type: PduType) -> Self {
         PduHeader::new(ProtocolVersion::IEEE1278_1A1998, exercise_id, pdu_type)
@@ -219,26 +266,26 @@ pub enum PduBody {
     IFF(Iff),
     UnderwaterAcoustic(UnderwaterAcoustic),
     SupplementalEmissionEntityState(SEES),
-    IntercomSignal,
-    IntercomControl,
+    IntercomSignal(IntercomSignal),
+    IntercomControl(IntercomControl),
     AggregateState(AggregateState),
     IsGroupOf(IsGroupOf),
     TransferOwnership(TransferOwnership),
     IsPartOf(IsPartOf),
-    MinefieldState,
-    MinefieldQuery,
-    MinefieldData,
-    MinefieldResponseNACK,
-    EnvironmentalProcess,
-    GriddedData,
-    PointObjectState,
-    LinearObjectState,
-    ArealObjectState,
-    TSPI,
-    Appearance,
-    ArticulatedParts,
-    LEFire,
-    LEDetonation,
+    MinefieldState(MinefieldState),
+    MinefieldQuery(MinefieldQuery),
+    MinefieldData(MinefieldData),
+    MinefieldResponseNACK(MinefieldResponseNack),
+    EnvironmentalProcess(EnvironmentalProcess),
+    GriddedData(GriddedData),
+    PointObjectState(PointObjectState),
+    LinearObjectState(LinearObjectState),
+    ArealObjectState(ArealObjectState),
+    TSPI(TSPI),
+    Appearance(Appearance),
+    ArticulatedParts(ArticulatedParts),
+    LEFire(LEFire),
+    LEDetonation(LEDetonation),
     CreateEntityR(CreateEntityR),
     RemoveEntityR(RemoveEntityR),
     StartResumeR(StartResumeR),
@@ -256,10 +303,10 @@ pub enum PduBody {
     RecordQueryR(RecordQueryR),
     CollisionElastic(CollisionElastic),
     EntityStateUpdate(EntityStateUpdate),
-    DirectedEnergyFire,
-    EntityDamageStatus,
-    InformationOperationsAction,
-    InformationOperationsReport,
+    DirectedEnergyFire(DirectedEnergyFire),
+    EntityDamageStatus(EntityDamageStatus),
+    InformationOperationsAction(InformationOperationsAction),
+    InformationOperationsReport(InformationOperationsReport),
     Attribute(Attribute),
 }
 
@@ -298,26 +345,26 @@ fn body_length(&self) -> u16 {
             PduBody::IFF(body) => body.body_length(),
             PduBody::UnderwaterAcoustic(body) => body.body_length(),
             PduBody::SupplementalEmissionEntityState(body) => body.body_length(),
-            PduBody::IntercomSignal => 0,
-            PduBody::IntercomControl => 0,
+            PduBody::IntercomSignal(body) => body.body_length(),
+            PduBody::IntercomControl(body) => body.body_length(),
             PduBody::AggregateState(body) => body.body_length(),
             PduBody::IsGroupOf(body) => body.body_length(),
             PduBody::TransferOwnership(body) => body.body_length(),
             PduBody::IsPartOf(body) => body.body_length(),
-            PduBody::MinefieldState => 0,
-            PduBody::MinefieldQuery => 0,
-            PduBody::MinefieldData => 0,
-            PduBody::MinefieldResponseNACK => 0,
-            PduBody::EnvironmentalProcess => 0,
-            PduBody::GriddedData => 0,
-            PduBody::PointObjectState => 0,
-            PduBody::LinearObjectState => 0,
-            PduBody::ArealObjectState => 0,
-            PduBody::TSPI => 0,
-            PduBody::Appearance => 0,
-            PduBody::ArticulatedParts => 0,
-            PduBody::LEFire => 0,
-            PduBody::LEDetonation => 0,
+            PduBody::MinefieldState(body) => body.body_length(),
+            PduBody::MinefieldQuery(body) => body.body_length(),
+            PduBody::MinefieldData(body) => body.body_length(),
+            PduBody::MinefieldResponseNACK(body) => body.body_length(),
+            PduBody::EnvironmentalProcess(body) => body.body_length(),
+            PduBody::GriddedData(body) => body.body_length(),
+            PduBody::PointObjectState(body) => body.body_length(),
+            PduBody::LinearObjectState(body) => body.body_length(),
+            PduBody::ArealObjectState(body) => body.body_length(),
+            PduBody::TSPI(body) => body.body_length(),
+            PduBody::Appearance(body) => body.body_length(),
+            PduBody::ArticulatedParts(body) => body.body_length(),
+            PduBody::LEFire(body) => body.body_length(),
+            PduBody::LEDetonation(body) => body.body_length(),
             PduBody::CreateEntityR(body) => body.body_length(),
             PduBody::RemoveEntityR(body) => body.body_length(),
             PduBody::StartResumeR(body) => body.body_length(),
@@ -335,10 +382,10 @@ fn body_length(&self) -> u16 {
             PduBody::RecordQueryR(body) => body.body_length(),
             PduBody::CollisionElastic(body) => body.body_length(),
             PduBody::EntityStateUpdate(body) => body.body_length(),
-            PduBody::DirectedEnergyFire => 0,
-            PduBody::EntityDamageStatus => 0,
-            PduBody::InformationOperationsAction => 0,
-            PduBody::InformationOperationsReport => 0,
+            PduBody::DirectedEnergyFire(body) => body.body_length(),
+            PduBody::EntityDamageStatus(body) => body.body_length(),
+            PduBody::InformationOperationsAction(body) => body.body_length(),
+            PduBody::InformationOperationsReport(body) => body.body_length(),
             PduBody::Attribute(body) => body.body_length(),
         }
     }
@@ -376,26 +423,26 @@ fn body_type(&self) -> PduType {
             PduBody::IFF(body) => body.body_type(),
             PduBody::UnderwaterAcoustic(body) => body.body_type(),
             PduBody::SupplementalEmissionEntityState(body) => body.body_type(),
-            PduBody::IntercomSignal => PduType::IntercomSignal,
-            PduBody::IntercomControl => PduType::IntercomControl,
+            PduBody::IntercomSignal(body) => body.body_type(),
+            PduBody::IntercomControl(body) => body.body_type(),
             PduBody::AggregateState(body) => body.body_type(),
             PduBody::IsGroupOf(body) => body.body_type(),
             PduBody::TransferOwnership(body) => body.body_type(),
             PduBody::IsPartOf(body) => body.body_type(),
-            PduBody::MinefieldState => PduType::MinefieldState,
-            PduBody::MinefieldQuery => PduType::MinefieldQuery,
-            PduBody::MinefieldData => PduType::MinefieldData,
-            PduBody::MinefieldResponseNACK => PduType::MinefieldResponseNACK,
-            PduBody::EnvironmentalProcess => PduType::EnvironmentalProcess,
-            PduBody::GriddedData => PduType::GriddedData,
-            PduBody::PointObjectState => PduType::PointObjectState,
-            PduBody::LinearObjectState => PduType::LinearObjectState,
-            PduBody::ArealObjectState => PduType::ArealObjectState,
-            PduBody::TSPI => PduType::TSPI,
-            PduBody::Appearance => PduType::Appearance,
-            PduBody::ArticulatedParts => PduType::ArticulatedParts,
-            PduBody::LEFire => PduType::LEFire,
-            PduBody::LEDetonation => PduType::LEDetonation,
+            PduBody::MinefieldState(body) => body.body_type(),
+            PduBody::MinefieldQuery(body) => body.body_type(),
+            PduBody::MinefieldData(body) => body.body_type(),
+            PduBody::MinefieldResponseNACK(body) => body.body_type(),
+            PduBody::EnvironmentalProcess(body) => body.body_type(),
+            PduBody::GriddedData(body) => body.body_type(),
+            PduBody::PointObjectState(body) => body.body_type(),
+            PduBody::LinearObjectState(body) => body.body_type(),
+            PduBody::ArealObjectState(body) => body.body_type(),
+            PduBody::TSPI(body) => body.body_type(),
+            PduBody::Appearance(body) => body.body_type(),
+            PduBody::ArticulatedParts(body) => body.body_type(),
+            PduBody::LEFire(body) => body.body_type(),
+            PduBody::LEDetonation(body) => body.body_type(),
             PduBody::CreateEntityR(body) => body.body_type(),
             PduBody::RemoveEntityR(body) => body.body_type(),
             PduBody::StartResumeR(body) => body.body_type(),
@@ -413,10 +460,10 @@ fn body_type(&self) -> PduType {
             PduBody::RecordQueryR(body) => body.body_type(),
             PduBody::CollisionElastic(body) => body.body_type(),
             PduBody::EntityStateUpdate(body) => body.body_type(),
-            PduBody::DirectedEnergyFire => PduType::DirectedEnergyFire,
-            PduBody::EntityDamageStatus => PduType::EntityDamageStatus,
-            PduBody::InformationOperationsAction => PduType::InformationOperationsAction,
-            PduBody::InformationOperationsReport => PduType::InformationOperationsReport,
+            PduBody::DirectedEnergyFire(body) => body.body_type(),
+            PduBody::EntityDamageStatus(body) => body.body_type(),
+            PduBody::InformationOperationsAction(body) => body.body_type(),
+            PduBody::InformationOperationsReport(body) => body.body_type(),
             PduBody::Attribute(body) => body.body_type(),
         }
     }
@@ -457,26 +504,26 @@ fn originator(&self) -> Option<&EntityId> {
             PduBody::IFF(body) => body.originator(),
             PduBody::UnderwaterAcoustic(body) => body.originator(),
             PduBody::SupplementalEmissionEntityState(body) => body.originator(),
-            PduBody::IntercomSignal => None,
-            PduBody::IntercomControl => None,
+            PduBody::IntercomSignal(body) => body.originator(),
+            PduBody::IntercomControl(body) => body.originator(),
             PduBody::AggregateState(body) => body.originator(),
             PduBody::IsGroupOf(body) => body.originator(),
             PduBody::TransferOwnership(body) => body.originator(),
             PduBody::IsPartOf(body) => body.originator(),
-            PduBody::MinefieldState => None,
-            PduBody::MinefieldQuery => None,
-            PduBody::MinefieldData => None,
-            PduBody::MinefieldResponseNACK => None,
-            PduBody::EnvironmentalProcess => None,
-            PduBody::GriddedData => None,
-            PduBody::PointObjectState => None,
-            PduBody::LinearObjectState => None,
-            PduBody::ArealObjectState => None,
-            PduBody::TSPI => None,
-            PduBody::Appearance => None,
-            PduBody::ArticulatedParts => None,
-            PduBody::LEFire => None,
-            PduBody::LEDetonation => None,
+            PduBody::MinefieldState(body) => body.originator(),
+            PduBody::MinefieldQuery(body) => body.originator(),
+            PduBody::MinefieldData(body) => body.originator(),
+            PduBody::MinefieldResponseNACK(body) => body.originator(),
+            PduBody::EnvironmentalProcess(body) => body.originator(),
+            PduBody::GriddedData(body) => body.originator(),
+            PduBody::PointObjectState(body) => body.originator(),
+            PduBody::LinearObjectState(body) => body.originator(),
+            PduBody::ArealObjectState(body) => body.originator(),
+            PduBody::TSPI(body) => body.originator(),
+            PduBody::Appearance(body) => body.originator(),
+            PduBody::ArticulatedParts(body) => body.originator(),
+            PduBody::LEFire(body) => body.originator(),
+            PduBody::LEDetonation(body) => body.originator(),
             PduBody::CreateEntityR(body) => body.originator(),
             PduBody::RemoveEntityR(body) => body.originator(),
             PduBody::StartResumeR(body) => body.originator(),
@@ -494,10 +541,10 @@ fn originator(&self) -> Option<&EntityId> {
             PduBody::RecordQueryR(body) => body.originator(),
             PduBody::CollisionElastic(body) => body.originator(),
             PduBody::EntityStateUpdate(body) => body.originator(),
-            PduBody::DirectedEnergyFire => None,
-            PduBody::EntityDamageStatus => None,
-            PduBody::InformationOperationsAction => None,
-            PduBody::InformationOperationsReport => None,
+            PduBody::DirectedEnergyFire(body) => body.originator(),
+            PduBody::EntityDamageStatus(body) => body.originator(),
+            PduBody::InformationOperationsAction(body) => body.originator(),
+            PduBody::InformationOperationsReport(body) => body.originator(),
             PduBody::Attribute(body) => body.originator(),
         }
     }
@@ -536,26 +583,26 @@ fn receiver(&self) -> Option<&EntityId> {
             PduBody::IFF(body) => body.receiver(),
             PduBody::UnderwaterAcoustic(body) => body.receiver(),
             PduBody::SupplementalEmissionEntityState(body) => body.receiver(),
-            PduBody::IntercomSignal => None,
-            PduBody::IntercomControl => None,
+            PduBody::IntercomSignal(body) => body.receiver(),
+            PduBody::IntercomControl(body) => body.receiver(),
             PduBody::AggregateState(body) => body.receiver(),
             PduBody::IsGroupOf(body) => body.receiver(),
             PduBody::TransferOwnership(body) => body.receiver(),
             PduBody::IsPartOf(body) => body.receiver(),
-            PduBody::MinefieldState => None,
-            PduBody::MinefieldQuery => None,
-            PduBody::MinefieldData => None,
-            PduBody::MinefieldResponseNACK => None,
-            PduBody::EnvironmentalProcess => None,
-            PduBody::GriddedData => None,
-            PduBody::PointObjectState => None,
-            PduBody::LinearObjectState => None,
-            PduBody::ArealObjectState => None,
-            PduBody::TSPI => None,
-            PduBody::Appearance => None,
-            PduBody::ArticulatedParts => None,
-            PduBody::LEFire => None,
-            PduBody::LEDetonation => None,
+            PduBody::MinefieldState(body) => body.receiver(),
+            PduBody::MinefieldQuery(body) => body.receiver(),
+            PduBody::MinefieldData(body) => body.receiver(),
+            PduBody::MinefieldResponseNACK(body) => body.receiver(),
+            PduBody::EnvironmentalProcess(body) => body.receiver(),
+            PduBody::GriddedData(body) => body.receiver(),
+            PduBody::PointObjectState(body) => body.receiver(),
+            PduBody::LinearObjectState(body) => body.receiver(),
+            PduBody::ArealObjectState(body) => body.receiver(),
+            PduBody::TSPI(body) => body.receiver(),
+            PduBody::Appearance(body) => body.receiver(),
+            PduBody::ArticulatedParts(body) => body.receiver(),
+            PduBody::LEFire(body) => body.receiver(),
+            PduBody::LEDetonation(body) => body.receiver(),
             PduBody::CreateEntityR(body) => body.receiver(),
             PduBody::RemoveEntityR(body) => body.receiver(),
             PduBody::StartResumeR(body) => body.receiver(),
@@ -573,10 +620,10 @@ fn receiver(&self) -> Option<&EntityId> {
             PduBody::RecordQueryR(body) => body.receiver(),
             PduBody::CollisionElastic(body) => body.receiver(),
             PduBody::EntityStateUpdate(body) => body.receiver(),
-            PduBody::DirectedEnergyFire => None,
-            PduBody::EntityDamageStatus => None,
-            PduBody::InformationOperationsAction => None,
-            PduBody::InformationOperationsReport => None,
+            PduBody::DirectedEnergyFire(body) => body.receiver(),
+            PduBody::EntityDamageStatus(body) => body.receiver(),
+            PduBody::InformationOperationsAction(body) => body.receiver(),
+            PduBody::InformationOperationsReport(body) => body.receiver(),
             PduBody::Attribute(body) => body.receiver(),
         }
     }
@@ -696,7 +743,7 @@ fn default() -> Self {
 }
 
 impl Display for SimulationAddress {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}:{}", self.site_id, self.application_id)
     }
 }
@@ -806,7 +853,7 @@ fn default() -> Self {
 }
 
 impl Display for EntityId {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}:{}", self.simulation_address, self.entity_id)
     }
 }
@@ -886,7 +933,7 @@ fn default() -> Self {
 }
 
 impl Display for EventId {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}:{}", self.simulation_address, self.event_id)
     }
 }
@@ -1045,6 +1092,74 @@ pub fn with_phi(mut self, phi: f32) -> Self {
     }
 }
 
+/// Conversions of `Orientation` from and to other representations of rotations.
+/// These require the floating point math functions of `std`.
+#[cfg(feature = "std")]
+impl Orientation {
+    /// The rotation matrix transforming vectors in world (ECEF) coordinates into entity body coordinates.
+    #[must_use]
+    pub fn world_to_body_matrix(&self) -> RotationMatrix {
+        euler_angles_to_matrix(
+            f64::from(self.psi),
+            f64::from(self.theta),
+            f64::from(self.phi),
+        )
+    }
+
+    /// The rotation matrix transforming vectors in entity body coordinates into world (ECEF) coordinates.
+    #[must_use]
+    pub fn body_to_world_matrix(&self) -> RotationMatrix {
+        transpose(&self.world_to_body_matrix())
+    }
+
+    /// Constructs the `Orientation` from a world (ECEF) to body rotation matrix.
+    #[must_use]
+    pub fn from_world_to_body_matrix(matrix: &RotationMatrix) -> Self {
+        let (psi, theta, phi) = matrix_to_euler_angles(matrix);
+        Self::new(psi as f32, theta as f32, phi as f32)
+    }
+
+    /// The quaternion rotating entity body coordinates into world (ECEF) coordinates,
+    /// i.e., `to_quaternion().to_rotation_matrix()` equals `body_to_world_matrix()`.
+    #[must_use]
+    pub fn to_quaternion(&self) -> Quaternion {
+        Quaternion::from_rotation_matrix(&self.body_to_world_matrix())
+    }
+
+    /// Constructs the `Orientation` from a quaternion rotating entity body coordinates into world (ECEF) coordinates.
+    #[must_use]
+    pub fn from_quaternion(quaternion: &Quaternion) -> Self {
+        Self::from_world_to_body_matrix(&transpose(&quaternion.to_rotation_matrix()))
+    }
+
+    /// Converts the `Orientation` of an entity at `location` into local `(heading, pitch, roll)`,
+    /// relative to the North-East-Down frame at that location. All angles are in radians.
+    #[must_use]
+    pub fn to_heading_pitch_roll(&self, location: &Location) -> (f64, f64, f64) {
+        let frame = LocalTangentPlane::from_ecef(location);
+        let ned_to_body =
+            multiply_matrices(&self.world_to_body_matrix(), &frame.ned_to_ecef_matrix());
+        matrix_to_euler_angles(&ned_to_body)
+    }
+
+    /// Constructs the `Orientation` of an entity at `location` from local `heading`, `pitch` and `roll`,
+    /// relative to the North-East-Down frame at that location. All angles are in radians.
+    #[must_use]
+    pub fn from_heading_pitch_roll(
+        heading: f64,
+        pitch: f64,
+        roll: f64,
+        location: &Location,
+    ) -> Self {
+        let frame = LocalTangentPlane::from_ecef(location);
+        let world_to_body = multiply_matrices(
+            &euler_angles_to_matrix(heading, pitch, roll),
+            &frame.ecef_to_ned_matrix(),
+        );
+        Self::from_world_to_body_matrix(&world_to_body)
+    }
+}
+
 /// 6.2.30 Entity Type record
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -1109,7 +1224,7 @@ pub fn record_length(&self) -> u16 {
 }
 
 impl Display for EntityType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
             "{}:{}:{}:{}:{}:{}:{}",
@@ -1573,6 +1688,20 @@ pub(crate) fn length_padded_to_num(data_length: usize, pad_to_num: usize) -> Pad
     PaddedRecordLengths::new(data_length, padding_num, record_length)
 }
 
+/// Rounds `value` to the nearest integer, rounding half-way cases away from zero (as `f64::round` does).
+/// `f64::round` itself is not available without `std`.
+pub(crate) fn round(value: f64) -> f64 {
+    let truncated = value as i64 as f64;
+    let fraction = value - truncated;
+    if fraction >= 0.5 {
+        truncated + 1.0
+    } else if fraction <= -0.5 {
+        truncated - 1.0
+    } else {
+        truncated
+    }
+}
+
 /// 6.2.94 Variable Parameter record
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -1980,214 +2109,1183 @@ pub fn with_records(mut self, records: Vec<Vec<u8>>) -> Self {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Perimeter Point Coordinates, as used by the Minefield State and Minefield Query PDUs
+///
+/// The coordinates are relative to the Minefield Location, in meters.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PerimeterPoint {
+    pub x: f32,
+    pub y: f32,
+}
 
-    const ENTITY_TYPE_STR: &str = "0:1:2:3:4:5:6";
-    const ENTITY_TYPE_STR_INVALID: &str = "0,1,2,3,4,5,6";
-    const ENTITY_TYPE_STR_INVALID_EXTRA: &str = "0:1:2:3:4:5:six";
-    const ENTITY_TYPE_STR_NOT_SEVEN_DIGITS: &str = "0:1:2:3:4:5";
-    const ENTITY_TYPE: EntityType = EntityType {
-        kind: EntityKind::Other,
-        domain: PlatformDomain::Land,
-        country: Country::Albania_ALB_,
-        category: 3,
-        subcategory: 4,
-        specific: 5,
-        extra: 6,
-    };
-    const SIMULATION_ADDRESS_STR: &str = "0:1";
-    const SIMULATION_ADDRESS_STR_INVALID: &str = "0,1";
-    const SIMULATION_ADDRESS_STR_INVALID_APPLICATION_ID: &str = "0:one";
-    const SIMULATION_ADDRESS_STR_NOT_TWO_DIGITS: &str = "0";
-    const SIMULATION_ADDRESS: SimulationAddress = SimulationAddress {
-        site_id: 0,
-        application_id: 1,
-    };
-    const ENTITY_ID_STR: &str = "0:1:2";
-    const ENTITY_ID_STR_INVALID: &str = "0,1,2";
-    const ENTITY_ID_STR_INVALID_ENTITY_ID: &str = "0:1:two";
-    const ENTITY_ID_STR_NOT_THREE_DIGITS: &str = "0:1";
-    const ENTITY_ID: EntityId = EntityId {
-        simulation_address: SIMULATION_ADDRESS,
-        entity_id: 2,
-    };
-    const EVENT_ID_STR: &str = "0:1:2";
-    const EVENT_ID_STR_INVALID: &str = "0,1,2";
-    const EVENT_ID_STR_INVALID_EVENT_ID: &str = "0:1:two";
-    const EVENT_ID_STR_NOT_THREE_DIGITS: &str = "0:1";
-    const EVENT_ID: EventId = EventId {
-        simulation_address: SIMULATION_ADDRESS,
-        event_id: 2,
-    };
+impl PerimeterPoint {
+    #[must_use]
+    pub fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
 
-    #[test]
-    fn entity_type_display() {
-        assert_eq!(ENTITY_TYPE_STR, ENTITY_TYPE.to_string());
+    #[must_use]
+    pub fn with_x(mut self, x: f32) -> Self {
+        self.x = x;
+        self
     }
 
-    #[test]
-    fn entity_type_from_str() {
-        assert_eq!(EntityType::from_str(ENTITY_TYPE_STR).unwrap(), ENTITY_TYPE);
-        let err = EntityType::from_str(ENTITY_TYPE_STR_INVALID);
-        assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
-        assert_eq!(
-            err.unwrap_err().to_string(),
-            "EntityType string pattern does not contain precisely 7 digits"
-        );
-        let err = EntityType::from_str(ENTITY_TYPE_STR_INVALID_EXTRA);
-        assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
-        assert_eq!(err.unwrap_err().to_string(), "Invalid extra digit");
+    #[must_use]
+    pub fn with_y(mut self, y: f32) -> Self {
+        self.y = y;
+        self
     }
 
-    #[test]
-    fn entity_type_from_str_not_seven_digits() {
-        let err = EntityType::from_str(ENTITY_TYPE_STR_NOT_SEVEN_DIGITS);
-        assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
-        assert_eq!(
-            err.unwrap_err().to_string(),
-            "EntityType string pattern does not contain precisely 7 digits"
-        );
+    #[must_use]
+    pub const fn record_length(&self) -> u16 {
+        EIGHT_OCTETS as u16
     }
+}
 
-    #[test]
-    fn entity_type_try_from_str() {
-        assert_eq!(
-            TryInto::<EntityType>::try_into(ENTITY_TYPE_STR).unwrap(),
-            ENTITY_TYPE
-        );
-        let err = TryInto::<EntityType>::try_into(ENTITY_TYPE_STR_INVALID);
-        assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
-        assert_eq!(
-            err.unwrap_err().to_string(),
-            "EntityType string pattern does not contain precisely 7 digits"
-        );
-        let err = TryInto::<EntityType>::try_into(ENTITY_TYPE_STR_INVALID_EXTRA);
-        assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
-        assert_eq!(err.unwrap_err().to_string(), "Invalid extra digit");
+/// Data Filter record, as used by the Minefield Query and Minefield Data PDUs
+///
+/// Each flag indicates whether the corresponding optional field is requested (Query)
+/// or present (Data) for the mines in the minefield.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MinefieldDataFilter {
+    pub ground_burial_depth_offset: bool,
+    pub water_depth_offset: bool,
+    pub snow_depth_offset: bool,
+    pub mine_orientation: bool,
+    pub thermal_contrast: bool,
+    pub reflectance: bool,
+    pub mine_emplacement_time: bool,
+    pub mine_entity_number: bool,
+    pub fusing: bool,
+    pub scalar_detection_coefficient: bool,
+    pub paint_scheme: bool,
+    pub number_of_trip_detonation_wires: bool,
+    pub number_of_vertices: bool,
+}
+
+impl MinefieldDataFilter {
+    #[must_use]
+    pub fn with_ground_burial_depth_offset(mut self) -> Self {
+        self.ground_burial_depth_offset = true;
+        self
     }
 
-    #[test]
-    fn entity_type_try_from_string() {
-        assert_eq!(
-            TryInto::<EntityType>::try_into(ENTITY_TYPE_STR.to_string()).unwrap(),
-            ENTITY_TYPE
-        );
-        let err = TryInto::<EntityType>::try_into(ENTITY_TYPE_STR_INVALID.to_string());
-        assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
-        assert_eq!(
-            err.unwrap_err().to_string(),
-            "EntityType string pattern does not contain precisely 7 digits"
-        );
-        let err = TryInto::<EntityType>::try_into(ENTITY_TYPE_STR_INVALID_EXTRA.to_string());
-        assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
-        assert_eq!(err.unwrap_err().to_string(), "Invalid extra digit");
+    #[must_use]
+    pub fn with_water_depth_offset(mut self) -> Self {
+        self.water_depth_offset = true;
+        self
     }
 
-    #[test]
-    fn simulation_address_display() {
-        assert_eq!(SIMULATION_ADDRESS_STR, SIMULATION_ADDRESS.to_string());
+    #[must_use]
+    pub fn with_snow_depth_offset(mut self) -> Self {
+        self.snow_depth_offset = true;
+        self
     }
 
-    #[test]
-    fn simulation_address_from_str() {
-        assert_eq!(
-            SimulationAddress::from_str(SIMULATION_ADDRESS_STR).unwrap(),
-            SIMULATION_ADDRESS
-        );
-        let err = SimulationAddress::from_str(SIMULATION_ADDRESS_STR_INVALID);
-        assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
-        assert_eq!(
-            err.unwrap_err().to_string(),
-            "SimulationAddress string pattern does not contain precisely 2 digits"
-        );
-        let err = SimulationAddress::from_str(SIMULATION_ADDRESS_STR_INVALID_APPLICATION_ID);
-        assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
-        assert_eq!(err.unwrap_err().to_string(), "Invalid application id digit");
+    #[must_use]
+    pub fn with_mine_orientation(mut self) -> Self {
+        self.mine_orientation = true;
+        self
     }
 
-    #[test]
-    fn simulation_address_from_str_not_two_digits() {
-        let err = SimulationAddress::from_str(SIMULATION_ADDRESS_STR_NOT_TWO_DIGITS);
-        assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
-        assert_eq!(
-            err.unwrap_err().to_string(),
-            "SimulationAddress string pattern does not contain precisely 2 digits"
-        );
+    #[must_use]
+    pub fn with_thermal_contrast(mut self) -> Self {
+        self.thermal_contrast = true;
+        self
     }
 
-    #[test]
-    fn simulation_address_try_from_str() {
-        assert_eq!(
-            TryInto::<SimulationAddress>::try_into(SIMULATION_ADDRESS_STR).unwrap(),
-            SIMULATION_ADDRESS
-        );
-        let err = TryInto::<SimulationAddress>::try_into(SIMULATION_ADDRESS_STR_INVALID);
-        assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
-        assert_eq!(
-            err.unwrap_err().to_string(),
-            "SimulationAddress string pattern does not contain precisely 2 digits"
-        );
-        let err =
-            TryInto::<SimulationAddress>::try_into(SIMULATION_ADDRESS_STR_INVALID_APPLICATION_ID);
-        assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
-        assert_eq!(err.unwrap_err().to_string(), "Invalid application id digit");
+    #[must_use]
+    pub fn with_reflectance(mut self) -> Self {
+        self.reflectance = true;
+        self
     }
 
-    #[test]
-    fn simulation_address_try_from_string() {
-        assert_eq!(
-            TryInto::<SimulationAddress>::try_into(SIMULATION_ADDRESS_STR.to_string()).unwrap(),
-            SIMULATION_ADDRESS
-        );
-        let err =
-            TryInto::<SimulationAddress>::try_into(SIMULATION_ADDRESS_STR_INVALID.to_string());
-        assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
-        assert_eq!(
-            err.unwrap_err().to_string(),
-            "SimulationAddress string pattern does not contain precisely 2 digits"
-        );
-        let err = TryInto::<SimulationAddress>::try_into(
-            SIMULATION_ADDRESS_STR_INVALID_APPLICATION_ID.to_string(),
-        );
-        assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
-        assert_eq!(err.unwrap_err().to_string(), "Invalid application id digit");
+    #[must_use]
+    pub fn with_mine_emplacement_time(mut self) -> Self {
+        self.mine_emplacement_time = true;
+        self
     }
 
-    #[test]
-    fn entity_id_display() {
-        assert_eq!(ENTITY_ID_STR, ENTITY_ID.to_string());
+    #[must_use]
+    pub fn with_mine_entity_number(mut self) -> Self {
+        self.mine_entity_number = true;
+        self
     }
 
-    #[test]
-    fn entity_id_from_str() {
-        assert_eq!(EntityId::from_str(ENTITY_ID_STR).unwrap(), ENTITY_ID);
-        let err = EntityId::from_str(ENTITY_ID_STR_INVALID);
-        assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
-        assert_eq!(
-            err.unwrap_err().to_string(),
-            "EntityId string pattern does not contain precisely 3 digits"
-        );
-        let err = EntityId::from_str(ENTITY_ID_STR_INVALID_ENTITY_ID);
-        assert!(err.is_err());
-        assert!(matches!(err, Err(DisError::ParseError(_))));
-        assert_eq!(err.unwrap_err().to_string(), "Invalid entity id digit");
+    #[must_use]
+    pub fn with_fusing(mut self) -> Self {
+        self.fusing = true;
+        self
+    }
+
+    #[must_use]
+    pub fn with_scalar_detection_coefficient(mut self) -> Self {
+        self.scalar_detection_coefficient = true;
+        self
+    }
+
+    #[must_use]
+    pub fn with_paint_scheme(mut self) -> Self {
+        self.paint_scheme = true;
+        self
+    }
+
+    #[must_use]
+    pub fn with_number_of_trip_detonation_wires(mut self) -> Self {
+        self.number_of_trip_detonation_wires = true;
+        self
+    }
+
+    #[must_use]
+    pub fn with_number_of_vertices(mut self) -> Self {
+        self.number_of_vertices = true;
+        self
+    }
+
+    #[must_use]
+    pub const fn record_length(&self) -> u16 {
+        FOUR_OCTETS as u16
+    }
+}
+
+impl From<u32> for MinefieldDataFilter {
+    fn from(value: u32) -> Self {
+        Self {
+            ground_burial_depth_offset: (value & 0x0001) != 0,
+            water_depth_offset: (value & 0x0002) != 0,
+            snow_depth_offset: (value & 0x0004) != 0,
+            mine_orientation: (value & 0x0008) != 0,
+            thermal_contrast: (value & 0x0010) != 0,
+            reflectance: (value & 0x0020) != 0,
+            mine_emplacement_time: (value & 0x0040) != 0,
+            mine_entity_number: (value & 0x0080) != 0,
+            fusing: (value & 0x0100) != 0,
+            scalar_detection_coefficient: (value & 0x0200) != 0,
+            paint_scheme: (value & 0x0400) != 0,
+            number_of_trip_detonation_wires: (value & 0x0800) != 0,
+            number_of_vertices: (value & 0x1000) != 0,
+        }
+    }
+}
+
+impl From<MinefieldDataFilter> for u32 {
+    fn from(value: MinefieldDataFilter) -> Self {
+        u32::from(value.ground_burial_depth_offset)
+            | u32::from(value.water_depth_offset) << 1
+            | u32::from(value.snow_depth_offset) << 2
+            | u32::from(value.mine_orientation) << 3
+            | u32::from(value.thermal_contrast) << 4
+            | u32::from(value.reflectance) << 5
+            | u32::from(value.mine_emplacement_time) << 6
+            | u32::from(value.mine_entity_number) << 7
+            | u32::from(value.fusing) << 8
+            | u32::from(value.scalar_detection_coefficient) << 9
+            | u32::from(value.paint_scheme) << 10
+            | u32::from(value.number_of_trip_detonation_wires) << 11
+            | u32::from(value.number_of_vertices) << 12
+    }
+}
+
+/// Sensor Type field, as used by the Minefield Query and Minefield Data PDUs
+///
+/// The four least significant bits hold the sensor category (e.g., Optical, FLIR, RADAR),
+/// the remaining twelve bits hold the sensor subcategory, as defined in SISO-REF-010.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MinefieldSensorType {
+    pub category: u8,
+    pub subcategory: u16,
+}
+
+impl MinefieldSensorType {
+    #[must_use]
+    pub fn new(category: u8, subcategory: u16) -> Self {
+        Self {
+            category,
+            subcategory,
+        }
+    }
+
+    #[must_use]
+    pub const fn record_length(&self) -> u16 {
+        TWO_OCTETS as u16
+    }
+}
+
+impl From<u16> for MinefieldSensorType {
+    fn from(value: u16) -> Self {
+        Self {
+            category: (value & 0x000F) as u8,
+            subcategory: value >> 4,
+        }
+    }
+}
+
+impl From<MinefieldSensorType> for u16 {
+    fn from(value: MinefieldSensorType) -> Self {
+        (value.subcategory << 4) | (u16::from(value.category) & 0x000F)
+    }
+}
+
+/// 6.2.62 Object Type record
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ObjectType {
+    pub domain: PlatformDomain,
+    pub kind: ObjectKind,
+    pub category: u8,
+    pub subcategory: u8,
+}
+
+impl ObjectType {
+    #[must_use]
+    pub fn new(domain: PlatformDomain, kind: ObjectKind, category: u8, subcategory: u8) -> Self {
+        Self {
+            domain,
+            kind,
+            category,
+            subcategory,
+        }
+    }
+
+    #[must_use]
+    pub fn with_domain(mut self, domain: PlatformDomain) -> Self {
+        self.domain = domain;
+        self
+    }
+
+    #[must_use]
+    pub fn with_kind(mut self, kind: ObjectKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    #[must_use]
+    pub fn with_category(mut self, category: u8) -> Self {
+        self.category = category;
+        self
+    }
+
+    #[must_use]
+    pub fn with_subcategory(mut self, subcategory: u8) -> Self {
+        self.subcategory = subcategory;
+        self
+    }
+
+    #[must_use]
+    pub const fn record_length(&self) -> u16 {
+        FOUR_OCTETS as u16
+    }
+}
+
+/// Object Kind (SISO-REF-010), as used in the Object Type record
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ObjectKind {
+    #[default]
+    Other,
+    Obstacle,
+    PreparedPosition,
+    CulturalFeature,
+    Passageway,
+    TacticalSmoke,
+    ObstacleMarker,
+    ObstacleBreach,
+    EnvironmentalObject,
+    Unspecified(u8),
+}
+
+impl From<u8> for ObjectKind {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::Other,
+            1 => Self::Obstacle,
+            2 => Self::PreparedPosition,
+            3 => Self::CulturalFeature,
+            4 => Self::Passageway,
+            5 => Self::TacticalSmoke,
+            6 => Self::ObstacleMarker,
+            7 => Self::ObstacleBreach,
+            8 => Self::EnvironmentalObject,
+            other => Self::Unspecified(other),
+        }
+    }
+}
+
+impl From<ObjectKind> for u8 {
+    fn from(value: ObjectKind) -> Self {
+        match value {
+            ObjectKind::Other => 0,
+            ObjectKind::Obstacle => 1,
+            ObjectKind::PreparedPosition => 2,
+            ObjectKind::CulturalFeature => 3,
+            ObjectKind::Passageway => 4,
+            ObjectKind::TacticalSmoke => 5,
+            ObjectKind::ObstacleMarker => 6,
+            ObjectKind::ObstacleBreach => 7,
+            ObjectKind::EnvironmentalObject => 8,
+            ObjectKind::Unspecified(value) => value,
+        }
+    }
+}
+
+/// Modifications field of the Point, Linear (segment) and Areal Object State PDUs
+///
+/// Indicates whether the location or orientation of the object has been modified
+/// since the last update.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ObjectStateModification {
+    pub location: bool,
+    pub orientation: bool,
+}
+
+impl ObjectStateModification {
+    #[must_use]
+    pub fn with_location(mut self) -> Self {
+        self.location = true;
+        self
+    }
+
+    #[must_use]
+    pub fn with_orientation(mut self) -> Self {
+        self.orientation = true;
+        self
+    }
+}
+
+impl From<u8> for ObjectStateModification {
+    fn from(value: u8) -> Self {
+        Self {
+            location: (value & 0x01) != 0,
+            orientation: (value & 0x02) != 0,
+        }
+    }
+}
+
+impl From<ObjectStateModification> for u8 {
+    fn from(value: ObjectStateModification) -> Self {
+        u8::from(value.location) | u8::from(value.orientation) << 1
+    }
+}
+
+/// General Object Appearance, as used by the Point, Linear and Areal Object State PDUs
+///
+/// The general appearance is common to all object kinds.
+/// The specific appearance of an object depends on its Object Type.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GeneralObjectAppearance {
+    pub percent_complete: u8,
+    pub damage: AppearanceObjectGeneralDamage,
+    pub predistributed: AppearanceObjectGeneralPredistributed,
+    pub state: AppearanceEntityOrObjectState,
+    pub is_smoking: bool,
+    pub is_flaming: bool,
+    pub ied_present: AppearanceObjectGeneralIEDPresent,
+}
+
+impl GeneralObjectAppearance {
+    #[must_use]
+    pub fn with_percent_complete(mut self, percent_complete: u8) -> Self {
+        self.percent_complete = percent_complete;
+        self
+    }
+
+    #[must_use]
+    pub fn with_damage(mut self, damage: AppearanceObjectGeneralDamage) -> Self {
+        self.damage = damage;
+        self
+    }
+
+    #[must_use]
+    pub fn with_predistributed(
+        mut self,
+        predistributed: AppearanceObjectGeneralPredistributed,
+    ) -> Self {
+        self.predistributed = predistributed;
+        self
+    }
+
+    #[must_use]
+    pub fn with_state(mut self, state: AppearanceEntityOrObjectState) -> Self {
+        self.state = state;
+        self
+    }
+
+    #[must_use]
+    pub fn with_smoking(mut self, is_smoking: bool) -> Self {
+        self.is_smoking = is_smoking;
+        self
+    }
+
+    #[must_use]
+    pub fn with_flaming(mut self, is_flaming: bool) -> Self {
+        self.is_flaming = is_flaming;
+        self
+    }
+
+    #[must_use]
+    pub fn with_ied_present(mut self, ied_present: AppearanceObjectGeneralIEDPresent) -> Self {
+        self.ied_present = ied_present;
+        self
+    }
+
+    #[must_use]
+    pub const fn record_length(&self) -> u16 {
+        TWO_OCTETS as u16
+    }
+}
+
+impl From<u16> for GeneralObjectAppearance {
+    fn from(value: u16) -> Self {
+        Self {
+            percent_complete: (value & 0x00FF) as u8,
+            damage: AppearanceObjectGeneralDamage::from(((value >> 8) & 0x03) as u8),
+            predistributed: AppearanceObjectGeneralPredistributed::from(
+                ((value >> 10) & 0x01) as u8,
+            ),
+            state: AppearanceEntityOrObjectState::from(((value >> 11) & 0x01) as u8),
+            is_smoking: ((value >> 12) & 0x01) != 0,
+            is_flaming: ((value >> 13) & 0x01) != 0,
+            ied_present: AppearanceObjectGeneralIEDPresent::from(((value >> 14) & 0x03) as u8),
+        }
+    }
+}
+
+impl From<GeneralObjectAppearance> for u16 {
+    fn from(value: GeneralObjectAppearance) -> Self {
+        u16::from(value.percent_complete)
+            | (u16::from(u8::from(value.damage)) & 0x03) << 8
+            | (u16::from(u8::from(value.predistributed)) & 0x01) << 10
+            | (u16::from(u8::from(value.state)) & 0x01) << 11
+            | u16::from(value.is_smoking) << 12
+            | u16::from(value.is_flaming) << 13
+            | (u16::from(u8::from(value.ied_present)) & 0x03) << 14
+    }
+}
+
+/// Live Entity Identifier record
+///
+/// Identifies an entity in the Live Entity (LE) PDUs, using 8-bit site and application numbers.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LiveEntityId {
+    pub site_number: u8,
+    pub application_number: u8,
+    pub entity_number: u16,
+}
+
+impl LiveEntityId {
+    #[must_use]
+    pub fn new(site_number: u8, application_number: u8, entity_number: u16) -> Self {
+        Self {
+            site_number,
+            application_number,
+            entity_number,
+        }
+    }
+
+    #[must_use]
+    pub const fn record_length(&self) -> u16 {
+        FOUR_OCTETS as u16
+    }
+}
+
+impl From<LiveEntityId> for EntityId {
+    fn from(value: LiveEntityId) -> Self {
+        EntityId::new(
+            value.site_number.into(),
+            value.application_number.into(),
+            value.entity_number,
+        )
+    }
+}
+
+/// Converts an `EntityId` to a `LiveEntityId`.
+/// The site and application numbers are truncated to their 8 least significant bits.
+impl From<&EntityId> for LiveEntityId {
+    fn from(value: &EntityId) -> Self {
+        LiveEntityId::new(
+            value.simulation_address.site_id as u8,
+            value.simulation_address.application_id as u8,
+            value.entity_id,
+        )
+    }
+}
+
+/// Live Event Identifier record
+///
+/// Identifies an event in the Live Entity (LE) PDUs, using 8-bit site and application numbers.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LiveEventId {
+    pub site_number: u8,
+    pub application_number: u8,
+    pub event_number: u16,
+}
+
+impl LiveEventId {
+    #[must_use]
+    pub fn new(site_number: u8, application_number: u8, event_number: u16) -> Self {
+        Self {
+            site_number,
+            application_number,
+            event_number,
+        }
+    }
+
+    #[must_use]
+    pub const fn record_length(&self) -> u16 {
+        FOUR_OCTETS as u16
+    }
+}
+
+impl From<LiveEventId> for EventId {
+    fn from(value: LiveEventId) -> Self {
+        EventId::new(
+            SimulationAddress::new(value.site_number.into(), value.application_number.into()),
+            value.event_number,
+        )
+    }
+}
+
+/// Converts an `EventId` to a `LiveEventId`.
+/// The site and application numbers are truncated to their 8 least significant bits.
+impl From<&EventId> for LiveEventId {
+    fn from(value: &EventId) -> Self {
+        LiveEventId::new(
+            value.simulation_address.site_id as u8,
+            value.simulation_address.application_id as u8,
+            value.event_id,
+        )
+    }
+}
+
+/// A reference point, as agreed upon for an exercise, against which the
+/// `RelativeWorldCoordinates` of the LE PDUs are expressed.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LiveReferencePoint {
+    pub reference_point: u16,
+    pub location: Location,
+}
+
+impl LiveReferencePoint {
+    #[must_use]
+    pub fn new(reference_point: u16, location: Location) -> Self {
+        Self {
+            reference_point,
+            location,
+        }
+    }
+}
+
+/// Relative World Coordinates record
+///
+/// A location in world coordinates, expressed as an offset in meters from a reference point.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RelativeWorldCoordinates {
+    pub reference_point: u16,
+    pub delta_x: i16,
+    pub delta_y: i16,
+    pub delta_z: i16,
+}
+
+impl RelativeWorldCoordinates {
+    #[must_use]
+    pub fn new(reference_point: u16, delta_x: i16, delta_y: i16, delta_z: i16) -> Self {
+        Self {
+            reference_point,
+            delta_x,
+            delta_y,
+            delta_z,
+        }
+    }
+
+    /// Expresses `location` relative to `reference_point`.
+    /// Offsets are rounded to whole meters, and saturate at the bounds of the record.
+    #[must_use]
+    pub fn from_location(reference_point: &LiveReferencePoint, location: &Location) -> Self {
+        Self {
+            reference_point: reference_point.reference_point,
+            delta_x: round(location.x_coordinate - reference_point.location.x_coordinate) as i16,
+            delta_y: round(location.y_coordinate - reference_point.location.y_coordinate) as i16,
+            delta_z: round(location.z_coordinate - reference_point.location.z_coordinate) as i16,
+        }
+    }
+
+    /// Converts the relative coordinates back to a `Location` in world coordinates,
+    /// using the location of `reference_point`.
+    #[must_use]
+    pub fn to_location(&self, reference_point: &LiveReferencePoint) -> Location {
+        Location::new(
+            reference_point.location.x_coordinate + f64::from(self.delta_x),
+            reference_point.location.y_coordinate + f64::from(self.delta_y),
+            reference_point.location.z_coordinate + f64::from(self.delta_z),
+        )
+    }
+
+    #[must_use]
+    pub const fn record_length(&self) -> u16 {
+        EIGHT_OCTETS as u16
+    }
+}
+
+/// Live Entity Orientation record
+///
+/// Euler angles encoded as 8-bit binary angles, where 256 units make a full circle.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LiveEntityOrientation {
+    pub psi: u8,
+    pub theta: u8,
+    pub phi: u8,
+}
+
+impl LiveEntityOrientation {
+    #[must_use]
+    pub fn new(psi: u8, theta: u8, phi: u8) -> Self {
+        Self { psi, theta, phi }
+    }
+
+    #[must_use]
+    pub fn from_orientation(orientation: &Orientation) -> Self {
+        Self {
+            psi: radians_to_binary_angle(orientation.psi),
+            theta: radians_to_binary_angle(orientation.theta),
+            phi: radians_to_binary_angle(orientation.phi),
+        }
+    }
+
+    #[must_use]
+    pub fn to_orientation(&self) -> Orientation {
+        Orientation::new(
+            binary_angle_to_radians(self.psi),
+            binary_angle_to_radians(self.theta),
+            binary_angle_to_radians(self.phi),
+        )
+    }
+
+    #[must_use]
+    pub const fn record_length(&self) -> u16 {
+        THREE_OCTETS as u16
+    }
+}
+
+fn radians_to_binary_angle(radians: f32) -> u8 {
+    (round(f64::from(radians * 128.0 / core::f32::consts::PI)) as i32).rem_euclid(256) as u8
+}
+
+fn binary_angle_to_radians(binary_angle: u8) -> f32 {
+    f32::from(binary_angle as i8) * core::f32::consts::PI / 128.0
+}
+
+/// Live Entity Linear Velocity record
+///
+/// Velocity components in decimeters per second.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LiveEntityLinearVelocity {
+    pub x_component: i16,
+    pub y_component: i16,
+    pub z_component: i16,
+}
+
+impl LiveEntityLinearVelocity {
+    #[must_use]
+    pub fn new(x_component: i16, y_component: i16, z_component: i16) -> Self {
+        Self {
+            x_component,
+            y_component,
+            z_component,
+        }
+    }
+
+    /// Converts a velocity in meters per second, saturating at the bounds of the record.
+    #[must_use]
+    pub fn from_velocity(velocity: &VectorF32) -> Self {
+        Self {
+            x_component: round(f64::from(velocity.first_vector_component * 10.0)) as i16,
+            y_component: round(f64::from(velocity.second_vector_component * 10.0)) as i16,
+            z_component: round(f64::from(velocity.third_vector_component * 10.0)) as i16,
+        }
+    }
+
+    /// Converts the velocity to meters per second.
+    #[must_use]
+    pub fn to_velocity(&self) -> VectorF32 {
+        VectorF32::new(
+            f32::from(self.x_component) / 10.0,
+            f32::from(self.y_component) / 10.0,
+            f32::from(self.z_component) / 10.0,
+        )
+    }
+
+    #[must_use]
+    pub const fn record_length(&self) -> u16 {
+        SIX_OCTETS as u16
+    }
+}
+
+/// Munition Descriptor record, as used in the LE Fire and LE Detonation PDUs
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LiveMunitionDescriptor {
+    pub munition_type: EntityType,
+    pub warhead: MunitionDescriptorWarhead,
+    pub fuse: MunitionDescriptorFuse,
+    pub quantity: u8,
+    pub rate: u8,
+}
+
+impl LiveMunitionDescriptor {
+    #[must_use]
+    pub fn with_munition_type(mut self, munition_type: EntityType) -> Self {
+        self.munition_type = munition_type;
+        self
+    }
+
+    #[must_use]
+    pub fn with_warhead(mut self, warhead: MunitionDescriptorWarhead) -> Self {
+        self.warhead = warhead;
+        self
+    }
+
+    #[must_use]
+    pub fn with_fuse(mut self, fuse: MunitionDescriptorFuse) -> Self {
+        self.fuse = fuse;
+        self
+    }
+
+    #[must_use]
+    pub fn with_quantity(mut self, quantity: u8) -> Self {
+        self.quantity = quantity;
+        self
+    }
+
+    #[must_use]
+    pub fn with_rate(mut self, rate: u8) -> Self {
+        self.rate = rate;
+        self
+    }
+
+    /// Creates a `LiveMunitionDescriptor` from a `DescriptorRecord`.
+    /// Explosion and expendable descriptors only provide the munition type.
+    /// Quantity and rate saturate at 255.
+    #[must_use]
+    pub fn from_descriptor(descriptor: &DescriptorRecord) -> Self {
+        match descriptor {
+            DescriptorRecord::Munition {
+                entity_type,
+                munition,
+            } => Self::default()
+                .with_munition_type(*entity_type)
+                .with_warhead(munition.warhead)
+                .with_fuse(munition.fuse)
+                .with_quantity(munition.quantity.min(u8::MAX.into()) as u8)
+                .with_rate(munition.rate.min(u8::MAX.into()) as u8),
+            DescriptorRecord::Expendable { entity_type }
+            | DescriptorRecord::Explosion { entity_type, .. } => {
+                Self::default().with_munition_type(*entity_type)
+            }
+        }
+    }
+
+    /// Converts to a Munition `DescriptorRecord`.
+    #[must_use]
+    pub fn to_descriptor(&self) -> DescriptorRecord {
+        DescriptorRecord::new_munition(
+            self.munition_type,
+            MunitionDescriptor::default()
+                .with_warhead(self.warhead)
+                .with_fuse(self.fuse)
+                .with_quantity(self.quantity.into())
+                .with_rate(self.rate.into()),
+        )
+    }
+
+    #[must_use]
+    pub const fn record_length(&self) -> u16 {
+        FOURTEEN_OCTETS as u16
+    }
+}
+
+/// 6.2.83 Standard Variable (SV) record
+///
+/// Generic representation of a standard variable record, used for record types that are not specifically modelled.
+/// The `data` field holds the record-specific fields, including any padding.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StandardVariableRecord {
+    pub record_type: u32,
+    pub data: Vec<u8>,
+}
+
+impl StandardVariableRecord {
+    #[must_use]
+    pub fn new(record_type: u32, data: Vec<u8>) -> Self {
+        Self { record_type, data }
+    }
+
+    #[must_use]
+    pub fn record_length(&self) -> u16 {
+        length_padded_to_num(SIX_OCTETS + self.data.len(), EIGHT_OCTETS).record_length as u16
+    }
+}
+
+/// 6.2.17 Communications Node ID record
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CommunicationsNodeId {
+    pub entity_id: EntityId,
+    pub element_id: u16,
+}
+
+impl CommunicationsNodeId {
+    #[must_use]
+    pub fn new(entity_id: EntityId, element_id: u16) -> Self {
+        Self {
+            entity_id,
+            element_id,
+        }
+    }
+
+    #[must_use]
+    pub const fn record_length(&self) -> u16 {
+        EIGHT_OCTETS as u16
+    }
+}
+
+pub(crate) const IO_COMMUNICATIONS_NODE_RECORD_TYPE: u32 = 5001;
+pub(crate) const IO_EFFECT_RECORD_TYPE: u32 = 5500;
+const IO_RECORD_LENGTH: u16 = 16;
+
+/// IO records, as used by the Information Operations Action and Information Operations Report PDUs
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IORecord {
+    CommunicationsNode(IOCommunicationsNode),
+    Effect(IOEffect),
+    Unspecified(StandardVariableRecord),
+}
+
+impl IORecord {
+    #[must_use]
+    pub fn record_length(&self) -> u16 {
+        match self {
+            IORecord::CommunicationsNode(record) => record.record_length(),
+            IORecord::Effect(record) => record.record_length(),
+            IORecord::Unspecified(record) => record.record_length(),
+        }
+    }
+}
+
+/// 6.2.48 IO Communications Node record
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IOCommunicationsNode {
+    pub communications_node_type: IOCommsNodeType,
+    pub communications_node_id: CommunicationsNodeId,
+}
+
+impl IOCommunicationsNode {
+    #[must_use]
+    pub fn new(
+        communications_node_type: IOCommsNodeType,
+        communications_node_id: CommunicationsNodeId,
+    ) -> Self {
+        Self {
+            communications_node_type,
+            communications_node_id,
+        }
+    }
+
+    #[must_use]
+    pub fn into_record(self) -> IORecord {
+        IORecord::CommunicationsNode(self)
+    }
+
+    #[must_use]
+    pub const fn record_length(&self) -> u16 {
+        IO_RECORD_LENGTH
+    }
+}
+
+/// 6.2.49 IO Effect record
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IOEffect {
+    pub status: IOEffectStatus,
+    pub link_type: IOEffectLinkType,
+    pub effect: IOEffectType,
+    /// The duty cycle of the effect, as a percentage (0 - 100)
+    pub effect_duty_cycle: u8,
+    /// The duration of the effect, in seconds
+    pub effect_duration: u16,
+    pub process: IOEffectProcess,
+}
+
+impl IOEffect {
+    #[must_use]
+    pub fn with_status(mut self, status: IOEffectStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    #[must_use]
+    pub fn with_link_type(mut self, link_type: IOEffectLinkType) -> Self {
+        self.link_type = link_type;
+        self
+    }
+
+    #[must_use]
+    pub fn with_effect(mut self, effect: IOEffectType) -> Self {
+        self.effect = effect;
+        self
+    }
+
+    #[must_use]
+    pub fn with_effect_duty_cycle(mut self, effect_duty_cycle: u8) -> Self {
+        self.effect_duty_cycle = effect_duty_cycle;
+        self
+    }
+
+    #[must_use]
+    pub fn with_effect_duration(mut self, effect_duration: u16) -> Self {
+        self.effect_duration = effect_duration;
+        self
+    }
+
+    #[must_use]
+    pub fn with_process(mut self, process: IOEffectProcess) -> Self {
+        self.process = process;
+        self
+    }
+
+    #[must_use]
+    pub fn into_record(self) -> IORecord {
+        IORecord::Effect(self)
+    }
+
+    #[must_use]
+    pub const fn record_length(&self) -> u16 {
+        IO_RECORD_LENGTH
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENTITY_TYPE_STR: &str = "0:1:2:3:4:5:6";
+    const ENTITY_TYPE_STR_INVALID: &str = "0,1,2,3,4,5,6";
+    const ENTITY_TYPE_STR_INVALID_EXTRA: &str = "0:1:2:3:4:5:six";
+    const ENTITY_TYPE_STR_NOT_SEVEN_DIGITS: &str = "0:1:2:3:4:5";
+    const ENTITY_TYPE: EntityType = EntityType {
+        kind: EntityKind::Other,
+        domain: PlatformDomain::Land,
+        country: Country::Albania_ALB_,
+        category: 3,
+        subcategory: 4,
+        specific: 5,
+        extra: 6,
+    };
+    const SIMULATION_ADDRESS_STR: &str = "0:1";
+    const SIMULATION_ADDRESS_STR_INVALID: &str = "0,1";
+    const SIMULATION_ADDRESS_STR_INVALID_APPLICATION_ID: &str = "0:one";
+    const SIMULATION_ADDRESS_STR_NOT_TWO_DIGITS: &str = "0";
+    const SIMULATION_ADDRESS: SimulationAddress = SimulationAddress {
+        site_id: 0,
+        application_id: 1,
+    };
+    const ENTITY_ID_STR: &str = "0:1:2";
+    const ENTITY_ID_STR_INVALID: &str = "0,1,2";
+    const ENTITY_ID_STR_INVALID_ENTITY_ID: &str = "0:1:two";
+    const ENTITY_ID_STR_NOT_THREE_DIGITS: &str = "0:1";
+    const ENTITY_ID: EntityId = EntityId {
+        simulation_address: SIMULATION_ADDRESS,
+        entity_id: 2,
+    };
+    const EVENT_ID_STR: &str = "0:1:2";
+    const EVENT_ID_STR_INVALID: &str = "0,1,2";
+    const EVENT_ID_STR_INVALID_EVENT_ID: &str = "0:1:two";
+    const EVENT_ID_STR_NOT_THREE_DIGITS: &str = "0:1";
+    const EVENT_ID: EventId = EventId {
+        simulation_address: SIMULATION_ADDRESS,
+        event_id: 2,
+    };
+
+    #[test]
+    fn entity_type_display() {
+        assert_eq!(ENTITY_TYPE_STR, ENTITY_TYPE.to_string());
+    }
+
+    #[test]
+    fn entity_type_from_str() {
+        assert_eq!(EntityType::from_str(ENTITY_TYPE_STR).unwrap(), ENTITY_TYPE);
+        let err = EntityType::from_str(ENTITY_TYPE_STR_INVALID);
+        assert!(err.is_err());
+        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "EntityType string pattern does not contain precisely 7 digits"
+        );
+        let err = EntityType::from_str(ENTITY_TYPE_STR_INVALID_EXTRA);
+        assert!(err.is_err());
+        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert_eq!(err.unwrap_err().to_string(), "Invalid extra digit");
+    }
+
+    #[test]
+    fn entity_type_from_str_not_seven_digits() {
+        let err = EntityType::from_str(ENTITY_TYPE_STR_NOT_SEVEN_DIGITS);
+        assert!(err.is_err());
+        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "EntityType string pattern does not contain precisely 7 digits"
+        );
+    }
+
+    #[test]
+    fn entity_type_try_from_str() {
+        assert_eq!(
+            TryInto::<EntityType>::try_into(ENTITY_TYPE_STR).unwrap(),
+            ENTITY_TYPE
+        );
+        let err = TryInto::<EntityType>::try_into(ENTITY_TYPE_STR_INVALID);
+        assert!(err.is_err());
+        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "EntityType string pattern does not contain precisely 7 digits"
+        );
+        let err = TryInto::<EntityType>::try_into(ENTITY_TYPE_STR_INVALID_EXTRA);
+        assert!(err.is_err());
+        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert_eq!(err.unwrap_err().to_string(), "Invalid extra digit");
+    }
+
+    #[test]
+    fn entity_type_try_from_string() {
+        assert_eq!(
+            TryInto::<EntityType>::try_into(ENTITY_TYPE_STR.to_string()).unwrap(),
+            ENTITY_TYPE
+        );
+        let err = TryInto::<EntityType>::try_into(ENTITY_TYPE_STR_INVALID.to_string());
+        assert!(err.is_err());
+        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "EntityType string pattern does not contain precisely 7 digits"
+        );
+        let err = TryInto::<EntityType>::try_into(ENTITY_TYPE_STR_INVALID_EXTRA.to_string());
+        assert!(err.is_err());
+        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert_eq!(err.unwrap_err().to_string(), "Invalid extra digit");
+    }
+
+    #[test]
+    fn simulation_address_display() {
+        assert_eq!(SIMULATION_ADDRESS_STR, SIMULATION_ADDRESS.to_string());
+    }
+
+    #[test]
+    fn simulation_address_from_str() {
+        assert_eq!(
+            SimulationAddress::from_str(SIMULATION_ADDRESS_STR).unwrap(),
+            SIMULATION_ADDRESS
+        );
+        let err = SimulationAddress::from_str(SIMULATION_ADDRESS_STR_INVALID);
+        assert!(err.is_err());
+        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "SimulationAddress string pattern does not contain precisely 2 digits"
+        );
+        let err = SimulationAddress::from_str(SIMULATION_ADDRESS_STR_INVALID_APPLICATION_ID);
+        assert!(err.is_err());
+        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert_eq!(err.unwrap_err().to_string(), "Invalid application id digit");
+    }
+
+    #[test]
+    fn simulation_address_from_str_not_two_digits() {
+        let err = SimulationAddress::from_str(SIMULATION_ADDRESS_STR_NOT_TWO_DIGITS);
+        assert!(err.is_err());
+        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "SimulationAddress string pattern does not contain precisely 2 digits"
+        );
+    }
+
+    #[test]
+    fn simulation_address_try_from_str() {
+        assert_eq!(
+            TryInto::<SimulationAddress>::try_into(SIMULATION_ADDRESS_STR).unwrap(),
+            SIMULATION_ADDRESS
+        );
+        let err = TryInto::<SimulationAddress>::try_into(SIMULATION_ADDRESS_STR_INVALID);
+        assert!(err.is_err());
+        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "SimulationAddress string pattern does not contain precisely 2 digits"
+        );
+        let err =
+            TryInto::<SimulationAddress>::try_into(SIMULATION_ADDRESS_STR_INVALID_APPLICATION_ID);
+        assert!(err.is_err());
+        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert_eq!(err.unwrap_err().to_string(), "Invalid application id digit");
+    }
+
+    #[test]
+    fn simulation_address_try_from_string() {
+        assert_eq!(
+            TryInto::<SimulationAddress>::try_into(SIMULATION_ADDRESS_STR.to_string()).unwrap(),
+            SIMULATION_ADDRESS
+        );
+        let err =
+            TryInto::<SimulationAddress>::try_into(SIMULATION_ADDRESS_STR_INVALID.to_string());
+        assert!(err.is_err());
+        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "SimulationAddress string pattern does not contain precisely 2 digits"
+        );
+        let err = TryInto::<SimulationAddress>::try_into(
+            SIMULATION_ADDRESS_STR_INVALID_APPLICATION_ID.to_string(),
+        );
+        assert!(err.is_err());
+        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert_eq!(err.unwrap_err().to_string(), "Invalid application id digit");
+    }
+
+    #[test]
+    fn entity_id_display() {
+        assert_eq!(ENTITY_ID_STR, ENTITY_ID.to_string());
+    }
+
+    #[test]
+    fn entity_id_from_str() {
+        assert_eq!(EntityId::from_str(ENTITY_ID_STR).unwrap(), ENTITY_ID);
+        let err = EntityId::from_str(ENTITY_ID_STR_INVALID);
+        assert!(err.is_err());
+        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "EntityId string pattern does not contain precisely 3 digits"
+        );
+        let err = EntityId::from_str(ENTITY_ID_STR_INVALID_ENTITY_ID);
+        assert!(err.is_err());
+        assert!(matches!(err, Err(DisError::ParseError(_))));
+        assert_eq!(err.unwrap_err().to_string(), "Invalid entity id digit");
     }
 
     #[test]
@@ -2308,4 +3406,13 @@ fn event_id_try_from_string() {
         assert!(matches!(err, Err(DisError::ParseError(_))));
         assert_eq!(err.unwrap_err().to_string(), "Invalid event id digit");
     }
+
+    #[test]
+    fn round_matches_std() {
+        for value in [
+            0.0, 0.4, 0.5, 1.5, 2.49, -0.5, -1.4, -2.5, 1234.5678, -32768.6,
+        ] {
+            assert_eq!(round(value), value.round());
+        }
+    }
 }
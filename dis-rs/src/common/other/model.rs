@@ -2,6 +2,7 @@
 use crate::common::other::builder::OtherBuilder;
 use crate::common::{BodyInfo, Interaction};
 use crate::enumerations::PduType;
+use alloc::vec::Vec;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -1,112 +1,115 @@
 use crate::common::model::{EntityId, PduBody, PduHeader};
 use crate::common::other::model::Other;
 use crate::common::parser::entity_id;
+use crate::common::parser::IResult;
 use crate::constants::PDU_HEADER_LEN_BYTES;
 use crate::enumerations::PduType;
 use nom::bytes::complete::take;
 use nom::combinator::peek;
+use nom::error::context;
 use nom::sequence::tuple;
-use nom::IResult;
 
 pub(crate) fn other_body(header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[u8], PduBody> + '_ {
     move |input: &[u8]| {
-        // Based on the PDU type, peek at the originating and receiving EntityIds.
-        let (input, originating, receiving) = match header.pdu_type {
-            // PDUs with only an origin
-            PduType::EntityState
-            | PduType::EntityStateUpdate
-            | PduType::ElectromagneticEmission
-            | PduType::Designator
-            | PduType::Transmitter
-            | PduType::Signal
-            | PduType::Receiver
-            | PduType::SupplementalEmissionEntityState
-            | PduType::UnderwaterAcoustic
-            | PduType::IsGroupOf
-            | PduType::CreateEntityR
-            | PduType::RemoveEntityR
-            | PduType::AggregateState
-            | PduType::IFF => {
-                let (input, originating) = peek_originating_field(input)?;
-                (input, Some(originating), None)
-            }
-            // PDUs with both an origin and a receiver
-            PduType::Fire
-            | PduType::Detonation
-            | PduType::Collision
-            | PduType::ServiceRequest
-            | PduType::ResupplyOffer
-            | PduType::ResupplyReceived
-            | PduType::ResupplyCancel
-            | PduType::RepairComplete
-            | PduType::RepairResponse
-            | PduType::CreateEntity
-            | PduType::RemoveEntity
-            | PduType::StartResume
-            | PduType::StopFreeze
-            | PduType::Acknowledge
-            | PduType::ActionRequest
-            | PduType::ActionResponse
-            | PduType::DataQuery
-            | PduType::SetData
-            | PduType::Data
-            | PduType::EventReport
-            | PduType::IsPartOf
-            | PduType::StartResumeR
-            | PduType::StopFreezeR
-            | PduType::AcknowledgeR
-            | PduType::ActionRequestR
-            | PduType::ActionResponseR
-            | PduType::DataQueryR
-            | PduType::SetDataR
-            | PduType::DataR
-            | PduType::EventReportR
-            | PduType::CommentR
-            | PduType::RecordR
-            | PduType::SetRecordR
-            | PduType::RecordQueryR
-            | PduType::CollisionElastic
-            | PduType::Comment => {
-                let (input, (origin, receiving)) = peek_originating_receiving_fields(input)?;
-                (input, Some(origin), Some(receiving))
-            }
-            // All others, and/or not evaluated TODO determine if these PDUs have an originating and receiving ID when implementing
-            PduType::IntercomSignal
-            | PduType::IntercomControl
-            | PduType::TransferOwnership
-            | PduType::MinefieldState
-            | PduType::MinefieldQuery
-            | PduType::MinefieldData
-            | PduType::MinefieldResponseNACK
-            | PduType::EnvironmentalProcess
-            | PduType::GriddedData
-            | PduType::PointObjectState
-            | PduType::LinearObjectState
-            | PduType::ArealObjectState
-            | PduType::TSPI
-            | PduType::Appearance
-            | PduType::ArticulatedParts
-            | PduType::LEFire
-            | PduType::LEDetonation
-            | PduType::DirectedEnergyFire
-            | PduType::EntityDamageStatus
-            | PduType::InformationOperationsAction
-            | PduType::InformationOperationsReport
-            | PduType::Attribute
-            | PduType::Other
-            | PduType::Unspecified(_) => (input, None, None),
-        };
+        context("Other Body", |input| {
+            // Based on the PDU type, peek at the originating and receiving EntityIds.
+            let (input, originating, receiving) = match header.pdu_type {
+                // PDUs with only an origin
+                PduType::EntityState
+                | PduType::EntityStateUpdate
+                | PduType::ElectromagneticEmission
+                | PduType::Designator
+                | PduType::Transmitter
+                | PduType::Signal
+                | PduType::Receiver
+                | PduType::SupplementalEmissionEntityState
+                | PduType::UnderwaterAcoustic
+                | PduType::IsGroupOf
+                | PduType::CreateEntityR
+                | PduType::RemoveEntityR
+                | PduType::AggregateState
+                | PduType::IFF => {
+                    let (input, originating) = peek_originating_field(input)?;
+                    (input, Some(originating), None)
+                }
+                // PDUs with both an origin and a receiver
+                PduType::Fire
+                | PduType::Detonation
+                | PduType::Collision
+                | PduType::ServiceRequest
+                | PduType::ResupplyOffer
+                | PduType::ResupplyReceived
+                | PduType::ResupplyCancel
+                | PduType::RepairComplete
+                | PduType::RepairResponse
+                | PduType::CreateEntity
+                | PduType::RemoveEntity
+                | PduType::StartResume
+                | PduType::StopFreeze
+                | PduType::Acknowledge
+                | PduType::ActionRequest
+                | PduType::ActionResponse
+                | PduType::DataQuery
+                | PduType::SetData
+                | PduType::Data
+                | PduType::EventReport
+                | PduType::IsPartOf
+                | PduType::StartResumeR
+                | PduType::StopFreezeR
+                | PduType::AcknowledgeR
+                | PduType::ActionRequestR
+                | PduType::ActionResponseR
+                | PduType::DataQueryR
+                | PduType::SetDataR
+                | PduType::DataR
+                | PduType::EventReportR
+                | PduType::CommentR
+                | PduType::RecordR
+                | PduType::SetRecordR
+                | PduType::RecordQueryR
+                | PduType::CollisionElastic
+                | PduType::Comment => {
+                    let (input, (origin, receiving)) = peek_originating_receiving_fields(input)?;
+                    (input, Some(origin), Some(receiving))
+                }
+                // All others, and/or not evaluated TODO determine if these PDUs have an originating and receiving ID when implementing
+                PduType::IntercomSignal
+                | PduType::IntercomControl
+                | PduType::TransferOwnership
+                | PduType::MinefieldState
+                | PduType::MinefieldQuery
+                | PduType::MinefieldData
+                | PduType::MinefieldResponseNACK
+                | PduType::EnvironmentalProcess
+                | PduType::GriddedData
+                | PduType::PointObjectState
+                | PduType::LinearObjectState
+                | PduType::ArealObjectState
+                | PduType::TSPI
+                | PduType::Appearance
+                | PduType::ArticulatedParts
+                | PduType::LEFire
+                | PduType::LEDetonation
+                | PduType::DirectedEnergyFire
+                | PduType::EntityDamageStatus
+                | PduType::InformationOperationsAction
+                | PduType::InformationOperationsReport
+                | PduType::Attribute
+                | PduType::Other
+                | PduType::Unspecified(_) => (input, None, None),
+            };
 
-        let body_length_bytes = header.pdu_length.saturating_sub(PDU_HEADER_LEN_BYTES);
-        let (input, body) = take(body_length_bytes)(input)?;
-        let inner_body = body.to_vec();
-        let body = Other::builder()
-            .with_body(inner_body)
-            .with_origin(originating)
-            .with_receiver(receiving)
-            .build();
+            let body_length_bytes = header.pdu_length.saturating_sub(PDU_HEADER_LEN_BYTES);
+            let (input, body) = take(body_length_bytes)(input)?;
+            let inner_body = body.to_vec();
+            let body = Other::builder()
+                .with_body(inner_body)
+                .with_origin(originating)
+                .with_receiver(receiving)
+                .build();
 
-        Ok((input, body.into_pdu_body()))
+            Ok((input, body.into_pdu_body()))
+        })(input)
     }
 }
 
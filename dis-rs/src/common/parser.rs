@@ -2,6 +2,9 @@
 use crate::action_request_r::parser::action_request_r_body;
 use crate::action_response_r::parser::action_response_r_body;
 use crate::aggregate_state::parser::aggregate_state_body;
+use crate::appearance::parser::appearance_body;
+use crate::areal_object_state::parser::areal_object_state_body;
+use crate::articulated_parts::parser::articulated_parts_body;
 use crate::comment_r::parser::comment_r_body;
 use crate::common::acknowledge::parser::acknowledge_body;
 use crate::common::action_request::parser::action_request_body;
@@ -18,7 +21,7 @@
 use crate::common::electromagnetic_emission::parser::emission_body;
 use crate::common::entity_state::parser::entity_state_body;
 use crate::common::entity_state_update::parser::entity_state_update_body;
-use crate::common::errors::DisError;
+use crate::common::errors::{DisError, ParserError, PduParseError};
 use crate::common::event_report::parser::event_report_body;
 use crate::common::fire::parser::fire_body;
 use crate::common::iff::parser::iff_body;
@@ -36,28 +39,53 @@
 use crate::common::start_resume::parser::start_resume_body;
 use crate::common::stop_freeze::parser::stop_freeze_body;
 use crate::common::transmitter::parser::transmitter_body;
+use crate::common::{LenientParseResult, SupportedVersion};
 use crate::constants::{
-    EIGHT_OCTETS, FIVE_LEAST_SIGNIFICANT_BITS, ONE_BYTE_IN_BITS, PDU_HEADER_LEN_BYTES,
+    EIGHT_OCTETS, FIVE_LEAST_SIGNIFICANT_BITS, ONE_BYTE_IN_BITS, PDU_HEADER_LEN_BYTES, SIX_OCTETS,
 };
 use crate::create_entity_r::parser::create_entity_r_body;
 use crate::data_query_r::parser::data_query_r_body;
 use crate::data_r::parser::data_r_body;
+use crate::directed_energy_fire::parser::directed_energy_fire_body;
+use crate::entity_damage_status::parser::entity_damage_status_body;
 use crate::enumerations::{
     ArticulatedPartsTypeClass, ArticulatedPartsTypeMetric, AttachedPartDetachedIndicator,
     AttachedParts, ChangeIndicator, EntityAssociationAssociationStatus,
     EntityAssociationGroupMemberType, EntityAssociationPhysicalAssociationType,
-    EntityAssociationPhysicalConnectionType, SeparationPreEntityIndicator,
-    SeparationReasonForSeparation, VariableParameterRecordType,
+    EntityAssociationPhysicalConnectionType, IOCommsNodeType, IOEffectLinkType, IOEffectProcess,
+    IOEffectStatus, IOEffectType, SeparationPreEntityIndicator, SeparationReasonForSeparation,
+    VariableParameterRecordType,
 };
 use crate::enumerations::{
     Country, DetonationTypeIndicator, EntityKind, ExplosiveMaterialCategories, FireTypeIndicator,
     MunitionDescriptorFuse, MunitionDescriptorWarhead, PduType, PlatformDomain, ProtocolFamily,
     ProtocolVersion, StationName, VariableRecordType,
 };
+use crate::environmental_process::parser::environmental_process_body;
 use crate::event_report_r::parser::event_report_r_body;
+use crate::gridded_data::parser::gridded_data_body;
+use crate::information_operations_action::parser::information_operations_action_body;
+use crate::information_operations_report::parser::information_operations_report_body;
+use crate::intercom_control::parser::intercom_control_body;
+use crate::intercom_signal::parser::intercom_signal_body;
 use crate::is_group_of::parser::is_group_of_body;
 use crate::is_part_of::parser::is_part_of_body;
-use crate::model::{RecordSet, RecordSpecification, SupplyQuantity};
+use crate::le_detonation::parser::le_detonation_body;
+use crate::le_fire::parser::le_fire_body;
+use crate::linear_object_state::parser::linear_object_state_body;
+use crate::minefield_data::parser::minefield_data_body;
+use crate::minefield_query::parser::minefield_query_body;
+use crate::minefield_response_nack::parser::minefield_response_nack_body;
+use crate::minefield_state::parser::minefield_state_body;
+use crate::model::{
+    CommunicationsNodeId, GeneralObjectAppearance, IOCommunicationsNode, IOEffect, IORecord,
+    LiveEntityId, LiveEntityLinearVelocity, LiveEntityOrientation, LiveEventId,
+    LiveMunitionDescriptor, MinefieldDataFilter, MinefieldSensorType, ObjectKind,
+    ObjectStateModification, ObjectType, PerimeterPoint, RecordSet, RecordSpecification,
+    RelativeWorldCoordinates, StandardVariableRecord, SupplyQuantity,
+    IO_COMMUNICATIONS_NODE_RECORD_TYPE, IO_EFFECT_RECORD_TYPE,
+};
+use crate::point_object_state::parser::point_object_state_body;
 use crate::record_query_r::parser::record_query_r_body;
 use crate::record_r::parser::record_r_body;
 use crate::remove_entity_r::parser::remove_entity_r_body;
@@ -73,33 +101,41 @@
 use crate::start_resume_r::parser::start_resume_r_body;
 use crate::stop_freeze_r::parser::stop_freeze_r_body;
 use crate::transfer_ownership::parser::transfer_ownership_body;
+use crate::tspi::parser::tspi_body;
 use crate::underwater_acoustic::parser::underwater_acoustic_body;
 use crate::v7::parser::parse_pdu_status;
+use crate::{v4, v5};
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
 use nom::bytes::complete::take;
 use nom::combinator::peek;
+use nom::error::context;
 use nom::error::ErrorKind::Eof;
 use nom::multi::{count, many1};
-use nom::number::complete::{be_f32, be_f64, be_i32, be_u16, be_u32, be_u64, be_u8};
+use nom::number::complete::{be_f32, be_f64, be_i16, be_i32, be_u16, be_u32, be_u64, be_u8};
 use nom::sequence::tuple;
 use nom::Err;
-use nom::IResult;
+
+/// `nom::IResult` using the `ParserError` type of this crate.
+pub type IResult<I, O> = nom::IResult<I, O, ParserError<I>>;
 
 pub(crate) fn parse_multiple_pdu(input: &[u8]) -> Result<Vec<Pdu>, DisError> {
     match many1(pdu)(input) {
         Ok((_, pdus)) => Ok(pdus),
-        Err(err) => Err(DisError::ParseError(err.to_string())), // TODO not very descriptive / error means we can not match any PDUs
+        // many1 only fails when not even the first PDU in the input can be parsed
+        Err(err) => Err(located_parse_error(input, 0, &err)),
     }
 }
 
-#[allow(dead_code)]
 pub(crate) fn parse_pdu(input: &[u8]) -> Result<Pdu, DisError> {
     match pdu(input) {
         Ok((_, pdu)) => Ok(pdu),
-        Err(err) => Err(DisError::ParseError(err.to_string())), // TODO not very descriptive / error means we can not match any PDUs
+        Err(err) => Err(located_parse_error(input, 0, &err)),
     }
 }
 
-#[allow(dead_code)]
 pub(crate) fn parse_multiple_header(input: &[u8]) -> Result<Vec<PduHeader>, DisError> {
     match many1(pdu_header_skip_body)(input) {
         Ok((_, headers)) => Ok(headers),
@@ -109,42 +145,125 @@ pub(crate) fn parse_multiple_header(input: &[u8]) -> Result<Vec<PduHeader>, DisE
                     return Err(DisError::InsufficientHeaderLength(input.len() as u16));
                 }
             }
-            Err(DisError::ParseError(parse_error.to_string()))
+            Err(located_parse_error(input, 0, &parse_error))
+        }
+    }
+}
+
+/// Parses all PDUs in the input, continuing with the next PDU when a PDU fails to parse.
+/// The PDU length field of the header is used to resynchronise to the start of the next PDU.
+/// Parsing stops when no valid header can be found, as the next PDU cannot be located anymore.
+pub(crate) fn parse_multiple_pdu_lenient(input: &[u8]) -> LenientParseResult {
+    let mut result = LenientParseResult::default();
+    let mut offset = 0;
+
+    while offset < input.len() {
+        let remaining = &input[offset..];
+        let header = match pdu_header(remaining) {
+            Ok((_, header)) => header,
+            Err(Err::Error(error)) if error.code == Eof => {
+                result.errors.push((
+                    offset,
+                    DisError::InsufficientHeaderLength(remaining.len() as u16),
+                ));
+                break;
+            }
+            Err(parse_error) => {
+                result
+                    .errors
+                    .push((offset, located_parse_error(remaining, offset, &parse_error)));
+                break;
+            }
+        };
+
+        let pdu_length = usize::from(header.pdu_length);
+        if pdu_length < usize::from(PDU_HEADER_LEN_BYTES) {
+            result.errors.push((
+                offset,
+                DisError::ParseError(format!(
+                    "PDU length of {pdu_length} bytes is smaller than the PDU header."
+                )),
+            ));
+            break;
         }
+        if pdu_length > remaining.len() {
+            result.errors.push((
+                offset,
+                DisError::InsufficientPduLength(
+                    header.pdu_length - PDU_HEADER_LEN_BYTES,
+                    (remaining.len() - usize::from(PDU_HEADER_LEN_BYTES)) as u16,
+                ),
+            ));
+            break;
+        }
+
+        let pdu_bytes = &remaining[..pdu_length];
+        match pdu(pdu_bytes) {
+            Ok((_, pdu)) => result.pdus.push(pdu),
+            Err(parse_error) => result
+                .errors
+                .push((offset, located_parse_error(pdu_bytes, offset, &parse_error))),
+        }
+        offset += pdu_length;
     }
+
+    result
 }
 
 /// Parse the input for a PDU header, and skip the rest of the pdu body in the input
-#[allow(dead_code)]
 pub(crate) fn parse_header(input: &[u8]) -> Result<PduHeader, DisError> {
+    parse_header_and_body(input).map(|(_, header, _)| header)
+}
+
+/// Parse the input for a PDU header, and split off the pdu body from the input.
+/// Returns the remaining input, the header and the raw bytes of the body.
+pub(crate) fn parse_header_and_body(input: &[u8]) -> Result<(&[u8], PduHeader, &[u8]), DisError> {
     match pdu_header(input) {
-        Ok((input, header)) => {
-            let skipped = skip_body(header.pdu_length)(input); // Discard the body
-            if let Err(Err::Error(error)) = skipped {
-                return if error.code == Eof {
-                    Err(DisError::InsufficientPduLength(
-                        header.pdu_length - PDU_HEADER_LEN_BYTES,
-                        input.len() as u16,
-                    ))
-                } else {
-                    Err(DisError::ParseError(
-                        "ParseError while parsing a pdu header and skipping body.".to_string(),
-                    ))
-                };
-            }
-            Ok(header)
-        }
+        Ok((body_input, header)) => match skip_body(header.pdu_length)(body_input) {
+            Ok((remainder, body)) => Ok((remainder, header, body)),
+            Err(Err::Error(error)) if error.code == Eof => Err(DisError::InsufficientPduLength(
+                header.pdu_length - PDU_HEADER_LEN_BYTES,
+                body_input.len() as u16,
+            )),
+            Err(parse_error) => Err(located_parse_error(input, 0, &parse_error)),
+        },
         Err(parse_error) => {
             if let Err::Error(ref error) = parse_error {
                 if error.code == Eof {
                     return Err(DisError::InsufficientHeaderLength(input.len() as u16));
                 }
             }
-            Err(DisError::ParseError(parse_error.to_string()))
+            Err(located_parse_error(input, 0, &parse_error))
         }
     }
 }
 
+/// Converts the error resulting from parsing the PDU at the start of `input` into a `DisError`,
+/// locating the failure by the type and protocol version of the PDU (when the header can be parsed),
+/// the byte offset in the input buffer, and the record or field that failed to parse.
+///
+/// `pdu_offset` is the offset of the PDU (the start of `input`) in the input buffer, such as a datagram
+/// holding multiple PDUs, so that the reported offset is relative to the start of the input buffer.
+pub(crate) fn located_parse_error(
+    input: &[u8],
+    pdu_offset: usize,
+    error: &Err<ParserError<&[u8]>>,
+) -> DisError {
+    match error {
+        Err::Error(parser_error) | Err::Failure(parser_error) => {
+            let header = pdu_header(input).ok().map(|(_, header)| header);
+            DisError::PduParseError(PduParseError {
+                pdu_type: header.map(|header| header.pdu_type),
+                protocol_version: header.map(|header| header.protocol_version),
+                offset: pdu_offset + input.len().saturating_sub(parser_error.input.len()),
+                field: parser_error.context.unwrap_or("PDU"),
+                reason: parser_error.code.description().to_string(),
+            })
+        }
+        Err::Incomplete(_) => DisError::ParseError(error.to_string()),
+    }
+}
+
 fn pdu(input: &[u8]) -> IResult<&[u8], Pdu> {
     // parse the header
     let (input, header) = pdu_header(input)?;
@@ -156,18 +275,18 @@ fn pdu(input: &[u8]) -> IResult<&[u8], Pdu> {
 
     // parse the body based on the type
     // and produce the final pdu combined with the header
-    let (input, body) = pdu_body(&header)(input)?;
+    let (input, body) = context("PDU Body", pdu_body(&header))(input)?;
 
     Ok((input, Pdu { header, body }))
 }
 
-fn pdu_header(input: &[u8]) -> IResult<&[u8], PduHeader> {
-    let protocol_version = protocol_version;
-    let exercise_id = be_u8;
-    let pdu_type = pdu_type;
-    let protocol_family = protocol_family;
-    let time_stamp = be_u32;
-    let pdu_length = be_u16;
+pub(crate) fn pdu_header(input: &[u8]) -> IResult<&[u8], PduHeader> {
+    let protocol_version = context("Protocol Version", protocol_version);
+    let exercise_id = context("Exercise ID", be_u8);
+    let pdu_type = context("PDU Type", pdu_type);
+    let protocol_family = context("Protocol Family", protocol_family);
+    let time_stamp = context("Timestamp", be_u32);
+    let pdu_length = context("PDU Length", be_u16);
 
     let (input, (protocol_version, exercise_id, pdu_type, protocol_family, time_stamp, pdu_length)) =
         tuple((
@@ -180,19 +299,21 @@ fn pdu_header(input: &[u8]) -> IResult<&[u8], PduHeader> {
         ))(input)?;
     let (input, pdu_status, padding) = match u8::from(protocol_version) {
         legacy_version if (1..=5).contains(&legacy_version) => {
-            let (input, padding) = be_u16(input)?;
+            let (input, padding) = context("Padding", be_u16)(input)?;
             (input, None, padding)
         }
         6 => {
-            let (input, padding) = be_u16(input)?;
+            let (input, padding) = context("Padding", be_u16)(input)?;
             (input, None, padding)
         }
         7 => {
-            let (input, (status, padding)) = parse_pdu_status(pdu_type)(input)?;
+            let (input, (status, padding)) =
+                context("PDU Status", parse_pdu_status(pdu_type))(input)?;
             (input, Some(status), padding)
         }
         _future_version => {
-            let (input, (status, padding)) = parse_pdu_status(pdu_type)(input)?;
+            let (input, (status, padding)) =
+                context("PDU Status", parse_pdu_status(pdu_type))(input)?;
             (input, Some(status), padding)
         }
     };
@@ -212,7 +333,6 @@ fn pdu_header(input: &[u8]) -> IResult<&[u8], PduHeader> {
     ))
 }
 
-#[allow(dead_code)]
 fn pdu_header_skip_body(input: &[u8]) -> IResult<&[u8], PduHeader> {
     let (input, header) = pdu_header(input)?;
     let (input, _) = skip_body(header.pdu_length)(input)?;
@@ -221,6 +341,16 @@ fn pdu_header_skip_body(input: &[u8]) -> IResult<&[u8], PduHeader> {
 
 fn pdu_body(header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[u8], PduBody> + '_ {
     move |input: &[u8]| {
+        // PDU types that are not defined by the legacy versions 4 and 5 have no known layout in these versions
+        let defined_by_version = match SupportedVersion::from(header.protocol_version) {
+            SupportedVersion::V4 => v4::defines_pdu_type(header.pdu_type),
+            SupportedVersion::V5 => v5::defines_pdu_type(header.pdu_type),
+            SupportedVersion::V6 | SupportedVersion::V7 | SupportedVersion::Unsupported => true,
+        };
+        if !defined_by_version {
+            return other_body(header)(input);
+        }
+
         // parse the body of the PDU based on the type
         // NOTE only processes supported PduTypes; process others as 'Other'
         let (input, body) = match header.pdu_type {
@@ -255,26 +385,26 @@ fn pdu_body(header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[u8], PduBody> + '
             PduType::IFF => iff_body(input)?,
             PduType::UnderwaterAcoustic => underwater_acoustic_body(input)?,
             PduType::SupplementalEmissionEntityState => sees_body(input)?,
-            // PduType::IntercomSignal => {}
-            // PduType::IntercomControl => {}
+            PduType::IntercomSignal => intercom_signal_body(input)?,
+            PduType::IntercomControl => intercom_control_body(input)?,
             PduType::AggregateState => aggregate_state_body(input)?,
             PduType::IsGroupOf => is_group_of_body(input)?,
             PduType::TransferOwnership => transfer_ownership_body(input)?,
             PduType::IsPartOf => is_part_of_body(input)?,
-            // PduType::MinefieldState => {}
-            // PduType::MinefieldQuery => {}
-            // PduType::MinefieldData => {}
-            // PduType::MinefieldResponseNACK => {}
-            // PduType::EnvironmentalProcess => {}
-            // PduType::GriddedData => {}
-            // PduType::PointObjectState => {}
-            // PduType::LinearObjectState => {}
-            // PduType::ArealObjectState => {}
-            // PduType::TSPI => {}
-            // PduType::Appearance => {}
-            // PduType::ArticulatedParts => {}
-            // PduType::LEFire => {}
-            // PduType::LEDetonation => {}
+            PduType::MinefieldState => minefield_state_body(input)?,
+            PduType::MinefieldQuery => minefield_query_body(input)?,
+            PduType::MinefieldData => minefield_data_body(input)?,
+            PduType::MinefieldResponseNACK => minefield_response_nack_body(input)?,
+            PduType::EnvironmentalProcess => environmental_process_body(input)?,
+            PduType::GriddedData => gridded_data_body(input)?,
+            PduType::PointObjectState => point_object_state_body(input)?,
+            PduType::LinearObjectState => linear_object_state_body(input)?,
+            PduType::ArealObjectState => areal_object_state_body(input)?,
+            PduType::TSPI => tspi_body(input)?,
+            PduType::Appearance => appearance_body(input)?,
+            PduType::ArticulatedParts => articulated_parts_body(input)?,
+            PduType::LEFire => le_fire_body(input)?,
+            PduType::LEDetonation => le_detonation_body(input)?,
             PduType::CreateEntityR => create_entity_r_body(input)?,
             PduType::RemoveEntityR => remove_entity_r_body(input)?,
             PduType::StartResumeR => start_resume_r_body(input)?,
@@ -292,13 +422,12 @@ fn pdu_body(header: &PduHeader) -> impl Fn(&[u8]) -> IResult<&[u8], PduBody> + '
             PduType::RecordQueryR => record_query_r_body(input)?,
             PduType::CollisionElastic => collision_elastic_body(input)?,
             PduType::EntityStateUpdate => entity_state_update_body(input)?,
-            // PduType::DirectedEnergyFire => {}
-            // PduType::EntityDamageStatus => {}
-            // PduType::InformationOperationsAction => {}
-            // PduType::InformationOperationsReport => {}
+            PduType::DirectedEnergyFire => directed_energy_fire_body(input)?,
+            PduType::EntityDamageStatus => entity_damage_status_body(input)?,
+            PduType::InformationOperationsAction => information_operations_action_body(input)?,
+            PduType::InformationOperationsReport => information_operations_report_body(input)?,
             PduType::Attribute => attribute_body(input)?,
             PduType::Unspecified(_type_number) => other_body(header)(input)?, // TODO Log unsupported type number?
-            _ => other_body(header)(input)?,
         };
         Ok((input, body))
     }
@@ -360,37 +489,41 @@ pub(crate) fn simulation_address(input: &[u8]) -> IResult<&[u8], SimulationAddre
 }
 
 pub(crate) fn entity_id(input: &[u8]) -> IResult<&[u8], EntityId> {
-    let (input, simulation_address) = simulation_address(input)?;
-    let (input, entity_id) = be_u16(input)?;
-    Ok((
-        input,
-        EntityId {
-            simulation_address,
-            entity_id,
-        },
-    ))
+    context("Entity ID", |input| {
+        let (input, simulation_address) = simulation_address(input)?;
+        let (input, entity_id) = be_u16(input)?;
+        Ok((
+            input,
+            EntityId {
+                simulation_address,
+                entity_id,
+            },
+        ))
+    })(input)
 }
 
 pub(crate) fn entity_type(input: &[u8]) -> IResult<&[u8], EntityType> {
-    let (input, kind) = kind(input)?;
-    let (input, domain) = domain(input)?;
-    let (input, country) = country(input)?;
-    let (input, category) = be_u8(input)?;
-    let (input, subcategory) = be_u8(input)?;
-    let (input, specific) = be_u8(input)?;
-    let (input, extra) = be_u8(input)?;
-    Ok((
-        input,
-        EntityType {
-            kind,
-            domain,
-            country,
-            category,
-            subcategory,
-            specific,
-            extra,
-        },
-    ))
+    context("Entity Type", |input| {
+        let (input, kind) = kind(input)?;
+        let (input, domain) = domain(input)?;
+        let (input, country) = country(input)?;
+        let (input, category) = be_u8(input)?;
+        let (input, subcategory) = be_u8(input)?;
+        let (input, specific) = be_u8(input)?;
+        let (input, extra) = be_u8(input)?;
+        Ok((
+            input,
+            EntityType {
+                kind,
+                domain,
+                country,
+                category,
+                subcategory,
+                specific,
+                extra,
+            },
+        ))
+    })(input)
 }
 
 fn kind(input: &[u8]) -> IResult<&[u8], EntityKind> {
@@ -412,76 +545,84 @@ fn country(input: &[u8]) -> IResult<&[u8], Country> {
 }
 
 pub(crate) fn vec3_f32(input: &[u8]) -> IResult<&[u8], VectorF32> {
-    let (input, elements) = count(be_f32, 3)(input)?;
-    #[allow(clippy::get_first)]
-    Ok((
-        input,
-        VectorF32 {
-            first_vector_component: *elements
-                .get(0)
-                .expect("Value supposed to be parsed successfully"),
-            second_vector_component: *elements
-                .get(1)
-                .expect("Value supposed to be parsed successfully"),
-            third_vector_component: *elements
-                .get(2)
-                .expect("Value supposed to be parsed successfully"),
-        },
-    ))
+    context("Vector", |input| {
+        let (input, elements) = count(be_f32, 3)(input)?;
+        #[allow(clippy::get_first)]
+        Ok((
+            input,
+            VectorF32 {
+                first_vector_component: *elements
+                    .get(0)
+                    .expect("Value supposed to be parsed successfully"),
+                second_vector_component: *elements
+                    .get(1)
+                    .expect("Value supposed to be parsed successfully"),
+                third_vector_component: *elements
+                    .get(2)
+                    .expect("Value supposed to be parsed successfully"),
+            },
+        ))
+    })(input)
 }
 
 pub(crate) fn location(input: &[u8]) -> IResult<&[u8], Location> {
-    let (input, locations) = count(be_f64, 3)(input)?;
-    #[allow(clippy::get_first)]
-    Ok((
-        input,
-        Location {
-            x_coordinate: *locations
-                .get(0)
-                .expect("Value supposed to be parsed successfully"),
-            y_coordinate: *locations
-                .get(1)
-                .expect("Value supposed to be parsed successfully"),
-            z_coordinate: *locations
-                .get(2)
-                .expect("Value supposed to be parsed successfully"),
-        },
-    ))
+    context("World Coordinates", |input| {
+        let (input, locations) = count(be_f64, 3)(input)?;
+        #[allow(clippy::get_first)]
+        Ok((
+            input,
+            Location {
+                x_coordinate: *locations
+                    .get(0)
+                    .expect("Value supposed to be parsed successfully"),
+                y_coordinate: *locations
+                    .get(1)
+                    .expect("Value supposed to be parsed successfully"),
+                z_coordinate: *locations
+                    .get(2)
+                    .expect("Value supposed to be parsed successfully"),
+            },
+        ))
+    })(input)
 }
 
 pub(crate) fn orientation(input: &[u8]) -> IResult<&[u8], Orientation> {
-    let (input, orientations) = count(be_f32, 3)(input)?;
-    #[allow(clippy::get_first)]
-    Ok((
-        input,
-        Orientation {
-            psi: *orientations
-                .get(0)
-                .expect("Value supposed to be parsed successfully"),
-            theta: *orientations
-                .get(1)
-                .expect("Value supposed to be parsed successfully"),
-            phi: *orientations
-                .get(2)
-                .expect("Value supposed to be parsed successfully"),
-        },
-    ))
+    context("Orientation", |input| {
+        let (input, orientations) = count(be_f32, 3)(input)?;
+        #[allow(clippy::get_first)]
+        Ok((
+            input,
+            Orientation {
+                psi: *orientations
+                    .get(0)
+                    .expect("Value supposed to be parsed successfully"),
+                theta: *orientations
+                    .get(1)
+                    .expect("Value supposed to be parsed successfully"),
+                phi: *orientations
+                    .get(2)
+                    .expect("Value supposed to be parsed successfully"),
+            },
+        ))
+    })(input)
 }
 
 pub(crate) fn event_id(input: &[u8]) -> IResult<&[u8], EventId> {
-    let (input, site_id) = be_u16(input)?;
-    let (input, application_id) = be_u16(input)?;
-    let (input, event_id) = be_u16(input)?;
-    Ok((
-        input,
-        EventId {
-            simulation_address: SimulationAddress {
-                site_id,
-                application_id,
+    context("Event ID", |input| {
+        let (input, site_id) = be_u16(input)?;
+        let (input, application_id) = be_u16(input)?;
+        let (input, event_id) = be_u16(input)?;
+        Ok((
+            input,
+            EventId {
+                simulation_address: SimulationAddress {
+                    site_id,
+                    application_id,
+                },
+                event_id,
             },
-            event_id,
-        },
-    ))
+        ))
+    })(input)
 }
 
 pub(crate) fn descriptor_record_fti(
@@ -562,20 +703,22 @@ pub(crate) fn descriptor_record_dti(
 }
 
 pub(crate) fn munition_descriptor(input: &[u8]) -> IResult<&[u8], MunitionDescriptor> {
-    let (input, warhead) = warhead(input)?;
-    let (input, fuse) = fuse(input)?;
-    let (input, quantity) = be_u16(input)?;
-    let (input, rate) = be_u16(input)?;
-
-    Ok((
-        input,
-        MunitionDescriptor {
-            warhead,
-            fuse,
-            quantity,
-            rate,
-        },
-    ))
+    context("Munition Descriptor", |input| {
+        let (input, warhead) = warhead(input)?;
+        let (input, fuse) = fuse(input)?;
+        let (input, quantity) = be_u16(input)?;
+        let (input, rate) = be_u16(input)?;
+
+        Ok((
+            input,
+            MunitionDescriptor {
+                warhead,
+                fuse,
+                quantity,
+                rate,
+            },
+        ))
+    })(input)
 }
 
 fn warhead(input: &[u8]) -> IResult<&[u8], MunitionDescriptorWarhead> {
@@ -591,75 +734,85 @@ fn fuse(input: &[u8]) -> IResult<&[u8], MunitionDescriptorFuse> {
 }
 
 pub(crate) fn clock_time(input: &[u8]) -> IResult<&[u8], ClockTime> {
-    let (input, hour) = be_i32(input)?;
-    let (input, time_past_hour) = be_u32(input)?;
-    let time = ClockTime::new(hour, time_past_hour);
-    Ok((input, time))
+    context("Clock Time", |input| {
+        let (input, hour) = be_i32(input)?;
+        let (input, time_past_hour) = be_u32(input)?;
+        let time = ClockTime::new(hour, time_past_hour);
+        Ok((input, time))
+    })(input)
 }
 
 pub(crate) fn datum_specification(input: &[u8]) -> IResult<&[u8], DatumSpecification> {
-    let (input, num_fixed_datums) = be_u32(input)?;
-    let (input, num_variable_datums) = be_u32(input)?;
+    context("Datum Specification", |input| {
+        let (input, num_fixed_datums) = be_u32(input)?;
+        let (input, num_variable_datums) = be_u32(input)?;
 
-    let (input, fixed_datums) = count(fixed_datum, num_fixed_datums as usize)(input)?;
-    let (input, variable_datums) = count(variable_datum, num_variable_datums as usize)(input)?;
+        let (input, fixed_datums) = count(fixed_datum, num_fixed_datums as usize)(input)?;
+        let (input, variable_datums) = count(variable_datum, num_variable_datums as usize)(input)?;
 
-    let datums = DatumSpecification::new(fixed_datums, variable_datums);
+        let datums = DatumSpecification::new(fixed_datums, variable_datums);
 
-    Ok((input, datums))
+        Ok((input, datums))
+    })(input)
 }
 
 pub(crate) fn fixed_datum(input: &[u8]) -> IResult<&[u8], FixedDatum> {
-    let (input, datum_id) = be_u32(input)?;
-    let (input, datum_value) = be_u32(input)?;
+    context("Fixed Datum", |input| {
+        let (input, datum_id) = be_u32(input)?;
+        let (input, datum_value) = be_u32(input)?;
 
-    let datum_id = VariableRecordType::from(datum_id);
-    let datum = FixedDatum::new(datum_id, datum_value);
+        let datum_id = VariableRecordType::from(datum_id);
+        let datum = FixedDatum::new(datum_id, datum_value);
 
-    Ok((input, datum))
+        Ok((input, datum))
+    })(input)
 }
 
 pub(crate) fn variable_datum(input: &[u8]) -> IResult<&[u8], VariableDatum> {
-    let (input, datum_id) = be_u32(input)?;
-    let datum_id = VariableRecordType::from(datum_id);
-    let (input, datum_length_bits) = be_u32(input)?;
+    context("Variable Datum", |input| {
+        let (input, datum_id) = be_u32(input)?;
+        let datum_id = VariableRecordType::from(datum_id);
+        let (input, datum_length_bits) = be_u32(input)?;
 
-    // NOTE: The standard defines the data length and padding in bits.
-    // However, we assume that one only puts in values that consists of whole bytes.
-    // (As why would one put 11 bits in a datum, which then ends up in a Vec<u8>)
-    let datum_length_bytes = datum_length_bits as usize / ONE_BYTE_IN_BITS;
-    let padded_record = length_padded_to_num(datum_length_bytes, EIGHT_OCTETS);
+        // NOTE: The standard defines the data length and padding in bits.
+        // However, we assume that one only puts in values that consists of whole bytes.
+        // (As why would one put 11 bits in a datum, which then ends up in a Vec<u8>)
+        let datum_length_bytes = datum_length_bits as usize / ONE_BYTE_IN_BITS;
+        let padded_record = length_padded_to_num(datum_length_bytes, EIGHT_OCTETS);
 
-    let (input, datum_value) = take(padded_record.data_length)(input)?;
-    let (input, _datum_padding) = take(padded_record.padding_length)(input)?;
+        let (input, datum_value): (&[u8], &[u8]) = take(padded_record.data_length)(input)?;
+        let (input, _datum_padding) = take(padded_record.padding_length)(input)?;
 
-    let variable_datum = VariableDatum::new(datum_id, datum_value.to_vec());
+        let variable_datum = VariableDatum::new(datum_id, datum_value.to_vec());
 
-    Ok((input, variable_datum))
+        Ok((input, variable_datum))
+    })(input)
 }
 
 pub(crate) fn variable_parameter(input: &[u8]) -> IResult<&[u8], VariableParameter> {
-    let (input, parameter_type_designator) = be_u8(input)?;
-    let parameter_type = VariableParameterRecordType::from(parameter_type_designator);
-    let (input, variable_parameter) = match parameter_type {
-        VariableParameterRecordType::ArticulatedPart => articulated_part(input)?,
-        VariableParameterRecordType::AttachedPart => attached_part(input)?,
-        VariableParameterRecordType::Separation => separation(input)?,
-        VariableParameterRecordType::EntityType => entity_type_variable_parameter(input)?,
-        VariableParameterRecordType::EntityAssociation => entity_association(input)?,
-        VariableParameterRecordType::Unspecified(_) => {
-            let (input, bytes) = take(15usize)(input)?;
-            (
-                input,
-                VariableParameter::Unspecified(
-                    parameter_type_designator,
-                    <[u8; 15]>::try_from(bytes).unwrap(),
-                ),
-            )
-        } // TODO sensible error
-    };
+    context("Variable Parameter", |input| {
+        let (input, parameter_type_designator) = be_u8(input)?;
+        let parameter_type = VariableParameterRecordType::from(parameter_type_designator);
+        let (input, variable_parameter) = match parameter_type {
+            VariableParameterRecordType::ArticulatedPart => articulated_part(input)?,
+            VariableParameterRecordType::AttachedPart => attached_part(input)?,
+            VariableParameterRecordType::Separation => separation(input)?,
+            VariableParameterRecordType::EntityType => entity_type_variable_parameter(input)?,
+            VariableParameterRecordType::EntityAssociation => entity_association(input)?,
+            VariableParameterRecordType::Unspecified(_) => {
+                let (input, bytes) = take(15usize)(input)?;
+                (
+                    input,
+                    VariableParameter::Unspecified(
+                        parameter_type_designator,
+                        <[u8; 15]>::try_from(bytes).unwrap(),
+                    ),
+                )
+            } // TODO sensible error
+        };
 
-    Ok((input, variable_parameter))
+        Ok((input, variable_parameter))
+    })(input)
 }
 
 /// I.2.2 Articulated parts
@@ -766,72 +919,294 @@ fn separation(input: &[u8]) -> IResult<&[u8], VariableParameter> {
 }
 
 pub(crate) fn beam_data(input: &[u8]) -> IResult<&[u8], BeamData> {
-    let (input, azimuth_center) = be_f32(input)?;
-    let (input, azimuth_sweep) = be_f32(input)?;
-    let (input, elevation_center) = be_f32(input)?;
-    let (input, elevation_sweep) = be_f32(input)?;
-    let (input, sweep_sync) = be_f32(input)?;
+    context("Beam Data", |input| {
+        let (input, azimuth_center) = be_f32(input)?;
+        let (input, azimuth_sweep) = be_f32(input)?;
+        let (input, elevation_center) = be_f32(input)?;
+        let (input, elevation_sweep) = be_f32(input)?;
+        let (input, sweep_sync) = be_f32(input)?;
+
+        let data = BeamData::new()
+            .with_azimuth_center(azimuth_center)
+            .with_azimuth_sweep(azimuth_sweep)
+            .with_elevation_center(elevation_center)
+            .with_elevation_sweep(elevation_sweep)
+            .with_sweep_sync(sweep_sync);
+
+        Ok((input, data))
+    })(input)
+}
+
+pub(crate) fn supply_quantity(input: &[u8]) -> IResult<&[u8], SupplyQuantity> {
+    context("Supply Quantity", |input| {
+        let (input, supply_type) = entity_type(input)?;
+        let (input, quantity) = be_f32(input)?;
+
+        Ok((
+            input,
+            SupplyQuantity::default()
+                .with_supply_type(supply_type)
+                .with_quantity(quantity),
+        ))
+    })(input)
+}
 
-    let data = BeamData::new()
-        .with_azimuth_center(azimuth_center)
-        .with_azimuth_sweep(azimuth_sweep)
-        .with_elevation_center(elevation_center)
-        .with_elevation_sweep(elevation_sweep)
-        .with_sweep_sync(sweep_sync);
+pub(crate) fn perimeter_point(input: &[u8]) -> IResult<&[u8], PerimeterPoint> {
+    context("Perimeter Point", |input| {
+        let (input, x) = be_f32(input)?;
+        let (input, y) = be_f32(input)?;
 
-    Ok((input, data))
+        Ok((input, PerimeterPoint::new(x, y)))
+    })(input)
 }
 
-pub(crate) fn supply_quantity(input: &[u8]) -> IResult<&[u8], SupplyQuantity> {
-    let (input, supply_type) = entity_type(input)?;
-    let (input, quantity) = be_f32(input)?;
+pub(crate) fn minefield_data_filter(input: &[u8]) -> IResult<&[u8], MinefieldDataFilter> {
+    let (input, filter) = be_u32(input)?;
+
+    Ok((input, MinefieldDataFilter::from(filter)))
+}
+
+pub(crate) fn minefield_sensor_type(input: &[u8]) -> IResult<&[u8], MinefieldSensorType> {
+    let (input, sensor_type) = be_u16(input)?;
+
+    Ok((input, MinefieldSensorType::from(sensor_type)))
+}
+
+pub(crate) fn object_type(input: &[u8]) -> IResult<&[u8], ObjectType> {
+    context("Object Type", |input| {
+        let (input, domain) = be_u8(input)?;
+        let (input, kind) = be_u8(input)?;
+        let (input, category) = be_u8(input)?;
+        let (input, subcategory) = be_u8(input)?;
+
+        Ok((
+            input,
+            ObjectType::new(
+                PlatformDomain::from(domain),
+                ObjectKind::from(kind),
+                category,
+                subcategory,
+            ),
+        ))
+    })(input)
+}
+
+pub(crate) fn object_state_modification(input: &[u8]) -> IResult<&[u8], ObjectStateModification> {
+    let (input, modification) = be_u8(input)?;
+
+    Ok((input, ObjectStateModification::from(modification)))
+}
+
+pub(crate) fn general_object_appearance(input: &[u8]) -> IResult<&[u8], GeneralObjectAppearance> {
+    let (input, appearance) = be_u16(input)?;
+
+    Ok((input, GeneralObjectAppearance::from(appearance)))
+}
+
+pub(crate) fn live_entity_id(input: &[u8]) -> IResult<&[u8], LiveEntityId> {
+    context("Live Entity ID", |input| {
+        let (input, site_number) = be_u8(input)?;
+        let (input, application_number) = be_u8(input)?;
+        let (input, entity_number) = be_u16(input)?;
+
+        Ok((
+            input,
+            LiveEntityId::new(site_number, application_number, entity_number),
+        ))
+    })(input)
+}
+
+pub(crate) fn live_event_id(input: &[u8]) -> IResult<&[u8], LiveEventId> {
+    context("Live Event ID", |input| {
+        let (input, site_number) = be_u8(input)?;
+        let (input, application_number) = be_u8(input)?;
+        let (input, event_number) = be_u16(input)?;
+
+        Ok((
+            input,
+            LiveEventId::new(site_number, application_number, event_number),
+        ))
+    })(input)
+}
+
+pub(crate) fn relative_world_coordinates(input: &[u8]) -> IResult<&[u8], RelativeWorldCoordinates> {
+    let (input, reference_point) = be_u16(input)?;
+    let (input, delta_x) = be_i16(input)?;
+    let (input, delta_y) = be_i16(input)?;
+    let (input, delta_z) = be_i16(input)?;
 
     Ok((
         input,
-        SupplyQuantity::default()
-            .with_supply_type(supply_type)
-            .with_quantity(quantity),
+        RelativeWorldCoordinates::new(reference_point, delta_x, delta_y, delta_z),
     ))
 }
 
-/// Parses the `RecordSpecification` record (6.2.73)
-pub(crate) fn record_specification(input: &[u8]) -> IResult<&[u8], RecordSpecification> {
-    let (input, number_of_records) = be_u32(input)?;
-    let (input, record_sets) = count(record_set, number_of_records as usize)(input)?;
+pub(crate) fn live_entity_orientation(input: &[u8]) -> IResult<&[u8], LiveEntityOrientation> {
+    let (input, psi) = be_u8(input)?;
+    let (input, theta) = be_u8(input)?;
+    let (input, phi) = be_u8(input)?;
+
+    Ok((input, LiveEntityOrientation::new(psi, theta, phi)))
+}
+
+pub(crate) fn live_entity_linear_velocity(
+    input: &[u8],
+) -> IResult<&[u8], LiveEntityLinearVelocity> {
+    let (input, x_component) = be_i16(input)?;
+    let (input, y_component) = be_i16(input)?;
+    let (input, z_component) = be_i16(input)?;
+
+    Ok((
+        input,
+        LiveEntityLinearVelocity::new(x_component, y_component, z_component),
+    ))
+}
+
+pub(crate) fn live_munition_descriptor(input: &[u8]) -> IResult<&[u8], LiveMunitionDescriptor> {
+    let (input, munition_type) = entity_type(input)?;
+    let (input, warhead) = warhead(input)?;
+    let (input, fuse) = fuse(input)?;
+    let (input, quantity) = be_u8(input)?;
+    let (input, rate) = be_u8(input)?;
+
+    Ok((
+        input,
+        LiveMunitionDescriptor::default()
+            .with_munition_type(munition_type)
+            .with_warhead(warhead)
+            .with_fuse(fuse)
+            .with_quantity(quantity)
+            .with_rate(rate),
+    ))
+}
+
+pub(crate) fn standard_variable_record(input: &[u8]) -> IResult<&[u8], StandardVariableRecord> {
+    context("Standard Variable Record", |input| {
+        let (input, record_type) = be_u32(input)?;
+        let (input, record_length) = be_u16(input)?;
+        let (input, data): (&[u8], &[u8]) =
+            take(record_length.saturating_sub(SIX_OCTETS as u16))(input)?;
+
+        Ok((
+            input,
+            StandardVariableRecord::new(record_type, data.to_vec()),
+        ))
+    })(input)
+}
+
+pub(crate) fn communications_node_id(input: &[u8]) -> IResult<&[u8], CommunicationsNodeId> {
+    context("Communications Node ID", |input| {
+        let (input, entity_id) = entity_id(input)?;
+        let (input, element_id) = be_u16(input)?;
+
+        Ok((input, CommunicationsNodeId::new(entity_id, element_id)))
+    })(input)
+}
+
+/// Parses an IO record, being either an IO Communications Node record (6.2.48),
+/// an IO Effect record (6.2.49), or any other standard variable record.
+pub(crate) fn io_record(input: &[u8]) -> IResult<&[u8], IORecord> {
+    context("IO Record", |input| {
+        let (_, record_type) = peek(be_u32)(input)?;
+
+        match record_type {
+            IO_COMMUNICATIONS_NODE_RECORD_TYPE => {
+                let (input, record) = io_communications_node(input)?;
+                Ok((input, IORecord::CommunicationsNode(record)))
+            }
+            IO_EFFECT_RECORD_TYPE => {
+                let (input, record) = io_effect(input)?;
+                Ok((input, IORecord::Effect(record)))
+            }
+            _ => {
+                let (input, record) = standard_variable_record(input)?;
+                Ok((input, IORecord::Unspecified(record)))
+            }
+        }
+    })(input)
+}
+
+fn io_communications_node(input: &[u8]) -> IResult<&[u8], IOCommunicationsNode> {
+    let (input, _record_type) = be_u32(input)?;
+    let (input, _record_length) = be_u16(input)?;
+    let (input, communications_node_type) = be_u8(input)?;
+    let (input, _padding) = be_u8(input)?;
+    let (input, communications_node_id) = communications_node_id(input)?;
 
     Ok((
         input,
-        RecordSpecification::default().with_record_sets(record_sets),
+        IOCommunicationsNode::new(
+            IOCommsNodeType::from(communications_node_type),
+            communications_node_id,
+        ),
     ))
 }
 
+fn io_effect(input: &[u8]) -> IResult<&[u8], IOEffect> {
+    let (input, _record_type) = be_u32(input)?;
+    let (input, _record_length) = be_u16(input)?;
+    let (input, status) = be_u8(input)?;
+    let (input, link_type) = be_u8(input)?;
+    let (input, effect) = be_u8(input)?;
+    let (input, effect_duty_cycle) = be_u8(input)?;
+    let (input, effect_duration) = be_u16(input)?;
+    let (input, process) = be_u16(input)?;
+    let (input, _padding) = be_u16(input)?;
+
+    Ok((
+        input,
+        IOEffect::default()
+            .with_status(IOEffectStatus::from(status))
+            .with_link_type(IOEffectLinkType::from(link_type))
+            .with_effect(IOEffectType::from(effect))
+            .with_effect_duty_cycle(effect_duty_cycle)
+            .with_effect_duration(effect_duration)
+            .with_process(IOEffectProcess::from(process)),
+    ))
+}
+
+/// Parses the `RecordSpecification` record (6.2.73)
+pub(crate) fn record_specification(input: &[u8]) -> IResult<&[u8], RecordSpecification> {
+    context("Record Specification", |input| {
+        let (input, number_of_records) = be_u32(input)?;
+        let (input, record_sets) = count(record_set, number_of_records as usize)(input)?;
+
+        Ok((
+            input,
+            RecordSpecification::default().with_record_sets(record_sets),
+        ))
+    })(input)
+}
+
 /// Parses a Record Set as part of a `RecordSpecification` record (6.2.73).
 ///
 /// Parsing will always consider record values to be byte-aligned.
 /// Record length is defined in bits, but this function always rounds up to the next full byte.
 /// This is compensated for in the padding.
 pub(crate) fn record_set(input: &[u8]) -> IResult<&[u8], RecordSet> {
-    let (input, record_id) = be_u32(input)?;
-    let record_id = VariableRecordType::from(record_id);
-    let (input, serial_number) = be_u32(input)?;
-    let (input, _padding) = be_u32(input)?;
-    let (input, record_length_bits) = be_u16(input)?;
-    let record_length_bytes = ceil_bits_to_bytes(record_length_bits);
-    let (input, record_count) = be_u16(input)?;
-    let (input, record_values): (&[u8], Vec<&[u8]>) =
-        count(take(record_length_bytes), record_count as usize)(input)?;
-    let record_values = record_values.iter().map(|values| values.to_vec()).collect();
-    let padded_record_length =
-        length_padded_to_num((record_length_bytes * record_count) as usize, EIGHT_OCTETS);
-    let (input, _padding) = take(padded_record_length.padding_length)(input)?;
-
-    Ok((
-        input,
-        RecordSet::default()
-            .with_record_id(record_id)
-            .with_record_serial_number(serial_number)
-            .with_records(record_values),
-    ))
+    context("Record Set", |input| {
+        let (input, record_id) = be_u32(input)?;
+        let record_id = VariableRecordType::from(record_id);
+        let (input, serial_number) = be_u32(input)?;
+        let (input, _padding) = be_u32(input)?;
+        let (input, record_length_bits) = be_u16(input)?;
+        let record_length_bytes = ceil_bits_to_bytes(record_length_bits);
+        let (input, record_count) = be_u16(input)?;
+        let (input, record_values): (&[u8], Vec<&[u8]>) =
+            count(take(record_length_bytes), record_count as usize)(input)?;
+        let record_values = record_values.iter().map(|values| values.to_vec()).collect();
+        let padded_record_length =
+            length_padded_to_num((record_length_bytes * record_count) as usize, EIGHT_OCTETS);
+        let (input, _padding) = take(padded_record_length.padding_length)(input)?;
+
+        Ok((
+            input,
+            RecordSet::default()
+                .with_record_id(record_id)
+                .with_record_serial_number(serial_number)
+                .with_records(record_values),
+        ))
+    })(input)
 }
 
 /// Takes a reference to a buffer and converts the contents into a String,
@@ -858,8 +1233,11 @@ fn ceil_bits_to_bytes(bits: u16) -> u16 {
 
 #[cfg(test)]
 mod tests {
-    use crate::common::errors::DisError;
-    use crate::common::parser::{parse_multiple_header, skip_body};
+    use crate::common::errors::{DisError, PduParseError};
+    use crate::common::model::PduBody;
+    use crate::common::parser::{
+        parse_multiple_header, parse_multiple_pdu, parse_multiple_pdu_lenient, parse_pdu, skip_body,
+    };
     use crate::constants::PDU_HEADER_LEN_BYTES;
     use crate::enumerations::{PduType, ProtocolFamily, ProtocolVersion};
 
@@ -913,6 +1291,22 @@ fn parse_header_unspecified_version() {
         assert_eq!(header.pdu_length, { PDU_HEADER_LEN_BYTES }); // only the header, 0-bytes pdu body
     }
 
+    #[test]
+    fn parse_legacy_version_undefined_pdu_type_as_other() {
+        // DIS v5 header of an IFF PDU (type 28), which is not defined in v5, with a 6 byte body
+        let bytes: [u8; 18] = [
+            0x05, 0x01, 0x1c, 0x06, 0x4e, 0xea, 0x3b, 0x60, 0x00, 0x12, 0x00, 0x00, 0x00, 0x01,
+            0x00, 0x02, 0x00, 0x03,
+        ];
+
+        let pdu = parse_pdu(&bytes).unwrap();
+        assert_eq!(pdu.header.pdu_type, PduType::IFF);
+        let PduBody::Other(body) = pdu.body else {
+            panic!("Expected an Other body");
+        };
+        assert_eq!(body.body, bytes[12..].to_vec());
+    }
+
     #[test]
     fn parse_header_body_too_short() {
         // PDU with header that states that the total length is 208 bytes, but only contains a 2 bytes body;
@@ -979,4 +1373,106 @@ fn skip_body_total_length_cannot_contain_a_header() {
         assert_eq!(input, [0x00, 0x00]);
         assert_eq!(skipped, []);
     }
+
+    #[test]
+    fn parse_pdu_body_error_is_located() {
+        // Entity State PDU of 144 bytes, but only 10 bytes of body are present;
+        // parsing fails in the Entity Type record, at the Country field, at offset 12 + 10 bytes.
+        let bytes: [u8; 22] = [
+            0x06, 0x01, 0x01, 0x01, 0x4e, 0xea, 0x3b, 0x60, 0x00, 0x90, 0x00, 0x00, 0x00, 0x01,
+            0x00, 0x02, 0x00, 0x03, 0x01, 0x00, 0x01, 0x02,
+        ];
+
+        let error = parse_pdu(&bytes).expect_err("Should be Err");
+        assert_eq!(
+            error,
+            DisError::PduParseError(PduParseError {
+                pdu_type: Some(PduType::EntityState),
+                protocol_version: Some(ProtocolVersion::IEEE1278_1A1998),
+                offset: 22,
+                field: "Entity Type",
+                reason: "End of file".to_string(),
+            })
+        );
+        assert_eq!(error.to_string(), "Failed to parse Entity Type of EntityState PDU (IEEE1278_1A1998) at byte offset 22: End of file");
+    }
+
+    #[test]
+    fn parse_pdu_body_record_error_is_named() {
+        // Entity State PDU with only the Entity ID present;
+        // parsing fails in the PDU specific Force ID field, at offset 12 + 6 bytes.
+        let bytes: [u8; 18] = [
+            0x06, 0x01, 0x01, 0x01, 0x4e, 0xea, 0x3b, 0x60, 0x00, 0x90, 0x00, 0x00, 0x00, 0x01,
+            0x00, 0x02, 0x00, 0x03,
+        ];
+
+        let error = parse_pdu(&bytes).expect_err("Should be Err");
+        let DisError::PduParseError(error) = error else {
+            panic!("Expected a PduParseError");
+        };
+        assert_eq!(error.offset, 18);
+        assert_eq!(error.field, "Force ID");
+    }
+
+    #[test]
+    fn parse_pdu_header_error_is_located() {
+        let bytes: [u8; 5] = [0x06, 0x01, 0x01, 0x01, 0x4e];
+
+        let error = parse_multiple_pdu(&bytes).expect_err("Should be Err");
+        let DisError::PduParseError(error) = error else {
+            panic!("Expected a PduParseError");
+        };
+        assert_eq!(error.pdu_type, None);
+        assert_eq!(error.protocol_version, None);
+        assert_eq!(error.offset, 4);
+        assert_eq!(error.field, "Timestamp");
+    }
+
+    #[test]
+    fn parse_multiple_pdu_lenient_skips_malformed_pdu() {
+        // Other PDU (14 bytes), truncated Entity State PDU (20 bytes), Other PDU (14 bytes)
+        let bytes: [u8; 48] = [
+            0x06, 0x01, 0x00, 0x00, 0x4e, 0xea, 0x3b, 0x60, 0x00, 0x0e, 0x00, 0x00, 0x01, 0x02,
+            0x06, 0x01, 0x01, 0x01, 0x4e, 0xea, 0x3b, 0x60, 0x00, 0x14, 0x00, 0x00, 0x00, 0x01,
+            0x00, 0x02, 0x00, 0x03, 0x01, 0x00, 0x06, 0x01, 0x00, 0x00, 0x4e, 0xea, 0x3b, 0x60,
+            0x00, 0x0e, 0x00, 0x00, 0x03, 0x04,
+        ];
+
+        assert!(parse_multiple_pdu(&bytes).is_ok_and(|pdus| pdus.len() == 1));
+
+        let result = parse_multiple_pdu_lenient(&bytes);
+        assert!(!result.is_ok());
+        assert_eq!(result.pdus.len(), 2);
+        assert_eq!(result.pdus[0].header.pdu_type, PduType::Other);
+        assert_eq!(result.pdus[1].header.pdu_type, PduType::Other);
+        assert_eq!(result.errors.len(), 1);
+        let (offset, error) = &result.errors[0];
+        assert_eq!(*offset, 14);
+        let DisError::PduParseError(error) = error else {
+            panic!("Expected a PduParseError");
+        };
+        assert_eq!(error.pdu_type, Some(PduType::EntityState));
+        assert_eq!(error.field, "Entity Type");
+        // the offset of the error is relative to the start of the buffer, at the end of the truncated PDU
+        assert_eq!(error.offset, 14 + 20);
+    }
+
+    #[test]
+    fn parse_multiple_pdu_lenient_stops_at_truncated_pdu() {
+        // Other PDU (14 bytes), followed by a PDU that indicates 208 bytes, but only has 2 bytes of body
+        let bytes: [u8; 28] = [
+            0x06, 0x01, 0x00, 0x00, 0x4e, 0xea, 0x3b, 0x60, 0x00, 0x0e, 0x00, 0x00, 0x01, 0x02,
+            0x06, 0x01, 0x01, 0x01, 0x4e, 0xea, 0x3b, 0x60, 0x00, 0xd0, 0x00, 0x00, 0x01, 0xf4,
+        ];
+
+        let result = parse_multiple_pdu_lenient(&bytes);
+        assert_eq!(result.pdus.len(), 1);
+        assert_eq!(
+            result.errors,
+            vec![(
+                14,
+                DisError::InsufficientPduLength(208 - PDU_HEADER_LEN_BYTES, 2)
+            )]
+        );
+    }
 }
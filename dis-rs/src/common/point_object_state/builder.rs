@@ -0,0 +1,113 @@
+use crate::enumerations::ForceId;
+use crate::model::{
+    EntityId, GeneralObjectAppearance, Location, ObjectStateModification, ObjectType, Orientation,
+    SimulationAddress,
+};
+use crate::point_object_state::model::{PointObjectAppearance, PointObjectState};
+
+pub struct PointObjectStateBuilder(PointObjectState);
+
+impl Default for PointObjectStateBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PointObjectStateBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        PointObjectStateBuilder(PointObjectState::default())
+    }
+
+    #[must_use]
+    pub fn new_from_body(body: PointObjectState) -> Self {
+        PointObjectStateBuilder(body)
+    }
+
+    #[must_use]
+    pub fn build(self) -> PointObjectState {
+        self.0
+    }
+
+    #[must_use]
+    pub fn with_object_id(mut self, object_id: EntityId) -> Self {
+        self.0.object_id = object_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_referenced_object_id(mut self, referenced_object_id: EntityId) -> Self {
+        self.0.referenced_object_id = referenced_object_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_update_number(mut self, update_number: u16) -> Self {
+        self.0.update_number = update_number;
+        self
+    }
+
+    #[must_use]
+    pub fn with_force_id(mut self, force_id: ForceId) -> Self {
+        self.0.force_id = force_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_modifications(mut self, modifications: ObjectStateModification) -> Self {
+        self.0.modifications = modifications;
+        self
+    }
+
+    #[must_use]
+    pub fn with_object_type(mut self, object_type: ObjectType) -> Self {
+        self.0.object_type = object_type;
+        self
+    }
+
+    #[must_use]
+    pub fn with_object_location(mut self, object_location: Location) -> Self {
+        self.0.object_location = object_location;
+        self
+    }
+
+    #[must_use]
+    pub fn with_object_orientation(mut self, object_orientation: Orientation) -> Self {
+        self.0.object_orientation = object_orientation;
+        self
+    }
+
+    #[must_use]
+    pub fn with_specific_appearance(mut self, specific_appearance: u32) -> Self {
+        self.0.specific_appearance = specific_appearance;
+        self
+    }
+
+    /// Sets the specific appearance from a Point Object Appearance record.
+    #[must_use]
+    pub fn with_typed_specific_appearance(
+        mut self,
+        specific_appearance: PointObjectAppearance,
+    ) -> Self {
+        self.0.specific_appearance = u32::from(&specific_appearance);
+        self
+    }
+
+    #[must_use]
+    pub fn with_general_appearance(mut self, general_appearance: GeneralObjectAppearance) -> Self {
+        self.0.general_appearance = general_appearance;
+        self
+    }
+
+    #[must_use]
+    pub fn with_requester_id(mut self, requester_id: SimulationAddress) -> Self {
+        self.0.requester_id = requester_id;
+        self
+    }
+
+    #[must_use]
+    pub fn with_receiving_id(mut self, receiving_id: SimulationAddress) -> Self {
+        self.0.receiving_id = receiving_id;
+        self
+    }
+}
@@ -0,0 +1,116 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::model::DisTimeStamp;
+    use crate::common::model::{Pdu, PduHeader};
+    use crate::common::parser::parse_pdu;
+    use crate::enumerations::{
+        CoupledExtensionIndicator, CraterAppearance, ForceId, GroundBurstAppearance, LvcIndicator,
+        PduType, PlatformDomain,
+    };
+    use crate::model::{
+        EntityId, GeneralObjectAppearance, Location, ObjectKind, ObjectStateModification,
+        ObjectType, Orientation, SimulationAddress,
+    };
+    use crate::point_object_state::model::{PointObjectAppearance, PointObjectState};
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+
+    #[test]
+    fn point_object_state_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::PointObjectState).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::NoStatement)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = PointObjectState::builder()
+            .with_object_id(EntityId::new(10, 10, 100))
+            .with_referenced_object_id(EntityId::new(10, 10, 1))
+            .with_update_number(2)
+            .with_force_id(ForceId::Opposing)
+            .with_modifications(ObjectStateModification::default().with_location())
+            .with_object_type(ObjectType::new(
+                PlatformDomain::Land,
+                ObjectKind::Obstacle,
+                2,
+                1,
+            ))
+            .with_object_location(Location::new(1000.0, 2000.0, 3000.0))
+            .with_object_orientation(Orientation::new(1.0, 0.0, 0.0))
+            .with_specific_appearance(0x0000_00FF)
+            .with_general_appearance(
+                GeneralObjectAppearance::default()
+                    .with_percent_complete(50)
+                    .with_smoking(true),
+            )
+            .with_requester_id(SimulationAddress::new(10, 10))
+            .with_receiving_id(SimulationAddress::new(20, 20))
+            .build()
+            .into_pdu_body();
+
+        let original_pdu =
+            Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+
+    #[test]
+    fn point_object_appearance_from_object_type() {
+        let appearance = 0x0C32_0000u32;
+
+        let ground_burst = ObjectType::new(PlatformDomain::Land, ObjectKind::TacticalSmoke, 2, 0);
+        let typed = PointObjectAppearance::from_bytes(appearance, &ground_burst);
+        assert_eq!(
+            typed,
+            PointObjectAppearance::GroundBurst(GroundBurstAppearance::from(appearance))
+        );
+        assert_eq!(u32::from(&typed), appearance);
+
+        let other = ObjectType::new(PlatformDomain::Land, ObjectKind::CulturalFeature, 1, 0);
+        assert_eq!(
+            PointObjectAppearance::from_bytes(appearance, &other),
+            PointObjectAppearance::Unspecified(appearance)
+        );
+    }
+
+    #[test]
+    fn point_object_state_typed_specific_appearance() {
+        let crater = CraterAppearance::from(0x0A00_0000);
+        let body = PointObjectState::builder()
+            .with_object_type(ObjectType::new(
+                PlatformDomain::Land,
+                ObjectKind::Obstacle,
+                2,
+                1,
+            ))
+            .with_typed_specific_appearance(PointObjectAppearance::Crater(crater))
+            .build();
+
+        assert_eq!(body.specific_appearance, u32::from(crater));
+        assert_eq!(
+            body.typed_specific_appearance(),
+            PointObjectAppearance::Crater(crater)
+        );
+    }
+}
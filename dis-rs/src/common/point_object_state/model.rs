@@ -0,0 +1,132 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::enumerations::{
+    AirBurstAppearance, CraterAppearance, ExhaustSmokeAppearance, ForceId, GroundBurstAppearance,
+    PduType, RibbonBridgeAppearance,
+};
+use crate::model::{
+    EntityId, GeneralObjectAppearance, Location, ObjectKind, ObjectStateModification, ObjectType,
+    Orientation, PduBody, SimulationAddress,
+};
+use crate::point_object_state::builder::PointObjectStateBuilder;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const POINT_OBJECT_STATE_BODY_LENGTH: u16 = 76;
+
+/// 7.10.4 Point Object State PDU
+///
+/// The layout of the `specific_appearance` field depends on the `object_type` of the object.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PointObjectState {
+    pub object_id: EntityId,
+    pub referenced_object_id: EntityId,
+    pub update_number: u16,
+    pub force_id: ForceId,
+    pub modifications: ObjectStateModification,
+    pub object_type: ObjectType,
+    pub object_location: Location,
+    pub object_orientation: Orientation,
+    pub specific_appearance: u32,
+    pub general_appearance: GeneralObjectAppearance,
+    pub requester_id: SimulationAddress,
+    pub receiving_id: SimulationAddress,
+}
+
+impl PointObjectState {
+    #[must_use]
+    pub fn builder() -> PointObjectStateBuilder {
+        PointObjectStateBuilder::new()
+    }
+
+    #[must_use]
+    pub fn into_builder(self) -> PointObjectStateBuilder {
+        PointObjectStateBuilder::new_from_body(self)
+    }
+
+    #[must_use]
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::PointObjectState(self)
+    }
+
+    /// Returns the `specific_appearance` as the Point Object Appearance record
+    /// matching the `object_type` of the object.
+    #[must_use]
+    pub fn typed_specific_appearance(&self) -> PointObjectAppearance {
+        PointObjectAppearance::from_bytes(self.specific_appearance, &self.object_type)
+    }
+}
+
+/// Specific appearance of a Point Object State PDU, being one of the
+/// Point Object Appearance records (UIDs 483-487)
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PointObjectAppearance {
+    Crater(CraterAppearance),
+    RibbonBridge(RibbonBridgeAppearance),
+    AirBurst(AirBurstAppearance),
+    GroundBurst(GroundBurstAppearance),
+    ExhaustSmoke(ExhaustSmokeAppearance),
+    Unspecified(u32),
+}
+
+impl Default for PointObjectAppearance {
+    fn default() -> Self {
+        Self::Unspecified(0)
+    }
+}
+
+impl PointObjectAppearance {
+    /// Interprets the specific appearance `appearance` according to the `object_type` of the object.
+    /// Object types without a specific appearance record result in `PointObjectAppearance::Unspecified`.
+    #[must_use]
+    pub fn from_bytes(appearance: u32, object_type: &ObjectType) -> Self {
+        match (object_type.kind, object_type.category) {
+            (ObjectKind::Obstacle, _) => Self::Crater(CraterAppearance::from(appearance)),
+            (ObjectKind::Passageway, _) => {
+                Self::RibbonBridge(RibbonBridgeAppearance::from(appearance))
+            }
+            (ObjectKind::TacticalSmoke, 1) => Self::AirBurst(AirBurstAppearance::from(appearance)),
+            (ObjectKind::TacticalSmoke, 2) => {
+                Self::GroundBurst(GroundBurstAppearance::from(appearance))
+            }
+            (ObjectKind::TacticalSmoke, 3) => {
+                Self::ExhaustSmoke(ExhaustSmokeAppearance::from(appearance))
+            }
+            (_, _) => Self::Unspecified(appearance),
+        }
+    }
+}
+
+impl From<&PointObjectAppearance> for u32 {
+    fn from(value: &PointObjectAppearance) -> Self {
+        match value {
+            PointObjectAppearance::Crater(appearance) => u32::from(*appearance),
+            PointObjectAppearance::RibbonBridge(appearance) => u32::from(*appearance),
+            PointObjectAppearance::AirBurst(appearance) => u32::from(*appearance),
+            PointObjectAppearance::GroundBurst(appearance) => u32::from(*appearance),
+            PointObjectAppearance::ExhaustSmoke(appearance) => u32::from(*appearance),
+            PointObjectAppearance::Unspecified(appearance) => *appearance,
+        }
+    }
+}
+
+impl BodyInfo for PointObjectState {
+    fn body_length(&self) -> u16 {
+        POINT_OBJECT_STATE_BODY_LENGTH
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::PointObjectState
+    }
+}
+
+impl Interaction for PointObjectState {
+    fn originator(&self) -> Option<&EntityId> {
+        Some(&self.object_id)
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
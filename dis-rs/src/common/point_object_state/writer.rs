@@ -0,0 +1,25 @@
+use crate::common::BodyInfo;
+use crate::point_object_state::model::PointObjectState;
+use crate::{Serialize, SerializePdu, SupportedVersion};
+use bytes::BufMut;
+
+impl SerializePdu for PointObjectState {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
+        self.object_id.serialize(buf);
+        self.referenced_object_id.serialize(buf);
+        buf.put_u16(self.update_number);
+        self.force_id.serialize(buf);
+        self.modifications.serialize(buf);
+        self.object_type.serialize(buf);
+        self.object_location.serialize(buf);
+        self.object_orientation.serialize(buf);
+        buf.put_u32(self.specific_appearance);
+        self.general_appearance.serialize(buf);
+        buf.put_u16(0u16);
+        self.requester_id.serialize(buf);
+        self.receiving_id.serialize(buf);
+        buf.put_u32(0u32);
+
+        self.body_length()
+    }
+}
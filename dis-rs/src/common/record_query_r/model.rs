@@ -6,6 +6,7 @@
 };
 use crate::model::{PduBody, TimeStamp};
 use crate::record_query_r::builder::RecordQueryRBuilder;
+use alloc::vec::Vec;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
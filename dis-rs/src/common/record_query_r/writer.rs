@@ -1,10 +1,10 @@
 use crate::constants::FOUR_OCTETS;
 use crate::record_query_r::model::{RecordQueryR, RecordQuerySpecification};
 use crate::{Serialize, SerializePdu, SupportedVersion};
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 
 impl SerializePdu for RecordQueryR {
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
         let origination_id_bytes = self.originating_id.serialize(buf);
         let receiving_id_bytes = self.receiving_id.serialize(buf);
         buf.put_u32(self.request_id);
@@ -19,7 +19,7 @@ fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for RecordQuerySpecification {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u32(self.record_ids.len() as u32);
         let record_bytes = self
             .record_ids
@@ -1,15 +1,15 @@
 use crate::record_r::model::RecordR;
 use crate::{Serialize, SerializePdu, SupportedVersion};
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 
 impl SerializePdu for RecordR {
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
         let origination_id_bytes = self.originating_id.serialize(buf);
         let receiving_id_bytes = self.receiving_id.serialize(buf);
         buf.put_u32(self.request_id);
         buf.put_u8(self.required_reliability_service.into());
         buf.put_u8(0u8);
-        buf.put_u32(self.event_type.into());
+        buf.put_u16(u32::from(self.event_type) as u16);
         buf.put_u32(self.response_serial_number);
         let record_specification_bytes = self.record_specification.serialize(buf);
 
@@ -1,9 +1,9 @@
 use crate::common::{Serialize, SerializePdu, SupportedVersion};
 use crate::repair_response::model::RepairResponse;
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 
 impl SerializePdu for RepairResponse {
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
         let receiving_id_bytes = self.receiving_id.serialize(buf);
         let repairing_id_bytes = self.repairing_id.serialize(buf);
         buf.put_u8(self.repair_result.into());
@@ -1,16 +1,19 @@
 use crate::common::model::PduBody;
 use crate::common::parser::entity_id;
+use crate::common::parser::IResult;
 use crate::resupply_cancel::model::ResupplyCancel;
-use nom::IResult;
+use nom::error::context;
 
 pub(crate) fn resupply_cancel_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, requesting_id) = entity_id(input)?;
-    let (input, servicing_id) = entity_id(input)?;
+    context("Resupply Cancel Body", |input| {
+        let (input, requesting_id) = entity_id(input)?;
+        let (input, servicing_id) = entity_id(input)?;
 
-    let body = ResupplyCancel::builder()
-        .with_requesting_id(requesting_id)
-        .with_servicing_id(servicing_id)
-        .build();
+        let body = ResupplyCancel::builder()
+            .with_requesting_id(requesting_id)
+            .with_servicing_id(servicing_id)
+            .build();
 
-    Ok((input, body.into_pdu_body()))
+        Ok((input, body.into_pdu_body()))
+    })(input)
 }
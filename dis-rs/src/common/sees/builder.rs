@@ -1,5 +1,6 @@
 use crate::model::EntityId;
 use crate::sees::model::{PropulsionSystemData, VectoringNozzleSystemData, SEES};
+use alloc::vec::Vec;
 
 pub struct SeesBuilder(SEES);
 
@@ -1,9 +1,9 @@
 use crate::sees::model::{PropulsionSystemData, VectoringNozzleSystemData, SEES};
 use crate::{Serialize, SerializePdu, SupportedVersion};
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 
 impl SerializePdu for SEES {
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
         let originating_bytes = self.originating_entity_id.serialize(buf);
         buf.put_u16(self.infrared_signature_representation_index);
         buf.put_u16(self.acoustic_signature_representation_index);
@@ -27,7 +27,7 @@ fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for PropulsionSystemData {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_f32(self.power_setting);
         buf.put_f32(self.engine_rpm);
 
@@ -36,7 +36,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for VectoringNozzleSystemData {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_f32(self.horizontal_deflection_angle);
         buf.put_f32(self.vertical_deflection_angle);
 
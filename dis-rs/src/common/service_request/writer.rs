@@ -1,9 +1,9 @@
 use crate::common::{Serialize, SerializePdu, SupportedVersion};
 use crate::service_request::model::ServiceRequest;
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 
 impl SerializePdu for ServiceRequest {
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
         let requesting_id_bytes = self.requesting_id.serialize(buf);
         let servicing_id_bytes = self.servicing_id.serialize(buf);
         buf.put_u8(self.service_type_requested.into());
@@ -6,6 +6,7 @@
     SignalUserProtocolIdentificationNumber,
 };
 use crate::signal::builder::SignalBuilder;
+use alloc::vec::Vec;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
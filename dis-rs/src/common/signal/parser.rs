@@ -1,42 +1,45 @@
 use crate::common::model::PduBody;
 use crate::common::parser::entity_id;
+use crate::common::parser::IResult;
 use crate::common::signal::model::{EncodingScheme, Signal};
 use crate::constants::ONE_BYTE_IN_BITS;
 use crate::enumerations::{
     SignalEncodingClass, SignalEncodingType, SignalTdlType, SignalUserProtocolIdentificationNumber,
 };
+use nom::error::context;
 use nom::number::complete::{be_u16, be_u32};
-use nom::IResult;
 
 pub(crate) fn signal_body(input: &[u8]) -> IResult<&[u8], PduBody> {
-    let (input, radio_reference_id) = entity_id(input)?;
-    let (input, radio_number) = be_u16(input)?;
-    let (input, encoding_scheme) = be_u16(input)?;
-    let (input, tdl_type) = be_u16(input)?;
-    let tdl_type = SignalTdlType::from(tdl_type);
-    let (input, sample_rate) = be_u32(input)?;
+    context("Signal Body", |input| {
+        let (input, radio_reference_id) = entity_id(input)?;
+        let (input, radio_number) = be_u16(input)?;
+        let (input, encoding_scheme) = be_u16(input)?;
+        let (input, tdl_type) = be_u16(input)?;
+        let tdl_type = SignalTdlType::from(tdl_type);
+        let (input, sample_rate) = be_u32(input)?;
 
-    let (input, data_length_in_bits) = be_u16(input)?;
-    let (input, samples) = be_u16(input)?;
-    let (input, data) =
-        nom::bytes::complete::take(data_length_in_bits / ONE_BYTE_IN_BITS as u16)(input)?;
+        let (input, data_length_in_bits) = be_u16(input)?;
+        let (input, samples) = be_u16(input)?;
+        let (input, data) =
+            nom::bytes::complete::take(data_length_in_bits / ONE_BYTE_IN_BITS as u16)(input)?;
 
-    let encoding_scheme = parse_encoding_scheme(encoding_scheme, data);
+        let encoding_scheme = parse_encoding_scheme(encoding_scheme, data);
 
-    let body = Signal::builder()
-        .with_radio_reference_id(radio_reference_id)
-        .with_radio_number(radio_number)
-        .with_encoding_scheme(encoding_scheme)
-        .with_tdl_type(tdl_type)
-        .with_sample_rate(sample_rate)
-        .with_samples(samples)
-        .with_data(data.to_vec())
-        .build();
+        let body = Signal::builder()
+            .with_radio_reference_id(radio_reference_id)
+            .with_radio_number(radio_number)
+            .with_encoding_scheme(encoding_scheme)
+            .with_tdl_type(tdl_type)
+            .with_sample_rate(sample_rate)
+            .with_samples(samples)
+            .with_data(data.to_vec())
+            .build();
 
-    Ok((input, body.into_pdu_body()))
+        Ok((input, body.into_pdu_body()))
+    })(input)
 }
 
-fn parse_encoding_scheme(encoding_scheme_bytes: u16, data: &[u8]) -> EncodingScheme {
+pub(crate) fn parse_encoding_scheme(encoding_scheme_bytes: u16, data: &[u8]) -> EncodingScheme {
     let encoding_class = encoding_scheme_bytes >> 14;
     let low_bits = encoding_scheme_bytes & 0x3FFF;
     let encoding_class = SignalEncodingClass::from(encoding_class);
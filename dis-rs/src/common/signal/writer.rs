@@ -2,10 +2,10 @@
 use crate::common::signal::model::{EncodingScheme, Signal};
 use crate::common::{Serialize, SerializePdu, SupportedVersion};
 use crate::constants::{FOUR_OCTETS, ONE_BYTE_IN_BITS};
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 
 impl SerializePdu for Signal {
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
         let radio_ref_id_bytes = self.radio_reference_id.serialize(buf);
         buf.put_u16(self.radio_number);
         let encoding_scheme_bytes = self.encoding_scheme.serialize(buf);
@@ -27,7 +27,7 @@ fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
 
 impl Serialize for EncodingScheme {
     #[allow(clippy::match_same_arms)]
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         match self {
             EncodingScheme::EncodedAudio {
                 encoding_class,
@@ -13,6 +13,7 @@
     TransmitterModulationTypeSystem, TransmitterTransmitState, VariableRecordType,
 };
 use crate::transmitter::builder::TransmitterBuilder;
+use alloc::vec::Vec;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -5,10 +5,10 @@
 };
 use crate::common::{Serialize, SerializePdu, SupportedVersion};
 use crate::constants::{EIGHT_OCTETS, ZERO_OCTETS};
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 
 impl SerializePdu for Transmitter {
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
         self.radio_reference_id.serialize(buf);
         buf.put_u16(self.radio_number);
         self.radio_type.serialize(buf);
@@ -68,7 +68,7 @@ fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for ModulationType {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let spread_spectrum_bytes = self.spread_spectrum.serialize(buf);
         let (major_modulation, detail) = self.major_modulation.to_bytes_with_detail();
         buf.put_u16(major_modulation);
@@ -80,14 +80,14 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for SpreadSpectrum {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u16(u16::from(self));
         2
     }
 }
 
 impl Serialize for CryptoKeyId {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let field = self.pseudo_crypto_key << 1;
         let field = match self.crypto_mode {
             CryptoMode::Baseband => field,
@@ -99,7 +99,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for BeamAntennaPattern {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         self.beam_direction.serialize(buf);
         buf.put_f32(self.azimuth_beamwidth);
         buf.put_f32(self.elevation_beamwidth);
@@ -116,7 +116,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for VariableTransmitterParameter {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let record_padded_lengths = length_padded_to_num(
             BASE_VTP_RECORD_LENGTH as usize + self.fields.len(),
             EIGHT_OCTETS,
@@ -0,0 +1,110 @@
+pub mod builder;
+pub mod model;
+pub mod parser;
+pub mod writer;
+
+#[cfg(test)]
+mod tests {
+    use crate::common::model::DisTimeStamp;
+    use crate::common::model::{Pdu, PduHeader};
+    use crate::common::parser::parse_pdu;
+    use crate::entity_state::model::EntityState;
+    use crate::enumerations::{
+        CoupledExtensionIndicator, DeadReckoningAlgorithm, LvcIndicator, PduType,
+    };
+    use crate::model::{
+        EntityId, LiveEntityId, LiveEntityLinearVelocity, LiveEntityOrientation,
+        LiveReferencePoint, Location, Orientation, RelativeWorldCoordinates, VectorF32,
+    };
+    use crate::tspi::model::{
+        LiveDeadReckoningParameters, LiveEntityOrientationError, LiveEntityPositionError, TSPI,
+    };
+    use crate::v7::model::PduStatus;
+    use bytes::BytesMut;
+
+    #[test]
+    fn tspi_internal_consistency() {
+        let header = PduHeader::new_v7(1, PduType::TSPI).with_pdu_status(
+            PduStatus::default()
+                .with_lvc_indicator(LvcIndicator::NoStatement)
+                .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+        );
+
+        let body = TSPI::builder()
+            .with_live_entity_id(LiveEntityId::new(1, 2, 3))
+            .with_entity_location(RelativeWorldCoordinates::new(1, 100, -200, 5))
+            .with_entity_linear_velocity(LiveEntityLinearVelocity::new(10, 20, -30))
+            .with_entity_orientation(LiveEntityOrientation::new(64, 0, 255))
+            .with_position_error(LiveEntityPositionError::new(2, 3))
+            .with_orientation_error(LiveEntityOrientationError::new(1, 2, 3))
+            .with_dead_reckoning_parameters(
+                LiveDeadReckoningParameters::default()
+                    .with_algorithm(DeadReckoningAlgorithm::DRM_FPW_ConstantVelocityLowAccelerationLinearMotionEntity)
+                    .with_linear_acceleration([1, -1, 0]),
+            )
+            .with_measured_speed(22)
+            .with_system_specific_data(vec![1, 2, 3, 4, 5])
+            .build()
+            .into_pdu_body();
+
+        let original_pdu =
+            Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+        let pdu_length = original_pdu.header.pdu_length;
+        let original_length = original_pdu.pdu_length();
+
+        let mut buf = BytesMut::with_capacity(pdu_length as usize);
+
+        let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+        assert_eq!(original_length, serialized_length);
+
+        let parsed = parse_pdu(&buf);
+        match parsed {
+            Ok(ref pdu) => {
+                assert_eq!(&original_pdu, pdu);
+            }
+            Err(ref err) => {
+                panic!("Parse error: {err}");
+            }
+        }
+    }
+
+    #[test]
+    fn tspi_entity_state_conversion() {
+        let reference_point = LiveReferencePoint::new(1, Location::new(1000.0, 2000.0, 3000.0));
+        let entity_state = EntityState::builder()
+            .with_entity_id(EntityId::new(1, 2, 3))
+            .with_location(Location::new(1100.0, 1800.0, 3005.0))
+            .with_velocity(VectorF32::new(1.5, -2.0, 0.0))
+            .with_orientation(Orientation::new(core::f32::consts::FRAC_PI_2, 0.0, 0.0))
+            .build();
+
+        let tspi = TSPI::from_entity_state(&entity_state, &reference_point);
+        assert_eq!(tspi.live_entity_id, LiveEntityId::new(1, 2, 3));
+        assert_eq!(
+            tspi.entity_location,
+            RelativeWorldCoordinates::new(1, 100, -200, 5)
+        );
+        assert_eq!(
+            tspi.entity_linear_velocity,
+            Some(LiveEntityLinearVelocity::new(15, -20, 0))
+        );
+        assert_eq!(
+            tspi.entity_orientation,
+            Some(LiveEntityOrientation::new(64, 0, 0))
+        );
+
+        let mut converted = EntityState::default();
+        tspi.apply_to_entity_state(&mut converted, &reference_point);
+        assert_eq!(converted.entity_id, entity_state.entity_id);
+        assert_eq!(converted.entity_location, entity_state.entity_location);
+        assert_eq!(
+            converted.entity_linear_velocity,
+            entity_state.entity_linear_velocity
+        );
+        assert_eq!(
+            converted.entity_orientation,
+            entity_state.entity_orientation
+        );
+    }
+}
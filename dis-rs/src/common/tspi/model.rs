@@ -0,0 +1,306 @@
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::{FOUR_OCTETS, ONE_OCTET, SIX_OCTETS, TWO_OCTETS};
+use crate::entity_state::model::{DrParameters, EntityState};
+use crate::enumerations::{DeadReckoningAlgorithm, PduType};
+use crate::model::{
+    round, EntityId, LiveEntityId, LiveEntityLinearVelocity, LiveEntityOrientation,
+    LiveReferencePoint, PduBody, RelativeWorldCoordinates, VectorF32,
+};
+use crate::tspi::builder::TSPIBuilder;
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const BASE_TSPI_BODY_LENGTH: u16 = 13;
+const LIVE_DEAD_RECKONING_PARAMETERS_LENGTH: u16 = 7;
+
+/// 9.4.2 Time Space Position Information (TSPI) PDU
+///
+/// The presence of the optional fields is signalled on the wire by the `TSPIFlag` field,
+/// which is derived from the fields that are set.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TSPI {
+    pub live_entity_id: LiveEntityId,
+    pub entity_location: RelativeWorldCoordinates,
+    pub entity_linear_velocity: Option<LiveEntityLinearVelocity>,
+    pub entity_orientation: Option<LiveEntityOrientation>,
+    pub position_error: Option<LiveEntityPositionError>,
+    pub orientation_error: Option<LiveEntityOrientationError>,
+    pub dead_reckoning_parameters: Option<LiveDeadReckoningParameters>,
+    pub measured_speed: Option<u16>,
+    pub system_specific_data: Option<Vec<u8>>,
+}
+
+impl TSPI {
+    #[must_use]
+    pub fn builder() -> TSPIBuilder {
+        TSPIBuilder::new()
+    }
+
+    #[must_use]
+    pub fn into_builder(self) -> TSPIBuilder {
+        TSPIBuilder::new_from_body(self)
+    }
+
+    #[must_use]
+    pub fn into_pdu_body(self) -> PduBody {
+        PduBody::TSPI(self)
+    }
+
+    /// Determines the `TSPIFlag` field based on the optional fields that are present.
+    #[must_use]
+    pub fn flags(&self) -> TSPIFlag {
+        TSPIFlag {
+            entity_linear_velocity_present: self.entity_linear_velocity.is_some(),
+            entity_orientation_present: self.entity_orientation.is_some(),
+            position_error_present: self.position_error.is_some(),
+            orientation_error_present: self.orientation_error.is_some(),
+            dead_reckoning_parameters_present: self.dead_reckoning_parameters.is_some(),
+            measured_speed_present: self.measured_speed.is_some(),
+            system_specific_data_present: self.system_specific_data.is_some(),
+        }
+    }
+
+    /// Creates a TSPI PDU from the spatial information of an `EntityState`, with the location
+    /// expressed relative to `reference_point`.
+    #[must_use]
+    pub fn from_entity_state(
+        entity_state: &EntityState,
+        reference_point: &LiveReferencePoint,
+    ) -> Self {
+        TSPI::builder()
+            .with_live_entity_id(LiveEntityId::from(&entity_state.entity_id))
+            .with_entity_location(RelativeWorldCoordinates::from_location(
+                reference_point,
+                &entity_state.entity_location,
+            ))
+            .with_entity_linear_velocity(LiveEntityLinearVelocity::from_velocity(
+                &entity_state.entity_linear_velocity,
+            ))
+            .with_entity_orientation(LiveEntityOrientation::from_orientation(
+                &entity_state.entity_orientation,
+            ))
+            .with_dead_reckoning_parameters(LiveDeadReckoningParameters::from_dr_parameters(
+                &entity_state.dead_reckoning_parameters,
+            ))
+            .build()
+    }
+
+    /// Updates the spatial information of `entity_state` with the fields present in this TSPI PDU.
+    /// The location is converted to world coordinates using `reference_point`.
+    pub fn apply_to_entity_state(
+        &self,
+        entity_state: &mut EntityState,
+        reference_point: &LiveReferencePoint,
+    ) {
+        entity_state.entity_id = EntityId::from(self.live_entity_id);
+        entity_state.entity_location = self.entity_location.to_location(reference_point);
+        if let Some(velocity) = &self.entity_linear_velocity {
+            entity_state.entity_linear_velocity = velocity.to_velocity();
+        }
+        if let Some(orientation) = &self.entity_orientation {
+            entity_state.entity_orientation = orientation.to_orientation();
+        }
+        if let Some(dr_parameters) = &self.dead_reckoning_parameters {
+            entity_state.dead_reckoning_parameters = dr_parameters.to_dr_parameters();
+        }
+    }
+}
+
+impl BodyInfo for TSPI {
+    fn body_length(&self) -> u16 {
+        BASE_TSPI_BODY_LENGTH
+            + self
+                .entity_linear_velocity
+                .map_or(0, |velocity| velocity.record_length())
+            + self
+                .entity_orientation
+                .map_or(0, |orientation| orientation.record_length())
+            + self.position_error.map_or(0, |error| error.record_length())
+            + self
+                .orientation_error
+                .map_or(0, |error| error.record_length())
+            + self
+                .dead_reckoning_parameters
+                .map_or(0, |parameters| parameters.record_length())
+            + self.measured_speed.map_or(0, |_| TWO_OCTETS as u16)
+            + self
+                .system_specific_data
+                .as_ref()
+                .map_or(0, |data| ONE_OCTET as u16 + data.len() as u16)
+    }
+
+    fn body_type(&self) -> PduType {
+        PduType::TSPI
+    }
+}
+
+impl Interaction for TSPI {
+    fn originator(&self) -> Option<&EntityId> {
+        None
+    }
+
+    fn receiver(&self) -> Option<&EntityId> {
+        None
+    }
+}
+
+/// TSPI Flag field, indicating which optional fields are present in a TSPI PDU
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[allow(clippy::struct_excessive_bools)]
+pub struct TSPIFlag {
+    pub entity_linear_velocity_present: bool,
+    pub entity_orientation_present: bool,
+    pub position_error_present: bool,
+    pub orientation_error_present: bool,
+    pub dead_reckoning_parameters_present: bool,
+    pub measured_speed_present: bool,
+    pub system_specific_data_present: bool,
+}
+
+impl From<u8> for TSPIFlag {
+    fn from(value: u8) -> Self {
+        Self {
+            entity_linear_velocity_present: (value & 0x01) != 0,
+            entity_orientation_present: ((value >> 1) & 0x01) != 0,
+            position_error_present: ((value >> 2) & 0x01) != 0,
+            orientation_error_present: ((value >> 3) & 0x01) != 0,
+            dead_reckoning_parameters_present: ((value >> 4) & 0x01) != 0,
+            measured_speed_present: ((value >> 5) & 0x01) != 0,
+            system_specific_data_present: ((value >> 6) & 0x01) != 0,
+        }
+    }
+}
+
+impl From<TSPIFlag> for u8 {
+    fn from(value: TSPIFlag) -> Self {
+        u8::from(value.entity_linear_velocity_present)
+            | u8::from(value.entity_orientation_present) << 1
+            | u8::from(value.position_error_present) << 2
+            | u8::from(value.orientation_error_present) << 3
+            | u8::from(value.dead_reckoning_parameters_present) << 4
+            | u8::from(value.measured_speed_present) << 5
+            | u8::from(value.system_specific_data_present) << 6
+    }
+}
+
+/// Live Entity Position Error record
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LiveEntityPositionError {
+    pub horizontal_error: u16,
+    pub vertical_error: u16,
+}
+
+impl LiveEntityPositionError {
+    #[must_use]
+    pub fn new(horizontal_error: u16, vertical_error: u16) -> Self {
+        Self {
+            horizontal_error,
+            vertical_error,
+        }
+    }
+
+    #[must_use]
+    pub const fn record_length(&self) -> u16 {
+        FOUR_OCTETS as u16
+    }
+}
+
+/// Live Entity Orientation Error record
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LiveEntityOrientationError {
+    pub azimuth_error: u16,
+    pub elevation_error: u16,
+    pub rotation_error: u16,
+}
+
+impl LiveEntityOrientationError {
+    #[must_use]
+    pub fn new(azimuth_error: u16, elevation_error: u16, rotation_error: u16) -> Self {
+        Self {
+            azimuth_error,
+            elevation_error,
+            rotation_error,
+        }
+    }
+
+    #[must_use]
+    pub const fn record_length(&self) -> u16 {
+        SIX_OCTETS as u16
+    }
+}
+
+/// Live Dead Reckoning Parameters record
+///
+/// Linear acceleration is expressed in decimeters per second squared,
+/// angular velocity in tenths of a radian per second.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LiveDeadReckoningParameters {
+    pub algorithm: DeadReckoningAlgorithm,
+    pub linear_acceleration: [i8; 3],
+    pub angular_velocity: [i8; 3],
+}
+
+impl LiveDeadReckoningParameters {
+    #[must_use]
+    pub fn with_algorithm(mut self, algorithm: DeadReckoningAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    #[must_use]
+    pub fn with_linear_acceleration(mut self, linear_acceleration: [i8; 3]) -> Self {
+        self.linear_acceleration = linear_acceleration;
+        self
+    }
+
+    #[must_use]
+    pub fn with_angular_velocity(mut self, angular_velocity: [i8; 3]) -> Self {
+        self.angular_velocity = angular_velocity;
+        self
+    }
+
+    /// Converts the dead reckoning parameters of an Entity State PDU.
+    /// Values saturate at the bounds of the record; the Other Parameters field is not carried over.
+    #[must_use]
+    pub fn from_dr_parameters(parameters: &DrParameters) -> Self {
+        Self::default()
+            .with_algorithm(parameters.algorithm)
+            .with_linear_acceleration(scale_to_tenths(&parameters.linear_acceleration))
+            .with_angular_velocity(scale_to_tenths(&parameters.angular_velocity))
+    }
+
+    #[must_use]
+    pub fn to_dr_parameters(&self) -> DrParameters {
+        DrParameters::default()
+            .with_algorithm(self.algorithm)
+            .with_linear_acceleration(scale_from_tenths(self.linear_acceleration))
+            .with_angular_velocity(scale_from_tenths(self.angular_velocity))
+    }
+
+    #[must_use]
+    pub const fn record_length(&self) -> u16 {
+        LIVE_DEAD_RECKONING_PARAMETERS_LENGTH
+    }
+}
+
+fn scale_to_tenths(vector: &VectorF32) -> [i8; 3] {
+    [
+        round(f64::from(vector.first_vector_component * 10.0)) as i8,
+        round(f64::from(vector.second_vector_component * 10.0)) as i8,
+        round(f64::from(vector.third_vector_component * 10.0)) as i8,
+    ]
+}
+
+fn scale_from_tenths(values: [i8; 3]) -> VectorF32 {
+    VectorF32::new(
+        f32::from(values[0]) / 10.0,
+        f32::from(values[1]) / 10.0,
+        f32::from(values[2]) / 10.0,
+    )
+}
@@ -0,0 +1,118 @@
+use crate::common::parser::IResult;
+use crate::common::parser::{
+    live_entity_id, live_entity_linear_velocity, live_entity_orientation,
+    relative_world_coordinates,
+};
+use crate::enumerations::DeadReckoningAlgorithm;
+use crate::model::PduBody;
+use crate::tspi::model::{
+    LiveDeadReckoningParameters, LiveEntityOrientationError, LiveEntityPositionError, TSPIFlag,
+    TSPI,
+};
+use alloc::vec::Vec;
+use nom::bytes::complete::take;
+use nom::combinator::cond;
+use nom::error::context;
+use nom::number::complete::{be_i8, be_u16, be_u8};
+
+pub(crate) fn tspi_body(input: &[u8]) -> IResult<&[u8], PduBody> {
+    context("TSPI Body", |input| {
+        let (input, live_entity_id) = live_entity_id(input)?;
+        let (input, flags) = be_u8(input)?;
+        let flags = TSPIFlag::from(flags);
+        let (input, entity_location) = relative_world_coordinates(input)?;
+        let (input, entity_linear_velocity) = cond(
+            flags.entity_linear_velocity_present,
+            live_entity_linear_velocity,
+        )(input)?;
+        let (input, entity_orientation) =
+            cond(flags.entity_orientation_present, live_entity_orientation)(input)?;
+        let (input, position_error) =
+            cond(flags.position_error_present, live_entity_position_error)(input)?;
+        let (input, orientation_error) = cond(
+            flags.orientation_error_present,
+            live_entity_orientation_error,
+        )(input)?;
+        let (input, dead_reckoning_parameters) = cond(
+            flags.dead_reckoning_parameters_present,
+            live_dead_reckoning_parameters,
+        )(input)?;
+        let (input, measured_speed) = cond(flags.measured_speed_present, be_u16)(input)?;
+        let (input, system_specific_data) =
+            cond(flags.system_specific_data_present, system_specific_data)(input)?;
+
+        let body = TSPI {
+            live_entity_id,
+            entity_location,
+            entity_linear_velocity,
+            entity_orientation,
+            position_error,
+            orientation_error,
+            dead_reckoning_parameters,
+            measured_speed,
+            system_specific_data,
+        };
+
+        Ok((input, body.into_pdu_body()))
+    })(input)
+}
+
+fn live_entity_position_error(input: &[u8]) -> IResult<&[u8], LiveEntityPositionError> {
+    context("Live Entity Position Error", |input| {
+        let (input, horizontal_error) = be_u16(input)?;
+        let (input, vertical_error) = be_u16(input)?;
+
+        Ok((
+            input,
+            LiveEntityPositionError::new(horizontal_error, vertical_error),
+        ))
+    })(input)
+}
+
+fn live_entity_orientation_error(input: &[u8]) -> IResult<&[u8], LiveEntityOrientationError> {
+    context("Live Entity Orientation Error", |input| {
+        let (input, azimuth_error) = be_u16(input)?;
+        let (input, elevation_error) = be_u16(input)?;
+        let (input, rotation_error) = be_u16(input)?;
+
+        Ok((
+            input,
+            LiveEntityOrientationError::new(azimuth_error, elevation_error, rotation_error),
+        ))
+    })(input)
+}
+
+fn live_dead_reckoning_parameters(input: &[u8]) -> IResult<&[u8], LiveDeadReckoningParameters> {
+    context("Live Dead Reckoning Parameters", |input| {
+        let (input, algorithm) = be_u8(input)?;
+        let (input, linear_acceleration) = vec3_i8(input)?;
+        let (input, angular_velocity) = vec3_i8(input)?;
+
+        Ok((
+            input,
+            LiveDeadReckoningParameters::default()
+                .with_algorithm(DeadReckoningAlgorithm::from(algorithm))
+                .with_linear_acceleration(linear_acceleration)
+                .with_angular_velocity(angular_velocity),
+        ))
+    })(input)
+}
+
+fn vec3_i8(input: &[u8]) -> IResult<&[u8], [i8; 3]> {
+    context("Vector", |input| {
+        let (input, first) = be_i8(input)?;
+        let (input, second) = be_i8(input)?;
+        let (input, third) = be_i8(input)?;
+
+        Ok((input, [first, second, third]))
+    })(input)
+}
+
+fn system_specific_data(input: &[u8]) -> IResult<&[u8], Vec<u8>> {
+    context("System Specific Data", |input| {
+        let (input, data_length) = be_u8(input)?;
+        let (input, data): (_, &[u8]) = take(data_length)(input)?;
+
+        Ok((input, data.to_vec()))
+    })(input)
+}
@@ -0,0 +1,71 @@
+use crate::common::BodyInfo;
+use crate::tspi::model::{
+    LiveDeadReckoningParameters, LiveEntityOrientationError, LiveEntityPositionError, TSPI,
+};
+use crate::{Serialize, SerializePdu, SupportedVersion};
+use bytes::BufMut;
+
+impl SerializePdu for TSPI {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
+        self.live_entity_id.serialize(buf);
+        buf.put_u8(self.flags().into());
+        self.entity_location.serialize(buf);
+        if let Some(velocity) = &self.entity_linear_velocity {
+            velocity.serialize(buf);
+        }
+        if let Some(orientation) = &self.entity_orientation {
+            orientation.serialize(buf);
+        }
+        if let Some(error) = &self.position_error {
+            error.serialize(buf);
+        }
+        if let Some(error) = &self.orientation_error {
+            error.serialize(buf);
+        }
+        if let Some(parameters) = &self.dead_reckoning_parameters {
+            parameters.serialize(buf);
+        }
+        if let Some(speed) = self.measured_speed {
+            buf.put_u16(speed);
+        }
+        if let Some(data) = &self.system_specific_data {
+            buf.put_u8(data.len() as u8);
+            buf.put_slice(data);
+        }
+
+        self.body_length()
+    }
+}
+
+impl Serialize for LiveEntityPositionError {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        buf.put_u16(self.horizontal_error);
+        buf.put_u16(self.vertical_error);
+
+        self.record_length()
+    }
+}
+
+impl Serialize for LiveEntityOrientationError {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        buf.put_u16(self.azimuth_error);
+        buf.put_u16(self.elevation_error);
+        buf.put_u16(self.rotation_error);
+
+        self.record_length()
+    }
+}
+
+impl Serialize for LiveDeadReckoningParameters {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        buf.put_u8(self.algorithm.into());
+        for value in self.linear_acceleration {
+            buf.put_i8(value);
+        }
+        for value in self.angular_velocity {
+            buf.put_i8(value);
+        }
+
+        self.record_length()
+    }
+}
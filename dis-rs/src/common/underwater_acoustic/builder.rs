@@ -3,6 +3,7 @@
 use crate::underwater_acoustic::model::{
     PropulsionPlantConfiguration, Shaft, UAEmitterSystem, UnderwaterAcoustic, APA,
 };
+use alloc::vec::Vec;
 
 pub struct UnderwaterAcousticBuilder(UnderwaterAcoustic);
 
@@ -8,6 +8,7 @@
 };
 use crate::model::{EntityId, EventId, PduBody, VectorF32};
 use crate::underwater_acoustic::builder::UnderwaterAcousticBuilder;
+use alloc::vec::Vec;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -73,13 +74,8 @@ fn receiver(&self) -> Option<&EntityId> {
     }
 }
 
-/// Implementation of UID 149
-#[derive(Clone, Debug, Default, PartialEq)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct PropulsionPlantConfiguration {
-    pub configuration: UAPropulsionPlantConfiguration,
-    pub hull_mounted_masker: bool,
-}
+/// The UA-Propulsion Plant Configuration record (UID 149) is generated from SISO-REF-010.
+pub use crate::enumerations::PropulsionPlantConfiguration;
 
 impl PropulsionPlantConfiguration {
     #[must_use]
@@ -95,7 +91,7 @@ pub fn with_hull_mounted_masker(mut self, masker_on: bool) -> Self {
     }
 
     #[must_use]
-    pub fn record_length(&self) -> u16 {
+    pub fn record_length(self) -> u16 {
         ONE_OCTET as u16
     }
 }
@@ -4,11 +4,11 @@
     UAFundamentalParameterData, UnderwaterAcoustic, APA,
 };
 use crate::{Serialize, SerializePdu, SupportedVersion};
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 
 impl SerializePdu for UnderwaterAcoustic {
     #[allow(clippy::cast_possible_truncation)]
-    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
+    fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut impl BufMut) -> u16 {
         let _emitter_bytes = self.emitting_entity_id.serialize(buf);
         let _event_id_bytes = self.event_id.serialize(buf);
         buf.put_u8(self.state_change_update_indicator.into());
@@ -39,18 +39,15 @@ fn serialize_pdu(&self, _version: SupportedVersion, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for PropulsionPlantConfiguration {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
-        let configuration: u8 = self.configuration.into();
-        let hull_mounted_masker_on = u8::from(self.hull_mounted_masker);
-        let final_field = (configuration << 1) | hull_mounted_masker_on;
-        buf.put_u8(final_field);
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        buf.put_u8(u8::from(*self));
 
         self.record_length()
     }
 }
 
 impl Serialize for Shaft {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_i16(self.current_rpm);
         buf.put_i16(self.ordered_rpm);
         buf.put_i32(self.rpm_rate_of_change);
@@ -60,7 +57,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for APA {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let parameter: u16 = self.parameter.into();
         let parameter_status: u8 = self.status.into();
         let parameter_index = (parameter << 2) & u16::from(parameter_status);
@@ -73,7 +70,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 
 impl Serialize for UAEmitterSystem {
     #[allow(clippy::cast_possible_truncation)]
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u8(self.record_length() as u8);
         buf.put_u8(self.beams.len() as u8);
         buf.put_u16(0u16);
@@ -89,7 +86,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for AcousticEmitterSystem {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u16(self.acoustic_system_name.into());
         buf.put_u8(self.function.into());
         buf.put_u8(self.acoustic_id_number);
@@ -99,7 +96,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for UABeam {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u8(self.beam_data_length);
         buf.put_u8(self.beam_id_number);
         buf.put_u16(0u16);
@@ -110,7 +107,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for UAFundamentalParameterData {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u16(self.active_emission_parameter_index.into());
         buf.put_u16(self.scan_pattern.into());
         buf.put_f32(self.beam_center_azimuth);
@@ -121,3 +118,25 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
         self.record_length()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::common::Serialize;
+    use crate::enumerations::UAPropulsionPlantConfiguration;
+    use crate::underwater_acoustic::model::PropulsionPlantConfiguration;
+    use bytes::BytesMut;
+
+    #[test]
+    fn write_propulsion_plant_configuration() {
+        let ppc = PropulsionPlantConfiguration {
+            configuration: UAPropulsionPlantConfiguration::from(1u8),
+            hull_mounted_masker: true,
+        };
+        let mut buf = BytesMut::with_capacity(1);
+
+        let written = ppc.serialize(&mut buf);
+
+        assert_eq!(written, 1);
+        assert_eq!(buf.as_ref(), [0b0000_0011]);
+    }
+}
@@ -0,0 +1,1194 @@
+//! Validation of PDUs against the constraints of the standard (IEEE 1278.1).
+//!
+//! The parsers and writers of the crate are lenient: a PDU that does not conform to the standard
+//! is parsed and serialized as good as possible. The `Validate` trait checks a `Pdu` or `PduBody`
+//! for violations of the standard, such as reserved entity ids, invalid markings, PDU lengths that
+//! do not match the contents, and enumeration values outside the SISO-REF-010 range.
+//! All violations are reported as a list of `ValidationFinding`s, which is empty for a conforming PDU.
+use crate::common::aggregate_state::model::{AggregateMarking, AggregateState};
+use crate::common::appearance::model::Appearance;
+use crate::common::areal_object_state::model::ArealObjectState;
+use crate::common::designator::model::Designator;
+use crate::common::detonation::model::Detonation;
+use crate::common::directed_energy_fire::model::DirectedEnergyFire;
+use crate::common::electromagnetic_emission::model::ElectromagneticEmission;
+use crate::common::entity_damage_status::model::EntityDamageStatus;
+use crate::common::entity_state::model::{EntityMarking, EntityState};
+use crate::common::environmental_process::model::EnvironmentalProcess;
+use crate::common::fire::model::Fire;
+use crate::common::iff::model::Iff;
+use crate::common::information_operations_action::model::InformationOperationsAction;
+use crate::common::information_operations_report::model::InformationOperationsReport;
+use crate::common::intercom_control::model::IntercomControl;
+use crate::common::intercom_signal::model::IntercomSignal;
+use crate::common::linear_object_state::model::LinearObjectState;
+use crate::common::minefield_data::model::MinefieldData;
+use crate::common::minefield_query::model::MinefieldQuery;
+use crate::common::minefield_response_nack::model::MinefieldResponseNack;
+use crate::common::minefield_state::model::MinefieldState;
+use crate::common::model::{
+    DescriptorRecord, EntityId, EntityType, Pdu, PduBody, PduHeader, VariableParameter,
+};
+use crate::common::point_object_state::model::PointObjectState;
+use crate::common::receiver::model::Receiver;
+use crate::common::signal::model::Signal;
+use crate::common::transmitter::model::Transmitter;
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::{EIGHT_OCTETS, ELEVEN_OCTETS, ONE_BYTE_IN_BITS, THIRTY_ONE_OCTETS};
+use crate::enumerations::{
+    Country, DeadReckoningAlgorithm, DetonationResult, EntityKind, EntityMarkingCharacterSet,
+    ForceId, IffSystemType, PduType, ProtocolFamily, ProtocolVersion,
+};
+use crate::fixed_parameters::{ALL_APPLIC, ALL_ENTITIES, ALL_SITES, NO_APPLIC, NO_ENTITY, NO_SITE};
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+
+/// The maximum number of Variable Parameter records in a PDU, as the count is an 8-bit field.
+const MAX_VARIABLE_PARAMETERS: usize = u8::MAX as usize;
+/// The maximum number of records counted by an 8-bit field.
+const MAX_U8_COUNT: usize = u8::MAX as usize;
+/// The maximum number of records counted by a 16-bit field.
+const MAX_U16_COUNT: usize = u16::MAX as usize;
+/// The maximum length of data of which the length is given in bits by a 16-bit field, in octets.
+const MAX_U16_BITS_DATA_LENGTH: usize = u16::MAX as usize / ONE_BYTE_IN_BITS;
+
+/// Trait for checking PDUs against the constraints of the standard.
+pub trait Validate {
+    /// Returns all violations of the standard, or an empty `Vec` when none are found.
+    fn validate(&self) -> Vec<ValidationFinding>;
+}
+
+/// A violation of the standard found by `Validate::validate`.
+///
+/// The `field` of a finding names the field of the PDU in which the violation was found.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ValidationFinding {
+    /// The PDU length in the header does not match the length of the contents of the PDU.
+    PduLengthMismatch {
+        header_length: u16,
+        expected_length: u16,
+    },
+    /// The PDU (v7) contains records that are padded to 64-bit boundaries, but its length is not a multiple of 8 octets.
+    NotPaddedToEightOctets { pdu_length: u16 },
+    /// An `EntityId` identifying a single entity uses a reserved value (`NO_SITE`, `NO_APPLIC`, `NO_ENTITY`, `ALL_SITES`, `ALL_APPLIC` or `ALL_ENTITIES`).
+    ReservedEntityId {
+        field: &'static str,
+        entity_id: EntityId,
+    },
+    /// A marking string is longer than the marking field. Lengths are in octets, as encoded in the PDU.
+    MarkingTooLong {
+        field: &'static str,
+        length: usize,
+        max_length: usize,
+    },
+    /// A marking string contains characters that are not part of the marking character set.
+    InvalidMarkingCharacters {
+        field: &'static str,
+        character_set: EntityMarkingCharacterSet,
+    },
+    /// The number of Variable Parameter records does not fit the 8-bit count field.
+    TooManyVariableParameters { field: &'static str, count: usize },
+    /// The number of records of a list does not fit the count field of the list.
+    TooManyRecords {
+        field: &'static str,
+        count: usize,
+        max_count: usize,
+    },
+    /// Data is longer than its length field can express. Lengths are in octets.
+    DataTooLong {
+        field: &'static str,
+        length: usize,
+        max_length: usize,
+    },
+    /// An enumeration has a value outside the range defined in SISO-REF-010.
+    UnspecifiedEnumeration { field: &'static str, value: u32 },
+}
+
+impl Display for ValidationFinding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ValidationFinding::PduLengthMismatch {
+                header_length,
+                expected_length,
+            } => write!(
+                f,
+                "PDU length in header is {header_length} octets, expected {expected_length} octets"
+            ),
+            ValidationFinding::NotPaddedToEightOctets { pdu_length } => write!(
+                f,
+                "PDU length of {pdu_length} octets is not padded to a multiple of 8 octets"
+            ),
+            ValidationFinding::ReservedEntityId { field, entity_id } => {
+                write!(f, "{field} uses reserved entity id {entity_id}")
+            }
+            ValidationFinding::MarkingTooLong {
+                field,
+                length,
+                max_length,
+            }
+            | ValidationFinding::DataTooLong {
+                field,
+                length,
+                max_length,
+            } => write!(
+                f,
+                "{field} is {length} octets long, maximum is {max_length} octets"
+            ),
+            ValidationFinding::InvalidMarkingCharacters {
+                field,
+                character_set,
+            } => write!(
+                f,
+                "{field} contains characters outside character set {character_set}"
+            ),
+            ValidationFinding::TooManyVariableParameters { field, count } => write!(
+                f,
+                "{field} contains {count} records, maximum is {MAX_VARIABLE_PARAMETERS}"
+            ),
+            ValidationFinding::TooManyRecords {
+                field,
+                count,
+                max_count,
+            } => write!(
+                f,
+                "{field} contains {count} records, maximum is {max_count}"
+            ),
+            ValidationFinding::UnspecifiedEnumeration { field, value } => {
+                write!(f, "{field} has unspecified enumeration value {value}")
+            }
+        }
+    }
+}
+
+/// Adds an `UnspecifiedEnumeration` finding when the value of an enumeration is `Unspecified`.
+macro_rules! check_enumeration {
+    ($findings:expr, $field:literal, $enumeration:ident, $value:expr) => {
+        if let $enumeration::Unspecified(value) = $value {
+            $findings.push(ValidationFinding::UnspecifiedEnumeration {
+                field: $field,
+                value: u32::from(value),
+            });
+        }
+    };
+}
+
+impl Validate for Pdu {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = self.header.validate();
+
+        let expected_length = self.pdu_length();
+        if self.header.pdu_length != expected_length {
+            findings.push(ValidationFinding::PduLengthMismatch {
+                header_length: self.header.pdu_length,
+                expected_length,
+            });
+        }
+
+        if self.header.protocol_version == ProtocolVersion::IEEE1278_12012
+            && has_eight_octet_padded_records(self.body.body_type())
+            && usize::from(self.header.pdu_length) % EIGHT_OCTETS != 0
+        {
+            findings.push(ValidationFinding::NotPaddedToEightOctets {
+                pdu_length: self.header.pdu_length,
+            });
+        }
+
+        findings.extend(self.body.validate());
+        findings
+    }
+}
+
+impl Validate for PduHeader {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        check_enumeration!(
+            findings,
+            "Protocol Version",
+            ProtocolVersion,
+            self.protocol_version
+        );
+        check_enumeration!(findings, "PDU Type", PduType, self.pdu_type);
+        check_enumeration!(
+            findings,
+            "Protocol Family",
+            ProtocolFamily,
+            self.protocol_family
+        );
+        findings
+    }
+}
+
+impl Validate for PduBody {
+    #[allow(clippy::too_many_lines)]
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        // Simulation management PDUs may originate from a simulation instead of an entity,
+        // so only the site and application of the originator are checked generically.
+        // PDUs that fully check their own entity id are skipped, so the id is not reported twice.
+        let checks_own_id = matches!(
+            self,
+            PduBody::EntityState(_)
+                | PduBody::EntityStateUpdate(_)
+                | PduBody::AggregateState(_)
+                | PduBody::MinefieldState(_)
+                | PduBody::MinefieldQuery(_)
+                | PduBody::MinefieldData(_)
+                | PduBody::MinefieldResponseNACK(_)
+                | PduBody::EnvironmentalProcess(_)
+                | PduBody::Transmitter(_)
+                | PduBody::Signal(_)
+                | PduBody::IFF(_)
+                | PduBody::Designator(_)
+                | PduBody::ElectromagneticEmission(_)
+                | PduBody::PointObjectState(_)
+                | PduBody::LinearObjectState(_)
+                | PduBody::ArealObjectState(_)
+                | PduBody::DirectedEnergyFire(_)
+                | PduBody::EntityDamageStatus(_)
+                | PduBody::IntercomSignal(_)
+                | PduBody::IntercomControl(_)
+        );
+        if let Some(originator) = self.originator().filter(|_| !checks_own_id) {
+            let address = &originator.simulation_address;
+            if address.site_id == NO_SITE
+                || u32::from(address.site_id) == ALL_SITES
+                || address.application_id == NO_APPLIC
+                || u32::from(address.application_id) == ALL_APPLIC
+                || u32::from(originator.entity_id) == ALL_ENTITIES
+            {
+                findings.push(ValidationFinding::ReservedEntityId {
+                    field: "Originating Entity ID",
+                    entity_id: *originator,
+                });
+            }
+        }
+
+        match self {
+            PduBody::EntityState(body) => findings.extend(body.validate()),
+            PduBody::EntityStateUpdate(body) => {
+                check_entity_id(&mut findings, "Entity ID", body.entity_id);
+                check_variable_parameters(&mut findings, &body.variable_parameters);
+            }
+            PduBody::Fire(body) => findings.extend(body.validate()),
+            PduBody::Detonation(body) => findings.extend(body.validate()),
+            PduBody::AggregateState(body) => findings.extend(body.validate()),
+            PduBody::Appearance(body) => findings.extend(body.validate()),
+            PduBody::ArticulatedParts(body) => {
+                check_variable_parameters(&mut findings, &body.variable_parameters);
+            }
+            PduBody::MinefieldState(body) => findings.extend(body.validate()),
+            PduBody::MinefieldQuery(body) => findings.extend(body.validate()),
+            PduBody::MinefieldData(body) => findings.extend(body.validate()),
+            PduBody::MinefieldResponseNACK(body) => findings.extend(body.validate()),
+            PduBody::EnvironmentalProcess(body) => findings.extend(body.validate()),
+            PduBody::Transmitter(body) => findings.extend(body.validate()),
+            PduBody::Signal(body) => findings.extend(body.validate()),
+            PduBody::Receiver(body) => findings.extend(body.validate()),
+            PduBody::IFF(body) => findings.extend(body.validate()),
+            PduBody::Designator(body) => findings.extend(body.validate()),
+            PduBody::ElectromagneticEmission(body) => findings.extend(body.validate()),
+            PduBody::PointObjectState(body) => findings.extend(body.validate()),
+            PduBody::LinearObjectState(body) => findings.extend(body.validate()),
+            PduBody::ArealObjectState(body) => findings.extend(body.validate()),
+            PduBody::DirectedEnergyFire(body) => findings.extend(body.validate()),
+            PduBody::EntityDamageStatus(body) => findings.extend(body.validate()),
+            PduBody::InformationOperationsAction(body) => findings.extend(body.validate()),
+            PduBody::InformationOperationsReport(body) => findings.extend(body.validate()),
+            PduBody::IntercomSignal(body) => findings.extend(body.validate()),
+            PduBody::IntercomControl(body) => findings.extend(body.validate()),
+            // These bodies have no constraints beyond the generic checks of the originator and PDU length
+            PduBody::Other(_)
+            | PduBody::Collision(_)
+            | PduBody::ServiceRequest(_)
+            | PduBody::ResupplyOffer(_)
+            | PduBody::ResupplyReceived(_)
+            | PduBody::ResupplyCancel(_)
+            | PduBody::RepairComplete(_)
+            | PduBody::RepairResponse(_)
+            | PduBody::CreateEntity(_)
+            | PduBody::RemoveEntity(_)
+            | PduBody::StartResume(_)
+            | PduBody::StopFreeze(_)
+            | PduBody::Acknowledge(_)
+            | PduBody::ActionRequest(_)
+            | PduBody::ActionResponse(_)
+            | PduBody::DataQuery(_)
+            | PduBody::SetData(_)
+            | PduBody::Data(_)
+            | PduBody::EventReport(_)
+            | PduBody::Comment(_)
+            | PduBody::UnderwaterAcoustic(_)
+            | PduBody::SupplementalEmissionEntityState(_)
+            | PduBody::IsGroupOf(_)
+            | PduBody::TransferOwnership(_)
+            | PduBody::IsPartOf(_)
+            | PduBody::GriddedData(_)
+            | PduBody::TSPI(_)
+            | PduBody::LEFire(_)
+            | PduBody::LEDetonation(_)
+            | PduBody::CreateEntityR(_)
+            | PduBody::RemoveEntityR(_)
+            | PduBody::StartResumeR(_)
+            | PduBody::StopFreezeR(_)
+            | PduBody::AcknowledgeR(_)
+            | PduBody::ActionRequestR(_)
+            | PduBody::ActionResponseR(_)
+            | PduBody::DataQueryR(_)
+            | PduBody::SetDataR(_)
+            | PduBody::DataR(_)
+            | PduBody::EventReportR(_)
+            | PduBody::CommentR(_)
+            | PduBody::RecordR(_)
+            | PduBody::SetRecordR(_)
+            | PduBody::RecordQueryR(_)
+            | PduBody::CollisionElastic(_)
+            | PduBody::Attribute(_) => {}
+        }
+        findings
+    }
+}
+
+impl Validate for EntityState {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        check_entity_id(&mut findings, "Entity ID", self.entity_id);
+        check_enumeration!(findings, "Force ID", ForceId, self.force_id);
+        check_entity_type(&mut findings, &self.entity_type);
+        check_enumeration!(
+            findings,
+            "Dead Reckoning Algorithm",
+            DeadReckoningAlgorithm,
+            self.dead_reckoning_parameters.algorithm
+        );
+        check_entity_marking(&mut findings, &self.entity_marking);
+        check_variable_parameters(&mut findings, &self.variable_parameters);
+        findings
+    }
+}
+
+impl Validate for Fire {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        check_descriptor(&mut findings, &self.descriptor);
+        findings
+    }
+}
+
+impl Validate for Detonation {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        check_descriptor(&mut findings, &self.descriptor);
+        check_enumeration!(
+            findings,
+            "Detonation Result",
+            DetonationResult,
+            self.detonation_result
+        );
+        check_variable_parameters(&mut findings, &self.variable_parameters);
+        findings
+    }
+}
+
+impl Validate for AggregateState {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        check_entity_id(&mut findings, "Aggregate ID", self.aggregate_id);
+        check_enumeration!(findings, "Force ID", ForceId, self.force_id);
+        check_aggregate_marking(&mut findings, &self.aggregate_marking);
+        findings
+    }
+}
+
+impl Validate for Appearance {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        if let Some(ForceId::Unspecified(value)) = self.force_id {
+            findings.push(ValidationFinding::UnspecifiedEnumeration {
+                field: "Force ID",
+                value: u32::from(value),
+            });
+        }
+        if let Some(entity_type) = &self.entity_type {
+            check_entity_type(&mut findings, entity_type);
+        }
+        if let Some(entity_marking) = &self.entity_marking {
+            check_entity_marking(&mut findings, entity_marking);
+        }
+        findings
+    }
+}
+
+impl Validate for MinefieldState {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        check_entity_id(&mut findings, "Minefield ID", self.minefield_id);
+        check_enumeration!(findings, "Force ID", ForceId, self.force_id);
+        check_record_count(
+            &mut findings,
+            "Perimeter Points",
+            self.perimeter_points.len(),
+            MAX_U8_COUNT,
+        );
+        check_record_count(
+            &mut findings,
+            "Mine Types",
+            self.mine_types.len(),
+            MAX_U16_COUNT,
+        );
+        findings
+    }
+}
+
+impl Validate for MinefieldQuery {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        check_entity_id(&mut findings, "Minefield ID", self.minefield_id);
+        check_entity_id(
+            &mut findings,
+            "Requesting Entity ID",
+            self.requesting_entity_id,
+        );
+        check_record_count(
+            &mut findings,
+            "Requested Perimeter Points",
+            self.requested_perimeter_points.len(),
+            MAX_U8_COUNT,
+        );
+        check_record_count(
+            &mut findings,
+            "Sensor Types",
+            self.sensor_types.len(),
+            MAX_U8_COUNT,
+        );
+        findings
+    }
+}
+
+impl Validate for MinefieldData {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        check_entity_id(&mut findings, "Minefield ID", self.minefield_id);
+        check_entity_id(
+            &mut findings,
+            "Requesting Entity ID",
+            self.requesting_entity_id,
+        );
+        check_record_count(&mut findings, "Mines", self.mines.len(), MAX_U8_COUNT);
+        check_record_count(
+            &mut findings,
+            "Sensor Types",
+            self.sensor_types.len(),
+            MAX_U8_COUNT,
+        );
+        for mine in &self.mines {
+            check_record_count(
+                &mut findings,
+                "Trip/Detonation Wires",
+                mine.trip_detonation_wires.len(),
+                MAX_U8_COUNT,
+            );
+            for wire in &mine.trip_detonation_wires {
+                check_record_count(
+                    &mut findings,
+                    "Trip/Detonation Wire Vertices",
+                    wire.vertices.len(),
+                    MAX_U8_COUNT,
+                );
+            }
+        }
+        findings
+    }
+}
+
+impl Validate for MinefieldResponseNack {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        check_entity_id(&mut findings, "Minefield ID", self.minefield_id);
+        check_entity_id(
+            &mut findings,
+            "Requesting Entity ID",
+            self.requesting_entity_id,
+        );
+        check_record_count(
+            &mut findings,
+            "Missing PDU Sequence Numbers",
+            self.missing_pdu_sequence_numbers.len(),
+            MAX_U8_COUNT,
+        );
+        findings
+    }
+}
+
+impl Validate for Transmitter {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        check_entity_id(&mut findings, "Radio Reference ID", self.radio_reference_id);
+        if let Some(modulation_parameters) = &self.modulation_parameters {
+            check_data_length(
+                &mut findings,
+                "Modulation Parameters",
+                modulation_parameters.len(),
+                MAX_U8_COUNT,
+            );
+        }
+        check_record_count(
+            &mut findings,
+            "Variable Transmitter Parameters",
+            self.variable_transmitter_parameters.len(),
+            MAX_U16_COUNT,
+        );
+        findings
+    }
+}
+
+impl Validate for Signal {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        check_entity_id(&mut findings, "Radio Reference ID", self.radio_reference_id);
+        check_data_length(
+            &mut findings,
+            "Data",
+            self.data.len(),
+            MAX_U16_BITS_DATA_LENGTH,
+        );
+        findings
+    }
+}
+
+impl Validate for Receiver {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        check_entity_id(&mut findings, "Radio Reference ID", self.radio_reference_id);
+        findings
+    }
+}
+
+impl Validate for Iff {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        check_entity_id(&mut findings, "Emitting Entity ID", self.emitting_entity_id);
+        check_enumeration!(
+            findings,
+            "System Type",
+            IffSystemType,
+            self.system_id.system_type
+        );
+        if let Some(layer_2) = &self.layer_2 {
+            check_record_count(
+                &mut findings,
+                "IFF Fundamental Parameter Data",
+                layer_2.iff_fundamental_parameters.len(),
+                MAX_U16_COUNT,
+            );
+        }
+        let data_records = [
+            self.layer_3.as_ref().map(|layer| &layer.data_records),
+            self.layer_4.as_ref().map(|layer| &layer.data_records),
+            self.layer_5.as_ref().map(|layer| &layer.data_records),
+        ];
+        for data_records in data_records.into_iter().flatten() {
+            check_record_count(
+                &mut findings,
+                "IFF Data Records",
+                data_records.iff_data_records.len(),
+                MAX_U16_COUNT,
+            );
+        }
+        findings
+    }
+}
+
+impl Validate for Designator {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        check_entity_id(
+            &mut findings,
+            "Designating Entity ID",
+            self.designating_entity_id,
+        );
+        check_enumeration!(
+            findings,
+            "Dead Reckoning Algorithm",
+            DeadReckoningAlgorithm,
+            self.dead_reckoning_algorithm
+        );
+        findings
+    }
+}
+
+impl Validate for ElectromagneticEmission {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        check_entity_id(&mut findings, "Emitting Entity ID", self.emitting_entity_id);
+        check_record_count(
+            &mut findings,
+            "Emitter Systems",
+            self.emitter_systems.len(),
+            MAX_U8_COUNT,
+        );
+        for system in &self.emitter_systems {
+            check_record_count(&mut findings, "Beams", system.beams.len(), MAX_U8_COUNT);
+            for beam in &system.beams {
+                check_record_count(
+                    &mut findings,
+                    "Track/Jam Data",
+                    beam.track_jam_data.len(),
+                    MAX_U8_COUNT,
+                );
+            }
+        }
+        findings
+    }
+}
+
+impl Validate for EnvironmentalProcess {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        check_entity_id(
+            &mut findings,
+            "Environmental Process ID",
+            self.environmental_process_id,
+        );
+        check_record_count(
+            &mut findings,
+            "Environment Records",
+            self.environment_records.len(),
+            MAX_U16_COUNT,
+        );
+        for record in &self.environment_records {
+            check_data_length(
+                &mut findings,
+                "Environment Record Data",
+                record.data.data_length(),
+                MAX_U16_BITS_DATA_LENGTH,
+            );
+        }
+        findings
+    }
+}
+
+impl Validate for PointObjectState {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        check_entity_id(&mut findings, "Object ID", self.object_id);
+        check_enumeration!(findings, "Force ID", ForceId, self.force_id);
+        findings
+    }
+}
+
+impl Validate for LinearObjectState {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        check_entity_id(&mut findings, "Object ID", self.object_id);
+        check_enumeration!(findings, "Force ID", ForceId, self.force_id);
+        check_record_count(
+            &mut findings,
+            "Linear Segment Parameters",
+            self.segments.len(),
+            MAX_U8_COUNT,
+        );
+        findings
+    }
+}
+
+impl Validate for ArealObjectState {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        check_entity_id(&mut findings, "Object ID", self.object_id);
+        check_enumeration!(findings, "Force ID", ForceId, self.force_id);
+        check_record_count(
+            &mut findings,
+            "Object Points",
+            self.object_points.len(),
+            MAX_U16_COUNT,
+        );
+        findings
+    }
+}
+
+impl Validate for DirectedEnergyFire {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        check_entity_id(&mut findings, "Firing Entity ID", self.firing_entity_id);
+        check_entity_type(&mut findings, &self.munition_type);
+        check_record_count(
+            &mut findings,
+            "DE Records",
+            self.de_records.len(),
+            MAX_U16_COUNT,
+        );
+        findings
+    }
+}
+
+impl Validate for EntityDamageStatus {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        check_entity_id(&mut findings, "Damaged Entity ID", self.damaged_entity_id);
+        check_record_count(
+            &mut findings,
+            "Damage Description Records",
+            self.damage_description_records.len(),
+            MAX_U16_COUNT,
+        );
+        findings
+    }
+}
+
+impl Validate for InformationOperationsAction {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        check_record_count(
+            &mut findings,
+            "IO Records",
+            self.io_records.len(),
+            MAX_U16_COUNT,
+        );
+        findings
+    }
+}
+
+impl Validate for InformationOperationsReport {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        check_record_count(
+            &mut findings,
+            "IO Records",
+            self.io_records.len(),
+            MAX_U16_COUNT,
+        );
+        findings
+    }
+}
+
+impl Validate for IntercomSignal {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        check_entity_id(
+            &mut findings,
+            "Intercom Reference ID",
+            self.intercom_reference_id,
+        );
+        check_data_length(
+            &mut findings,
+            "Data",
+            self.data.len(),
+            MAX_U16_BITS_DATA_LENGTH,
+        );
+        findings
+    }
+}
+
+impl Validate for IntercomControl {
+    fn validate(&self) -> Vec<ValidationFinding> {
+        let mut findings = Vec::new();
+        check_entity_id(&mut findings, "Source Entity ID", self.source_entity_id);
+        for parameters in &self.intercom_parameters {
+            check_data_length(
+                &mut findings,
+                "Intercom Communications Parameters",
+                parameters.record_specific_field.len(),
+                MAX_U16_COUNT,
+            );
+        }
+        findings
+    }
+}
+
+/// In v7, the variable records of these PDUs are padded to 64-bit boundaries,
+/// with the fixed part of the PDU also being a multiple of 8 octets.
+fn has_eight_octet_padded_records(pdu_type: PduType) -> bool {
+    matches!(
+        pdu_type,
+        PduType::DirectedEnergyFire
+            | PduType::EntityDamageStatus
+            | PduType::InformationOperationsAction
+            | PduType::InformationOperationsReport
+            | PduType::Comment
+            | PduType::EventReport
+            | PduType::ActionRequest
+            | PduType::ActionResponse
+            | PduType::Data
+            | PduType::SetData
+            | PduType::CommentR
+            | PduType::EventReportR
+            | PduType::ActionRequestR
+            | PduType::ActionResponseR
+            | PduType::DataR
+            | PduType::SetDataR
+    )
+}
+
+/// Checks that an `EntityId` identifies a single entity, i.e. uses no reserved values.
+fn check_entity_id(findings: &mut Vec<ValidationFinding>, field: &'static str, id: EntityId) {
+    let address = &id.simulation_address;
+    let is_reserved = |value: u16, none: u16, all: u32| value == none || u32::from(value) == all;
+    if is_reserved(address.site_id, NO_SITE, ALL_SITES)
+        || is_reserved(address.application_id, NO_APPLIC, ALL_APPLIC)
+        || is_reserved(id.entity_id, NO_ENTITY, ALL_ENTITIES)
+    {
+        findings.push(ValidationFinding::ReservedEntityId {
+            field,
+            entity_id: id,
+        });
+    }
+}
+
+fn check_entity_type(findings: &mut Vec<ValidationFinding>, entity_type: &EntityType) {
+    check_enumeration!(findings, "Entity Kind", EntityKind, entity_type.kind);
+    check_enumeration!(findings, "Country", Country, entity_type.country);
+}
+
+fn check_descriptor(findings: &mut Vec<ValidationFinding>, descriptor: &DescriptorRecord) {
+    match descriptor {
+        DescriptorRecord::Munition { entity_type, .. }
+        | DescriptorRecord::Expendable { entity_type } => {
+            check_entity_type(findings, entity_type);
+        }
+        DescriptorRecord::Explosion { .. } => {}
+    }
+}
+
+fn check_entity_marking(findings: &mut Vec<ValidationFinding>, marking: &EntityMarking) {
+    check_marking(
+        findings,
+        "Entity Marking",
+        marking.marking_character_set,
+        &marking.marking_string,
+        ELEVEN_OCTETS,
+    );
+}
+
+fn check_aggregate_marking(findings: &mut Vec<ValidationFinding>, marking: &AggregateMarking) {
+    check_marking(
+        findings,
+        "Aggregate Marking",
+        marking.marking_character_set,
+        &marking.marking_string,
+        THIRTY_ONE_OCTETS,
+    );
+}
+
+fn check_marking(
+    findings: &mut Vec<ValidationFinding>,
+    field: &'static str,
+    character_set: EntityMarkingCharacterSet,
+    marking: &str,
+    max_length: usize,
+) {
+    if marking.len() > max_length {
+        findings.push(ValidationFinding::MarkingTooLong {
+            field,
+            length: marking.len(),
+            max_length,
+        });
+    }
+    match character_set {
+        EntityMarkingCharacterSet::ASCII if !marking.is_ascii() => {
+            findings.push(ValidationFinding::InvalidMarkingCharacters {
+                field,
+                character_set,
+            });
+        }
+        EntityMarkingCharacterSet::Unspecified(value) => {
+            findings.push(ValidationFinding::UnspecifiedEnumeration {
+                field: "Entity Marking Character Set",
+                value: u32::from(value),
+            });
+        }
+        _ => {}
+    }
+}
+
+fn check_variable_parameters(
+    findings: &mut Vec<ValidationFinding>,
+    variable_parameters: &[VariableParameter],
+) {
+    if variable_parameters.len() > MAX_VARIABLE_PARAMETERS {
+        findings.push(ValidationFinding::TooManyVariableParameters {
+            field: "Variable Parameters",
+            count: variable_parameters.len(),
+        });
+    }
+}
+
+fn check_record_count(
+    findings: &mut Vec<ValidationFinding>,
+    field: &'static str,
+    count: usize,
+    max_count: usize,
+) {
+    if count > max_count {
+        findings.push(ValidationFinding::TooManyRecords {
+            field,
+            count,
+            max_count,
+        });
+    }
+}
+
+fn check_data_length(
+    findings: &mut Vec<ValidationFinding>,
+    field: &'static str,
+    length: usize,
+    max_length: usize,
+) {
+    if length > max_length {
+        findings.push(ValidationFinding::DataTooLong {
+            field,
+            length,
+            max_length,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::comment::model::Comment;
+    use crate::common::directed_energy_fire::model::{DEPrecisionAimpoint, DERecord};
+    use crate::common::entity_state::model::DrParameters;
+    use crate::common::environmental_process::model::{
+        EnvironmentRecord, EnvironmentRecordData, UnspecifiedEnvironmentRecord,
+    };
+    use crate::common::model::{PduHeader, PerimeterPoint, VariableDatum};
+    use crate::enumerations::VariableRecordType;
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    fn entity_state() -> EntityState {
+        EntityState::builder()
+            .with_entity_id(EntityId::new(1, 1, 1))
+            .with_force_id(ForceId::Friendly)
+            .with_entity_type(
+                EntityType::default()
+                    .with_kind(EntityKind::Platform)
+                    .with_country(Country::Netherlands_NLD_),
+            )
+            .with_dead_reckoning_parameters(DrParameters::default().with_algorithm(
+                DeadReckoningAlgorithm::DRM_FPW_ConstantVelocityLowAccelerationLinearMotionEntity,
+            ))
+            .with_marking(EntityMarking::new("TANK", EntityMarkingCharacterSet::ASCII))
+            .build()
+    }
+
+    #[test]
+    fn validate_conforming_entity_state() {
+        let pdu = Pdu::finalize_from_parts(
+            PduHeader::new_v7(1, PduType::EntityState),
+            entity_state().into_pdu_body(),
+            0,
+        );
+
+        assert!(pdu.validate().is_empty());
+    }
+
+    #[test]
+    fn validate_entity_state_findings() {
+        let body = entity_state()
+            .into_builder()
+            .with_entity_id(EntityId::new(1, 1, NO_ENTITY))
+            .with_force_id(ForceId::Unspecified(200))
+            .with_marking(EntityMarking::new(
+                "MUCH TOO LONG Ä",
+                EntityMarkingCharacterSet::ASCII,
+            ))
+            .build();
+
+        let findings = body.validate();
+        assert_eq!(findings.len(), 4);
+        assert!(findings.contains(&ValidationFinding::ReservedEntityId {
+            field: "Entity ID",
+            entity_id: EntityId::new(1, 1, 0),
+        }));
+        assert!(
+            findings.contains(&ValidationFinding::UnspecifiedEnumeration {
+                field: "Force ID",
+                value: 200,
+            })
+        );
+        assert!(findings.contains(&ValidationFinding::MarkingTooLong {
+            field: "Entity Marking",
+            length: 16,
+            max_length: 11,
+        }));
+        assert_eq!(
+            ValidationFinding::MarkingTooLong {
+                field: "Entity Marking",
+                length: 16,
+                max_length: 11,
+            }
+            .to_string(),
+            "Entity Marking is 16 octets long, maximum is 11 octets"
+        );
+        assert!(
+            findings.contains(&ValidationFinding::InvalidMarkingCharacters {
+                field: "Entity Marking",
+                character_set: EntityMarkingCharacterSet::ASCII,
+            })
+        );
+    }
+
+    #[test]
+    fn validate_reserved_entity_id_is_reported_once() {
+        let pdu = Pdu::finalize_from_parts(
+            PduHeader::new_v7(1, PduType::EntityState),
+            entity_state()
+                .into_builder()
+                .with_entity_id(EntityId::new(NO_SITE, 1, 1))
+                .build()
+                .into_pdu_body(),
+            0,
+        );
+
+        assert_eq!(
+            pdu.validate(),
+            vec![ValidationFinding::ReservedEntityId {
+                field: "Entity ID",
+                entity_id: EntityId::new(NO_SITE, 1, 1),
+            }]
+        );
+    }
+
+    #[test]
+    fn validate_reserved_originator_id() {
+        let pdu = Pdu::finalize_from_parts(
+            PduHeader::new_v7(1, PduType::Comment),
+            Comment::builder()
+                .with_origination_id(EntityId::new(NO_SITE, 1, 1))
+                .with_receiving_id(EntityId::new(1, 2, 1))
+                .build()
+                .into_pdu_body(),
+            0,
+        );
+
+        assert_eq!(
+            pdu.validate(),
+            vec![ValidationFinding::ReservedEntityId {
+                field: "Originating Entity ID",
+                entity_id: EntityId::new(NO_SITE, 1, 1),
+            }]
+        );
+    }
+
+    #[test]
+    fn validate_pdu_length() {
+        let mut pdu = Pdu::finalize_from_parts(
+            PduHeader::new_v7(1, PduType::Comment),
+            Comment::builder()
+                .with_origination_id(EntityId::new(1, 1, 1))
+                .with_receiving_id(EntityId::new(1, 2, 1))
+                .with_variable_datums(vec![VariableDatum::new(
+                    VariableRecordType::from(1u32),
+                    "abc".to_string().into_bytes(),
+                )])
+                .build()
+                .into_pdu_body(),
+            0,
+        );
+        assert!(pdu.validate().is_empty());
+
+        pdu.header.pdu_length -= 4;
+        let findings = pdu.validate();
+        assert_eq!(
+            findings,
+            vec![
+                ValidationFinding::PduLengthMismatch {
+                    header_length: pdu.pdu_length() - 4,
+                    expected_length: pdu.pdu_length(),
+                },
+                ValidationFinding::NotPaddedToEightOctets {
+                    pdu_length: pdu.pdu_length() - 4,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_minefield_state_findings() {
+        let pdu = Pdu::finalize_from_parts(
+            PduHeader::new_v7(1, PduType::MinefieldState),
+            MinefieldState::builder()
+                .with_minefield_id(EntityId::new(1, 1, NO_ENTITY))
+                .with_perimeter_points(vec![PerimeterPoint::new(0.0, 0.0); 256])
+                .build()
+                .into_pdu_body(),
+            0,
+        );
+
+        assert_eq!(
+            pdu.validate(),
+            vec![
+                ValidationFinding::ReservedEntityId {
+                    field: "Minefield ID",
+                    entity_id: EntityId::new(1, 1, NO_ENTITY),
+                },
+                ValidationFinding::TooManyRecords {
+                    field: "Perimeter Points",
+                    count: 256,
+                    max_count: 255,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_signal_data_too_long() {
+        let body = Signal::builder()
+            .with_radio_reference_id(EntityId::new(1, 1, 1))
+            .with_data(vec![0u8; 8192])
+            .build();
+
+        assert_eq!(
+            body.validate(),
+            vec![ValidationFinding::DataTooLong {
+                field: "Data",
+                length: 8192,
+                max_length: 8191,
+            }]
+        );
+    }
+
+    #[test]
+    fn validate_environment_record_data_too_long() {
+        let body = EnvironmentalProcess::builder()
+            .with_environmental_process_id(EntityId::new(1, 1, 1))
+            .with_environment_record(EnvironmentRecord::new(
+                1,
+                EnvironmentRecordData::Unspecified(UnspecifiedEnvironmentRecord {
+                    record_type: 0,
+                    data: vec![0u8; 8192],
+                }),
+            ))
+            .build();
+
+        assert_eq!(
+            body.validate(),
+            vec![ValidationFinding::DataTooLong {
+                field: "Environment Record Data",
+                length: 8192,
+                max_length: 8191,
+            }]
+        );
+    }
+
+    #[test]
+    fn validate_conforming_directed_energy_fire() {
+        let pdu = Pdu::finalize_from_parts(
+            PduHeader::new_v7(1, PduType::DirectedEnergyFire),
+            DirectedEnergyFire::builder()
+                .with_firing_entity_id(EntityId::new(1, 1, 1))
+                .with_munition_type(
+                    EntityType::default()
+                        .with_kind(EntityKind::Munition)
+                        .with_country(Country::Netherlands_NLD_),
+                )
+                .with_de_record(DERecord::PrecisionAimpoint(DEPrecisionAimpoint::default()))
+                .build()
+                .into_pdu_body(),
+            0,
+        );
+
+        assert!(pdu.validate().is_empty());
+    }
+}
@@ -0,0 +1,517 @@
+//! Conversion of PDUs between DIS version 6 (IEEE 1278.1A-1998) and version 7 (IEEE 1278.1-2012).
+//!
+//! `upgrade_to_v7` converts a v6 PDU to v7. The header gets a `PduStatus` record with the fields that apply
+//! to the PDU type, set to their 'no statement' values, and the capabilities of an Entity State PDU are typed
+//! according to the kind and domain of the entity. PDUs of the legacy versions 4 and 5 are upgraded the same way.
+//!
+//! `downgrade_to_v6` converts a v7 PDU to v6. The `PduStatus` record is replaced by padding, and the records
+//! that v6 does not define are stripped from the body. PDUs of a type that v6 does not define are either
+//! converted to an `Other` PDU holding the serialized body, or dropped, as selected with `V7OnlyPdus`.
+//!
+//! Both conversions report the information of the original PDU that cannot be represented
+//! in the target version as a list of `LostInformation`.
+use crate::common::model::{
+    DescriptorRecord, EntityType, MunitionDescriptor, Pdu, PduBody, PduStatus, VariableParameter,
+};
+use crate::common::other::model::Other;
+use crate::common::transmitter::model::VariableTransmitterParameter;
+use crate::common::{BodyInfo, Interaction};
+use crate::constants::PDU_HEADER_LEN_BYTES;
+use crate::enumerations::{
+    DetonationTypeIndicator, EntityCapabilities, FireTypeIndicator, PduType, ProtocolVersion,
+};
+use crate::v6;
+use crate::v6::entity_state::model::EntityCapabilities as EntityCapabilitiesV6;
+use crate::v7::entity_state::entity_capabilities_from_bytes;
+use crate::v7::parser::parse_pdu_status_fields;
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+use core::mem;
+
+/// Selects what `downgrade_to_v6` does with PDUs of a type that v6 does not define,
+/// such as the Directed Energy Fire and Attribute PDUs.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum V7OnlyPdus {
+    /// Keep the PDU, with the serialized (v7) body in an `Other` body. The PDU type in the header is set to `Other`,
+    /// the original PDU type is reported as `LostInformation::PduBody`.
+    #[default]
+    ConvertToOther,
+    /// Drop the PDU, returning a `DropReason`.
+    Drop,
+}
+
+/// A PDU converted to another protocol version, together with the information that was lost in the conversion.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConvertedPdu {
+    pub pdu: Pdu,
+    /// The information of the original PDU that cannot be represented in the target version.
+    pub lost: Vec<LostInformation>,
+}
+
+impl ConvertedPdu {
+    fn lossless(pdu: Pdu) -> Self {
+        Self {
+            pdu,
+            lost: Vec::new(),
+        }
+    }
+
+    /// Returns true when no information was lost in the conversion.
+    #[must_use]
+    pub fn is_lossless(&self) -> bool {
+        self.lost.is_empty()
+    }
+}
+
+/// Information of a PDU that is lost when converting the PDU to another protocol version.
+///
+/// Each variant holds the original information, so it can be inspected or logged by the caller.
+#[derive(Clone, Debug, PartialEq)]
+pub enum LostInformation {
+    /// The PDU Status record of the header, which v6 does not have.
+    /// Only reported when the record holds other values than the defaults for the PDU type.
+    PduStatus(PduStatus),
+    /// The typed body of a PDU type that v6 does not define. The serialized body is kept in an `Other` body.
+    PduBody(PduType),
+    /// Entity capabilities that cannot be represented in the capabilities record of the target version.
+    EntityCapabilities(EntityCapabilities),
+    /// Variable Parameter records of a type that v6 does not define (Separation, Entity Type and Entity Association).
+    VariableParameters(Vec<VariableParameter>),
+    /// The Variable Transmitter Parameter records of a Transmitter PDU, which v6 does not have.
+    VariableTransmitterParameters(Vec<VariableTransmitterParameter>),
+    /// An Expendable or Explosion Descriptor record, which v6 does not define.
+    /// The record is replaced by a Munition Descriptor record for the same entity type.
+    DescriptorRecord(DescriptorRecord),
+}
+
+impl Display for LostInformation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            LostInformation::PduStatus(_) => write!(f, "PDU Status record of the header"),
+            LostInformation::PduBody(pdu_type) => {
+                write!(f, "typed body of PDU type {pdu_type}, kept as raw bytes")
+            }
+            LostInformation::EntityCapabilities(_) => write!(
+                f,
+                "entity capabilities that have no counterpart in the target version"
+            ),
+            LostInformation::VariableParameters(parameters) => {
+                write!(f, "{} Variable Parameter record(s)", parameters.len())
+            }
+            LostInformation::VariableTransmitterParameters(parameters) => write!(
+                f,
+                "{} Variable Transmitter Parameter record(s)",
+                parameters.len()
+            ),
+            LostInformation::DescriptorRecord(_) => write!(
+                f,
+                "Expendable or Explosion Descriptor record, replaced by a Munition Descriptor record"
+            ),
+        }
+    }
+}
+
+/// The reason for dropping a PDU in a conversion, because it cannot be represented in the target version.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DropReason {
+    /// The PDU type is not defined in v6, and `V7OnlyPdus::Drop` was selected.
+    PduTypeNotDefined(PduType),
+}
+
+impl Display for DropReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DropReason::PduTypeNotDefined(pdu_type) => {
+                write!(f, "PDU type {pdu_type} is not defined in DIS v6")
+            }
+        }
+    }
+}
+
+/// Converts a v6 PDU to v7, returning the converted PDU and the information that was lost.
+///
+/// The `PduStatus` record of the header holds the fields that apply to the PDU type, set to their default
+/// ('no statement') values. The Fire and Detonation Type Indicators are set according to the Descriptor record.
+/// PDUs of the legacy versions 4 and 5 are converted the same way; v7 PDUs are returned unchanged.
+#[must_use]
+pub fn upgrade_to_v7(pdu: Pdu) -> ConvertedPdu {
+    if pdu.header.protocol_version == ProtocolVersion::IEEE1278_12012 {
+        return ConvertedPdu::lossless(pdu);
+    }
+
+    let Pdu {
+        mut header,
+        mut body,
+    } = pdu;
+    let mut lost = Vec::new();
+    let mut pdu_status = default_pdu_status(header.pdu_type);
+
+    match &mut body {
+        PduBody::EntityState(body) => {
+            let typed = typed_capabilities(body.entity_capabilities.into(), &body.entity_type);
+            if EntityCapabilitiesV6::from(typed)
+                != EntityCapabilitiesV6::from(body.entity_capabilities)
+            {
+                lost.push(LostInformation::EntityCapabilities(
+                    body.entity_capabilities,
+                ));
+            }
+            body.entity_capabilities = typed;
+        }
+        PduBody::Fire(body) => {
+            pdu_status = pdu_status.with_fire_type_indicator(match body.descriptor {
+                DescriptorRecord::Expendable { .. } => FireTypeIndicator::Expendable,
+                DescriptorRecord::Munition { .. } | DescriptorRecord::Explosion { .. } => {
+                    FireTypeIndicator::Munition
+                }
+            });
+        }
+        PduBody::Detonation(body) => {
+            pdu_status = pdu_status.with_detonation_type_indicator(match body.descriptor {
+                DescriptorRecord::Munition { .. } => DetonationTypeIndicator::Munition,
+                DescriptorRecord::Expendable { .. } => DetonationTypeIndicator::Expendable,
+                DescriptorRecord::Explosion { .. } => DetonationTypeIndicator::NonmunitionExplosion,
+            });
+        }
+        _ => {}
+    }
+
+    header.protocol_version = ProtocolVersion::IEEE1278_12012;
+    header.pdu_status = Some(pdu_status);
+    header.padding = 0;
+    let header = header.with_length(body.body_length());
+
+    ConvertedPdu {
+        pdu: Pdu { header, body },
+        lost,
+    }
+}
+
+/// Converts a v7 PDU to v6, returning the converted PDU and the information that was lost.
+///
+/// The `PduStatus` record of the header is removed, and records that v6 does not define are stripped
+/// from the body. PDUs of a type that v6 does not define are handled as selected with `v7_only_pdus`.
+/// PDUs of versions 4, 5 and 6 are returned unchanged.
+///
+/// # Errors
+/// Returns a `DropReason` when the PDU type is not defined in v6 and `V7OnlyPdus::Drop` is selected.
+pub fn downgrade_to_v6(pdu: Pdu, v7_only_pdus: V7OnlyPdus) -> Result<ConvertedPdu, DropReason> {
+    if pdu.header.protocol_version != ProtocolVersion::IEEE1278_12012 {
+        return Ok(ConvertedPdu::lossless(pdu));
+    }
+
+    let pdu_type = pdu.header.pdu_type;
+    let is_v7_only = !matches!(pdu.body, PduBody::Other(_)) && !v6::defines_pdu_type(pdu_type);
+    if is_v7_only && v7_only_pdus == V7OnlyPdus::Drop {
+        return Err(DropReason::PduTypeNotDefined(pdu_type));
+    }
+
+    let mut lost = Vec::new();
+    if let Some(pdu_status) = pdu.header.pdu_status {
+        if pdu_status != default_pdu_status(pdu_type) {
+            lost.push(LostInformation::PduStatus(pdu_status));
+        }
+    }
+    let mut body = if is_v7_only {
+        lost.push(LostInformation::PduBody(pdu_type));
+        untyped_body(&pdu)
+    } else {
+        pdu.body
+    };
+    let mut header = pdu.header;
+    if is_v7_only {
+        header.pdu_type = PduType::Other;
+        header.protocol_family = PduType::Other.into();
+    }
+
+    match &mut body {
+        PduBody::EntityState(body) => {
+            // keep the capabilities in the same form as the v6 parser produces them
+            let capabilities =
+                EntityCapabilities::from(EntityCapabilitiesV6::from(body.entity_capabilities));
+            if entity_capabilities_from_bytes(capabilities.into(), &body.entity_type)
+                != body.entity_capabilities
+            {
+                lost.push(LostInformation::EntityCapabilities(
+                    body.entity_capabilities,
+                ));
+            }
+            body.entity_capabilities = capabilities;
+            strip_variable_parameters(&mut body.variable_parameters, &mut lost);
+        }
+        PduBody::EntityStateUpdate(body) => {
+            strip_variable_parameters(&mut body.variable_parameters, &mut lost);
+        }
+        PduBody::ArticulatedParts(body) => {
+            strip_variable_parameters(&mut body.variable_parameters, &mut lost);
+        }
+        PduBody::Fire(body) => strip_descriptor(&mut body.descriptor, &mut lost),
+        PduBody::Detonation(body) => {
+            strip_descriptor(&mut body.descriptor, &mut lost);
+            strip_variable_parameters(&mut body.variable_parameters, &mut lost);
+        }
+        PduBody::Transmitter(body) if !body.variable_transmitter_parameters.is_empty() => {
+            lost.push(LostInformation::VariableTransmitterParameters(mem::take(
+                &mut body.variable_transmitter_parameters,
+            )));
+        }
+        _ => {}
+    }
+
+    header.protocol_version = ProtocolVersion::IEEE1278_1A1998;
+    header.pdu_status = None;
+    header.padding = 0;
+    let header = header.with_length(body.body_length());
+
+    Ok(ConvertedPdu {
+        pdu: Pdu { header, body },
+        lost,
+    })
+}
+
+/// The `PduStatus` record for the PDU type, with all applicable fields set to their default values.
+fn default_pdu_status(pdu_type: PduType) -> PduStatus {
+    parse_pdu_status_fields(pdu_type.into(), 0)
+}
+
+/// Types the v6 capabilities according to the kind and domain of the entity,
+/// by interpreting the capabilities as encoded on the wire.
+fn typed_capabilities(
+    capabilities: EntityCapabilitiesV6,
+    entity_type: &EntityType,
+) -> EntityCapabilities {
+    entity_capabilities_from_bytes(EntityCapabilities::from(capabilities).into(), entity_type)
+}
+
+/// Serializes the body of the PDU into an `Other` body, keeping the originator and receiver of the PDU.
+fn untyped_body(pdu: &Pdu) -> PduBody {
+    let mut buf = Vec::with_capacity(pdu.pdu_length() as usize);
+    pdu.serialize_unchecked(&mut buf);
+    Other {
+        originating_entity_id: pdu.body.originator().copied(),
+        receiving_entity_id: pdu.body.receiver().copied(),
+        body: buf.split_off(PDU_HEADER_LEN_BYTES as usize),
+    }
+    .into_pdu_body()
+}
+
+fn strip_variable_parameters(
+    parameters: &mut Vec<VariableParameter>,
+    lost: &mut Vec<LostInformation>,
+) {
+    let (kept, stripped): (Vec<_>, Vec<_>) =
+        mem::take(parameters).into_iter().partition(|parameter| {
+            matches!(
+                parameter,
+                VariableParameter::Articulated(_)
+                    | VariableParameter::Attached(_)
+                    | VariableParameter::Unspecified(_, _)
+            )
+        });
+    *parameters = kept;
+    if !stripped.is_empty() {
+        lost.push(LostInformation::VariableParameters(stripped));
+    }
+}
+
+fn strip_descriptor(descriptor: &mut DescriptorRecord, lost: &mut Vec<LostInformation>) {
+    if let DescriptorRecord::Expendable { entity_type }
+    | DescriptorRecord::Explosion { entity_type, .. } = descriptor
+    {
+        let munition = DescriptorRecord::new_munition(*entity_type, MunitionDescriptor::default());
+        lost.push(LostInformation::DescriptorRecord(mem::replace(
+            descriptor, munition,
+        )));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::attribute::model::Attribute;
+    use crate::common::detonation::model::Detonation;
+    use crate::common::entity_state::model::EntityState;
+    use crate::common::model::{ArticulatedPart, EntityId, PduHeader, SeparationParameter};
+    use crate::common::remove_entity::model::RemoveEntity;
+    use crate::enumerations::{
+        EntityKind, ExplosiveMaterialCategories, LandPlatformCapabilities, LvcIndicator,
+        PlatformDomain, ProtocolFamily,
+    };
+    use alloc::vec;
+
+    fn remove_entity_pdu(header: PduHeader) -> Pdu {
+        let body = RemoveEntity::builder()
+            .with_origination_id(EntityId::new(1, 1, 1))
+            .with_receiving_id(EntityId::new(2, 2, 2))
+            .with_request_id(5)
+            .build()
+            .into_pdu_body();
+        Pdu::finalize_from_parts(header, body, 0)
+    }
+
+    #[test]
+    fn upgrade_and_downgrade_without_loss() {
+        let v6_pdu = remove_entity_pdu(PduHeader::new_v6(1, PduType::RemoveEntity));
+
+        let upgraded = upgrade_to_v7(v6_pdu.clone());
+        assert!(upgraded.is_lossless());
+        assert_eq!(
+            upgraded.pdu.header.protocol_version,
+            ProtocolVersion::IEEE1278_12012
+        );
+        let pdu_status = upgraded.pdu.header.pdu_status.unwrap();
+        assert_eq!(pdu_status, default_pdu_status(PduType::RemoveEntity));
+        assert!(pdu_status.lvc_indicator.is_some());
+        assert!(pdu_status.coupled_extension_indicator.is_some());
+        assert!(pdu_status.transferred_entity_indicator.is_none());
+
+        let downgraded = downgrade_to_v6(upgraded.pdu, V7OnlyPdus::default()).unwrap();
+        assert!(downgraded.is_lossless());
+        assert_eq!(downgraded.pdu, v6_pdu);
+    }
+
+    #[test]
+    fn upgrade_reports_entity_capabilities() {
+        let entity_type = EntityType::default().with_kind(EntityKind::Other);
+        let body = EntityState::builder()
+            .with_entity_type(entity_type)
+            .with_capabilities_flags(true, false, false, false)
+            .build()
+            .into_pdu_body();
+        let v6_pdu = Pdu::finalize_from_parts(PduHeader::new_v6(1, PduType::EntityState), body, 0);
+        let PduBody::EntityState(original) = &v6_pdu.body else {
+            unreachable!()
+        };
+        let original_capabilities = original.entity_capabilities;
+
+        let upgraded = upgrade_to_v7(v6_pdu);
+        assert_eq!(
+            upgraded.lost,
+            vec![LostInformation::EntityCapabilities(original_capabilities)]
+        );
+        let PduBody::EntityState(entity_state) = &upgraded.pdu.body else {
+            panic!("Expected an Entity State body");
+        };
+        assert_eq!(
+            entity_state.entity_capabilities,
+            EntityCapabilities::Unspecified(0)
+        );
+    }
+
+    #[test]
+    fn downgrade_reports_entity_capabilities() {
+        let entity_type = EntityType::default()
+            .with_kind(EntityKind::Platform)
+            .with_domain(PlatformDomain::Land);
+        let capabilities =
+            EntityCapabilities::LandPlatformEntityCapabilities(LandPlatformCapabilities {
+                fuel_supply: true,
+                sling_loadable: true,
+                ..Default::default()
+            });
+        let body = EntityState::builder()
+            .with_entity_type(entity_type)
+            .with_capabilities(capabilities)
+            .build()
+            .into_pdu_body();
+        let v7_pdu = Pdu::finalize_from_parts(PduHeader::new_v7(1, PduType::EntityState), body, 0);
+
+        let downgraded = downgrade_to_v6(v7_pdu, V7OnlyPdus::default()).unwrap();
+        assert_eq!(
+            downgraded.lost,
+            vec![LostInformation::EntityCapabilities(capabilities)]
+        );
+        let PduBody::EntityState(entity_state) = &downgraded.pdu.body else {
+            panic!("Expected an Entity State body");
+        };
+        assert_eq!(
+            EntityCapabilitiesV6::from(entity_state.entity_capabilities),
+            EntityCapabilitiesV6::default().fuel_supply()
+        );
+    }
+
+    #[test]
+    fn downgrade_reports_pdu_status() {
+        let pdu_status =
+            default_pdu_status(PduType::RemoveEntity).with_lvc_indicator(LvcIndicator::Live);
+        let v7_pdu = remove_entity_pdu(
+            PduHeader::new_v7(1, PduType::RemoveEntity).with_pdu_status(pdu_status),
+        );
+
+        let downgraded = downgrade_to_v6(v7_pdu, V7OnlyPdus::default()).unwrap();
+        assert_eq!(
+            downgraded.lost,
+            vec![LostInformation::PduStatus(pdu_status)]
+        );
+        assert_eq!(downgraded.pdu.header.pdu_status, None);
+        assert_eq!(
+            downgraded.pdu.header.protocol_version,
+            ProtocolVersion::IEEE1278_1A1998
+        );
+    }
+
+    #[test]
+    fn downgrade_strips_v7_only_records() {
+        let entity_type = EntityType::default().with_category(2);
+        let articulated = VariableParameter::Articulated(ArticulatedPart::default());
+        let separation = VariableParameter::Separation(SeparationParameter::default());
+        let body = Detonation::builder()
+            .with_explosion_descriptor(entity_type, ExplosiveMaterialCategories::from(0u16), 10.0)
+            .with_variable_parameters(vec![articulated.clone(), separation.clone()])
+            .build()
+            .into_pdu_body();
+        let v7_pdu = Pdu::finalize_from_parts(PduHeader::new_v7(1, PduType::Detonation), body, 0);
+        let original_descriptor = match &v7_pdu.body {
+            PduBody::Detonation(detonation) => detonation.descriptor.clone(),
+            _ => unreachable!(),
+        };
+
+        let downgraded = downgrade_to_v6(v7_pdu, V7OnlyPdus::default()).unwrap();
+        assert_eq!(
+            downgraded.lost,
+            vec![
+                LostInformation::DescriptorRecord(original_descriptor),
+                LostInformation::VariableParameters(vec![separation]),
+            ]
+        );
+        let PduBody::Detonation(detonation) = &downgraded.pdu.body else {
+            panic!("Expected a Detonation body");
+        };
+        assert_eq!(
+            detonation.descriptor,
+            DescriptorRecord::new_munition(entity_type, MunitionDescriptor::default())
+        );
+        assert_eq!(detonation.variable_parameters, vec![articulated]);
+        assert_eq!(
+            downgraded.pdu.header.pdu_length,
+            downgraded.pdu.pdu_length()
+        );
+    }
+
+    #[test]
+    fn downgrade_v7_only_pdu_type() {
+        let body = Attribute::builder().build().into_pdu_body();
+        let v7_pdu = Pdu::finalize_from_parts(PduHeader::new_v7(1, PduType::Attribute), body, 0);
+        let body_length = v7_pdu.body.body_length();
+
+        let converted = downgrade_to_v6(v7_pdu.clone(), V7OnlyPdus::ConvertToOther).unwrap();
+        assert_eq!(
+            converted.lost,
+            vec![LostInformation::PduBody(PduType::Attribute)]
+        );
+        assert_eq!(converted.pdu.header.pdu_type, PduType::Other);
+        assert_eq!(
+            converted.pdu.header.protocol_family,
+            ProtocolFamily::from(PduType::Other)
+        );
+        let PduBody::Other(other) = &converted.pdu.body else {
+            panic!("Expected an Other body");
+        };
+        assert_eq!(other.body.len(), body_length as usize);
+
+        assert_eq!(
+            downgrade_to_v6(v7_pdu, V7OnlyPdus::Drop),
+            Err(DropReason::PduTypeNotDefined(PduType::Attribute))
+        );
+    }
+}
@@ -0,0 +1,448 @@
+use crate::common::errors::DisError;
+use crate::common::model::{EntityId, EntityType, Location, Pdu, PduHeader, PduStatus};
+use crate::common::parser::{
+    entity_id, entity_type, located_parse_error, parse_header_and_body, parse_pdu, pdu_header,
+    IResult,
+};
+use crate::constants::{EIGHT_OCTETS, PDU_HEADER_LEN_BYTES};
+use crate::enumerations::{PduType, ProtocolFamily, ProtocolVersion};
+use crate::v7::parser::parse_pdu_status_fields;
+
+const PROTOCOL_VERSION_OFFSET: usize = 0;
+const EXERCISE_ID_OFFSET: usize = 1;
+const PDU_TYPE_OFFSET: usize = 2;
+const PROTOCOL_FAMILY_OFFSET: usize = 3;
+const TIME_STAMP_OFFSET: usize = 4;
+const PDU_LENGTH_OFFSET: usize = 8;
+const PDU_STATUS_OFFSET: usize = 10;
+const BODY_OFFSET: usize = PDU_HEADER_LEN_BYTES as usize;
+
+/// Offset of the Entity Type field in the body of an Entity State PDU.
+const ENTITY_STATE_ENTITY_TYPE_OFFSET: usize = 8;
+/// Offset of the Entity Location field in the body of an Entity State PDU.
+const ENTITY_STATE_LOCATION_OFFSET: usize = 36;
+/// Offset of the Entity Location field in the body of an Entity State Update PDU.
+const ENTITY_STATE_UPDATE_LOCATION_OFFSET: usize = 20;
+/// Offset of the Location in World Coordinates field in the body of a Fire PDU.
+const FIRE_LOCATION_OFFSET: usize = 28;
+/// Offset of the Location in World Coordinates field in the body of a Detonation PDU.
+const DETONATION_LOCATION_OFFSET: usize = 36;
+
+/// A borrowed, zero-copy view on a single PDU in a buffer.
+///
+/// The view provides lazy access to the header fields and a selection of common body fields,
+/// reading the values directly from the underlying bytes without allocating.
+/// When the full contents of the PDU are needed, the view can be converted into an owned `Pdu`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PduView<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> PduView<'a> {
+    /// Creates a view on the first PDU in `input`.
+    ///
+    /// Only the presence of a complete header and the length of the PDU are validated;
+    /// any bytes following the PDU (as indicated by the PDU length field) are not part of the view.
+    ///
+    /// # Errors
+    /// Returns a `DisError` when the input is too small to contain a header,
+    /// or when the input is smaller than the PDU length indicated in the header.
+    pub fn new(input: &'a [u8]) -> Result<Self, DisError> {
+        if input.len() < BODY_OFFSET {
+            return Err(DisError::InsufficientHeaderLength(input.len() as u16));
+        }
+        let pdu_length = read_u16(input, PDU_LENGTH_OFFSET) as usize;
+        if pdu_length < BODY_OFFSET || input.len() < pdu_length {
+            return Err(DisError::InsufficientPduLength(
+                pdu_length as u16,
+                input.len() as u16,
+            ));
+        }
+
+        Ok(Self {
+            bytes: &input[..pdu_length],
+        })
+    }
+
+    /// The raw bytes of the complete PDU, header included.
+    #[must_use]
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// The raw bytes of the body of the PDU.
+    #[must_use]
+    pub fn body_bytes(&self) -> &'a [u8] {
+        &self.bytes[BODY_OFFSET..]
+    }
+
+    #[must_use]
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        ProtocolVersion::from(self.bytes[PROTOCOL_VERSION_OFFSET])
+    }
+
+    #[must_use]
+    pub fn exercise_id(&self) -> u8 {
+        self.bytes[EXERCISE_ID_OFFSET]
+    }
+
+    #[must_use]
+    pub fn pdu_type(&self) -> PduType {
+        PduType::from(self.bytes[PDU_TYPE_OFFSET])
+    }
+
+    #[must_use]
+    pub fn protocol_family(&self) -> ProtocolFamily {
+        ProtocolFamily::from(self.bytes[PROTOCOL_FAMILY_OFFSET])
+    }
+
+    #[must_use]
+    pub fn time_stamp(&self) -> u32 {
+        u32::from_be_bytes([
+            self.bytes[TIME_STAMP_OFFSET],
+            self.bytes[TIME_STAMP_OFFSET + 1],
+            self.bytes[TIME_STAMP_OFFSET + 2],
+            self.bytes[TIME_STAMP_OFFSET + 3],
+        ])
+    }
+
+    #[must_use]
+    pub fn pdu_length(&self) -> u16 {
+        read_u16(self.bytes, PDU_LENGTH_OFFSET)
+    }
+
+    /// The PDU Status record of the header, which is only present for DIS v7 and later.
+    #[must_use]
+    pub fn pdu_status(&self) -> Option<PduStatus> {
+        if u8::from(self.protocol_version()) < 7 {
+            return None;
+        }
+        Some(parse_pdu_status_fields(
+            self.bytes[PDU_TYPE_OFFSET],
+            self.bytes[PDU_STATUS_OFFSET],
+        ))
+    }
+
+    /// Parses the complete header of the PDU.
+    ///
+    /// # Errors
+    /// Returns a `DisError` when the header cannot be parsed.
+    pub fn header(&self) -> Result<PduHeader, DisError> {
+        pdu_header(self.bytes)
+            .map(|(_, header)| header)
+            .map_err(|err| located_parse_error(self.bytes, 0, &err))
+    }
+
+    /// The id of the entity that is the subject of the PDU, for PDU types that start their body with an Entity Identifier:
+    /// Entity State, Entity State Update, Fire, Detonation, Collision, Collision-Elastic,
+    /// Electromagnetic Emission, Designator, Transmitter, Signal, Receiver, IFF, Underwater Acoustic,
+    /// SEES, Directed Energy Fire and Entity Damage Status.
+    #[must_use]
+    pub fn entity_id(&self) -> Option<EntityId> {
+        match self.pdu_type() {
+            PduType::EntityState
+            | PduType::EntityStateUpdate
+            | PduType::Fire
+            | PduType::Detonation
+            | PduType::Collision
+            | PduType::CollisionElastic
+            | PduType::ElectromagneticEmission
+            | PduType::Designator
+            | PduType::Transmitter
+            | PduType::Signal
+            | PduType::Receiver
+            | PduType::IFF
+            | PduType::UnderwaterAcoustic
+            | PduType::SupplementalEmissionEntityState
+            | PduType::DirectedEnergyFire
+            | PduType::EntityDamageStatus => self.body_field(0, entity_id),
+            _ => None,
+        }
+    }
+
+    /// The Entity Type of the entity, for Entity State PDUs.
+    #[must_use]
+    pub fn entity_type(&self) -> Option<EntityType> {
+        match self.pdu_type() {
+            PduType::EntityState => self.body_field(ENTITY_STATE_ENTITY_TYPE_OFFSET, entity_type),
+            _ => None,
+        }
+    }
+
+    /// The location in world coordinates, for Entity State, Entity State Update, Fire and Detonation PDUs.
+    #[must_use]
+    pub fn location(&self) -> Option<Location> {
+        let offset = match self.pdu_type() {
+            PduType::EntityState => ENTITY_STATE_LOCATION_OFFSET,
+            PduType::EntityStateUpdate => ENTITY_STATE_UPDATE_LOCATION_OFFSET,
+            PduType::Fire => FIRE_LOCATION_OFFSET,
+            PduType::Detonation => DETONATION_LOCATION_OFFSET,
+            _ => return None,
+        };
+        let location = self.body_bytes().get(offset..offset + 3 * EIGHT_OCTETS)?;
+        Some(Location::new(
+            read_f64(location, 0),
+            read_f64(location, EIGHT_OCTETS),
+            read_f64(location, 2 * EIGHT_OCTETS),
+        ))
+    }
+
+    /// Parses the PDU into an owned `Pdu`.
+    ///
+    /// # Errors
+    /// Returns a `DisError` when the PDU cannot be parsed.
+    pub fn to_pdu(&self) -> Result<Pdu, DisError> {
+        parse_pdu(self.bytes)
+    }
+
+    fn body_field<T>(&self, offset: usize, parser: fn(&[u8]) -> IResult<&[u8], T>) -> Option<T> {
+        let body = self.body_bytes();
+        if offset > body.len() {
+            return None;
+        }
+        parser(&body[offset..]).ok().map(|(_, value)| value)
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for PduView<'a> {
+    type Error = DisError;
+
+    fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> {
+        PduView::new(value)
+    }
+}
+
+impl TryFrom<PduView<'_>> for Pdu {
+    type Error = DisError;
+
+    fn try_from(value: PduView<'_>) -> Result<Self, Self::Error> {
+        value.to_pdu()
+    }
+}
+
+/// Iterator over the PDUs in a buffer (e.g., a datagram), yielding the parsed `PduHeader`
+/// of each PDU together with the raw bytes of its body, without parsing the bodies.
+///
+/// When a PDU cannot be split off, an error is yielded and the iteration ends.
+#[derive(Clone, Debug)]
+pub struct PduSplitter<'a> {
+    remainder: &'a [u8],
+    failed: bool,
+}
+
+impl<'a> PduSplitter<'a> {
+    #[must_use]
+    pub fn new(input: &'a [u8]) -> Self {
+        Self {
+            remainder: input,
+            failed: false,
+        }
+    }
+
+    /// The bytes in the buffer that have not been split off yet.
+    #[must_use]
+    pub fn remainder(&self) -> &'a [u8] {
+        self.remainder
+    }
+}
+
+impl<'a> Iterator for PduSplitter<'a> {
+    type Item = Result<(PduHeader, &'a [u8]), DisError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.remainder.is_empty() {
+            return None;
+        }
+
+        match parse_header_and_body(self.remainder) {
+            Ok((remainder, header, body)) => {
+                self.remainder = remainder;
+                Some(Ok((header, body)))
+            }
+            Err(error) => {
+                self.failed = true;
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> u16 {
+    u16::from_be_bytes([bytes[offset], bytes[offset + 1]])
+}
+
+fn read_f64(bytes: &[u8], offset: usize) -> f64 {
+    let mut value = [0u8; EIGHT_OCTETS];
+    value.copy_from_slice(&bytes[offset..offset + EIGHT_OCTETS]);
+    f64::from_be_bytes(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::common::model::{
+        DisTimeStamp, EntityId, EntityType, Location, Pdu, PduHeader, VectorF32,
+    };
+    use crate::common::view::{PduSplitter, PduView};
+    use crate::constants::PDU_HEADER_LEN_BYTES;
+    use crate::entity_state::model::EntityState;
+    use crate::enumerations::{
+        Country, EntityKind, PduType, PlatformDomain, ProtocolFamily, ProtocolVersion,
+    };
+    use crate::fire::model::Fire;
+    use crate::remove_entity::model::RemoveEntity;
+    use crate::DisError;
+    use bytes::BytesMut;
+
+    fn serialize(pdu: &Pdu) -> BytesMut {
+        let mut buf = BytesMut::with_capacity(pdu.header.pdu_length as usize);
+        pdu.serialize(&mut buf).unwrap();
+        buf
+    }
+
+    #[test]
+    fn view_entity_state() {
+        let entity_type = EntityType::default()
+            .with_kind(EntityKind::Platform)
+            .with_domain(PlatformDomain::Land)
+            .with_country(Country::from(153u16))
+            .with_category(1);
+        let body = EntityState::builder()
+            .with_entity_id(EntityId::new(1, 2, 3))
+            .with_entity_type(entity_type)
+            .with_location(Location::new(1000.0, 2000.0, 3000.0))
+            .with_velocity(VectorF32::new(1.0, 2.0, 3.0))
+            .build()
+            .into_pdu_body();
+        let pdu = Pdu::finalize_from_parts(
+            PduHeader::new_v7(7, PduType::EntityState),
+            body,
+            DisTimeStamp::new_absolute_from_secs(100),
+        );
+        let buf = serialize(&pdu);
+
+        let view = PduView::new(&buf).unwrap();
+        assert_eq!(view.protocol_version(), ProtocolVersion::IEEE1278_12012);
+        assert_eq!(view.exercise_id(), 7);
+        assert_eq!(view.pdu_type(), PduType::EntityState);
+        assert_eq!(
+            view.protocol_family(),
+            ProtocolFamily::EntityInformationInteraction
+        );
+        assert_eq!(view.time_stamp(), pdu.header.time_stamp);
+        assert_eq!(view.pdu_length(), pdu.header.pdu_length);
+        let parsed = view.to_pdu().unwrap();
+        assert_eq!(view.header().unwrap(), parsed.header);
+        assert_eq!(view.pdu_status(), parsed.header.pdu_status);
+        assert_eq!(view.entity_id(), Some(EntityId::new(1, 2, 3)));
+        assert_eq!(view.entity_type(), Some(entity_type));
+        assert_eq!(view.location(), Some(Location::new(1000.0, 2000.0, 3000.0)));
+    }
+
+    #[test]
+    fn view_fire() {
+        let body = Fire::builder()
+            .with_firing_entity_id(EntityId::new(4, 5, 6))
+            .with_location_in_world(Location::new(10.0, 20.0, 30.0))
+            .build()
+            .into_pdu_body();
+        let pdu = Pdu::finalize_from_parts(
+            PduHeader::new_v6(1, PduType::Fire),
+            body,
+            DisTimeStamp::new_absolute_from_secs(100),
+        );
+        let buf = serialize(&pdu);
+
+        let view = PduView::new(&buf).unwrap();
+        assert_eq!(view.pdu_status(), None);
+        assert_eq!(view.entity_id(), Some(EntityId::new(4, 5, 6)));
+        assert_eq!(view.entity_type(), None);
+        assert_eq!(view.location(), Some(Location::new(10.0, 20.0, 30.0)));
+        assert_eq!(Pdu::try_from(view).unwrap(), pdu);
+    }
+
+    #[test]
+    fn view_truncated_body() {
+        let body = EntityState::builder()
+            .with_entity_id(EntityId::new(1, 2, 3))
+            .with_location(Location::new(1000.0, 2000.0, 3000.0))
+            .build()
+            .into_pdu_body();
+        let pdu = Pdu::finalize_from_parts(PduHeader::new_v7(7, PduType::EntityState), body, 0);
+        let mut buf = serialize(&pdu);
+        // cut the body off halfway the Entity Location field
+        let truncated_length = PDU_HEADER_LEN_BYTES + 48;
+        buf.truncate(truncated_length as usize);
+        buf[8..10].copy_from_slice(&truncated_length.to_be_bytes());
+
+        let view = PduView::new(&buf).unwrap();
+        assert_eq!(view.pdu_length(), truncated_length);
+        assert_eq!(view.entity_id(), Some(EntityId::new(1, 2, 3)));
+        assert_eq!(view.location(), None);
+        assert!(view.to_pdu().is_err());
+    }
+
+    #[test]
+    fn view_insufficient_input() {
+        let bytes = [0x07u8, 0x01, 0x01, 0x01, 0x00];
+        assert_eq!(
+            PduView::new(&bytes),
+            Err(DisError::InsufficientHeaderLength(5))
+        );
+
+        let bytes = [
+            0x07u8, 0x01, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x90, 0x00, 0x00,
+        ];
+        assert_eq!(
+            PduView::new(&bytes),
+            Err(DisError::InsufficientPduLength(144, 12))
+        );
+    }
+
+    #[test]
+    fn split_pdus() {
+        let first = Pdu::finalize_from_parts(
+            PduHeader::new_v6(1, PduType::Fire),
+            Fire::builder().build().into_pdu_body(),
+            DisTimeStamp::new_absolute_from_secs(100),
+        );
+        let second = Pdu::finalize_from_parts(
+            PduHeader::new_v6(2, PduType::RemoveEntity),
+            RemoveEntity::builder().build().into_pdu_body(),
+            DisTimeStamp::new_absolute_from_secs(100),
+        );
+        let mut buf = serialize(&first);
+        buf.extend_from_slice(&serialize(&second));
+
+        let mut splitter = PduSplitter::new(&buf);
+        let (header, body) = splitter.next().unwrap().unwrap();
+        assert_eq!(header, first.header);
+        assert_eq!(
+            body.len(),
+            (first.header.pdu_length - PDU_HEADER_LEN_BYTES) as usize
+        );
+        let (header, body) = splitter.next().unwrap().unwrap();
+        assert_eq!(header.exercise_id, 2);
+        assert_eq!(header.pdu_type, PduType::RemoveEntity);
+        assert_eq!(body.len(), 16);
+        assert!(splitter.next().is_none());
+        assert!(splitter.remainder().is_empty());
+    }
+
+    #[test]
+    fn split_pdus_truncated() {
+        let pdu = Pdu::finalize_from_parts(
+            PduHeader::new_v6(1, PduType::RemoveEntity),
+            RemoveEntity::builder().build().into_pdu_body(),
+            DisTimeStamp::new_absolute_from_secs(100),
+        );
+        let buf = serialize(&pdu);
+        let truncated = &buf[..buf.len() - 2];
+
+        let mut splitter = PduSplitter::new(truncated);
+        assert_eq!(
+            splitter.next(),
+            Some(Err(DisError::InsufficientPduLength(16, 14)))
+        );
+        assert!(splitter.next().is_none());
+    }
+}
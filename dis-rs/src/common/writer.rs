@@ -1,3 +1,4 @@
+use crate::common::iff::model::ModeSBasicData;
 use crate::common::model::{
     length_padded_to_num, ArticulatedPart, AttachedPart, BeamData, ClockTime, DescriptorRecord,
     EntityAssociationParameter, EntityId, EntityTypeParameter, EventId, FixedDatum, Location,
@@ -6,14 +7,26 @@
 };
 use crate::common::model::{Pdu, PduBody, PduHeader};
 use crate::common::{Serialize, SerializePdu, SupportedVersion};
-use crate::constants::{EIGHT_OCTETS, FOUR_OCTETS, ONE_BYTE_IN_BITS, PDU_HEADER_LEN_BYTES};
+use crate::constants::{
+    EIGHT_OCTETS, ELEVEN_OCTETS, FOUR_OCTETS, ONE_BYTE_IN_BITS, ONE_OCTET, PDU_HEADER_LEN_BYTES,
+    SIX_OCTETS, THIRTY_ONE_OCTETS,
+};
 use crate::enumerations::{ProtocolVersion, VariableParameterRecordType};
-use crate::model::{RecordSet, RecordSpecification, SupplyQuantity};
+use crate::environmental_process::model::MAX_ENVIRONMENT_RECORD_DATA_LENGTH;
+use crate::model::{
+    CommunicationsNodeId, GeneralObjectAppearance, IOCommunicationsNode, IOEffect, IORecord,
+    LiveEntityId, LiveEntityLinearVelocity, LiveEntityOrientation, LiveEventId,
+    LiveMunitionDescriptor, MinefieldDataFilter, MinefieldSensorType, ObjectStateModification,
+    ObjectType, PerimeterPoint, RecordSet, RecordSpecification, RelativeWorldCoordinates,
+    StandardVariableRecord, SupplyQuantity, IO_COMMUNICATIONS_NODE_RECORD_TYPE,
+    IO_EFFECT_RECORD_TYPE,
+};
 use crate::DisError;
+use alloc::vec::Vec;
 use bytes::{BufMut, BytesMut};
 
 impl Serialize for PduHeader {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u8(self.protocol_version.into());
         buf.put_u8(self.exercise_id);
         buf.put_u8(self.pdu_type.into());
@@ -42,17 +55,59 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 impl Pdu {
     /// Serializes `self` into the buffer.
     ///
-    /// Fails when the capacity of the buffer is smaller than the serialized length of the PDU (header + body).
+    /// Fails when the capacity of the buffer is smaller than the serialized length of the PDU (header + body),
+    /// or when the data of a record does not fit the length field of the record.
     ///
     /// # Errors
     /// Returns a `DisError` when parsing fails
     pub fn serialize(&self, buf: &mut BytesMut) -> Result<u16, DisError> {
+        validate_data_lengths(&self.body)?;
         if self.pdu_length() as usize > buf.capacity() {
             return Err(DisError::InsufficientBufferSize(
                 self.pdu_length(),
                 buf.capacity(),
             ));
         }
+        Ok(self.serialize_unchecked(buf))
+    }
+
+    /// Serializes `self` into the provided slice, without allocating.
+    /// Returns the number of bytes written.
+    ///
+    /// The length of the PDU (header + body, as calculated by `BodyInfo::body_length`) and the string fields
+    /// of the body are validated before anything is written, so fixed buffers can be reused for sending PDUs.
+    ///
+    /// Writing is bounded to the slice: should the body serialize to more bytes than its calculated length,
+    /// the excess is not written and an error is returned instead of panicking.
+    ///
+    /// # Errors
+    /// Returns `DisError::InsufficientBufferSize` when the slice is smaller than the length of the PDU,
+    /// or smaller than the number of bytes the PDU actually serializes to,
+    /// `DisError::StringTooLongError` or `DisError::StringNotAsciiError` when a string field cannot be encoded,
+    /// and `DisError::DataTooLongError` when the data of a record does not fit the length field of the record.
+    pub fn serialize_into(&self, buf: &mut [u8]) -> Result<usize, DisError> {
+        validate_data_lengths(&self.body)?;
+        let pdu_length = self.pdu_length();
+        if pdu_length as usize > buf.len() {
+            return Err(DisError::InsufficientBufferSize(pdu_length, buf.len()));
+        }
+        validate_string_fields(&self.body)?;
+
+        // Bytes that do not fit the slice end up in the (then allocating) overflow `Vec`, instead of panicking.
+        let available = buf.len();
+        let mut writer = buf.chain_mut(Vec::new());
+        self.serialize_unchecked(&mut writer);
+        let overflow = writer.last_ref().len();
+        if overflow > 0 {
+            return Err(DisError::InsufficientBufferSize(
+                u16::try_from(available + overflow).unwrap_or(u16::MAX),
+                available,
+            ));
+        }
+        Ok(available - writer.first_ref().len())
+    }
+
+    pub(crate) fn serialize_unchecked(&self, buf: &mut impl BufMut) -> u16 {
         let header_size = self.header.serialize(buf);
         let version: SupportedVersion = self.header.protocol_version.into();
         let body_size = match &self.body {
@@ -87,26 +142,26 @@ pub fn serialize(&self, buf: &mut BytesMut) -> Result<u16, DisError> {
             PduBody::IFF(body) => body.serialize_pdu(version, buf),
             PduBody::UnderwaterAcoustic(body) => body.serialize_pdu(version, buf),
             PduBody::SupplementalEmissionEntityState(body) => body.serialize_pdu(version, buf),
-            // PduBody::IntercomSignal(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::IntercomControl(body) => { body.serialize_pdu(version, buf) }
+            PduBody::IntercomSignal(body) => body.serialize_pdu(version, buf),
+            PduBody::IntercomControl(body) => body.serialize_pdu(version, buf),
             PduBody::AggregateState(body) => body.serialize_pdu(version, buf),
             PduBody::IsGroupOf(body) => body.serialize_pdu(version, buf),
             PduBody::TransferOwnership(body) => body.serialize_pdu(version, buf),
             PduBody::IsPartOf(body) => body.serialize_pdu(version, buf),
-            // PduBody::MinefieldState(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::MinefieldQuery(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::MinefieldData(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::MinefieldResponseNACK(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::EnvironmentalProcess(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::GriddedData(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::PointObjectState(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::LinearObjectState(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::ArealObjectState(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::TSPI(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::Appearance(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::ArticulatedParts(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::LEFire(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::LEDetonation(body) => { body.serialize_pdu(version, buf) }
+            PduBody::MinefieldState(body) => body.serialize_pdu(version, buf),
+            PduBody::MinefieldQuery(body) => body.serialize_pdu(version, buf),
+            PduBody::MinefieldData(body) => body.serialize_pdu(version, buf),
+            PduBody::MinefieldResponseNACK(body) => body.serialize_pdu(version, buf),
+            PduBody::EnvironmentalProcess(body) => body.serialize_pdu(version, buf),
+            PduBody::GriddedData(body) => body.serialize_pdu(version, buf),
+            PduBody::PointObjectState(body) => body.serialize_pdu(version, buf),
+            PduBody::LinearObjectState(body) => body.serialize_pdu(version, buf),
+            PduBody::ArealObjectState(body) => body.serialize_pdu(version, buf),
+            PduBody::TSPI(body) => body.serialize_pdu(version, buf),
+            PduBody::Appearance(body) => body.serialize_pdu(version, buf),
+            PduBody::ArticulatedParts(body) => body.serialize_pdu(version, buf),
+            PduBody::LEFire(body) => body.serialize_pdu(version, buf),
+            PduBody::LEDetonation(body) => body.serialize_pdu(version, buf),
             PduBody::CreateEntityR(body) => body.serialize_pdu(version, buf),
             PduBody::RemoveEntityR(body) => body.serialize_pdu(version, buf),
             PduBody::StartResumeR(body) => body.serialize_pdu(version, buf),
@@ -124,20 +179,74 @@ pub fn serialize(&self, buf: &mut BytesMut) -> Result<u16, DisError> {
             PduBody::RecordQueryR(body) => body.serialize_pdu(version, buf),
             PduBody::CollisionElastic(body) => body.serialize_pdu(version, buf),
             PduBody::EntityStateUpdate(body) => body.serialize_pdu(version, buf),
-            // PduBody::DirectedEnergyFire(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::EntityDamageStatus(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::InformationOperationsAction(body) => { body.serialize_pdu(version, buf) }
-            // PduBody::InformationOperationsReport(body) => { body.serialize_pdu(version, buf) }
+            PduBody::DirectedEnergyFire(body) => body.serialize_pdu(version, buf),
+            PduBody::EntityDamageStatus(body) => body.serialize_pdu(version, buf),
+            PduBody::InformationOperationsAction(body) => body.serialize_pdu(version, buf),
+            PduBody::InformationOperationsReport(body) => body.serialize_pdu(version, buf),
             PduBody::Attribute(body) => body.serialize_pdu(version, buf),
-            _ => 0,
         };
 
-        Ok(header_size + body_size)
+        header_size + body_size
+    }
+}
+
+/// Checks that the string fields of a PDU body fit the length of their field, and can be encoded.
+fn validate_string_fields(body: &PduBody) -> Result<(), DisError> {
+    match body {
+        PduBody::EntityState(body) => {
+            validate_string_length(&body.entity_marking.marking_string, ELEVEN_OCTETS)
+        }
+        PduBody::Appearance(body) => body.entity_marking.as_ref().map_or(Ok(()), |marking| {
+            validate_string_length(&marking.marking_string, ELEVEN_OCTETS)
+        }),
+        PduBody::AggregateState(body) => {
+            validate_string_length(&body.aggregate_marking.marking_string, THIRTY_ONE_OCTETS)
+        }
+        PduBody::IFF(body) => match body.layer_4.as_ref().map(|layer| &layer.mode_s_basic_data) {
+            Some(ModeSBasicData::Transponder(basic_data)) => {
+                validate_string_length(&basic_data.aircraft_identification, EIGHT_OCTETS)?;
+                if basic_data.aircraft_identification.is_ascii() {
+                    Ok(())
+                } else {
+                    Err(DisError::StringNotAsciiError)
+                }
+            }
+            _ => Ok(()),
+        },
+        _ => Ok(()),
+    }
+}
+
+/// Checks that the data of the records of a PDU body fits the length fields of the records.
+fn validate_data_lengths(body: &PduBody) -> Result<(), DisError> {
+    match body {
+        PduBody::EnvironmentalProcess(body) => {
+            body.environment_records.iter().try_for_each(|record| {
+                let length = record.data.data_length();
+                if length > MAX_ENVIRONMENT_RECORD_DATA_LENGTH {
+                    Err(DisError::DataTooLongError(
+                        length,
+                        MAX_ENVIRONMENT_RECORD_DATA_LENGTH,
+                    ))
+                } else {
+                    Ok(())
+                }
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+fn validate_string_length(value: &str, max_length: usize) -> Result<(), DisError> {
+    if value.len() > max_length {
+        Err(DisError::StringTooLongError)
+    } else {
+        Ok(())
     }
 }
 
 impl Serialize for EntityId {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let num_bytes = self.simulation_address.serialize(buf);
         buf.put_u16(self.entity_id);
         num_bytes + 2
@@ -145,7 +254,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for SimulationAddress {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u16(self.site_id);
         buf.put_u16(self.application_id);
         4
@@ -153,7 +262,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for EventId {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let num_bytes = self.simulation_address.serialize(buf);
         buf.put_u16(self.event_id);
         num_bytes + 2
@@ -161,7 +270,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for VectorF32 {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_f32(self.first_vector_component);
         buf.put_f32(self.second_vector_component);
         buf.put_f32(self.third_vector_component);
@@ -170,7 +279,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for Location {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_f64(self.x_coordinate);
         buf.put_f64(self.y_coordinate);
         buf.put_f64(self.z_coordinate);
@@ -179,7 +288,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for Orientation {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_f32(self.psi);
         buf.put_f32(self.theta);
         buf.put_f32(self.phi);
@@ -188,7 +297,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for DescriptorRecord {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         match self {
             DescriptorRecord::Munition {
                 entity_type,
@@ -219,7 +328,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for MunitionDescriptor {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u16(self.warhead.into());
         buf.put_u16(self.fuse.into());
         buf.put_u16(self.quantity);
@@ -229,7 +338,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for ClockTime {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_i32(self.hour);
         buf.put_u32(self.time_past_hour);
         8
@@ -237,7 +346,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for FixedDatum {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u32(self.datum_id.into());
         buf.put_u32(self.datum_value);
 
@@ -247,7 +356,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 
 impl Serialize for VariableDatum {
     #[allow(clippy::cast_possible_truncation)]
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         const SIXTY_FOUR_BITS: usize = 64;
         let data_length_bits: usize = self.datum_value.len() * 8;
         let padded_record_bits = length_padded_to_num(data_length_bits, SIXTY_FOUR_BITS);
@@ -265,7 +374,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for VariableParameter {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         match self {
             VariableParameter::Articulated(inner) => {
                 buf.put_u8(VariableParameterRecordType::ArticulatedPart.into());
@@ -297,7 +406,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for ArticulatedPart {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u8(self.change_indicator.into());
         buf.put_u16(self.attachment_id);
         let type_class: u32 = self.type_class.into();
@@ -311,7 +420,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for AttachedPart {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u8(self.detached_indicator.into());
         buf.put_u16(self.attachment_id);
         buf.put_u32(self.parameter_type.into());
@@ -322,7 +431,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for SeparationParameter {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u8(self.reason.into());
         buf.put_u8(self.pre_entity_indicator.into());
         buf.put_u8(0u8);
@@ -336,7 +445,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for EntityTypeParameter {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u8(self.change_indicator.into());
         self.entity_type.serialize(buf);
         buf.put_u16(0u16);
@@ -347,7 +456,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for EntityAssociationParameter {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u8(self.change_indicator.into());
         buf.put_u8(self.association_status.into());
         buf.put_u8(self.association_type.into());
@@ -362,7 +471,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for BeamData {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_f32(self.azimuth_center);
         buf.put_f32(self.azimuth_sweep);
         buf.put_f32(self.elevation_center);
@@ -374,7 +483,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for SupplyQuantity {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let type_bytes = self.supply_type.serialize(buf);
         buf.put_f32(self.quantity);
 
@@ -382,8 +491,184 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
     }
 }
 
+impl Serialize for PerimeterPoint {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        buf.put_f32(self.x);
+        buf.put_f32(self.y);
+
+        self.record_length()
+    }
+}
+
+impl Serialize for MinefieldDataFilter {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        buf.put_u32((*self).into());
+
+        self.record_length()
+    }
+}
+
+impl Serialize for MinefieldSensorType {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        buf.put_u16((*self).into());
+
+        self.record_length()
+    }
+}
+
+impl Serialize for ObjectType {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        buf.put_u8(self.domain.into());
+        buf.put_u8(self.kind.into());
+        buf.put_u8(self.category);
+        buf.put_u8(self.subcategory);
+
+        self.record_length()
+    }
+}
+
+impl Serialize for ObjectStateModification {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        buf.put_u8((*self).into());
+
+        ONE_OCTET as u16
+    }
+}
+
+impl Serialize for GeneralObjectAppearance {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        buf.put_u16((*self).into());
+
+        self.record_length()
+    }
+}
+
+impl Serialize for LiveEntityId {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        buf.put_u8(self.site_number);
+        buf.put_u8(self.application_number);
+        buf.put_u16(self.entity_number);
+
+        self.record_length()
+    }
+}
+
+impl Serialize for LiveEventId {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        buf.put_u8(self.site_number);
+        buf.put_u8(self.application_number);
+        buf.put_u16(self.event_number);
+
+        self.record_length()
+    }
+}
+
+impl Serialize for RelativeWorldCoordinates {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        buf.put_u16(self.reference_point);
+        buf.put_i16(self.delta_x);
+        buf.put_i16(self.delta_y);
+        buf.put_i16(self.delta_z);
+
+        self.record_length()
+    }
+}
+
+impl Serialize for LiveEntityOrientation {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        buf.put_u8(self.psi);
+        buf.put_u8(self.theta);
+        buf.put_u8(self.phi);
+
+        self.record_length()
+    }
+}
+
+impl Serialize for LiveEntityLinearVelocity {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        buf.put_i16(self.x_component);
+        buf.put_i16(self.y_component);
+        buf.put_i16(self.z_component);
+
+        self.record_length()
+    }
+}
+
+impl Serialize for LiveMunitionDescriptor {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        self.munition_type.serialize(buf);
+        buf.put_u16(self.warhead.into());
+        buf.put_u16(self.fuse.into());
+        buf.put_u8(self.quantity);
+        buf.put_u8(self.rate);
+
+        self.record_length()
+    }
+}
+
+impl Serialize for StandardVariableRecord {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        let padded_lengths = length_padded_to_num(SIX_OCTETS + self.data.len(), EIGHT_OCTETS);
+        let record_length = padded_lengths.record_length as u16;
+
+        buf.put_u32(self.record_type);
+        buf.put_u16(record_length);
+        buf.put_slice(&self.data);
+        buf.put_bytes(0u8, padded_lengths.padding_length);
+
+        record_length
+    }
+}
+
+impl Serialize for CommunicationsNodeId {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        self.entity_id.serialize(buf);
+        buf.put_u16(self.element_id);
+
+        self.record_length()
+    }
+}
+
+impl Serialize for IORecord {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        match self {
+            IORecord::CommunicationsNode(record) => record.serialize(buf),
+            IORecord::Effect(record) => record.serialize(buf),
+            IORecord::Unspecified(record) => record.serialize(buf),
+        }
+    }
+}
+
+impl Serialize for IOCommunicationsNode {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        buf.put_u32(IO_COMMUNICATIONS_NODE_RECORD_TYPE);
+        buf.put_u16(self.record_length());
+        buf.put_u8(self.communications_node_type.into());
+        buf.put_u8(0u8);
+        self.communications_node_id.serialize(buf);
+
+        self.record_length()
+    }
+}
+
+impl Serialize for IOEffect {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
+        buf.put_u32(IO_EFFECT_RECORD_TYPE);
+        buf.put_u16(self.record_length());
+        buf.put_u8(self.status.into());
+        buf.put_u8(self.link_type.into());
+        buf.put_u8(self.effect.into());
+        buf.put_u8(self.effect_duty_cycle);
+        buf.put_u16(self.effect_duration);
+        buf.put_u16(self.process.into());
+        buf.put_u16(0u16);
+
+        self.record_length()
+    }
+}
+
 impl Serialize for RecordSpecification {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u32(self.record_sets.len() as u32);
         let record_sets_bytes: u16 = self
             .record_sets
@@ -396,7 +681,7 @@ fn serialize(&self, buf: &mut BytesMut) -> u16 {
 }
 
 impl Serialize for RecordSet {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         buf.put_u32(self.record_id.into());
         buf.put_u32(self.record_serial_number);
         buf.put_u32(0u32);
@@ -472,4 +757,150 @@ fn serialize_header_v7_with_status() {
         ];
         assert_eq!(buf.as_ref(), expected.as_ref());
     }
+
+    #[test]
+    #[allow(clippy::too_many_lines)]
+    fn serialize_into_all_body_types() {
+        use crate::common::model::Pdu;
+        use crate::common::BodyInfo;
+        use crate::DisError;
+        use crate::{
+            acknowledge::model::Acknowledge, acknowledge_r::model::AcknowledgeR,
+            action_request::model::ActionRequest, action_request_r::model::ActionRequestR,
+            action_response::model::ActionResponse, action_response_r::model::ActionResponseR,
+            aggregate_state::model::AggregateState, appearance::model::Appearance,
+            areal_object_state::model::ArealObjectState,
+            articulated_parts::model::ArticulatedParts, attribute::model::Attribute,
+            collision::model::Collision, collision_elastic::model::CollisionElastic,
+            comment::model::Comment, comment_r::model::CommentR,
+            create_entity::model::CreateEntity, create_entity_r::model::CreateEntityR,
+            data::model::Data, data_query::model::DataQuery, data_query_r::model::DataQueryR,
+            data_r::model::DataR, designator::model::Designator, detonation::model::Detonation,
+            directed_energy_fire::model::DirectedEnergyFire,
+            electromagnetic_emission::model::ElectromagneticEmission,
+            entity_damage_status::model::EntityDamageStatus, entity_state::model::EntityState,
+            entity_state_update::model::EntityStateUpdate,
+            environmental_process::model::EnvironmentalProcess, event_report::model::EventReport,
+            event_report_r::model::EventReportR, fire::model::Fire,
+            gridded_data::model::GriddedData, iff::model::Iff,
+            information_operations_action::model::InformationOperationsAction,
+            information_operations_report::model::InformationOperationsReport,
+            intercom_control::model::IntercomControl, intercom_signal::model::IntercomSignal,
+            is_group_of::model::IsGroupOf, is_part_of::model::IsPartOf,
+            le_detonation::model::LEDetonation, le_fire::model::LEFire,
+            linear_object_state::model::LinearObjectState, minefield_data::model::MinefieldData,
+            minefield_query::model::MinefieldQuery,
+            minefield_response_nack::model::MinefieldResponseNack,
+            minefield_state::model::MinefieldState, other::model::Other,
+            point_object_state::model::PointObjectState, receiver::model::Receiver,
+            record_query_r::model::RecordQueryR, record_r::model::RecordR,
+            remove_entity::model::RemoveEntity, remove_entity_r::model::RemoveEntityR,
+            repair_complete::model::RepairComplete, repair_response::model::RepairResponse,
+            resupply_cancel::model::ResupplyCancel, resupply_offer::model::ResupplyOffer,
+            resupply_received::model::ResupplyReceived, sees::model::SEES,
+            service_request::model::ServiceRequest, set_data::model::SetData,
+            set_data_r::model::SetDataR, set_record_r::model::SetRecordR, signal::model::Signal,
+            start_resume::model::StartResume, start_resume_r::model::StartResumeR,
+            stop_freeze::model::StopFreeze, stop_freeze_r::model::StopFreezeR,
+            transfer_ownership::model::TransferOwnership, transmitter::model::Transmitter,
+            tspi::model::TSPI, underwater_acoustic::model::UnderwaterAcoustic,
+        };
+
+        let bodies = vec![
+            Other::default().into_pdu_body(),
+            EntityState::default().into_pdu_body(),
+            Fire::default().into_pdu_body(),
+            Detonation::default().into_pdu_body(),
+            Collision::default().into_pdu_body(),
+            ServiceRequest::default().into_pdu_body(),
+            ResupplyOffer::default().into_pdu_body(),
+            ResupplyReceived::default().into_pdu_body(),
+            ResupplyCancel::default().into_pdu_body(),
+            RepairComplete::default().into_pdu_body(),
+            RepairResponse::default().into_pdu_body(),
+            CreateEntity::default().into_pdu_body(),
+            RemoveEntity::default().into_pdu_body(),
+            StartResume::default().into_pdu_body(),
+            StopFreeze::default().into_pdu_body(),
+            Acknowledge::default().into_pdu_body(),
+            ActionRequest::default().into_pdu_body(),
+            ActionResponse::default().into_pdu_body(),
+            DataQuery::default().into_pdu_body(),
+            SetData::default().into_pdu_body(),
+            Data::default().into_pdu_body(),
+            EventReport::default().into_pdu_body(),
+            Comment::default().into_pdu_body(),
+            ElectromagneticEmission::default().into_pdu_body(),
+            Designator::default().into_pdu_body(),
+            Transmitter::default().into_pdu_body(),
+            Signal::default().into_pdu_body(),
+            Receiver::default().into_pdu_body(),
+            Iff::default().into_pdu_body(),
+            UnderwaterAcoustic::default().into_pdu_body(),
+            SEES::default().into_pdu_body(),
+            IntercomSignal::default().into_pdu_body(),
+            IntercomControl::default().into_pdu_body(),
+            AggregateState::default().into_pdu_body(),
+            IsGroupOf::default().into_pdu_body(),
+            TransferOwnership::default().into_pdu_body(),
+            IsPartOf::default().into_pdu_body(),
+            MinefieldState::default().into_pdu_body(),
+            MinefieldQuery::default().into_pdu_body(),
+            MinefieldData::default().into_pdu_body(),
+            MinefieldResponseNack::default().into_pdu_body(),
+            EnvironmentalProcess::default().into_pdu_body(),
+            GriddedData::default().into_pdu_body(),
+            PointObjectState::default().into_pdu_body(),
+            LinearObjectState::default().into_pdu_body(),
+            ArealObjectState::default().into_pdu_body(),
+            TSPI::default().into_pdu_body(),
+            Appearance::default().into_pdu_body(),
+            ArticulatedParts::default().into_pdu_body(),
+            LEFire::default().into_pdu_body(),
+            LEDetonation::default().into_pdu_body(),
+            CreateEntityR::default().into_pdu_body(),
+            RemoveEntityR::default().into_pdu_body(),
+            StartResumeR::default().into_pdu_body(),
+            StopFreezeR::default().into_pdu_body(),
+            AcknowledgeR::default().into_pdu_body(),
+            ActionRequestR::default().into_pdu_body(),
+            ActionResponseR::default().into_pdu_body(),
+            DataQueryR::default().into_pdu_body(),
+            SetDataR::default().into_pdu_body(),
+            DataR::default().into_pdu_body(),
+            EventReportR::default().into_pdu_body(),
+            CommentR::default().into_pdu_body(),
+            RecordR::default().into_pdu_body(),
+            SetRecordR::default().into_pdu_body(),
+            RecordQueryR::default().into_pdu_body(),
+            CollisionElastic::default().into_pdu_body(),
+            EntityStateUpdate::default().into_pdu_body(),
+            DirectedEnergyFire::default().into_pdu_body(),
+            EntityDamageStatus::default().into_pdu_body(),
+            InformationOperationsAction::default().into_pdu_body(),
+            InformationOperationsReport::default().into_pdu_body(),
+            Attribute::default().into_pdu_body(),
+        ];
+
+        for body in bodies {
+            let pdu_type = body.body_type();
+            let pdu = Pdu::finalize_from_parts(PduHeader::new_v7(1, pdu_type), body, 0);
+            let pdu_length = pdu.pdu_length() as usize;
+            let mut expected = BytesMut::with_capacity(pdu_length);
+            pdu.serialize(&mut expected).unwrap();
+
+            let mut buf = vec![0u8; pdu_length];
+            assert_eq!(pdu.serialize_into(&mut buf), Ok(pdu_length), "{pdu_type}");
+            assert_eq!(buf, expected.to_vec(), "{pdu_type}");
+
+            let mut small_buf = vec![0u8; pdu_length - 1];
+            assert_eq!(
+                pdu.serialize_into(&mut small_buf),
+                Err(DisError::InsufficientBufferSize(
+                    pdu.pdu_length(),
+                    pdu_length - 1
+                ))
+            );
+        }
+    }
 }
@@ -0,0 +1,438 @@
+//! Dead reckoning of entities, based on IEEE 1278.1-2012 Annex E.
+//!
+//! The module extrapolates the location, orientation and linear velocity of an entity
+//! from the state reported in an `EntityState` PDU, using the Dead Reckoning Algorithm (DRM)
+//! and the Dead Reckoning Parameters (linear acceleration, angular velocity) of that PDU.
+//!
+//! All nine standard algorithms are supported:
+//! - Static (no extrapolation),
+//! - the world coordinate variants DRM(FPW), DRM(RPW), DRM(RVW) and DRM(FVW),
+//! - the body coordinate variants DRM(FPB), DRM(RPB), DRM(RVB) and DRM(FVB).
+//!
+//! Algorithms `Other` and unspecified values are not extrapolated.
+use crate::entity_state::model::EntityState;
+use crate::enumerations::DeadReckoningAlgorithm;
+use crate::model::{Location, Orientation, VectorF32};
+use crate::utils::{multiply_matrices, rotate, transpose, RotationMatrix};
+
+/// Angular velocities (rad/s) below this magnitude are treated as zero,
+/// to avoid numerical instability when dividing by the magnitude of the angular velocity.
+const ANGULAR_VELOCITY_EPSILON: f64 = 1e-9;
+
+type Vec3 = [f64; 3];
+type Matrix3 = RotationMatrix;
+
+const IDENTITY: Matrix3 = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+/// The extrapolated state of an entity, resulting from dead reckoning.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct DeadReckonedState {
+    pub location: Location,
+    pub orientation: Orientation,
+    pub velocity: VectorF32,
+}
+
+/// Orientation of the entity is extrapolated (R) or kept fixed (F)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Rotation {
+    Fixed,
+    Rotating,
+}
+
+/// The extrapolation of the location uses first order (P) or second order (V) terms
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Order {
+    Position,
+    Velocity,
+}
+
+/// The velocity and acceleration are expressed in world (W) or body (B) coordinates
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Frame {
+    World,
+    Body,
+}
+
+fn algorithm_properties(algorithm: DeadReckoningAlgorithm) -> Option<(Rotation, Order, Frame)> {
+    match algorithm {
+        DeadReckoningAlgorithm::DRM_FPW_ConstantVelocityLowAccelerationLinearMotionEntity => {
+            Some((Rotation::Fixed, Order::Position, Frame::World))
+        }
+        DeadReckoningAlgorithm::DRM_RPW_ConstantVelocityLowAccelerationLinearMotionEntityWithExtrapolationOfOrientation => {
+            Some((Rotation::Rotating, Order::Position, Frame::World))
+        }
+        DeadReckoningAlgorithm::DRM_RVW_HighSpeedOrManeuveringEntityWithExtrapolationOfOrientation => {
+            Some((Rotation::Rotating, Order::Velocity, Frame::World))
+        }
+        DeadReckoningAlgorithm::DRM_FVW_HighSpeedOrManeuveringEntity => {
+            Some((Rotation::Fixed, Order::Velocity, Frame::World))
+        }
+        DeadReckoningAlgorithm::DRM_FPB_SimilarToFPWExceptInBodyCoordinates => {
+            Some((Rotation::Fixed, Order::Position, Frame::Body))
+        }
+        DeadReckoningAlgorithm::DRM_RPB_SimilarToRPWExceptInBodyCoordinates => {
+            Some((Rotation::Rotating, Order::Position, Frame::Body))
+        }
+        DeadReckoningAlgorithm::DRM_RVB_SimilarToRVWExceptInBodyCoordinates => {
+            Some((Rotation::Rotating, Order::Velocity, Frame::Body))
+        }
+        DeadReckoningAlgorithm::DRM_FVB_SimilarToFVWExceptInBodyCoordinates => {
+            Some((Rotation::Fixed, Order::Velocity, Frame::Body))
+        }
+        DeadReckoningAlgorithm::StaticNonmovingEntity
+        | DeadReckoningAlgorithm::Other
+        | DeadReckoningAlgorithm::Unspecified(_) => None,
+    }
+}
+
+/// Extrapolates the location, orientation and linear velocity of the entity described by `entity_state`,
+/// `elapsed_seconds` after the moment the state was valid.
+///
+/// The dead reckoning algorithm, linear acceleration and angular velocity are taken from the
+/// `dead_reckoning_parameters` of the `EntityState`.
+#[must_use]
+pub fn dead_reckon(entity_state: &EntityState, elapsed_seconds: f64) -> DeadReckonedState {
+    let parameters = &entity_state.dead_reckoning_parameters;
+    extrapolate(
+        parameters.algorithm,
+        &entity_state.entity_location,
+        &entity_state.entity_orientation,
+        &entity_state.entity_linear_velocity,
+        &parameters.linear_acceleration,
+        &parameters.angular_velocity,
+        elapsed_seconds,
+    )
+}
+
+/// Extrapolates a location, orientation and linear velocity `elapsed_seconds` into the future,
+/// using the provided dead reckoning algorithm.
+///
+/// - `location` is in ECEF world coordinates (meters),
+/// - `orientation` are the Euler angles of the entity with respect to the world coordinate system (radians),
+/// - `linear_velocity` is in world coordinates (m/s),
+/// - `linear_acceleration` is in world coordinates for the W-algorithms, and in body coordinates for the B-algorithms (m/s^2),
+/// - `angular_velocity` is in body coordinates (rad/s).
+#[must_use]
+pub fn extrapolate(
+    algorithm: DeadReckoningAlgorithm,
+    location: &Location,
+    orientation: &Orientation,
+    linear_velocity: &VectorF32,
+    linear_acceleration: &VectorF32,
+    angular_velocity: &VectorF32,
+    elapsed_seconds: f64,
+) -> DeadReckonedState {
+    let Some((rotation, order, frame)) = algorithm_properties(algorithm) else {
+        return DeadReckonedState {
+            location: *location,
+            orientation: *orientation,
+            velocity: *linear_velocity,
+        };
+    };
+
+    let t = elapsed_seconds;
+    let p0 = [
+        location.x_coordinate,
+        location.y_coordinate,
+        location.z_coordinate,
+    ];
+    let v0 = vector_to_f64(linear_velocity);
+    let a0 = match order {
+        Order::Position => [0.0; 3],
+        Order::Velocity => vector_to_f64(linear_acceleration),
+    };
+    let omega = vector_to_f64(angular_velocity);
+    let world_to_body = orientation.world_to_body_matrix();
+
+    let (position, velocity) = match frame {
+        Frame::World => {
+            let position = add(add(p0, scale(v0, t)), scale(a0, 0.5 * t * t));
+            let velocity = add(v0, scale(a0, t));
+            (position, velocity)
+        }
+        Frame::Body => {
+            let body_to_world = transpose(&world_to_body);
+            let v_body = rotate(&world_to_body, v0);
+            // The acceleration in the body frame is corrected for the centripetal component (E.7)
+            let a_body = match order {
+                Order::Position => [0.0; 3],
+                Order::Velocity => subtract(a0, cross(omega, v_body)),
+            };
+            let displacement_body = add(
+                rotate(&r1_matrix(omega, t), v_body),
+                rotate(&r2_matrix(omega, t), a_body),
+            );
+            let position = add(p0, rotate(&body_to_world, displacement_body));
+            let velocity = match rotation {
+                Rotation::Fixed => rotate(&body_to_world, add(v_body, scale(a_body, t))),
+                Rotation::Rotating => {
+                    let body_to_world_t =
+                        transpose(&multiply_matrices(&dr_matrix(omega, t), &world_to_body));
+                    rotate(&body_to_world_t, add(v_body, scale(a_body, t)))
+                }
+            };
+            (position, velocity)
+        }
+    };
+
+    let orientation = match rotation {
+        Rotation::Fixed => *orientation,
+        Rotation::Rotating => Orientation::from_world_to_body_matrix(&multiply_matrices(
+            &dr_matrix(omega, t),
+            &world_to_body,
+        )),
+    };
+
+    DeadReckonedState {
+        location: Location::new(position[0], position[1], position[2]),
+        orientation,
+        velocity: VectorF32::new(velocity[0] as f32, velocity[1] as f32, velocity[2] as f32),
+    }
+}
+
+fn vector_to_f64(vector: &VectorF32) -> Vec3 {
+    [
+        f64::from(vector.first_vector_component),
+        f64::from(vector.second_vector_component),
+        f64::from(vector.third_vector_component),
+    ]
+}
+
+fn add(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn subtract(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn scale(a: Vec3, factor: f64) -> Vec3 {
+    [a[0] * factor, a[1] * factor, a[2] * factor]
+}
+
+fn cross(a: Vec3, b: Vec3) -> Vec3 {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn magnitude(a: Vec3) -> f64 {
+    (a[0] * a[0] + a[1] * a[1] + a[2] * a[2]).sqrt()
+}
+
+/// Linear combination `a * (w w^T) + b * I + c * skew(w)`, the building block of the DR matrices of Annex E.
+fn combine(omega: Vec3, a: f64, b: f64, c: f64) -> Matrix3 {
+    let mut result = [[0.0; 3]; 3];
+    let skew = skew_symmetric(omega);
+    for row in 0..3 {
+        for column in 0..3 {
+            result[row][column] =
+                a * omega[row] * omega[column] + b * IDENTITY[row][column] + c * skew[row][column];
+        }
+    }
+    result
+}
+
+fn skew_symmetric(omega: Vec3) -> Matrix3 {
+    [
+        [0.0, -omega[2], omega[1]],
+        [omega[2], 0.0, -omega[0]],
+        [-omega[1], omega[0], 0.0],
+    ]
+}
+
+/// The rotation matrix `[DR]` (E.5), rotating the world-to-body matrix over time `t`.
+fn dr_matrix(omega: Vec3, t: f64) -> Matrix3 {
+    let w = magnitude(omega);
+    if w < ANGULAR_VELOCITY_EPSILON {
+        return IDENTITY;
+    }
+    let wt = w * t;
+    combine(omega, (1.0 - wt.cos()) / (w * w), wt.cos(), -wt.sin() / w)
+}
+
+/// The matrix `R1` (E.7), integrating the body velocity over time `t`.
+fn r1_matrix(omega: Vec3, t: f64) -> Matrix3 {
+    let w = magnitude(omega);
+    if w < ANGULAR_VELOCITY_EPSILON {
+        return combine(omega, 0.0, t, 0.0);
+    }
+    let wt = w * t;
+    combine(
+        omega,
+        (wt - wt.sin()) / w.powi(3),
+        wt.sin() / w,
+        (1.0 - wt.cos()) / (w * w),
+    )
+}
+
+/// The matrix `R2` (E.7), double integrating the body acceleration over time `t`.
+fn r2_matrix(omega: Vec3, t: f64) -> Matrix3 {
+    let w = magnitude(omega);
+    if w < ANGULAR_VELOCITY_EPSILON {
+        return combine(omega, 0.0, 0.5 * t * t, 0.0);
+    }
+    let wt = w * t;
+    combine(
+        omega,
+        (0.5 * wt * wt - wt.cos() - wt * wt.sin() + 1.0) / w.powi(4),
+        (wt.cos() + wt * wt.sin() - 1.0) / (w * w),
+        (wt.sin() - wt * wt.cos()) / w.powi(3),
+    )
+}
+
+/// Calculates the angle (radians) of the single rotation that turns orientation `a` into orientation `b`.
+pub(crate) fn orientation_difference(a: &Orientation, b: &Orientation) -> f64 {
+    let relative = multiply_matrices(
+        &b.world_to_body_matrix(),
+        &transpose(&a.world_to_body_matrix()),
+    );
+    let trace = relative[0][0] + relative[1][1] + relative[2][2];
+
+    ((trace - 1.0) / 2.0).clamp(-1.0, 1.0).acos()
+}
+
+/// Calculates the distance (meters) between two locations.
+pub(crate) fn location_difference(a: &Location, b: &Location) -> f64 {
+    magnitude([
+        b.x_coordinate - a.x_coordinate,
+        b.y_coordinate - a.y_coordinate,
+        b.z_coordinate - a.z_coordinate,
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity_state::model::DrParameters;
+    use std::f32::consts::FRAC_PI_2;
+
+    const EPSILON: f64 = 1e-3;
+
+    fn assert_location(actual: &Location, x: f64, y: f64, z: f64) {
+        assert!((actual.x_coordinate - x).abs() < EPSILON, "x: {actual:?}");
+        assert!((actual.y_coordinate - y).abs() < EPSILON, "y: {actual:?}");
+        assert!((actual.z_coordinate - z).abs() < EPSILON, "z: {actual:?}");
+    }
+
+    fn assert_vector(actual: &VectorF32, x: f32, y: f32, z: f32) {
+        assert!(
+            (actual.first_vector_component - x).abs() < 1e-3,
+            "{actual:?}"
+        );
+        assert!(
+            (actual.second_vector_component - y).abs() < 1e-3,
+            "{actual:?}"
+        );
+        assert!(
+            (actual.third_vector_component - z).abs() < 1e-3,
+            "{actual:?}"
+        );
+    }
+
+    fn entity_state(algorithm: DeadReckoningAlgorithm) -> EntityState {
+        EntityState::builder()
+            .with_location(Location::new(100.0, 200.0, 300.0))
+            .with_orientation(Orientation::new(0.0, 0.0, 0.0))
+            .with_velocity(VectorF32::new(10.0, 0.0, 0.0))
+            .with_dead_reckoning_parameters(
+                DrParameters::default()
+                    .with_algorithm(algorithm)
+                    .with_linear_acceleration(VectorF32::new(2.0, 0.0, 0.0))
+                    .with_angular_velocity(VectorF32::new(0.0, 0.0, 0.0)),
+            )
+            .build()
+    }
+
+    #[test]
+    fn static_entity() {
+        let state = entity_state(DeadReckoningAlgorithm::StaticNonmovingEntity);
+        let result = dead_reckon(&state, 10.0);
+
+        assert_location(&result.location, 100.0, 200.0, 300.0);
+        assert_eq!(result.orientation, state.entity_orientation);
+    }
+
+    #[test]
+    fn fpw_constant_velocity() {
+        let state = entity_state(
+            DeadReckoningAlgorithm::DRM_FPW_ConstantVelocityLowAccelerationLinearMotionEntity,
+        );
+        let result = dead_reckon(&state, 2.0);
+
+        assert_location(&result.location, 120.0, 200.0, 300.0);
+        assert_vector(&result.velocity, 10.0, 0.0, 0.0);
+    }
+
+    #[test]
+    fn fvw_with_acceleration() {
+        let state = entity_state(DeadReckoningAlgorithm::DRM_FVW_HighSpeedOrManeuveringEntity);
+        let result = dead_reckon(&state, 2.0);
+
+        // 100 + 10 * 2 + 0.5 * 2 * 2^2
+        assert_location(&result.location, 124.0, 200.0, 300.0);
+        assert_vector(&result.velocity, 14.0, 0.0, 0.0);
+    }
+
+    #[test]
+    fn rpw_rotates_orientation() {
+        let mut state = entity_state(
+            DeadReckoningAlgorithm::DRM_RPW_ConstantVelocityLowAccelerationLinearMotionEntityWithExtrapolationOfOrientation,
+        );
+        state.dead_reckoning_parameters.angular_velocity = VectorF32::new(0.0, 0.0, FRAC_PI_2);
+        let result = dead_reckon(&state, 1.0);
+
+        assert_location(&result.location, 110.0, 200.0, 300.0);
+        assert!((result.orientation.psi - FRAC_PI_2).abs() < 1e-4);
+        assert!(result.orientation.theta.abs() < 1e-4);
+        assert!(result.orientation.phi.abs() < 1e-4);
+    }
+
+    #[test]
+    fn body_algorithms_without_rotation_match_world_algorithms() {
+        let mut state =
+            entity_state(DeadReckoningAlgorithm::DRM_FPB_SimilarToFPWExceptInBodyCoordinates);
+        state.entity_orientation = Orientation::new(FRAC_PI_2, 0.0, 0.0);
+        state.entity_linear_velocity = VectorF32::new(0.0, 10.0, 0.0);
+        let result = dead_reckon(&state, 2.0);
+
+        assert_location(&result.location, 100.0, 220.0, 300.0);
+        assert_vector(&result.velocity, 0.0, 10.0, 0.0);
+
+        // body acceleration along the x-axis of the entity, which points along the world y-axis
+        state.dead_reckoning_parameters.algorithm =
+            DeadReckoningAlgorithm::DRM_FVB_SimilarToFVWExceptInBodyCoordinates;
+        let result = dead_reckon(&state, 2.0);
+
+        assert_location(&result.location, 100.0, 224.0, 300.0);
+        assert_vector(&result.velocity, 0.0, 14.0, 0.0);
+    }
+
+    #[test]
+    fn rvb_turning_entity() {
+        // Entity moving along world x with 10 m/s, turning with a yaw rate of pi/2 rad/s,
+        // results in a quarter circle with radius 10 / (pi/2) after 1 second.
+        let mut state =
+            entity_state(DeadReckoningAlgorithm::DRM_RVB_SimilarToRVWExceptInBodyCoordinates);
+        state.dead_reckoning_parameters.angular_velocity = VectorF32::new(0.0, 0.0, FRAC_PI_2);
+        // centripetal acceleration as measured in the body frame: w x v
+        state.dead_reckoning_parameters.linear_acceleration =
+            VectorF32::new(0.0, 10.0 * FRAC_PI_2, 0.0);
+        let result = dead_reckon(&state, 1.0);
+
+        let radius = 10.0 / std::f64::consts::FRAC_PI_2;
+        assert_location(&result.location, 100.0 + radius, 200.0 + radius, 300.0);
+        assert_vector(&result.velocity, 0.0, 10.0, 0.0);
+        assert!((result.orientation.psi - FRAC_PI_2).abs() < 1e-4);
+    }
+
+    #[test]
+    fn other_algorithm_is_not_extrapolated() {
+        let state = entity_state(DeadReckoningAlgorithm::Other);
+        let result = dead_reckon(&state, 5.0);
+
+        assert_location(&result.location, 100.0, 200.0, 300.0);
+    }
+}
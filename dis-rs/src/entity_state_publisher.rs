@@ -0,0 +1,295 @@
+//! Publisher-side logic for issuing Entity State PDUs, based on IEEE 1278.1-2012 section 5.3.2.3 and Annex E.
+//!
+//! An entity owned by a simulation must issue a new Entity State PDU when the dead reckoned state,
+//! as calculated by receiving simulations from the last issued PDU, deviates more than the
+//! `DRA_POS_THRSH` or `DRA_ORIENT_THRSH` thresholds from the true state of the entity,
+//! when the appearance or other non dead reckoned state of the entity changes,
+//! or when the heartbeat interval (`HBT_ESPDU_*`) for the kind/domain of the entity has expired.
+//!
+//! The `EntityStatePublisher` keeps track of the last published state of a single entity and
+//! decides, given the current true state and the current time, whether a new PDU has to be issued.
+//! Time is expressed in seconds, using a clock of the choosing of the application.
+use crate::dead_reckoning::{dead_reckon, location_difference, orientation_difference};
+use crate::entity_state::model::EntityState;
+use crate::model::EntityType;
+use crate::VariableParameters;
+
+/// The reason an Entity State PDU has to be issued.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PublishReason {
+    /// No Entity State PDU has been issued for the entity yet.
+    Initial,
+    /// The appearance, capabilities, force, dead reckoning algorithm or variable parameters of the entity changed.
+    StateChanged,
+    /// The dead reckoned location deviates more than `DRA_POS_THRSH` from the true location.
+    PositionThresholdExceeded,
+    /// The dead reckoned orientation deviates more than `DRA_ORIENT_THRSH` from the true orientation.
+    OrientationThresholdExceeded,
+    /// The heartbeat interval for the kind/domain of the entity has expired.
+    HeartbeatExpired,
+}
+
+#[derive(Clone, Debug)]
+struct PublishedState {
+    entity_state: EntityState,
+    time: f64,
+}
+
+/// Decides when an owned entity must issue a new Entity State PDU.
+///
+/// Use `publish` for each (simulation) update of the entity, and send the `EntityState`
+/// when the function returns a `PublishReason`.
+#[derive(Clone, Debug, Default)]
+pub struct EntityStatePublisher {
+    parameters: VariableParameters,
+    last_published: Option<PublishedState>,
+}
+
+impl EntityStatePublisher {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use the provided thresholds and heartbeat intervals instead of the defaults of the standard.
+    #[must_use]
+    pub fn with_parameters(mut self, parameters: VariableParameters) -> Self {
+        self.parameters = parameters;
+        self
+    }
+
+    #[must_use]
+    pub fn parameters(&self) -> &VariableParameters {
+        &self.parameters
+    }
+
+    /// The last `EntityState` that was published, if any.
+    #[must_use]
+    pub fn last_published(&self) -> Option<&EntityState> {
+        self.last_published
+            .as_ref()
+            .map(|published| &published.entity_state)
+    }
+
+    /// The time at which the last `EntityState` was published, if any.
+    #[must_use]
+    pub fn last_published_time(&self) -> Option<f64> {
+        self.last_published.as_ref().map(|published| published.time)
+    }
+
+    /// Returns the heartbeat interval (seconds) for an entity of the given type.
+    ///
+    /// See `VariableParameters::espdu_heartbeat_interval`.
+    #[must_use]
+    pub fn heartbeat_interval(&self, entity_type: &EntityType) -> f64 {
+        f64::from(self.parameters.espdu_heartbeat_interval(entity_type))
+    }
+
+    /// Checks whether `entity_state`, being the true state of the entity at `time`, must be published.
+    ///
+    /// The state of the publisher is not modified; use `publish` or `mark_published` to register
+    /// that a PDU has actually been issued.
+    #[must_use]
+    pub fn check(&self, entity_state: &EntityState, time: f64) -> Option<PublishReason> {
+        let Some(last) = &self.last_published else {
+            return Some(PublishReason::Initial);
+        };
+
+        if state_changed(&last.entity_state, entity_state) {
+            return Some(PublishReason::StateChanged);
+        }
+
+        let elapsed = (time - last.time).max(0.0);
+        let dead_reckoned = dead_reckon(&last.entity_state, elapsed);
+
+        if location_difference(&dead_reckoned.location, &entity_state.entity_location)
+            > f64::from(self.parameters.DRA_POS_THRSH)
+        {
+            return Some(PublishReason::PositionThresholdExceeded);
+        }
+
+        if orientation_difference(&dead_reckoned.orientation, &entity_state.entity_orientation)
+            .to_degrees()
+            > f64::from(self.parameters.DRA_ORIENT_THRSH)
+        {
+            return Some(PublishReason::OrientationThresholdExceeded);
+        }
+
+        if elapsed >= self.heartbeat_interval(&entity_state.entity_type) {
+            return Some(PublishReason::HeartbeatExpired);
+        }
+
+        None
+    }
+
+    /// Checks whether `entity_state`, being the true state of the entity at `time`, must be published.
+    /// When this is the case, the state is registered as the last published state and the reason is returned.
+    pub fn publish(&mut self, entity_state: &EntityState, time: f64) -> Option<PublishReason> {
+        let reason = self.check(entity_state, time);
+        if reason.is_some() {
+            self.mark_published(entity_state.clone(), time);
+        }
+        reason
+    }
+
+    /// Registers `entity_state` as published at `time`, for instance when the application
+    /// issues a PDU on its own accord.
+    pub fn mark_published(&mut self, entity_state: EntityState, time: f64) {
+        self.last_published = Some(PublishedState { entity_state, time });
+    }
+
+    /// Forgets the last published state, causing the next check to result in `PublishReason::Initial`.
+    pub fn reset(&mut self) {
+        self.last_published = None;
+    }
+}
+
+/// Compares the parts of the Entity State that are not dead reckoned.
+fn state_changed(published: &EntityState, current: &EntityState) -> bool {
+    published.entity_appearance != current.entity_appearance
+        || published.entity_capabilities != current.entity_capabilities
+        || published.force_id != current.force_id
+        || published.entity_type != current.entity_type
+        || published.dead_reckoning_parameters.algorithm
+            != current.dead_reckoning_parameters.algorithm
+        || published.variable_parameters != current.variable_parameters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity_state::model::{DrParameters, EntityAppearance};
+    use crate::enumerations::{
+        DeadReckoningAlgorithm, EntityKind, LandPlatformAppearance, PlatformDomain,
+    };
+    use crate::model::{Location, Orientation, VectorF32};
+
+    fn tank() -> EntityState {
+        EntityState::builder()
+            .with_entity_type(
+                EntityType::default()
+                    .with_kind(EntityKind::Platform)
+                    .with_domain(PlatformDomain::Land),
+            )
+            .with_appearance(EntityAppearance::LandPlatform(
+                LandPlatformAppearance::default(),
+            ))
+            .with_location(Location::new(0.0, 0.0, 0.0))
+            .with_orientation(Orientation::new(0.0, 0.0, 0.0))
+            .with_velocity(VectorF32::new(10.0, 0.0, 0.0))
+            .with_dead_reckoning_parameters(DrParameters::default().with_algorithm(
+                DeadReckoningAlgorithm::DRM_FPW_ConstantVelocityLowAccelerationLinearMotionEntity,
+            ))
+            .build()
+    }
+
+    fn at(entity_state: &EntityState, x: f64, psi: f32) -> EntityState {
+        entity_state
+            .clone()
+            .into_builder()
+            .with_location(Location::new(x, 0.0, 0.0))
+            .with_orientation(Orientation::new(psi, 0.0, 0.0))
+            .build()
+    }
+
+    #[test]
+    fn publish_initial_state() {
+        let mut publisher = EntityStatePublisher::new();
+        let tank = tank();
+
+        assert_eq!(publisher.publish(&tank, 0.0), Some(PublishReason::Initial));
+        assert_eq!(publisher.last_published(), Some(&tank));
+        assert_eq!(publisher.last_published_time(), Some(0.0));
+    }
+
+    #[test]
+    fn no_publish_within_thresholds() {
+        let mut publisher = EntityStatePublisher::new();
+        let tank = tank();
+        let _ = publisher.publish(&tank, 0.0);
+
+        // moving as dead reckoned, within the position threshold
+        assert_eq!(publisher.publish(&at(&tank, 10.5, 0.0), 1.0), None);
+        assert_eq!(publisher.publish(&at(&tank, 19.5, 0.01), 2.0), None);
+        assert_eq!(publisher.last_published_time(), Some(0.0));
+    }
+
+    #[test]
+    fn publish_when_position_threshold_exceeded() {
+        let mut publisher = EntityStatePublisher::new();
+        let tank = tank();
+        let _ = publisher.publish(&tank, 0.0);
+
+        assert_eq!(
+            publisher.publish(&at(&tank, 12.0, 0.0), 1.0),
+            Some(PublishReason::PositionThresholdExceeded)
+        );
+        assert_eq!(publisher.last_published_time(), Some(1.0));
+        // subsequent checks dead reckon from the newly published state
+        assert_eq!(publisher.publish(&at(&tank, 22.0, 0.0), 2.0), None);
+    }
+
+    #[test]
+    fn publish_when_orientation_threshold_exceeded() {
+        let mut publisher = EntityStatePublisher::new();
+        let tank = tank();
+        let _ = publisher.publish(&tank, 0.0);
+
+        assert_eq!(
+            publisher.publish(&at(&tank, 10.0, 4f32.to_radians()), 1.0),
+            Some(PublishReason::OrientationThresholdExceeded)
+        );
+    }
+
+    #[test]
+    fn publish_when_state_changed() {
+        let mut publisher = EntityStatePublisher::new();
+        let tank = tank();
+        let _ = publisher.publish(&tank, 0.0);
+
+        let changed = tank
+            .clone()
+            .into_builder()
+            .with_appearance(EntityAppearance::LandPlatform(
+                LandPlatformAppearance::from(1u32),
+            ))
+            .build();
+        assert_eq!(
+            publisher.check(&changed, 0.1),
+            Some(PublishReason::StateChanged)
+        );
+    }
+
+    #[test]
+    fn publish_when_heartbeat_expired() {
+        let mut publisher = EntityStatePublisher::new();
+        let tank = tank();
+        let _ = publisher.publish(&tank, 0.0);
+
+        assert_eq!(publisher.check(&at(&tank, 49.0, 0.0), 4.9), None);
+        assert_eq!(
+            publisher.check(&at(&tank, 50.0, 0.0), 5.0),
+            Some(PublishReason::HeartbeatExpired)
+        );
+    }
+
+    #[test]
+    fn heartbeat_interval_per_kind_and_domain() {
+        let parameters = VariableParameters {
+            HBT_ESPDU_PLATFORM_AIR: 2.0,
+            HBT_ESPDU_KIND_MUNITION: 1.0,
+            ..Default::default()
+        };
+        let publisher = EntityStatePublisher::new().with_parameters(parameters);
+
+        let air = EntityType::default()
+            .with_kind(EntityKind::Platform)
+            .with_domain(PlatformDomain::Air);
+        let munition = EntityType::default().with_kind(EntityKind::Munition);
+        let other = EntityType::default().with_kind(EntityKind::Other);
+
+        assert_eq!(publisher.heartbeat_interval(&air), 2.0);
+        assert_eq!(publisher.heartbeat_interval(&munition), 1.0);
+        assert_eq!(publisher.heartbeat_interval(&other), 2.0);
+    }
+}
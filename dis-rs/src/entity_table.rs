@@ -0,0 +1,370 @@
+//! Table of remote entities, maintained from received PDUs.
+//!
+//! The `EntityTable` keeps the latest known `EntityState` of each entity in the exercise, keyed by `EntityId`.
+//! The table is updated using Entity State, Entity State Update and Remove Entity PDUs,
+//! and entities are timed out when no update has been received within the heartbeat interval
+//! for the kind/domain of the entity (`HBT_ESPDU_*`) times `HBT_TIMEOUT_MPLIER`.
+//!
+//! Each change to the table is reported as an `EntityEvent`.
+//! Time is expressed in seconds, using a clock of the choosing of the application.
+use crate::common::model::{EntityId, Pdu, PduBody};
+use crate::dead_reckoning::{dead_reckon, DeadReckonedState};
+use crate::entity_state::model::EntityState;
+use crate::entity_state_update::model::EntityStateUpdate;
+use crate::enumerations::AppearanceEntityOrObjectState;
+use crate::remove_entity::model::RemoveEntity;
+use crate::{VariableParameters, ALL_APPLIC, ALL_ENTITIES, ALL_SITES, NO_ENTITY};
+use std::collections::hash_map::Iter;
+use std::collections::HashMap;
+
+/// A change to the `EntityTable`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EntityEvent {
+    /// An entity that was not known to the table has been added.
+    Appeared(EntityId),
+    /// The state of a known entity has been updated.
+    Updated(EntityId),
+    /// An entity has been removed, due to a Remove Entity PDU or because it was deactivated.
+    Removed(EntityId),
+    /// An entity has been removed because no update was received within the timeout interval.
+    TimedOut(EntityId),
+}
+
+/// The latest known state of a remote entity.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RemoteEntity {
+    pub entity_state: EntityState,
+    pub exercise_id: u8,
+    /// The time at which the last update for the entity was received.
+    pub last_update: f64,
+}
+
+impl RemoteEntity {
+    /// Extrapolates the state of the entity to `time`, using the dead reckoning parameters of the last update.
+    #[must_use]
+    pub fn dead_reckon(&self, time: f64) -> DeadReckonedState {
+        dead_reckon(&self.entity_state, (time - self.last_update).max(0.0))
+    }
+}
+
+/// Stateful table of remote entities, keyed by `EntityId`.
+#[derive(Clone, Debug, Default)]
+pub struct EntityTable {
+    parameters: VariableParameters,
+    entities: HashMap<EntityId, RemoteEntity>,
+}
+
+impl EntityTable {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use the provided heartbeat intervals and timeout multiplier instead of the defaults of the standard.
+    #[must_use]
+    pub fn with_parameters(mut self, parameters: VariableParameters) -> Self {
+        self.parameters = parameters;
+        self
+    }
+
+    #[must_use]
+    pub fn parameters(&self) -> &VariableParameters {
+        &self.parameters
+    }
+
+    /// Processes a received `Pdu`, received at `time`, and returns the resulting changes to the table.
+    ///
+    /// PDUs other than Entity State, Entity State Update and Remove Entity are ignored.
+    pub fn process(&mut self, pdu: &Pdu, time: f64) -> Vec<EntityEvent> {
+        match &pdu.body {
+            PduBody::EntityState(body) => self
+                .apply_entity_state(body, pdu.header.exercise_id, time)
+                .into_iter()
+                .collect(),
+            PduBody::EntityStateUpdate(body) => self
+                .apply_entity_state_update(body, time)
+                .into_iter()
+                .collect(),
+            PduBody::RemoveEntity(body) => self.apply_remove_entity(body),
+            _ => vec![],
+        }
+    }
+
+    /// Applies an `EntityState`, received at `time`.
+    /// An entity that reports the Deactivated state is removed from the table.
+    pub fn apply_entity_state(
+        &mut self,
+        entity_state: &EntityState,
+        exercise_id: u8,
+        time: f64,
+    ) -> Option<EntityEvent> {
+        let entity_id = entity_state.entity_id;
+
+        if entity_state.entity_appearance.state()
+            == Some(AppearanceEntityOrObjectState::Deactivated)
+        {
+            return self
+                .entities
+                .remove(&entity_id)
+                .map(|_| EntityEvent::Removed(entity_id));
+        }
+
+        let entity = RemoteEntity {
+            entity_state: entity_state.clone(),
+            exercise_id,
+            last_update: time,
+        };
+        match self.entities.insert(entity_id, entity) {
+            None => Some(EntityEvent::Appeared(entity_id)),
+            Some(_) => Some(EntityEvent::Updated(entity_id)),
+        }
+    }
+
+    /// Applies an `EntityStateUpdate`, received at `time`, to a known entity.
+    /// Updates for entities that are not (yet) in the table are ignored,
+    /// because an Entity State Update PDU does not contain the full state of the entity.
+    pub fn apply_entity_state_update(
+        &mut self,
+        update: &EntityStateUpdate,
+        time: f64,
+    ) -> Option<EntityEvent> {
+        let entity_id = update.entity_id;
+
+        if update.entity_appearance.state() == Some(AppearanceEntityOrObjectState::Deactivated) {
+            return self
+                .entities
+                .remove(&entity_id)
+                .map(|_| EntityEvent::Removed(entity_id));
+        }
+
+        let entity = self.entities.get_mut(&entity_id)?;
+        let state = &mut entity.entity_state;
+        state.entity_linear_velocity = update.entity_linear_velocity;
+        state.entity_location = update.entity_location;
+        state.entity_orientation = update.entity_orientation;
+        state.entity_appearance = update.entity_appearance;
+        state
+            .variable_parameters
+            .clone_from(&update.variable_parameters);
+        entity.last_update = time;
+
+        Some(EntityEvent::Updated(entity_id))
+    }
+
+    /// Applies a `RemoveEntity`, removing the entity identified by the receiving id.
+    ///
+    /// When the entity number of the receiving id is `NO_ENTITY` or `ALL_ENTITIES`, all entities of the
+    /// addressed simulation(s) are removed, taking `ALL_SITES` and `ALL_APPLIC` into account.
+    pub fn apply_remove_entity(&mut self, remove_entity: &RemoveEntity) -> Vec<EntityEvent> {
+        let receiver = remove_entity.receiving_id;
+        let entity_number = u32::from(receiver.entity_id);
+
+        if receiver.entity_id != NO_ENTITY && entity_number != ALL_ENTITIES {
+            return self
+                .entities
+                .remove(&receiver)
+                .map(|_| EntityEvent::Removed(receiver))
+                .into_iter()
+                .collect();
+        }
+
+        let site_id = u32::from(receiver.simulation_address.site_id);
+        let application_id = u32::from(receiver.simulation_address.application_id);
+        let removed: Vec<EntityId> = self
+            .entities
+            .keys()
+            .filter(|id| {
+                (site_id == ALL_SITES || site_id == u32::from(id.simulation_address.site_id))
+                    && (application_id == ALL_APPLIC
+                        || application_id == u32::from(id.simulation_address.application_id))
+            })
+            .copied()
+            .collect();
+
+        removed
+            .into_iter()
+            .filter_map(|id| self.entities.remove(&id).map(|_| EntityEvent::Removed(id)))
+            .collect()
+    }
+
+    /// Removes all entities for which no update has been received within their timeout interval, as of `time`.
+    pub fn timeout(&mut self, time: f64) -> Vec<EntityEvent> {
+        let parameters = &self.parameters;
+        let timed_out: Vec<EntityId> = self
+            .entities
+            .iter()
+            .filter(|(_, entity)| {
+                time - entity.last_update
+                    > f64::from(parameters.espdu_timeout(&entity.entity_state.entity_type))
+            })
+            .map(|(id, _)| *id)
+            .collect();
+
+        timed_out
+            .into_iter()
+            .filter_map(|id| self.entities.remove(&id).map(|_| EntityEvent::TimedOut(id)))
+            .collect()
+    }
+
+    #[must_use]
+    pub fn get(&self, entity_id: &EntityId) -> Option<&RemoteEntity> {
+        self.entities.get(entity_id)
+    }
+
+    #[must_use]
+    pub fn contains(&self, entity_id: &EntityId) -> bool {
+        self.entities.contains_key(entity_id)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    /// Iterates over all entities in the table, in arbitrary order.
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_, EntityId, RemoteEntity> {
+        self.entities.iter()
+    }
+
+    /// Removes all entities from the table, without reporting events.
+    pub fn clear(&mut self) {
+        self.entities.clear();
+    }
+}
+
+impl<'a> IntoIterator for &'a EntityTable {
+    type Item = (&'a EntityId, &'a RemoteEntity);
+    type IntoIter = Iter<'a, EntityId, RemoteEntity>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entities.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::model::{DisTimeStamp, EntityType, Location, PduHeader};
+    use crate::common::BodyInfo;
+    use crate::entity_state::model::EntityAppearance;
+    use crate::enumerations::{EntityKind, LandPlatformAppearance, PlatformDomain};
+
+    fn entity_state(entity_id: EntityId) -> EntityState {
+        EntityState::builder()
+            .with_entity_id(entity_id)
+            .with_entity_type(
+                EntityType::default()
+                    .with_kind(EntityKind::Platform)
+                    .with_domain(PlatformDomain::Land),
+            )
+            .with_appearance(EntityAppearance::LandPlatform(
+                LandPlatformAppearance::default(),
+            ))
+            .build()
+    }
+
+    fn pdu(body: PduBody) -> Pdu {
+        let header = PduHeader::new_v6(1, body.body_type());
+        Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(0))
+    }
+
+    #[test]
+    fn entity_appears_and_updates() {
+        let mut table = EntityTable::new();
+        let id = EntityId::new(1, 1, 1);
+
+        let events = table.process(&pdu(entity_state(id).into_pdu_body()), 0.0);
+        assert_eq!(events, vec![EntityEvent::Appeared(id)]);
+        assert_eq!(table.len(), 1);
+        assert_eq!(table.get(&id).unwrap().exercise_id, 1);
+
+        let events = table.process(&pdu(entity_state(id).into_pdu_body()), 1.0);
+        assert_eq!(events, vec![EntityEvent::Updated(id)]);
+        assert_eq!(table.get(&id).unwrap().last_update, 1.0);
+    }
+
+    #[test]
+    fn entity_state_update_applies_to_known_entities() {
+        let mut table = EntityTable::new();
+        let id = EntityId::new(1, 1, 1);
+        let update = EntityStateUpdate::builder()
+            .with_entity_id(id)
+            .with_location(Location::new(1.0, 2.0, 3.0))
+            .with_appearance(EntityAppearance::LandPlatform(
+                LandPlatformAppearance::default(),
+            ))
+            .build();
+
+        assert!(table
+            .process(&pdu(update.clone().into_pdu_body()), 0.0)
+            .is_empty());
+        assert!(table.is_empty());
+
+        let _ = table.process(&pdu(entity_state(id).into_pdu_body()), 0.0);
+        let events = table.process(&pdu(update.into_pdu_body()), 1.0);
+        assert_eq!(events, vec![EntityEvent::Updated(id)]);
+        let entity = table.get(&id).unwrap();
+        assert_eq!(
+            entity.entity_state.entity_location,
+            Location::new(1.0, 2.0, 3.0)
+        );
+        assert_eq!(entity.entity_state.entity_type.domain, PlatformDomain::Land);
+    }
+
+    #[test]
+    fn remove_entity_removes_entities() {
+        let mut table = EntityTable::new();
+        let first = EntityId::new(1, 1, 1);
+        let second = EntityId::new(1, 1, 2);
+        let other = EntityId::new(1, 2, 1);
+        for id in [first, second, other] {
+            let _ = table.process(&pdu(entity_state(id).into_pdu_body()), 0.0);
+        }
+
+        let remove_single = RemoveEntity::builder()
+            .with_receiving_id(first)
+            .build()
+            .into_pdu_body();
+        assert_eq!(
+            table.process(&pdu(remove_single), 0.0),
+            vec![EntityEvent::Removed(first)]
+        );
+
+        let remove_application = RemoveEntity::builder()
+            .with_receiving_id(EntityId::new(1, 1, ALL_ENTITIES as u16))
+            .build()
+            .into_pdu_body();
+        assert_eq!(
+            table.process(&pdu(remove_application), 0.0),
+            vec![EntityEvent::Removed(second)]
+        );
+        assert!(table.contains(&other));
+        assert_eq!(table.len(), 1);
+    }
+
+    #[test]
+    fn entities_time_out() {
+        let parameters = VariableParameters {
+            HBT_ESPDU_PLATFORM_LAND: 5.0,
+            HBT_TIMEOUT_MPLIER: 2.0,
+            ..Default::default()
+        };
+        let mut table = EntityTable::new().with_parameters(parameters);
+        let early = EntityId::new(1, 1, 1);
+        let late = EntityId::new(1, 1, 2);
+
+        let _ = table.process(&pdu(entity_state(early).into_pdu_body()), 0.0);
+        let _ = table.process(&pdu(entity_state(late).into_pdu_body()), 5.0);
+
+        assert!(table.timeout(10.0).is_empty());
+        assert_eq!(table.timeout(10.5), vec![EntityEvent::TimedOut(early)]);
+        assert_eq!(table.timeout(15.5), vec![EntityEvent::TimedOut(late)]);
+        assert!(table.is_empty());
+    }
+}
@@ -0,0 +1,275 @@
+//! Catalogue of the entity types defined in SISO-REF-010 (the Entity Types records, UID 30).
+//!
+//! The catalogue is generated from the SISO-REF-010 XML file at build time, just like the `enumerations`.
+//! It contains a record for each category, subcategory, specific and extra of each kind, domain and country,
+//! holding the description given by SISO-REF-010 (e.g., 'M1A2' for entity type `1:1:225:1:1:3:0`).
+//!
+//! The records of categories and subcategories have the lower fields set to zero, such that
+//! `describe()` can fall back to the description of the category when a specific entity type is not listed.
+use crate::common::model::EntityType;
+use crate::enumerations::{
+    EntityKind, MunitionCategory, PlatformAirCategory, PlatformDomain, PlatformLandCategory,
+    PlatformSpaceCategory, PlatformSubsurfaceCategory, PlatformSurfaceCategory,
+};
+
+include!(concat!(env!("OUT_DIR"), "/entity_types.rs"));
+
+/// An entity type record of SISO-REF-010, consisting of the fields of an `EntityType` and its description.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EntityTypeRecord {
+    pub kind: u8,
+    pub domain: u8,
+    pub country: u16,
+    pub category: u8,
+    pub subcategory: u8,
+    pub specific: u8,
+    pub extra: u8,
+    pub description: &'static str,
+}
+
+impl EntityTypeRecord {
+    #[allow(clippy::too_many_arguments)]
+    const fn new(
+        kind: u8,
+        domain: u8,
+        country: u16,
+        category: u8,
+        subcategory: u8,
+        specific: u8,
+        extra: u8,
+        description: &'static str,
+    ) -> Self {
+        Self {
+            kind,
+            domain,
+            country,
+            category,
+            subcategory,
+            specific,
+            extra,
+            description,
+        }
+    }
+
+    /// Returns the `EntityType` described by this record.
+    #[must_use]
+    pub fn entity_type(&self) -> EntityType {
+        EntityType::default()
+            .with_kind(EntityKind::from(self.kind))
+            .with_domain(PlatformDomain::from(self.domain))
+            .with_country(self.country.into())
+            .with_category(self.category)
+            .with_subcategory(self.subcategory)
+            .with_specific(self.specific)
+            .with_extra(self.extra)
+    }
+
+    fn key(&self) -> u64 {
+        entity_type_key(
+            self.kind,
+            self.domain,
+            self.country,
+            [self.category, self.subcategory, self.specific, self.extra],
+        )
+    }
+}
+
+/// Packs the fields of an entity type in wire order, so that the key orders the same as the fields.
+fn entity_type_key(kind: u8, domain: u8, country: u16, lower_fields: [u8; 4]) -> u64 {
+    (u64::from(kind) << 56)
+        | (u64::from(domain) << 48)
+        | (u64::from(country) << 32)
+        | u64::from(u32::from_be_bytes(lower_fields))
+}
+
+fn lookup_fields(
+    kind: u8,
+    domain: u8,
+    country: u16,
+    lower_fields: [u8; 4],
+) -> Option<&'static EntityTypeRecord> {
+    let key = entity_type_key(kind, domain, country, lower_fields);
+    ENTITY_TYPE_RECORDS
+        .binary_search_by_key(&key, EntityTypeRecord::key)
+        .ok()
+        .map(|index| &ENTITY_TYPE_RECORDS[index])
+}
+
+/// Returns all records in the catalogue, sorted by entity type.
+#[must_use]
+pub fn records() -> &'static [EntityTypeRecord] {
+    &ENTITY_TYPE_RECORDS
+}
+
+/// Returns the record that exactly matches the provided `EntityType`, if any.
+#[must_use]
+pub fn lookup(entity_type: &EntityType) -> Option<&'static EntityTypeRecord> {
+    lookup_fields(
+        entity_type.kind.into(),
+        entity_type.domain.into(),
+        entity_type.country.into(),
+        [
+            entity_type.category,
+            entity_type.subcategory,
+            entity_type.specific,
+            entity_type.extra,
+        ],
+    )
+}
+
+/// Returns the description of the most specific record matching the provided `EntityType`.
+///
+/// When the entity type itself is not in the catalogue, the extra, specific and subcategory fields
+/// are successively ignored, e.g. resulting in the description of the subcategory for an unlisted specific.
+#[must_use]
+pub fn describe(entity_type: &EntityType) -> Option<&'static str> {
+    let mut lower_fields = [
+        entity_type.category,
+        entity_type.subcategory,
+        entity_type.specific,
+        entity_type.extra,
+    ];
+    for level in (0..lower_fields.len()).rev() {
+        let record = lookup_fields(
+            entity_type.kind.into(),
+            entity_type.domain.into(),
+            entity_type.country.into(),
+            lower_fields,
+        );
+        if let Some(record) = record {
+            return Some(record.description);
+        }
+        lower_fields[level] = 0;
+    }
+    None
+}
+
+/// Returns an iterator over all records with the provided description, compared case-insensitively.
+///
+/// Descriptions are not unique; the same platform is often listed for multiple countries.
+pub fn find_by_name(name: &str) -> impl Iterator<Item = &'static EntityTypeRecord> + '_ {
+    ENTITY_TYPE_RECORDS
+        .iter()
+        .filter(move |record| record.description.eq_ignore_ascii_case(name))
+}
+
+/// The category of an `EntityType`, typed according to the kind and domain of the entity type.
+///
+/// SISO-REF-010 defines category enumerations for platforms (per domain) and munitions.
+/// The categories of the other kinds, such as life forms, are only defined by the entity type records,
+/// so these are available as `Other` and described by the catalogue (see `describe()`).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EntityCategory {
+    Land(PlatformLandCategory),
+    Air(PlatformAirCategory),
+    Surface(PlatformSurfaceCategory),
+    Subsurface(PlatformSubsurfaceCategory),
+    Space(PlatformSpaceCategory),
+    Munition(MunitionCategory),
+    /// A category for which no typed enumeration is available.
+    Other(u8),
+}
+
+impl EntityType {
+    /// Returns the category of the entity type as a typed enumeration, based on the kind and domain.
+    #[must_use]
+    pub fn typed_category(&self) -> EntityCategory {
+        match self.kind {
+            EntityKind::Platform => {}
+            EntityKind::Munition => return EntityCategory::Munition(self.category.into()),
+            _ => return EntityCategory::Other(self.category),
+        }
+        match self.domain {
+            PlatformDomain::Land => EntityCategory::Land(self.category.into()),
+            PlatformDomain::Air => EntityCategory::Air(self.category.into()),
+            PlatformDomain::Surface => EntityCategory::Surface(self.category.into()),
+            PlatformDomain::Subsurface => EntityCategory::Subsurface(self.category.into()),
+            PlatformDomain::Space => EntityCategory::Space(self.category.into()),
+            _ => EntityCategory::Other(self.category),
+        }
+    }
+
+    /// Returns the description of the entity type from the SISO-REF-010 catalogue, see `entity_types::describe`.
+    #[must_use]
+    pub fn description(&self) -> Option<&'static str> {
+        describe(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enumerations::Country;
+
+    #[test]
+    fn records_are_sorted_for_lookup() {
+        assert!(records()
+            .windows(2)
+            .all(|pair| pair[0].key() < pair[1].key()));
+        for record in records() {
+            assert_eq!(lookup(&record.entity_type()), Some(record));
+            assert_eq!(record.entity_type().description(), Some(record.description));
+        }
+    }
+
+    fn m1a2() -> EntityType {
+        EntityType::default()
+            .with_kind(EntityKind::Platform)
+            .with_domain(PlatformDomain::Land)
+            .with_country(Country::from(225u16))
+            .with_category(1)
+            .with_subcategory(1)
+            .with_specific(3)
+    }
+
+    #[test]
+    fn describe_falls_back_to_less_specific_records() {
+        assert_eq!(describe(&m1a2()), Some("M1A2"));
+
+        let unlisted_extra = m1a2().with_extra(255);
+        assert_eq!(lookup(&unlisted_extra), None);
+        assert_eq!(describe(&unlisted_extra), Some("M1A2"));
+
+        let unlisted_specific = m1a2().with_specific(255);
+        assert_eq!(lookup(&unlisted_specific), None);
+        assert_eq!(describe(&unlisted_specific), Some("M1 Abrams"));
+
+        let unlisted_subcategory = m1a2().with_subcategory(255).with_specific(255);
+        assert_eq!(describe(&unlisted_subcategory), Some("Tank"));
+    }
+
+    #[test]
+    fn find_records_by_name() {
+        let found: Vec<_> = find_by_name("m1a2").collect();
+
+        assert!(found
+            .iter()
+            .any(|record| record.entity_type() == m1a2() && record.description == "M1A2"));
+        assert!(found.iter().all(|record| record.description == "M1A2"));
+    }
+
+    #[test]
+    fn typed_category_per_domain() {
+        let land_tank = EntityType::default()
+            .with_kind(EntityKind::Platform)
+            .with_domain(PlatformDomain::Land)
+            .with_category(1);
+        let munition = EntityType::default()
+            .with_kind(EntityKind::Munition)
+            .with_category(2);
+
+        assert_eq!(
+            land_tank.typed_category(),
+            EntityCategory::Land(PlatformLandCategory::from(1u8))
+        );
+        let life_form = EntityType::default()
+            .with_kind(EntityKind::LifeForm)
+            .with_category(2);
+
+        assert_eq!(
+            munition.typed_category(),
+            EntityCategory::Munition(MunitionCategory::from(2u8))
+        );
+        assert_eq!(life_form.typed_category(), EntityCategory::Other(2));
+    }
+}
@@ -1,3 +1,4 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![allow(
     clippy::cast_possible_truncation,
     clippy::cast_precision_loss,
@@ -7,12 +8,25 @@
     reason = "Parsing, writing, encoding, decoding PDUs uses many valid conversions"
 )]
 
-extern crate core;
+extern crate alloc;
 
+#[cfg(feature = "codec")]
+pub mod codec;
 mod common;
 mod constants;
+#[cfg(feature = "std")]
+pub mod dead_reckoning;
+#[cfg(feature = "std")]
+pub mod entity_state_publisher;
+#[cfg(feature = "std")]
+pub mod entity_table;
+#[cfg(feature = "entity-types")]
+pub mod entity_types;
 mod fixed_parameters;
+#[cfg(feature = "std")]
 pub mod utils;
+mod v4;
+mod v5;
 mod v6;
 mod v7;
 mod variable_parameters;
@@ -21,6 +35,8 @@
 
 pub use common::entity_state::parser::dr_other_parameters as parse_dr_other_parameters;
 pub use common::parse;
+pub use common::parse_v4 as parse_v4_pdus;
+pub use common::parse_v5 as parse_v5_pdus;
 pub use common::parse_v6 as parse_v6_pdus;
 pub use common::parse_v7 as parse_v7_pdus;
 pub use common::supported_protocol_versions;
@@ -1,3 +1,8 @@
+use crate::common::model::{Location, VectorF32};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::ops::Mul;
+
 struct EcefToGeoConstants;
 
 #[allow(clippy::excessive_precision)]
@@ -82,3 +87,527 @@ pub fn geodetic_lla_to_ecef(latitude: f64, longitude: f64, altitude_msl: f64) ->
 
     (ecef_x, ecef_y, ecef_z)
 }
+
+/// A 3x3 rotation matrix, in row-major order.
+pub type RotationMatrix = [[f64; 3]; 3];
+
+/// A local tangent plane reference frame, with its origin at a geodetic reference point on (or above) the WGS-84 ellipsoid.
+///
+/// Supports conversion of locations and vectors (velocities, accelerations) between
+/// Geocentric (ECEF) coordinates and the local East-North-Up (ENU) or North-East-Down (NED) frames.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LocalTangentPlane {
+    latitude: f64,
+    longitude: f64,
+    altitude: f64,
+    origin: Location,
+    ecef_to_enu: RotationMatrix,
+}
+
+impl LocalTangentPlane {
+    /// Creates a reference frame with its origin at the given geodetic location.
+    ///
+    /// ``latitude`` and ``longitude`` are in _radians_, ``altitude`` is in _meters_ (MSL).
+    #[must_use]
+    pub fn from_geodetic(latitude: f64, longitude: f64, altitude: f64) -> Self {
+        let (x, y, z) = geodetic_lla_to_ecef(latitude, longitude, altitude);
+        let (sin_lat, cos_lat) = latitude.sin_cos();
+        let (sin_lon, cos_lon) = longitude.sin_cos();
+
+        Self {
+            latitude,
+            longitude,
+            altitude,
+            origin: Location::new(x, y, z),
+            ecef_to_enu: [
+                [-sin_lon, cos_lon, 0.0],
+                [-sin_lat * cos_lon, -sin_lat * sin_lon, cos_lat],
+                [cos_lat * cos_lon, cos_lat * sin_lon, sin_lat],
+            ],
+        }
+    }
+
+    /// Creates a reference frame with its origin at the given Geocentric (ECEF) location.
+    #[must_use]
+    pub fn from_ecef(origin: &Location) -> Self {
+        let (latitude, longitude, altitude) = ecef_to_geodetic_lla(
+            origin.x_coordinate,
+            origin.y_coordinate,
+            origin.z_coordinate,
+        );
+        Self::from_geodetic(latitude, longitude, altitude)
+    }
+
+    /// The origin of the frame as a tuple `(lat, lon, alt)`, in _radians_ and _meters_ (MSL).
+    #[must_use]
+    pub fn geodetic_origin(&self) -> (f64, f64, f64) {
+        (self.latitude, self.longitude, self.altitude)
+    }
+
+    /// The origin of the frame in Geocentric (ECEF) coordinates.
+    #[must_use]
+    pub fn origin(&self) -> &Location {
+        &self.origin
+    }
+
+    /// Rotation matrix transforming ECEF vectors into ENU vectors.
+    #[must_use]
+    pub fn ecef_to_enu_matrix(&self) -> RotationMatrix {
+        self.ecef_to_enu
+    }
+
+    /// Rotation matrix transforming ENU vectors into ECEF vectors.
+    #[must_use]
+    pub fn enu_to_ecef_matrix(&self) -> RotationMatrix {
+        transpose(&self.ecef_to_enu)
+    }
+
+    /// Rotation matrix transforming ECEF vectors into NED vectors.
+    #[must_use]
+    pub fn ecef_to_ned_matrix(&self) -> RotationMatrix {
+        let [east, north, up] = self.ecef_to_enu;
+        [north, east, [-up[0], -up[1], -up[2]]]
+    }
+
+    /// Rotation matrix transforming NED vectors into ECEF vectors.
+    #[must_use]
+    pub fn ned_to_ecef_matrix(&self) -> RotationMatrix {
+        transpose(&self.ecef_to_ned_matrix())
+    }
+
+    /// Converts an ECEF location into local `(east, north, up)` coordinates, in meters.
+    #[must_use]
+    pub fn ecef_to_enu(&self, location: &Location) -> (f64, f64, f64) {
+        let [east, north, up] = rotate(&self.ecef_to_enu, self.offset(location));
+        (east, north, up)
+    }
+
+    /// Converts local `(east, north, up)` coordinates, in meters, into an ECEF location.
+    #[must_use]
+    pub fn enu_to_ecef(&self, east: f64, north: f64, up: f64) -> Location {
+        self.translate(rotate(&self.enu_to_ecef_matrix(), [east, north, up]))
+    }
+
+    /// Converts an ECEF location into local `(north, east, down)` coordinates, in meters.
+    #[must_use]
+    pub fn ecef_to_ned(&self, location: &Location) -> (f64, f64, f64) {
+        let [north, east, down] = rotate(&self.ecef_to_ned_matrix(), self.offset(location));
+        (north, east, down)
+    }
+
+    /// Converts local `(north, east, down)` coordinates, in meters, into an ECEF location.
+    #[must_use]
+    pub fn ned_to_ecef(&self, north: f64, east: f64, down: f64) -> Location {
+        self.translate(rotate(&self.ned_to_ecef_matrix(), [north, east, down]))
+    }
+
+    /// Rotates an ECEF vector (e.g., a linear velocity or acceleration) into the ENU frame.
+    #[must_use]
+    pub fn ecef_vector_to_enu(&self, vector: &VectorF32) -> VectorF32 {
+        rotate_vector(&self.ecef_to_enu, vector)
+    }
+
+    /// Rotates an ENU vector (e.g., a linear velocity or acceleration) into the ECEF frame.
+    #[must_use]
+    pub fn enu_vector_to_ecef(&self, vector: &VectorF32) -> VectorF32 {
+        rotate_vector(&self.enu_to_ecef_matrix(), vector)
+    }
+
+    /// Rotates an ECEF vector (e.g., a linear velocity or acceleration) into the NED frame.
+    #[must_use]
+    pub fn ecef_vector_to_ned(&self, vector: &VectorF32) -> VectorF32 {
+        rotate_vector(&self.ecef_to_ned_matrix(), vector)
+    }
+
+    /// Rotates a NED vector (e.g., a linear velocity or acceleration) into the ECEF frame.
+    #[must_use]
+    pub fn ned_vector_to_ecef(&self, vector: &VectorF32) -> VectorF32 {
+        rotate_vector(&self.ned_to_ecef_matrix(), vector)
+    }
+
+    fn offset(&self, location: &Location) -> [f64; 3] {
+        [
+            location.x_coordinate - self.origin.x_coordinate,
+            location.y_coordinate - self.origin.y_coordinate,
+            location.z_coordinate - self.origin.z_coordinate,
+        ]
+    }
+
+    fn translate(&self, offset: [f64; 3]) -> Location {
+        Location::new(
+            self.origin.x_coordinate + offset[0],
+            self.origin.y_coordinate + offset[1],
+            self.origin.z_coordinate + offset[2],
+        )
+    }
+}
+
+/// A quaternion `w + xi + yj + zk`, for representing attitudes without gimbal lock.
+///
+/// A unit quaternion represents a rotation; `to_rotation_matrix` yields the matrix that applies that rotation to a vector.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Quaternion {
+    pub w: f64,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Default for Quaternion {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Quaternion {
+    #[must_use]
+    pub fn new(w: f64, x: f64, y: f64, z: f64) -> Self {
+        Self { w, x, y, z }
+    }
+
+    /// The quaternion representing no rotation.
+    #[must_use]
+    pub fn identity() -> Self {
+        Self::new(1.0, 0.0, 0.0, 0.0)
+    }
+
+    #[must_use]
+    pub fn norm(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    #[must_use]
+    pub fn normalized(&self) -> Self {
+        let norm = self.norm();
+        Self::new(self.w / norm, self.x / norm, self.y / norm, self.z / norm)
+    }
+
+    /// The conjugate, which for a unit quaternion is the inverse rotation.
+    #[must_use]
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    #[must_use]
+    pub fn dot(&self, other: &Self) -> f64 {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Spherical linear interpolation between `self` (`t = 0`) and `other` (`t = 1`), along the shortest path.
+    #[must_use]
+    pub fn slerp(&self, other: &Self, t: f64) -> Self {
+        let a = self.normalized();
+        let mut b = other.normalized();
+        let mut cos_angle = a.dot(&b);
+        if cos_angle < 0.0 {
+            b = Self::new(-b.w, -b.x, -b.y, -b.z);
+            cos_angle = -cos_angle;
+        }
+
+        let (factor_a, factor_b) = if cos_angle > 1.0 - 1e-9 {
+            (1.0 - t, t)
+        } else {
+            let angle = cos_angle.acos();
+            let sin_angle = angle.sin();
+            (
+                ((1.0 - t) * angle).sin() / sin_angle,
+                (t * angle).sin() / sin_angle,
+            )
+        };
+
+        Self::new(
+            factor_a * a.w + factor_b * b.w,
+            factor_a * a.x + factor_b * b.x,
+            factor_a * a.y + factor_b * b.y,
+            factor_a * a.z + factor_b * b.z,
+        )
+        .normalized()
+    }
+
+    /// The rotation matrix applying the rotation of this (unit) quaternion to a vector.
+    #[must_use]
+    pub fn to_rotation_matrix(&self) -> RotationMatrix {
+        let Self { w, x, y, z } = self.normalized();
+        [
+            [
+                1.0 - 2.0 * (y * y + z * z),
+                2.0 * (x * y - w * z),
+                2.0 * (x * z + w * y),
+            ],
+            [
+                2.0 * (x * y + w * z),
+                1.0 - 2.0 * (x * x + z * z),
+                2.0 * (y * z - w * x),
+            ],
+            [
+                2.0 * (x * z - w * y),
+                2.0 * (y * z + w * x),
+                1.0 - 2.0 * (x * x + y * y),
+            ],
+        ]
+    }
+
+    /// Constructs the unit quaternion from a rotation matrix, the inverse of `to_rotation_matrix`.
+    #[must_use]
+    pub fn from_rotation_matrix(matrix: &RotationMatrix) -> Self {
+        let m = matrix;
+        let trace = m[0][0] + m[1][1] + m[2][2];
+        let quaternion = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Self::new(
+                0.25 * s,
+                (m[2][1] - m[1][2]) / s,
+                (m[0][2] - m[2][0]) / s,
+                (m[1][0] - m[0][1]) / s,
+            )
+        } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+            let s = (1.0 + m[0][0] - m[1][1] - m[2][2]).sqrt() * 2.0;
+            Self::new(
+                (m[2][1] - m[1][2]) / s,
+                0.25 * s,
+                (m[0][1] + m[1][0]) / s,
+                (m[0][2] + m[2][0]) / s,
+            )
+        } else if m[1][1] > m[2][2] {
+            let s = (1.0 + m[1][1] - m[0][0] - m[2][2]).sqrt() * 2.0;
+            Self::new(
+                (m[0][2] - m[2][0]) / s,
+                (m[0][1] + m[1][0]) / s,
+                0.25 * s,
+                (m[1][2] + m[2][1]) / s,
+            )
+        } else {
+            let s = (1.0 + m[2][2] - m[0][0] - m[1][1]).sqrt() * 2.0;
+            Self::new(
+                (m[1][0] - m[0][1]) / s,
+                (m[0][2] + m[2][0]) / s,
+                (m[1][2] + m[2][1]) / s,
+                0.25 * s,
+            )
+        };
+        quaternion.normalized()
+    }
+}
+
+/// The Hamilton product; `a * b` represents the rotation `b` followed by rotation `a`.
+impl Mul for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Quaternion::new(
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+        )
+    }
+}
+
+pub(crate) fn transpose(matrix: &RotationMatrix) -> RotationMatrix {
+    let mut transposed = [[0.0; 3]; 3];
+    for (i, row) in matrix.iter().enumerate() {
+        for (j, value) in row.iter().enumerate() {
+            transposed[j][i] = *value;
+        }
+    }
+    transposed
+}
+
+pub(crate) fn multiply_matrices(a: &RotationMatrix, b: &RotationMatrix) -> RotationMatrix {
+    let mut result = [[0.0; 3]; 3];
+    for (row, result_row) in result.iter_mut().enumerate() {
+        for (column, value) in result_row.iter_mut().enumerate() {
+            *value = (0..3).map(|k| a[row][k] * b[k][column]).sum();
+        }
+    }
+    result
+}
+
+pub(crate) fn rotate(matrix: &RotationMatrix, vector: [f64; 3]) -> [f64; 3] {
+    matrix.map(|row| row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2])
+}
+
+/// Constructs the rotation matrix transforming vectors from a reference frame into a frame rotated
+/// by the Euler angles `psi` (about z), `theta` (about y') and `phi` (about x''), in radians.
+#[allow(clippy::similar_names)]
+pub(crate) fn euler_angles_to_matrix(psi: f64, theta: f64, phi: f64) -> RotationMatrix {
+    let (sin_psi, cos_psi) = psi.sin_cos();
+    let (sin_theta, cos_theta) = theta.sin_cos();
+    let (sin_phi, cos_phi) = phi.sin_cos();
+
+    [
+        [cos_theta * cos_psi, cos_theta * sin_psi, -sin_theta],
+        [
+            sin_phi * sin_theta * cos_psi - cos_phi * sin_psi,
+            sin_phi * sin_theta * sin_psi + cos_phi * cos_psi,
+            sin_phi * cos_theta,
+        ],
+        [
+            cos_phi * sin_theta * cos_psi + sin_phi * sin_psi,
+            cos_phi * sin_theta * sin_psi - sin_phi * cos_psi,
+            cos_phi * cos_theta,
+        ],
+    ]
+}
+
+/// Extracts the Euler angles `(psi, theta, phi)`, in radians, from a rotation matrix constructed by `euler_angles_to_matrix`.
+pub(crate) fn matrix_to_euler_angles(matrix: &RotationMatrix) -> (f64, f64, f64) {
+    let theta = (-matrix[0][2]).clamp(-1.0, 1.0).asin();
+    let psi = matrix[0][1].atan2(matrix[0][0]);
+    let phi = matrix[1][2].atan2(matrix[2][2]);
+
+    (psi, theta, phi)
+}
+
+fn rotate_vector(matrix: &RotationMatrix, vector: &VectorF32) -> VectorF32 {
+    let [first, second, third] = rotate(
+        matrix,
+        [
+            f64::from(vector.first_vector_component),
+            f64::from(vector.second_vector_component),
+            f64::from(vector.third_vector_component),
+        ],
+    );
+    VectorF32::new(first as f32, second as f32, third as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::model::Orientation;
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    const EPSILON: f64 = 1e-6;
+
+    #[test]
+    fn local_tangent_plane_origin() {
+        let frame = LocalTangentPlane::from_geodetic(52f64.to_radians(), 5f64.to_radians(), 10.0);
+        let (east, north, up) = frame.ecef_to_enu(frame.origin());
+
+        assert!(east.abs() < EPSILON);
+        assert!(north.abs() < EPSILON);
+        assert!(up.abs() < EPSILON);
+    }
+
+    #[test]
+    fn local_tangent_plane_axes_at_equator() {
+        let frame = LocalTangentPlane::from_geodetic(0.0, 0.0, 0.0);
+        let origin = frame.origin();
+
+        // At lat/lon (0, 0), ECEF x points up, y points east and z points north.
+        let above = Location::new(
+            origin.x_coordinate + 100.0,
+            origin.y_coordinate,
+            origin.z_coordinate,
+        );
+        let (east, north, up) = frame.ecef_to_enu(&above);
+        assert!(east.abs() < EPSILON);
+        assert!(north.abs() < EPSILON);
+        assert!((up - 100.0).abs() < EPSILON);
+
+        let (north, east, down) = frame.ecef_to_ned(&above);
+        assert!(north.abs() < EPSILON);
+        assert!(east.abs() < EPSILON);
+        assert!((down + 100.0).abs() < EPSILON);
+
+        let velocity = frame.ecef_vector_to_ned(&VectorF32::new(0.0, 10.0, 20.0));
+        assert_eq!(velocity, VectorF32::new(20.0, 10.0, 0.0));
+    }
+
+    #[test]
+    fn local_tangent_plane_round_trip() {
+        let frame =
+            LocalTangentPlane::from_geodetic(-33f64.to_radians(), 151f64.to_radians(), 50.0);
+
+        let location = frame.enu_to_ecef(1200.0, -300.0, 45.0);
+        let (east, north, up) = frame.ecef_to_enu(&location);
+        assert!((east - 1200.0).abs() < EPSILON);
+        assert!((north + 300.0).abs() < EPSILON);
+        assert!((up - 45.0).abs() < EPSILON);
+
+        let location = frame.ned_to_ecef(-300.0, 1200.0, -45.0);
+        let (east, north, up) = frame.ecef_to_enu(&location);
+        assert!((east - 1200.0).abs() < EPSILON);
+        assert!((north + 300.0).abs() < EPSILON);
+        assert!((up - 45.0).abs() < EPSILON);
+
+        let velocity = VectorF32::new(12.5, -3.0, 7.25);
+        let round_trip = frame.ecef_vector_to_enu(&frame.enu_vector_to_ecef(&velocity));
+        assert!((round_trip.first_vector_component - 12.5).abs() < 1e-4);
+        assert!((round_trip.second_vector_component + 3.0).abs() < 1e-4);
+        assert!((round_trip.third_vector_component - 7.25).abs() < 1e-4);
+    }
+
+    fn assert_orientation(actual: &Orientation, psi: f32, theta: f32, phi: f32) {
+        assert!((actual.psi - psi).abs() < 1e-4, "psi: {actual:?}");
+        assert!((actual.theta - theta).abs() < 1e-4, "theta: {actual:?}");
+        assert!((actual.phi - phi).abs() < 1e-4, "phi: {actual:?}");
+    }
+
+    #[test]
+    fn orientation_quaternion_round_trip() {
+        let orientation = Orientation::new(0.3, -0.7, 1.2);
+        let quaternion = orientation.to_quaternion();
+
+        assert!((quaternion.norm() - 1.0).abs() < EPSILON);
+        assert_orientation(&Orientation::from_quaternion(&quaternion), 0.3, -0.7, 1.2);
+
+        let matrix = quaternion.to_rotation_matrix();
+        let expected = orientation.body_to_world_matrix();
+        for (row, expected_row) in matrix.iter().zip(expected.iter()) {
+            for (value, expected_value) in row.iter().zip(expected_row.iter()) {
+                assert!((value - expected_value).abs() < EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn quaternion_composition_and_slerp() {
+        let quarter_turn = Orientation::new(FRAC_PI_2, 0.0, 0.0).to_quaternion();
+        let eighth_turn = Orientation::new(FRAC_PI_4, 0.0, 0.0).to_quaternion();
+        let combined = quarter_turn * eighth_turn;
+        assert_orientation(
+            &Orientation::from_quaternion(&combined),
+            FRAC_PI_2 + FRAC_PI_4,
+            0.0,
+            0.0,
+        );
+
+        let halfway = Quaternion::identity().slerp(&quarter_turn, 0.5);
+        assert_orientation(&Orientation::from_quaternion(&halfway), FRAC_PI_4, 0.0, 0.0);
+        assert_eq!(
+            Quaternion::identity().slerp(&quarter_turn, 1.0),
+            quarter_turn.normalized()
+        );
+    }
+
+    #[test]
+    fn heading_pitch_roll_at_equator() {
+        let location = Location::new(6_378_137.0, 0.0, 0.0);
+
+        // Heading east, level: body x-axis along ECEF y, body z-axis (down) along ECEF -x.
+        let orientation =
+            Orientation::from_heading_pitch_roll(FRAC_PI_2.into(), 0.0, 0.0, &location);
+        assert_orientation(&orientation, FRAC_PI_2, 0.0, -FRAC_PI_2);
+
+        let (heading, pitch, roll) = orientation.to_heading_pitch_roll(&location);
+        assert!((heading - std::f64::consts::FRAC_PI_2).abs() < 1e-6);
+        assert!(pitch.abs() < 1e-6);
+        assert!(roll.abs() < 1e-6);
+    }
+
+    #[test]
+    fn heading_pitch_roll_round_trip() {
+        let (latitude, longitude, altitude) = (52f64.to_radians(), 5f64.to_radians(), 100.0);
+        let (x, y, z) = geodetic_lla_to_ecef(latitude, longitude, altitude);
+        let location = Location::new(x, y, z);
+
+        let orientation = Orientation::from_heading_pitch_roll(2.5, 0.2, -0.4, &location);
+        let (heading, pitch, roll) = orientation.to_heading_pitch_roll(&location);
+
+        assert!((heading - 2.5).abs() < 1e-5);
+        assert!((pitch - 0.2).abs() < 1e-5);
+        assert!((roll + 0.4).abs() < 1e-5);
+    }
+}
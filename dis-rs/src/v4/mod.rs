@@ -0,0 +1,11 @@
+//! DIS version 4, DIS Application Protocols Version 2.0 - Fourth Draft (Revised), March 16, 1994.
+//!
+//! The fourth draft was adopted as IEEE 1278.1-1995 (version 5), and defines the same PDUs (PDU types 1 to 27),
+//! with the same encoding of the header and the Entity State PDU. Like version 5 PDUs, version 4 PDUs are
+//! parsed and serialized by the version 6 code.
+use crate::enumerations::PduType;
+
+/// Returns true when the PDU type is defined by the fourth draft of DIS 2.0.
+pub(crate) fn defines_pdu_type(pdu_type: PduType) -> bool {
+    crate::v5::defines_pdu_type(pdu_type)
+}
@@ -0,0 +1,14 @@
+//! DIS version 5, IEEE 1278.1-1995.
+//!
+//! Version 5 defines the PDUs of the Entity Information/Interaction, Warfare, Logistics, Simulation Management,
+//! Distributed Emission Regeneration and Radio Communications protocol families (PDU types 1 to 27).
+//! The header and the bodies of these PDUs are encoded the same as in version 6 (IEEE 1278.1A-1998):
+//! the header ends with 16 bits of padding instead of the PDU Status record, and the Entity State PDU
+//! carries the version 6 Entity Capabilities record instead of the capabilities bitfields of version 7.
+//! Version 5 PDUs are therefore parsed and serialized by the version 6 code, without version specific modules.
+use crate::enumerations::PduType;
+
+/// Returns true when the PDU type is defined by IEEE 1278.1-1995.
+pub(crate) fn defines_pdu_type(pdu_type: PduType) -> bool {
+    (1..=27).contains(&u8::from(pdu_type))
+}
@@ -1,30 +1,34 @@
+use crate::common::errors::ParserError;
+use crate::common::parser::IResult;
 use crate::v6::entity_state::model::EntityCapabilities;
+use nom::bits;
 use nom::bytes::complete::take as take_bytes;
 use nom::complete::take as take_bits;
-use nom::error::Error;
+use nom::error::context;
 use nom::sequence::tuple;
-use nom::{bits, IResult};
 
 pub(crate) fn entity_capabilities(input: &[u8]) -> IResult<&[u8], EntityCapabilities> {
-    let (input, (ammunition_supply, fuel_supply, recovery, repair, _pad_out)): (
-        &[u8],
-        (u8, u8, u8, u8, u8),
-    ) = bits::<_, _, Error<(&[u8], usize)>, _, _>(tuple((
-        take_bits(1usize),
-        take_bits(1usize),
-        take_bits(1usize),
-        take_bits(1usize),
-        take_bits(3usize),
-    )))(input)?;
-    let (input, _pad_3_bytes) = take_bytes(3usize)(input)?;
+    context("Entity Capabilities", |input| {
+        let (input, (ammunition_supply, fuel_supply, recovery, repair, _pad_out)): (
+            &[u8],
+            (u8, u8, u8, u8, u8),
+        ) = bits::<_, _, ParserError<(&[u8], usize)>, _, _>(tuple((
+            take_bits(1usize),
+            take_bits(1usize),
+            take_bits(1usize),
+            take_bits(1usize),
+            take_bits(3usize),
+        )))(input)?;
+        let (input, _pad_3_bytes) = take_bytes(3usize)(input)?;
 
-    Ok((
-        input,
-        EntityCapabilities {
-            ammunition_supply: ammunition_supply != 0,
-            fuel_supply: fuel_supply != 0,
-            recovery: recovery != 0,
-            repair: repair != 0,
-        },
-    ))
+        Ok((
+            input,
+            EntityCapabilities {
+                ammunition_supply: ammunition_supply != 0,
+                fuel_supply: fuel_supply != 0,
+                recovery: recovery != 0,
+                repair: repair != 0,
+            },
+        ))
+    })(input)
 }
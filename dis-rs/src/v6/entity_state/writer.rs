@@ -1,9 +1,9 @@
 use crate::common::Serialize;
 use crate::v6::entity_state::model::EntityCapabilities;
-use bytes::{BufMut, BytesMut};
+use bytes::BufMut;
 
 impl Serialize for EntityCapabilities {
-    fn serialize(&self, buf: &mut BytesMut) -> u16 {
+    fn serialize(&self, buf: &mut impl BufMut) -> u16 {
         let ammunition_supply = u32::from(self.ammunition_supply) << 31;
         let fuel_supply = u32::from(self.fuel_supply) << 30;
         let recovery = u32::from(self.recovery) << 29;
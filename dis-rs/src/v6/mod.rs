@@ -1 +1,8 @@
 pub mod entity_state;
+
+use crate::enumerations::PduType;
+
+/// Returns true when the PDU type is defined by IEEE 1278.1A-1998.
+pub(crate) fn defines_pdu_type(pdu_type: PduType) -> bool {
+    (1..=67).contains(&u8::from(pdu_type))
+}
@@ -1,3 +1,4 @@
+use crate::common::parser::IResult;
 use crate::constants::{
     BITS_2_3_IN_BYTE, BITS_5_6_IN_BYTE, BIT_2_IN_BYTE, BIT_3_IN_BYTE, BIT_4_IN_BYTE, BIT_7_IN_BYTE,
 };
@@ -13,7 +14,6 @@
 };
 use crate::v7::model::PduStatus;
 use nom::number::complete::be_u8;
-use nom::IResult;
 
 pub fn parse_pdu_status(pdu_type: PduType) -> impl Fn(&[u8]) -> IResult<&[u8], (PduStatus, u16)> {
     move |input: &[u8]| {
@@ -1,4 +1,5 @@
-use crate::common::model::EntityId;
+use crate::common::model::{EntityId, EntityType};
+use crate::enumerations::{EntityKind, PlatformDomain};
 use crate::{NO_APPLIC, NO_ENTITY, NO_SITE};
 
 #[allow(non_snake_case)]
@@ -112,6 +113,46 @@ impl VariableParameters {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Returns the Entity State PDU heartbeat interval (seconds) for an entity of the given type.
+    ///
+    /// Platforms use the interval of their domain, other entities the interval of their kind.
+    /// Entities of kind `Other`, or platforms with an unknown domain, use the shortest platform interval.
+    #[must_use]
+    pub fn espdu_heartbeat_interval(&self, entity_type: &EntityType) -> f32 {
+        match (entity_type.kind, entity_type.domain) {
+            (EntityKind::Platform, PlatformDomain::Land) => self.HBT_ESPDU_PLATFORM_LAND,
+            (EntityKind::Platform, PlatformDomain::Air) => self.HBT_ESPDU_PLATFORM_AIR,
+            (EntityKind::Platform, PlatformDomain::Surface) => self.HBT_ESPDU_PLATFORM_SURFACE,
+            (EntityKind::Platform, PlatformDomain::Subsurface) => {
+                self.HBT_ESPDU_PLATFORM_SUBSURFACE
+            }
+            (EntityKind::Platform, PlatformDomain::Space) => self.HBT_ESPDU_PLATFORM_SPACE,
+            (EntityKind::Munition, _) => self.HBT_ESPDU_KIND_MUNITION,
+            (EntityKind::LifeForm, _) => self.HBT_ESPDU_KIND_LIFE_FORM,
+            (EntityKind::Environmental, _) => self.HBT_ESPDU_KIND_ENVIRONMENTAL,
+            (EntityKind::CulturalFeature, _) => self.HBT_ESPDU_KIND_CULTURAL_FEATURE,
+            (EntityKind::Supply, _) => self.HBT_ESPDU_KIND_SUPPLY,
+            (EntityKind::Radio, _) => self.HBT_ESPDU_KIND_RADIO,
+            (EntityKind::Expendable, _) => self.HBT_ESPDU_KIND_EXPENDABLE,
+            (EntityKind::SensorEmitter, _) => {
+                self.HBT_ESPDU_KIND_SENSOR.min(self.HBT_ESPDU_KIND_EMITTER)
+            }
+            (_, _) => self
+                .HBT_ESPDU_PLATFORM_LAND
+                .min(self.HBT_ESPDU_PLATFORM_AIR)
+                .min(self.HBT_ESPDU_PLATFORM_SURFACE)
+                .min(self.HBT_ESPDU_PLATFORM_SUBSURFACE)
+                .min(self.HBT_ESPDU_PLATFORM_SPACE),
+        }
+    }
+
+    /// Returns the time (seconds) after which a remote entity of the given type is considered timed out,
+    /// being the Entity State PDU heartbeat interval times `HBT_TIMEOUT_MPLIER`.
+    #[must_use]
+    pub fn espdu_timeout(&self, entity_type: &EntityType) -> f32 {
+        self.espdu_heartbeat_interval(entity_type) * self.HBT_TIMEOUT_MPLIER
+    }
 }
 
 impl Default for VariableParameters {
@@ -1,11 +1,30 @@
 use bytes::BytesMut;
 use dis_rs::{
-    enumerations::{PduType, SignalEncodingClass, SignalEncodingType},
-    model::{Pdu, PduBody, PduHeader},
+    entity_state::model::{EntityMarking, EntityState},
+    enumerations::{
+        CoupledExtensionIndicator, EnvironmentalProcessModelType, GriddedDataConstantGrid,
+        GriddedDataCoordinateSystem, GriddedDataSampleType, LvcIndicator, PduType,
+        SignalEncodingClass, SignalEncodingType, TransferredEntityIndicator,
+    },
+    environmental_process::model::{
+        BoundingSphere, CombicState, EnvironmentRecord, EnvironmentRecordData,
+        EnvironmentalProcess, EnvironmentalProcessStatus, GaussianPuff, UniformGeometry,
+        UnspecifiedEnvironmentRecord,
+    },
+    gridded_data::model::{
+        GridAxisDescriptor, GridAxisIrregular, GridAxisRegular, GridData, GridDataRepresentation,
+        GriddedData,
+    },
+    model::{
+        ClockTime, DisTimeStamp, EntityId, EntityType, Location, Orientation, Pdu, PduBody,
+        PduHeader, PduStatus, VectorF32,
+    },
+    other::model::Other,
     signal::model::{EncodingScheme, Signal},
     DisError,
 };
 use nom::AsBytes;
+use std::str::FromStr;
 
 #[test]
 fn test_pdu() {
@@ -102,3 +121,292 @@ fn test_insufficient_buffer_capacity() {
         ))
     );
 }
+
+#[test]
+fn test_serialize_into_slice() {
+    let pdu = Pdu::finalize_from_parts(
+        PduHeader::new_v7(1, PduType::Signal),
+        Signal::builder()
+            .with_encoding_scheme(EncodingScheme::EncodedAudio {
+                encoding_class: SignalEncodingClass::EncodedAudio,
+                encoding_type: SignalEncodingType::_16bitLinearPCM2sComplement_LittleEndian_100,
+            })
+            .with_data(vec![1, 2, 3, 4])
+            .build()
+            .into_pdu_body(),
+        0,
+    );
+    let mut expected = BytesMut::with_capacity(pdu.pdu_length() as usize);
+    let _ = pdu.serialize(&mut expected);
+
+    let mut buf = [0u8; 64];
+    let serialized_length = pdu.serialize_into(&mut buf).unwrap();
+
+    assert_eq!(serialized_length, pdu.pdu_length() as usize);
+    assert_eq!(&buf[..serialized_length], expected.as_bytes());
+
+    let mut small_buf = [0u8; 20];
+    assert_eq!(
+        pdu.serialize_into(&mut small_buf),
+        Err(DisError::InsufficientBufferSize(pdu.pdu_length(), 20))
+    );
+}
+
+#[test]
+fn test_serialize_into_slice_body_larger_than_pdu_length() {
+    // The length of the body does not fit the PDU Length field, so it cannot be checked up-front
+    let pdu = Pdu::finalize_from_parts(
+        PduHeader::new_v7(1, PduType::Other),
+        Other::builder()
+            .with_body(vec![0u8; u16::MAX as usize + 1])
+            .build()
+            .into_pdu_body(),
+        0,
+    );
+
+    let mut buf = [0u8; 64];
+    assert_eq!(
+        pdu.serialize_into(&mut buf),
+        Err(DisError::InsufficientBufferSize(u16::MAX, 64))
+    );
+}
+
+#[test]
+fn test_serialize_into_marking_too_long() {
+    let pdu = Pdu::finalize_from_parts(
+        PduHeader::new_v7(1, PduType::EntityState),
+        EntityState::builder()
+            .with_marking(EntityMarking::new_ascii("MARKING TOO LONG"))
+            .build()
+            .into_pdu_body(),
+        0,
+    );
+
+    let mut buf = [0u8; 256];
+    assert_eq!(
+        pdu.serialize_into(&mut buf),
+        Err(DisError::StringTooLongError)
+    );
+}
+
+#[test]
+fn test_serialize_environment_record_data_too_long() {
+    // The length of the record data is given in bits by a 16-bit field, fitting at most 8191 octets
+    let pdu = Pdu::finalize_from_parts(
+        PduHeader::new_v7(1, PduType::EnvironmentalProcess),
+        EnvironmentalProcess::builder()
+            .with_environment_record(EnvironmentRecord::new(
+                1,
+                EnvironmentRecordData::Unspecified(UnspecifiedEnvironmentRecord {
+                    record_type: 0,
+                    data: vec![0u8; 8192],
+                }),
+            ))
+            .build()
+            .into_pdu_body(),
+        0,
+    );
+
+    let mut buf = BytesMut::with_capacity(16384);
+    assert_eq!(
+        pdu.serialize(&mut buf),
+        Err(DisError::DataTooLongError(8192, 8191))
+    );
+    assert!(buf.is_empty());
+
+    let mut slice = [0u8; 16384];
+    assert_eq!(
+        pdu.serialize_into(&mut slice),
+        Err(DisError::DataTooLongError(8192, 8191))
+    );
+}
+
+fn v7_header(pdu_type: PduType) -> PduHeader {
+    PduHeader::new_v7(1, pdu_type).with_pdu_status(
+        PduStatus::default()
+            .with_lvc_indicator(LvcIndicator::NoStatement)
+            .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+    )
+}
+
+fn assert_round_trip(original_pdu: &Pdu) {
+    let mut buf = BytesMut::with_capacity(original_pdu.pdu_length() as usize);
+    let serialized_length = original_pdu.serialize(&mut buf).unwrap();
+
+    assert_eq!(original_pdu.pdu_length(), serialized_length);
+    assert_eq!(buf.len(), serialized_length as usize);
+
+    let pdus = dis_rs::parse(buf.as_bytes()).unwrap();
+
+    assert_eq!(pdus.len(), 1);
+    assert_eq!(pdus.first().unwrap(), original_pdu);
+}
+
+#[test]
+fn test_environmental_process_round_trip() {
+    let body = EnvironmentalProcess::builder()
+        .with_environmental_process_id(EntityId::new(10, 10, 1))
+        .with_environment_type(EntityType::from_str("4:2:0:1:0:0:0").unwrap())
+        .with_model_type(EnvironmentalProcessModelType::from(1u8))
+        .with_environment_status(
+            EnvironmentalProcessStatus::default()
+                .with_last(true)
+                .with_active(true),
+        )
+        .with_sequence_number(7)
+        .with_environment_record(EnvironmentRecord::new(
+            0,
+            EnvironmentRecordData::CombicState(CombicState {
+                time_since_creation: 1000,
+                munition_source: EntityType::from_str("2:9:1:1:1:0:0").unwrap(),
+                number_of_sources: 2,
+                geometry_index: 1,
+                source_type: 3,
+                barrage_rate: 1.5,
+                barrage_duration: 60.0,
+                barrage_crosswind_length: 100.0,
+                barrage_downwind_length: 200.0,
+                detonation_velocity: VectorF32::new(1.0, 2.0, 3.0),
+            }),
+        ))
+        .with_environment_record(EnvironmentRecord::new(
+            1,
+            EnvironmentRecordData::BoundingSphere(BoundingSphere {
+                centroid: Location::new(100.0, 200.0, 300.0),
+                radius: 50.0,
+            }),
+        ))
+        .with_environment_record(EnvironmentRecord::new(
+            2,
+            EnvironmentRecordData::UniformGeometry(UniformGeometry { geometry_index: 4 }),
+        ))
+        .with_environment_record(EnvironmentRecord::new(
+            3,
+            EnvironmentRecordData::GaussianPuff(GaussianPuff {
+                puff_location: Location::new(1.0, 2.0, 3.0),
+                origination_location: Location::new(4.0, 5.0, 6.0),
+                sigma: VectorF32::new(1.0, 1.0, 1.0),
+                centroid_height: 10.0,
+                ..Default::default()
+            }),
+        ))
+        .with_environment_record(EnvironmentRecord::new(
+            4,
+            EnvironmentRecordData::Unspecified(UnspecifiedEnvironmentRecord {
+                record_type: 12345,
+                data: vec![1, 2, 3],
+            }),
+        ))
+        .build()
+        .into_pdu_body();
+    let header = PduHeader::new_v7(1, PduType::EnvironmentalProcess).with_pdu_status(
+        PduStatus::default()
+            .with_transferred_entity_indicator(TransferredEntityIndicator::NoDifference)
+            .with_lvc_indicator(LvcIndicator::NoStatement)
+            .with_coupled_extension_indicator(CoupledExtensionIndicator::NotCoupled),
+    );
+    let pdu = Pdu::finalize_from_parts(header, body, DisTimeStamp::new_absolute_from_secs(100));
+
+    assert_round_trip(&pdu);
+}
+
+#[test]
+fn test_gridded_data_round_trip() {
+    let body = GriddedData::builder()
+        .with_environmental_simulation_id(EntityId::new(10, 10, 0))
+        .with_field_number(1)
+        .with_pdu_number(1)
+        .with_pdu_total(1)
+        .with_coordinate_system(GriddedDataCoordinateSystem::from(2u16))
+        .with_constant_grid(GriddedDataConstantGrid::from(1u8))
+        .with_environment_type(EntityType::from_str("4:2:0:1:0:0:0").unwrap())
+        .with_orientation(Orientation::new(0.0, 0.0, 0.0))
+        .with_sample_time(ClockTime::new(1, 500))
+        .with_total_values(9)
+        .with_grid_axis_descriptor(GridAxisDescriptor::Regular(GridAxisRegular {
+            domain_initial: 0.0,
+            domain_final: 100.0,
+            domain_points: 3,
+            interleaf_factor: 1,
+            number_of_points_on_axis: 3,
+            initial_index: 0,
+        }))
+        .with_grid_axis_descriptor(GridAxisDescriptor::Irregular(GridAxisIrregular {
+            domain_initial: 0.0,
+            domain_final: 50.0,
+            domain_points: 3,
+            interleaf_factor: 1,
+            initial_index: 0,
+            coordinate_scale: 0.5,
+            coordinate_offset: 0.0,
+            x_values: vec![0, 10, 100],
+        }))
+        .with_grid_data(GridData::new(
+            GriddedDataSampleType::from(1u16),
+            GridDataRepresentation::Type0 {
+                values: vec![1, 2, 3, 4, 5],
+            },
+        ))
+        .with_grid_data(GridData::new(
+            GriddedDataSampleType::from(2u16),
+            GridDataRepresentation::Type1 {
+                field_scale: 0.1,
+                field_offset: -5.0,
+                values: vec![100, 200, 300],
+            },
+        ))
+        .with_grid_data(GridData::new(
+            GriddedDataSampleType::from(3u16),
+            GridDataRepresentation::Type2 {
+                values: vec![1.5, 2.5, 3.5],
+            },
+        ))
+        .build()
+        .into_pdu_body();
+    let pdu = Pdu::finalize_from_parts(
+        v7_header(PduType::GriddedData),
+        body,
+        DisTimeStamp::new_absolute_from_secs(100),
+    );
+
+    assert_round_trip(&pdu);
+}
+
+#[test]
+fn test_legacy_versions_round_trip() {
+    for header in [
+        PduHeader::new_v4(1, PduType::Signal),
+        PduHeader::new_v5(1, PduType::Signal),
+    ] {
+        let body = Signal::builder()
+            .with_encoding_scheme(EncodingScheme::EncodedAudio {
+                encoding_class: SignalEncodingClass::EncodedAudio,
+                encoding_type: SignalEncodingType::_16bitLinearPCM2sComplement_LittleEndian_100,
+            })
+            .with_data(vec![1, 2, 3, 4])
+            .build()
+            .into_pdu_body();
+        let pdu = Pdu::finalize_from_parts(header, body, 0);
+
+        assert_round_trip(&pdu);
+    }
+}
+
+#[test]
+fn test_parse_v5_filters_other_versions() {
+    let mut buf = BytesMut::with_capacity(64);
+    for header in [
+        PduHeader::new_v5(1, PduType::Signal),
+        PduHeader::new_v6(1, PduType::Signal),
+    ] {
+        let body = Signal::builder().build().into_pdu_body();
+        Pdu::finalize_from_parts(header, body, 0)
+            .serialize(&mut buf)
+            .unwrap();
+    }
+
+    let pdus = dis_rs::parse_v5_pdus(buf.as_bytes()).unwrap();
+
+    assert_eq!(pdus.len(), 1);
+    assert_eq!(u8::from(pdus[0].header.protocol_version), 5);
+}
@@ -88,6 +88,8 @@ pub struct DisStatistics {
 pub enum DisNodeError {
     #[error("The Exercise ID must be withing 1-128, but is {0}.")]
     InvalidExerciseId(u8),
+    #[error("DIS version {0} is not supported, supported versions are 4, 5, 6 and 7.")]
+    UnsupportedDisVersion(u8),
 }
 
 impl NodeError for DisNodeError {}
@@ -134,16 +136,22 @@ fn new(
             None
         };
 
-        let allow_dis_versions = node_spec
-            .allow_dis_versions
-            .clone()
-            .map(|versions| {
-                versions
-                    .iter()
-                    .map(|&version| ProtocolVersion::from(version))
-                    .collect()
-            })
-            .unwrap_or(dis_rs::supported_protocol_versions());
+        let supported_versions = dis_rs::supported_protocol_versions();
+        let allow_dis_versions = if let Some(versions) = &node_spec.allow_dis_versions {
+            let mut allowed = Vec::with_capacity(versions.len());
+            for &version in versions {
+                let protocol_version = ProtocolVersion::from(version);
+                if !supported_versions.contains(&protocol_version) {
+                    return Err(SpecificationError::Module(Box::new(
+                        DisNodeError::UnsupportedDisVersion(version),
+                    )));
+                }
+                allowed.push(protocol_version);
+            }
+            allowed
+        } else {
+            supported_versions
+        };
 
         Ok(Self {
             base: BaseNode {
@@ -106,6 +106,27 @@ async fn build_spec_incorrect_node_type_field() {
     }
 }
 
+#[tokio::test]
+async fn build_spec_unsupported_dis_version() {
+    let spec = r#"
+        [[ nodes ]]
+        type = "dis_receiver"
+        name = "DIS"
+        allow_dis_versions = [5, 3]
+    "#;
+
+    let mut infra_builder = InfraBuilder::new();
+    let error = infra_builder.build_from_str(spec).unwrap_err();
+    if let GatewayError::Specification(SpecificationError::Module(error)) = error {
+        assert_eq!(
+            error.to_string(),
+            "DIS version 3 is not supported, supported versions are 4, 5, 6 and 7."
+        );
+    } else {
+        panic!()
+    }
+}
+
 #[tokio::test]
 async fn build_spec_incorrect_channel_from() {
     let spec = r#"